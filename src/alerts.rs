@@ -0,0 +1,817 @@
+use crate::cli::CheckArgs;
+use crate::config::{BatteryConfig, Config, Thresholds};
+use crate::discovery::{MetricsSampler, get_hardware_report};
+use crate::history::{flatten, metric_unit, thresholds_for};
+use crate::i18n::t;
+use crate::model::{BatteryInfo, HardwareReport, InterfaceKind, MetricsSample, NetworkInfo};
+use crate::mounts::filter_mounts;
+use crate::theme::theme;
+use anyhow::Result;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertTarget {
+    /// journald (with HWCHECKER_* fields) or the local syslog socket
+    Syslog,
+    /// The webhooks listed under `alerts.webhooks` in the config
+    Webhook,
+    /// A summary email through the SMTP server in `alerts.email`
+    Email,
+}
+
+/// The `alerts:` config section.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct AlertsConfig {
+    /// Targets enabled in `watch`, `record` and `check` without passing `--alert-target`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<AlertTarget>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub webhooks: Vec<WebhookConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<EmailConfig>,
+    /// Debouncing per metric (`disk:/home`) or metric kind (`cpu`, `temp`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, AlertRule>,
+}
+
+impl AlertsConfig {
+    /// The rule for an exact metric name, else for its kind.
+    pub fn rule_for(&self, metric: &str) -> Option<&AlertRule> {
+        self.rules
+            .get(metric)
+            .or_else(|| self.rules.get(metric.split(':').next()?))
+    }
+}
+
+/// Keeps `watch` and `record` from flapping on the raw per-sample checks.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields, default)]
+pub struct AlertRule {
+    /// Seconds a metric must stay past a threshold before that level is raised
+    pub for_secs: u64,
+    /// How far (in the metric's unit) below a raised level's threshold the
+    /// metric must drop before the level clears
+    pub clear_margin: f32,
+}
+
+/// SMTP settings for the email alert target. Credentials are read from the
+/// environment so they never have to live in the config file.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct EmailConfig {
+    pub server: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+    pub from: String,
+    pub to: Vec<String>,
+    #[serde(default = "EmailConfig::default_username_env")]
+    pub username_env: String,
+    #[serde(default = "EmailConfig::default_password_env")]
+    pub password_env: String,
+}
+
+impl EmailConfig {
+    fn default_username_env() -> String {
+        "HW_CHECKER_SMTP_USERNAME".to_string()
+    }
+
+    fn default_password_env() -> String {
+        "HW_CHECKER_SMTP_PASSWORD".to_string()
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// Plain connection upgraded with STARTTLS (port 587)
+    #[default]
+    Starttls,
+    /// Implicit TLS (port 465)
+    Tls,
+    /// No encryption, e.g. a local relay on port 25
+    None,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookConfig {
+    pub url: String,
+    #[serde(default)]
+    pub format: WebhookFormat,
+}
+
+/// Payload shape posted to a webhook.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookFormat {
+    /// The threshold event as a plain JSON object
+    #[default]
+    Json,
+    Slack,
+    Discord,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertLevel {
+    #[default]
+    Ok,
+    Warning,
+    Critical,
+}
+
+impl AlertLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AlertLevel::Ok => "ok",
+            AlertLevel::Warning => "warning",
+            AlertLevel::Critical => "critical",
+        }
+    }
+
+    pub fn classify(value: f32, thresholds: &Thresholds) -> AlertLevel {
+        if value > thresholds.critical {
+            AlertLevel::Critical
+        } else if value > thresholds.warning {
+            AlertLevel::Warning
+        } else {
+            AlertLevel::Ok
+        }
+    }
+
+    /// Battery charge is flagged when it drops below a level, not above one.
+    pub fn classify_charge(capacity: u8, battery: &BatteryConfig) -> AlertLevel {
+        if capacity < battery.critical {
+            AlertLevel::Critical
+        } else if capacity < battery.warning {
+            AlertLevel::Warning
+        } else {
+            AlertLevel::Ok
+        }
+    }
+
+    pub fn classify_wear(wear_percent: f32, battery: &BatteryConfig) -> AlertLevel {
+        if wear_percent > battery.max_wear {
+            AlertLevel::Warning
+        } else {
+            AlertLevel::Ok
+        }
+    }
+}
+
+/// A metric moving into a different threshold band (including back to normal).
+#[derive(Debug, Clone, Serialize)]
+pub struct ThresholdEvent {
+    pub timestamp: String,
+    pub hostname: String,
+    pub metric: String,
+    pub value: f64,
+    pub unit: &'static str,
+    pub level: AlertLevel,
+    pub previous: AlertLevel,
+    /// The threshold that was crossed; absent when the metric recovers.
+    pub threshold: Option<f32>,
+    /// The message of a [`Problem`] found in a report, which is not always a
+    /// value past a threshold (e.g. a bond with members down).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl ThresholdEvent {
+    pub fn message(&self) -> String {
+        if let Some(detail) = &self.detail {
+            return detail.clone();
+        }
+        match (self.level, self.threshold) {
+            (AlertLevel::Critical, Some(limit)) => format!(
+                "{}: {:.1}{} > {} {}{}",
+                self.metric,
+                self.value,
+                self.unit,
+                t("critical"),
+                limit,
+                self.unit
+            ),
+            (AlertLevel::Warning, Some(limit)) => format!(
+                "{}: {:.1}{} > {} {}{}",
+                self.metric,
+                self.value,
+                self.unit,
+                t("warning"),
+                limit,
+                self.unit
+            ),
+            _ => format!(
+                "{}: {:.1}{} {}",
+                self.metric,
+                self.value,
+                self.unit,
+                t("back to normal")
+            ),
+        }
+    }
+}
+
+/// What the alerter remembers of one metric between samples.
+#[derive(Default)]
+struct MetricState {
+    /// The level last reported
+    level: AlertLevel,
+    /// Since when the metric has been past each threshold without a break
+    warning_since: Option<Instant>,
+    critical_since: Option<Instant>,
+}
+
+impl MetricState {
+    /// The level to report for `value` at `now`: a raised level holds until
+    /// the value is `clear_margin` below its threshold, and a level is only
+    /// raised once the value has been past its threshold for `for_secs`.
+    fn update(
+        &mut self,
+        value: f32,
+        thresholds: &Thresholds,
+        rule: &AlertRule,
+        now: Instant,
+    ) -> AlertLevel {
+        let past = |threshold: f32, level: AlertLevel| {
+            value > threshold || (self.level >= level && value > threshold - rule.clear_margin)
+        };
+        let critical = past(thresholds.critical, AlertLevel::Critical);
+        let warning = critical || past(thresholds.warning, AlertLevel::Warning);
+        track(&mut self.critical_since, critical, now);
+        track(&mut self.warning_since, warning, now);
+
+        let held = |since: Option<Instant>| {
+            since.is_some_and(|since| now - since >= Duration::from_secs(rule.for_secs))
+        };
+        self.level = if held(self.critical_since) {
+            AlertLevel::Critical
+        } else if held(self.warning_since) {
+            AlertLevel::Warning
+        } else {
+            AlertLevel::Ok
+        };
+        self.level
+    }
+}
+
+fn track(since: &mut Option<Instant>, past: bool, now: Instant) {
+    if !past {
+        *since = None;
+    } else if since.is_none() {
+        *since = Some(now);
+    }
+}
+
+/// Somewhere threshold events get delivered to. `events` are the level changes
+/// found in `sample`, and are never empty.
+pub trait AlertSink {
+    fn send(&mut self, events: &[ThresholdEvent], sample: &MetricsSample) -> Result<()>;
+}
+
+/// Classifies each sample against the configured thresholds and forwards
+/// level changes to the alert targets.
+pub struct Alerter<'a> {
+    config: &'a Config,
+    hostname: String,
+    metrics: HashMap<String, MetricState>,
+    /// The level of each report problem last delivered
+    problems: HashMap<String, AlertLevel>,
+    sinks: Vec<Box<dyn AlertSink>>,
+}
+
+impl<'a> Alerter<'a> {
+    pub fn new(targets: &[AlertTarget], config: &'a Config) -> Result<Self> {
+        let mut enabled: Vec<AlertTarget> = Vec::new();
+        for &target in targets.iter().chain(&config.alerts.targets) {
+            if !enabled.contains(&target) {
+                enabled.push(target);
+            }
+        }
+        let mut sinks: Vec<Box<dyn AlertSink>> = Vec::new();
+        for target in enabled {
+            match target {
+                AlertTarget::Syslog => sinks.push(Box::new(crate::syslog::SyslogSink::new()?)),
+                AlertTarget::Webhook => sinks.push(Box::new(crate::webhook::WebhookSink::new(
+                    &config.alerts.webhooks,
+                )?)),
+                AlertTarget::Email => match &config.alerts.email {
+                    Some(email) => sinks.push(Box::new(crate::email::EmailSink::new(email)?)),
+                    None => anyhow::bail!("the email alert target needs an `alerts.email` section"),
+                },
+            }
+        }
+        Ok(Self {
+            config,
+            hostname: config
+                .redact
+                .hostname(&sysinfo::System::host_name().unwrap_or_default()),
+            metrics: HashMap::new(),
+            problems: HashMap::new(),
+            sinks,
+        })
+    }
+
+    /// Returns (and delivers) the metrics whose level changed since the previous sample.
+    pub fn check(&mut self, sample: &MetricsSample) -> Vec<ThresholdEvent> {
+        let now = Instant::now();
+        let mut events = Vec::new();
+        for (metric, value) in flatten(sample) {
+            let Some(thresholds) = thresholds_for(&metric, self.config) else {
+                continue;
+            };
+            let rule = self
+                .config
+                .alerts
+                .rule_for(&metric)
+                .cloned()
+                .unwrap_or_default();
+            let state = self.metrics.entry(metric.clone()).or_default();
+            let previous = state.level;
+            let level = state.update(value as f32, thresholds, &rule, now);
+            if level == previous {
+                continue;
+            }
+            events.push(ThresholdEvent {
+                timestamp: sample.timestamp.clone(),
+                hostname: self.hostname.clone(),
+                unit: metric_unit(&metric),
+                metric,
+                value,
+                level,
+                previous,
+                threshold: match level {
+                    AlertLevel::Critical => Some(thresholds.critical),
+                    AlertLevel::Warning => Some(thresholds.warning),
+                    AlertLevel::Ok => None,
+                },
+                detail: None,
+            });
+        }
+        self.deliver(&events, sample);
+        events
+    }
+
+    /// Returns (and delivers) the problems found in a report (see
+    /// [`report_problems`]) whose level changed since the previous call,
+    /// including those that went away.
+    pub fn check_problems(
+        &mut self,
+        problems: &[Problem],
+        sample: &MetricsSample,
+    ) -> Vec<ThresholdEvent> {
+        let mut current: BTreeMap<String, (AlertLevel, String)> = self
+            .problems
+            .iter()
+            .filter(|(_, level)| **level != AlertLevel::Ok)
+            .map(|(metric, _)| {
+                let message = format!("{}: {}", metric, t("back to normal"));
+                (metric.clone(), (AlertLevel::Ok, message))
+            })
+            .collect();
+        for problem in problems {
+            current.insert(
+                problem.metric.clone(),
+                (problem.level, problem.message.clone()),
+            );
+        }
+        let mut events = Vec::new();
+        for (metric, (level, message)) in current {
+            let previous = self
+                .problems
+                .insert(metric.clone(), level)
+                .unwrap_or_default();
+            if level == previous {
+                continue;
+            }
+            events.push(ThresholdEvent {
+                timestamp: sample.timestamp.clone(),
+                hostname: self.hostname.clone(),
+                metric,
+                value: 0.0,
+                unit: "",
+                level,
+                previous,
+                threshold: None,
+                detail: Some(message),
+            });
+        }
+        self.deliver(&events, sample);
+        events
+    }
+
+    fn deliver(&mut self, events: &[ThresholdEvent], sample: &MetricsSample) {
+        if events.is_empty() {
+            return;
+        }
+        for sink in &mut self.sinks {
+            // A flaky target must not stop the sampling loop.
+            if let Err(err) = sink.send(events, sample) {
+                eprintln!("{}: {:#}", t("Failed to deliver alert"), err);
+            }
+        }
+    }
+}
+
+/// Something in a report past its threshold, as listed under "Issues" and
+/// reported by `check`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Problem {
+    /// Named like the recorded metrics, e.g. "disk:/" or "battery:BAT0:wear"
+    pub metric: String,
+    pub level: AlertLevel,
+    pub message: String,
+}
+
+impl Problem {
+    fn new(metric: String, level: AlertLevel, message: String) -> Self {
+        Self {
+            metric,
+            level,
+            message,
+        }
+    }
+}
+
+/// Bonds that lost some (warning) or all (critical) of their members, and
+/// bridges whose ports are all down.
+pub fn link_problems(network: &[NetworkInfo]) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    for net in network {
+        let Some(members) = &net.members else {
+            continue;
+        };
+        let down: Vec<&str> = members.down().map(|port| port.name.as_str()).collect();
+        if down.is_empty() {
+            continue;
+        }
+        let level = match net.kind {
+            Some(InterfaceKind::Bond) if down.len() == members.ports.len() => AlertLevel::Critical,
+            Some(InterfaceKind::Bond) => AlertLevel::Warning,
+            _ if down.len() == members.ports.len() => AlertLevel::Warning,
+            _ => continue,
+        };
+        problems.push(Problem::new(
+            format!("link:{}", net.name),
+            level,
+            format!(
+                "{}: {}/{} {} ({})",
+                net.name,
+                down.len(),
+                members.ports.len(),
+                t("members down"),
+                down.join(", ")
+            ),
+        ));
+    }
+    problems
+}
+
+/// Batteries below the charge thresholds or worn past `max_wear`.
+pub fn battery_problems(batteries: &[BatteryInfo], battery: &BatteryConfig) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    for bat in batteries {
+        let metric = format!("battery:{}", bat.name);
+        let limit = match AlertLevel::classify_charge(bat.capacity, battery) {
+            AlertLevel::Critical => Some((AlertLevel::Critical, t("critical"), battery.critical)),
+            AlertLevel::Warning => Some((AlertLevel::Warning, t("warning"), battery.warning)),
+            AlertLevel::Ok => None,
+        };
+        if let Some((level, label, limit)) = limit {
+            problems.push(Problem::new(
+                metric.clone(),
+                level,
+                format!("{}: {}% < {} {}%", metric, bat.capacity, label, limit),
+            ));
+        }
+        if let Some(wear) = bat.wear_percent
+            && AlertLevel::classify_wear(wear, battery) != AlertLevel::Ok
+        {
+            problems.push(Problem::new(
+                format!("{}:wear", metric),
+                AlertLevel::Warning,
+                format!(
+                    "{} {}: {:.1}% > {} {}%",
+                    metric,
+                    t("wear"),
+                    wear,
+                    t("max"),
+                    battery.max_wear
+                ),
+            ));
+        }
+    }
+    problems
+}
+
+/// Everything in a collected report past its configured threshold, critical
+/// first: CPU, memory, filesystem and inode usage, temperatures, failed SMART
+/// self-assessments, batteries and degraded bonds/bridges.
+pub fn report_problems(report: &HardwareReport, config: &Config) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let cores: Vec<f32> = report.cores().map(|c| c.usage).collect();
+    if !cores.is_empty() {
+        let usage = cores.iter().sum::<f32>() / cores.len() as f32;
+        problems.extend(breach("cpu", usage, "%", &config.cpu_thresholds));
+    }
+    let ram = &report.ram;
+    if ram.total > 0 {
+        let usage = ram.used as f32 / ram.total as f32 * 100.0;
+        problems.extend(breach("ram", usage, "%", &config.ram_thresholds));
+    }
+    if ram.swap_total > 0 {
+        let usage = ram.swap_used as f32 / ram.swap_total as f32 * 100.0;
+        problems.extend(breach("swap", usage, "%", &config.ram_thresholds));
+    }
+    for fs in report.filesystems.iter().filter(|fs| fs.total > 0) {
+        let usage = fs.used as f32 / fs.total as f32 * 100.0;
+        problems.extend(breach(
+            &format!("disk:{}", fs.mount_point),
+            usage,
+            "%",
+            &config.storage_thresholds,
+        ));
+        if let Some(usage) = fs.inodes.and_then(|inodes| inodes.usage_percent()) {
+            problems.extend(breach(
+                &format!("inodes:{}", fs.mount_point),
+                usage,
+                "%",
+                &config.inode_thresholds,
+            ));
+        }
+    }
+    if let Some(pressure) = &report.pressure {
+        for (name, stall) in pressure.resources() {
+            problems.extend(breach(
+                &format!("pressure:{}", name),
+                stall.some[0],
+                "%",
+                config.pressure.thresholds(name),
+            ));
+        }
+    }
+    for sensor in &report.temperatures {
+        if let Some(celsius) = sensor.temperature {
+            problems.extend(breach(
+                &format!("temp:{}", sensor.label),
+                celsius,
+                "°C",
+                &config.temperature_thresholds,
+            ));
+        }
+    }
+    for disk in &report.disks {
+        if let Some(smart) = &disk.smart
+            && !smart.passed
+        {
+            problems.push(Problem::new(
+                format!("smart:{}", disk.name),
+                AlertLevel::Critical,
+                format!("{}: {}", disk.name, t("SMART self-assessment failed")),
+            ));
+        }
+    }
+    problems.extend(battery_problems(&report.battery, &config.battery));
+    problems.extend(link_problems(&report.network));
+    problems.sort_by_key(|problem| problem.level != AlertLevel::Critical);
+    problems
+}
+
+fn breach(metric: &str, value: f32, unit: &str, thresholds: &Thresholds) -> Option<Problem> {
+    let level = AlertLevel::classify(value, thresholds);
+    let (limit, label) = match level {
+        AlertLevel::Critical => (thresholds.critical, t("critical")),
+        AlertLevel::Warning => (thresholds.warning, t("warning")),
+        AlertLevel::Ok => return None,
+    };
+    Some(Problem::new(
+        metric.to_string(),
+        level,
+        format!(
+            "{}: {:.1}{} > {} {}{}",
+            metric, value, unit, label, limit, unit
+        ),
+    ))
+}
+
+/// One look at the machine: everything [`report_problems`] finds, printed,
+/// sent to the alert targets and turned into the exit code.
+pub fn run_check(args: &CheckArgs, config: &Config) -> Result<()> {
+    let mut alerter = Alerter::new(&args.alert_target, config)?;
+    let mut sampler = MetricsSampler::new();
+    let mut report = get_hardware_report(&config.sections, &config.probe_timeouts);
+    filter_mounts(&mut report.filesystems, &config.mounts);
+    let problems = report_problems(&report, config);
+    // The sample only fills the tables of alert emails; collecting the report
+    // took longer than the CPU usage interval it needs
+    alerter.check_problems(&problems, &sampler.sample());
+
+    if problems.is_empty() {
+        println!("{}", t("All metrics within thresholds").color(theme().ok));
+    }
+    for problem in &problems {
+        let label = match problem.level {
+            AlertLevel::Critical => t("CRITICAL").color(theme().critical),
+            _ => t("WARNING").color(theme().warning),
+        };
+        println!("{:<8}  {}", label.bold(), problem.message);
+    }
+
+    match problems.iter().map(|problem| problem.level).max() {
+        Some(AlertLevel::Critical) => std::process::exit(2),
+        Some(AlertLevel::Warning) => std::process::exit(1),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_debounce_levels() {
+        let thresholds = Thresholds {
+            warning: 70.0,
+            critical: 90.0,
+        };
+        let rule = AlertRule {
+            for_secs: 300,
+            clear_margin: 20.0,
+        };
+        let start = Instant::now();
+        let at = |secs: u64| start + Duration::from_secs(secs);
+        let mut state = MetricState::default();
+
+        // A spike shorter than for_secs never raises anything
+        assert_eq!(
+            state.update(95.0, &thresholds, &rule, at(0)),
+            AlertLevel::Ok
+        );
+        assert_eq!(
+            state.update(50.0, &thresholds, &rule, at(60)),
+            AlertLevel::Ok
+        );
+        // Five minutes past critical does
+        assert_eq!(
+            state.update(95.0, &thresholds, &rule, at(120)),
+            AlertLevel::Ok
+        );
+        assert_eq!(
+            state.update(92.0, &thresholds, &rule, at(420)),
+            AlertLevel::Critical
+        );
+        // Dipping under critical but not under warning keeps it raised
+        assert_eq!(
+            state.update(75.0, &thresholds, &rule, at(480)),
+            AlertLevel::Critical
+        );
+        // Under warning it drops to warning, which itself holds down to 50
+        assert_eq!(
+            state.update(65.0, &thresholds, &rule, at(540)),
+            AlertLevel::Warning
+        );
+        assert_eq!(
+            state.update(45.0, &thresholds, &rule, at(600)),
+            AlertLevel::Ok
+        );
+    }
+
+    #[test]
+    fn no_rule_follows_each_sample() {
+        let thresholds = Thresholds {
+            warning: 70.0,
+            critical: 90.0,
+        };
+        let rule = AlertRule::default();
+        let now = Instant::now();
+        let mut state = MetricState::default();
+        assert_eq!(
+            state.update(95.0, &thresholds, &rule, now),
+            AlertLevel::Critical
+        );
+        assert_eq!(
+            state.update(80.0, &thresholds, &rule, now),
+            AlertLevel::Warning
+        );
+        assert_eq!(state.update(10.0, &thresholds, &rule, now), AlertLevel::Ok);
+    }
+
+    struct Recorder(std::rc::Rc<std::cell::RefCell<Vec<String>>>);
+
+    impl AlertSink for Recorder {
+        fn send(&mut self, events: &[ThresholdEvent], _sample: &MetricsSample) -> Result<()> {
+            self.0
+                .borrow_mut()
+                .extend(events.iter().map(ThresholdEvent::message));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn problems_reach_the_sinks() {
+        let config = Config::default();
+        let delivered = std::rc::Rc::default();
+        let mut alerter = Alerter::new(&[], &config).unwrap();
+        alerter
+            .sinks
+            .push(Box::new(Recorder(std::rc::Rc::clone(&delivered))));
+        let sample = MetricsSample {
+            timestamp: "2026-10-16T09:00:00+00:00".to_string(),
+            cpu_usage: 10.0,
+            ram_used: 1,
+            ram_total: 10,
+            swap_used: 0,
+            swap_total: 0,
+            disks: Vec::new(),
+            temperatures: Vec::new(),
+            network: Vec::new(),
+        };
+        let battery = BatteryConfig {
+            warning: 50,
+            critical: 10,
+            max_wear: 20.0,
+        };
+        let low = BatteryInfo {
+            name: "BAT0".to_string(),
+            status: "Discharging".to_string(),
+            capacity: 8,
+            power_watts: None,
+            cycle_count: None,
+            wear_percent: Some(31.0),
+            time_remaining: None,
+        };
+        let mut problems = battery_problems(&[low], &battery);
+        problems.push(Problem::new(
+            "link:br0".to_string(),
+            AlertLevel::Warning,
+            "br0: 2/2 members down (eth1, eth2)".to_string(),
+        ));
+        let events = alerter.check_problems(&problems, &sample);
+        let levels = |events: &[ThresholdEvent]| -> Vec<(String, AlertLevel, AlertLevel)> {
+            events
+                .iter()
+                .map(|e| (e.metric.clone(), e.previous, e.level))
+                .collect()
+        };
+        let raised = |metric: &str, level| (metric.to_string(), AlertLevel::Ok, level);
+        assert_eq!(
+            levels(&events),
+            [
+                raised("battery:BAT0", AlertLevel::Critical),
+                raised("battery:BAT0:wear", AlertLevel::Warning),
+                raised("link:br0", AlertLevel::Warning),
+            ]
+        );
+        let messages: Vec<String> = problems.iter().map(|p| p.message.clone()).collect();
+        assert_eq!(*delivered.borrow(), messages);
+
+        // Unchanged problems are not sent again, and those gone come back to normal
+        let events = alerter.check_problems(&problems, &sample);
+        assert!(events.is_empty());
+        let events = alerter.check_problems(&problems[1..], &sample);
+        assert_eq!(
+            levels(&events),
+            [(
+                "battery:BAT0".to_string(),
+                AlertLevel::Critical,
+                AlertLevel::Ok
+            )]
+        );
+        assert_eq!(events[0].message(), "battery:BAT0: back to normal");
+    }
+
+    #[test]
+    fn redacted_hostname() {
+        let host = sysinfo::System::host_name().unwrap_or_default();
+        let mut config = Config::default();
+        config.redact.enabled = true;
+        let problems = [Problem::new(
+            "cpu".to_string(),
+            AlertLevel::Warning,
+            "CPU at 95%".to_string(),
+        )];
+        let sample = MetricsSample {
+            timestamp: "2026-10-16T09:00:00+00:00".to_string(),
+            cpu_usage: 95.0,
+            ram_used: 1,
+            ram_total: 10,
+            swap_used: 0,
+            swap_total: 0,
+            disks: Vec::new(),
+            temperatures: Vec::new(),
+            network: Vec::new(),
+        };
+        let events = Alerter::new(&[], &config)
+            .unwrap()
+            .check_problems(&problems, &sample);
+        assert_eq!(events[0].hostname, config.redact.hostname(&host));
+        assert!(host.is_empty() || events[0].hostname != host);
+    }
+}
@@ -0,0 +1,596 @@
+//! `--audit`: remembers the hardware seen on the last run in a state file and
+//! appends what changed since (a disk removed, a DIMM with another serial, a
+//! BIOS update) to an append-only log, one JSON object per line, which
+//! `hw-checker audit log` reads back.
+//!
+//! Only sections collected on both runs are compared, so a run with `--cpu`
+//! does not log every disk as removed; sections that timed out are skipped
+//! the same way and keep their last known state.
+
+use crate::cli::{AuditArgs, AuditCommand, AuditLogArgs, StreamFormat};
+use crate::config::Sections;
+use crate::history::data_dir;
+use crate::i18n::t;
+use crate::model::{HardwareReport, InterfaceKind};
+use anyhow::{Context, Result};
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::{Cell, Table};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// The `audit:` config section.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields, default)]
+pub struct AuditConfig {
+    /// Last-seen hardware (defaults to the user data directory)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<PathBuf>,
+    /// Append-only change log, one JSON object per line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log: Option<PathBuf>,
+}
+
+impl AuditConfig {
+    pub fn state_path(&self) -> PathBuf {
+        self.state
+            .clone()
+            .unwrap_or_else(|| data_dir().join("audit-state.json"))
+    }
+
+    pub fn log_path(&self) -> PathBuf {
+        self.log
+            .clone()
+            .unwrap_or_else(|| data_dir().join("audit.log"))
+    }
+}
+
+/// Component ("disk") -> item ("S64ANS0R123") -> field ("model") -> value.
+pub type Inventory = BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AuditState {
+    updated: String,
+    inventory: Inventory,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Change {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AuditEvent {
+    pub timestamp: String,
+    pub hostname: String,
+    pub component: String,
+    pub item: String,
+    pub change: Change,
+    /// The field that changed; `None` for added and removed items
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
+impl AuditEvent {
+    pub fn message(&self) -> String {
+        let or_unknown = |v: &Option<String>| v.clone().unwrap_or_else(|| "?".to_string());
+        match self.change {
+            Change::Added => format!(
+                "{} {} {} ({})",
+                self.component,
+                self.item,
+                t("added"),
+                or_unknown(&self.after)
+            ),
+            Change::Removed => format!(
+                "{} {} {} ({})",
+                self.component,
+                self.item,
+                t("removed"),
+                or_unknown(&self.before)
+            ),
+            Change::Changed => format!(
+                "{} {}: {} {} -> {}",
+                self.component,
+                self.item,
+                self.field.as_deref().unwrap_or_default(),
+                or_unknown(&self.before),
+                or_unknown(&self.after)
+            ),
+        }
+    }
+}
+
+/// What happened on this run.
+pub enum AuditOutcome {
+    /// No earlier state: this run's hardware is the baseline
+    Baseline(PathBuf),
+    Changes {
+        since: String,
+        events: Vec<AuditEvent>,
+    },
+}
+
+/// Compares `report` with the saved state, logs and returns the changes, and
+/// saves the new state.
+pub fn audit_report(
+    report: &HardwareReport,
+    sections: &Sections,
+    config: &AuditConfig,
+) -> Result<AuditOutcome> {
+    let state_path = config.state_path();
+    let current = inventory(report, sections);
+    let now = chrono::Local::now().to_rfc3339();
+
+    let previous: Option<AuditState> = match std::fs::read(&state_path) {
+        Ok(bytes) => Some(
+            serde_json::from_slice(&bytes)
+                .with_context(|| format!("invalid audit state in {}", state_path.display()))?,
+        ),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => {
+            return Err(err).with_context(|| format!("failed to read {}", state_path.display()));
+        }
+    };
+
+    let (outcome, mut inventory) = match previous {
+        None => (AuditOutcome::Baseline(state_path.clone()), Inventory::new()),
+        Some(previous) => {
+            let events = diff(&previous.inventory, &current, &now, &report.hostname);
+            append_log(&config.log_path(), &events)?;
+            (
+                AuditOutcome::Changes {
+                    since: previous.updated,
+                    events,
+                },
+                previous.inventory,
+            )
+        }
+    };
+    // Sections not collected this time keep their last known state
+    inventory.extend(current);
+    write_state(
+        &state_path,
+        &AuditState {
+            updated: now,
+            inventory,
+        },
+    )?;
+    Ok(outcome)
+}
+
+/// The audited components of `report`, leaving out sections that were not
+/// collected or timed out.
+pub fn inventory(report: &HardwareReport, sections: &Sections) -> Inventory {
+    let collected = |enabled: bool, step: &str| {
+        enabled && !report.diagnostics.timed_out.iter().any(|s| s == step)
+    };
+    let fields = |pairs: &[(&str, Option<String>)]| -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .filter_map(|(k, v)| Some((k.to_string(), v.clone()?)))
+            .collect()
+    };
+    let mut inventory = Inventory::new();
+
+    if collected(sections.cpu, "cpu") {
+        let items = report
+            .cpu
+            .iter()
+            .enumerate()
+            .map(|(i, package)| {
+                (
+                    format!("CPU {}", i),
+                    fields(&[
+                        ("model", Some(package.model.trim().to_string())),
+                        ("cores", Some(package.physical_cores.to_string())),
+                        ("threads", Some(package.logical_cores.to_string())),
+                    ]),
+                )
+            })
+            .collect();
+        inventory.insert("cpu".to_string(), items);
+    }
+
+    // No modules at all usually means SMBIOS was unreadable (not root), not
+    // that every DIMM was pulled
+    if collected(sections.ram, "ram") && !report.ram.sticks.is_empty() {
+        let items = report
+            .ram
+            .sticks
+            .iter()
+            .enumerate()
+            .map(|(i, stick)| {
+                (
+                    format!("DIMM {}", i),
+                    fields(&[
+                        ("manufacturer", stick.manufacturer.clone()),
+                        ("part_number", stick.part_number.clone()),
+                        ("serial", stick.serial_number.clone()),
+                        ("speed", stick.speed.map(|s| s.to_string())),
+                    ]),
+                )
+            })
+            .collect();
+        inventory.insert("memory".to_string(), items);
+    }
+
+    if collected(sections.storage, "disks") {
+        // Keyed by serial, so a disk renamed from sdb to sdc is not a change
+        let items = report
+            .disks
+            .iter()
+            .map(|disk| {
+                (
+                    disk.serial_number
+                        .clone()
+                        .unwrap_or_else(|| disk.name.clone()),
+                    fields(&[
+                        ("model", disk.model_name.clone()),
+                        ("size", Some(disk.size.to_string())),
+                        ("interface", disk.interface.clone()),
+                    ]),
+                )
+            })
+            .collect();
+        inventory.insert("disk".to_string(), items);
+    }
+
+    if collected(sections.motherboard, "motherboard")
+        && let Some(board) = &report.motherboard
+    {
+        let items = BTreeMap::from([
+            (
+                "board".to_string(),
+                fields(&[
+                    ("vendor", Some(board.vendor.clone())),
+                    ("product", Some(board.product.clone())),
+                ]),
+            ),
+            (
+                "BIOS".to_string(),
+                fields(&[
+                    ("vendor", Some(board.bios_vendor.clone())),
+                    ("version", Some(board.bios_version.clone())),
+                    ("date", Some(board.bios_date.clone())),
+                ]),
+            ),
+        ]);
+        inventory.insert("motherboard".to_string(), items);
+    }
+
+    if collected(sections.network, "network") {
+        let items = report
+            .network
+            .iter()
+            .filter(|net| matches!(net.kind, None | Some(InterfaceKind::Physical)))
+            .filter(|net| !net.mac_address.is_empty() && net.mac_address != "00:00:00:00:00:00")
+            .map(|net| {
+                (
+                    net.mac_address.clone(),
+                    fields(&[("name", Some(net.name.clone()))]),
+                )
+            })
+            .collect();
+        inventory.insert("network".to_string(), items);
+    }
+
+    if collected(sections.pci, "pci") {
+        let items = report
+            .pci
+            .iter()
+            .map(|device| {
+                (
+                    device.slot.clone(),
+                    fields(&[
+                        (
+                            "id",
+                            Some(format!("{:04x}:{:04x}", device.vendor_id, device.device_id)),
+                        ),
+                        ("name", device.device_name.clone()),
+                    ]),
+                )
+            })
+            .collect();
+        inventory.insert("pci".to_string(), items);
+    }
+
+    if collected(sections.gpu, "gpus") {
+        let items = report
+            .gpus
+            .iter()
+            .map(|gpu| {
+                (
+                    gpu.slot.clone(),
+                    fields(&[
+                        ("name", Some(gpu.name.clone())),
+                        ("vram", gpu.vram_total.map(|v| v.to_string())),
+                    ]),
+                )
+            })
+            .collect();
+        inventory.insert("gpu".to_string(), items);
+    }
+
+    inventory
+}
+
+/// Changes from `before` to `after`, for components present in both.
+pub fn diff(
+    before: &Inventory,
+    after: &Inventory,
+    timestamp: &str,
+    hostname: &str,
+) -> Vec<AuditEvent> {
+    let summary = |fields: &BTreeMap<String, String>| {
+        fields
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let event =
+        |component: &str, item: &str, change, field: Option<&str>, before, after| AuditEvent {
+            timestamp: timestamp.to_string(),
+            hostname: hostname.to_string(),
+            component: component.to_string(),
+            item: item.to_string(),
+            change,
+            field: field.map(String::from),
+            before,
+            after,
+        };
+
+    let mut events = Vec::new();
+    for (component, old_items) in before {
+        let Some(new_items) = after.get(component) else {
+            continue;
+        };
+        for (item, old) in old_items {
+            match new_items.get(item) {
+                None => events.push(event(
+                    component,
+                    item,
+                    Change::Removed,
+                    None,
+                    Some(summary(old)),
+                    None,
+                )),
+                Some(new) => {
+                    let names: std::collections::BTreeSet<&String> =
+                        old.keys().chain(new.keys()).collect();
+                    for name in names {
+                        let (was, is) = (old.get(name), new.get(name));
+                        if was != is {
+                            events.push(event(
+                                component,
+                                item,
+                                Change::Changed,
+                                Some(name),
+                                was.cloned(),
+                                is.cloned(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        for (item, new) in new_items {
+            if !old_items.contains_key(item) {
+                events.push(event(
+                    component,
+                    item,
+                    Change::Added,
+                    None,
+                    None,
+                    Some(summary(new)),
+                ));
+            }
+        }
+    }
+    events
+}
+
+fn append_log(path: &Path, events: &[AuditEvent]) -> Result<()> {
+    if events.is_empty() {
+        return Ok(());
+    }
+    let mut file = private_file(path, std::fs::OpenOptions::new().append(true))?;
+    let mut lines = String::new();
+    for event in events {
+        lines.push_str(&serde_json::to_string(event)?);
+        lines.push('\n');
+    }
+    // One write, so concurrent runs do not interleave within a line
+    file.write_all(lines.as_bytes())
+        .with_context(|| format!("failed to append to {}", path.display()))
+}
+
+fn write_state(path: &Path, state: &AuditState) -> Result<()> {
+    // Written aside and renamed, so an interrupted run leaves the old state
+    let staging = path.with_extension("json.tmp");
+    private_file(
+        &staging,
+        std::fs::OpenOptions::new().write(true).truncate(true),
+    )?
+    .write_all(&serde_json::to_vec_pretty(state)?)
+    .with_context(|| format!("failed to write {}", staging.display()))?;
+    std::fs::rename(&staging, path).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Opens (creating) `path` with `options`; new files are readable by the
+/// owner only, as they hold serial numbers and MACs.
+fn private_file(path: &Path, options: &mut std::fs::OpenOptions) -> Result<std::fs::File> {
+    create_parent(path)?;
+    options.create(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(options, 0o600);
+    options
+        .open(path)
+        .with_context(|| format!("failed to open {}", path.display()))
+}
+
+fn create_parent(path: &Path) -> Result<()> {
+    if let Some(dir) = path.parent()
+        && !dir.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    Ok(())
+}
+
+/// Every event in the log, oldest first; lines that do not parse are skipped.
+pub fn read_log(path: &Path) -> Result<Vec<AuditEvent>> {
+    let file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err).with_context(|| format!("failed to read {}", path.display())),
+    };
+    let mut events = Vec::new();
+    for line in BufReader::new(file).lines() {
+        if let Ok(event) = serde_json::from_str(&line?) {
+            events.push(event);
+        }
+    }
+    Ok(events)
+}
+
+pub fn run_audit(args: &AuditArgs, config: &AuditConfig) -> Result<()> {
+    match &args.command {
+        AuditCommand::Log(log_args) => print_log(log_args, config),
+    }
+}
+
+fn print_log(args: &AuditLogArgs, config: &AuditConfig) -> Result<()> {
+    let path = config.log_path();
+    let since = args
+        .since
+        .and_then(|since| chrono::Duration::from_std(since).ok())
+        .and_then(|since| chrono::Local::now().checked_sub_signed(since));
+    let events: Vec<AuditEvent> = read_log(&path)?
+        .into_iter()
+        .filter(|event| {
+            args.component
+                .as_deref()
+                .is_none_or(|component| event.component == component)
+        })
+        .filter(|event| {
+            since.is_none_or(|since| {
+                chrono::DateTime::parse_from_rfc3339(&event.timestamp)
+                    .is_ok_and(|time| time >= since)
+            })
+        })
+        .collect();
+
+    match args.format {
+        StreamFormat::Jsonl => {
+            for event in &events {
+                println!("{}", serde_json::to_string(event)?);
+            }
+        }
+        StreamFormat::Table => {
+            if events.is_empty() {
+                println!("{} ({})", t("No hardware changes logged"), path.display());
+                return Ok(());
+            }
+            let mut table = Table::new();
+            table
+                .load_preset(UTF8_FULL)
+                .apply_modifier(UTF8_ROUND_CORNERS)
+                .set_header(vec![t("Time"), t("Host"), t("Change")]);
+            for event in &events {
+                let time = chrono::DateTime::parse_from_rfc3339(&event.timestamp)
+                    .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_else(|_| event.timestamp.clone());
+                table.add_row(vec![
+                    Cell::new(time),
+                    Cell::new(&event.hostname),
+                    Cell::new(event.message()),
+                ]);
+            }
+            println!("{table}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(entries: &[(&str, &[(&str, &str)])]) -> BTreeMap<String, BTreeMap<String, String>> {
+        entries
+            .iter()
+            .map(|(item, fields)| {
+                let fields = fields
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect();
+                (item.to_string(), fields)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn changes_between_runs() {
+        let before = Inventory::from([
+            (
+                "disk".to_string(),
+                items(&[
+                    ("S64ANS0R1", &[("model", "Samsung SSD 980")]),
+                    ("WD-123", &[("model", "WDC WD40EFRX")]),
+                ]),
+            ),
+            (
+                "memory".to_string(),
+                items(&[("DIMM 0", &[("serial", "1A2B3C4D")])]),
+            ),
+            (
+                "motherboard".to_string(),
+                items(&[("BIOS", &[("version", "1.20"), ("date", "03/01/2024")])]),
+            ),
+            (
+                "gpu".to_string(),
+                items(&[("0000:01:00.0", &[("name", "RTX 4090")])]),
+            ),
+        ]);
+        // The GPU section was not collected this time
+        let after = Inventory::from([
+            (
+                "disk".to_string(),
+                items(&[("S64ANS0R1", &[("model", "Samsung SSD 980")])]),
+            ),
+            (
+                "memory".to_string(),
+                items(&[("DIMM 0", &[("serial", "9F8E7D6C")])]),
+            ),
+            (
+                "motherboard".to_string(),
+                items(&[("BIOS", &[("version", "1.30"), ("date", "03/01/2024")])]),
+            ),
+        ]);
+
+        let events = diff(&before, &after, "2026-10-16T10:00:00+00:00", "db-1");
+        let messages: Vec<String> = events.iter().map(AuditEvent::message).collect();
+        assert_eq!(
+            messages,
+            [
+                "disk WD-123 removed (model=WDC WD40EFRX)",
+                "memory DIMM 0: serial 1A2B3C4D -> 9F8E7D6C",
+                "motherboard BIOS: version 1.20 -> 1.30",
+            ]
+        );
+        assert_eq!(events[1].change, Change::Changed);
+        assert_eq!(events[1].field.as_deref(), Some("serial"));
+    }
+}
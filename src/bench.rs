@@ -0,0 +1,302 @@
+use crate::cli::{BenchArgs, BenchCommand, NetBenchArgs};
+use crate::i18n::t;
+use crate::theme::theme;
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use comfy_table::Table;
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+use comfy_table::presets::UTF8_FULL;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{IpAddr, Shutdown, TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+// Each stream opens with MAGIC, a direction byte and the test length in milliseconds.
+const MAGIC: &[u8; 4] = b"HWCB";
+const UPLOAD: u8 = 0;
+const DOWNLOAD: u8 = 1;
+const BUFFER: usize = 128 * 1024;
+// What a server accepts from any client: the longest test, how long a stream
+// may sit idle, and how many streams run at once (one client at --streams 128)
+const MAX_DURATION: Duration = Duration::from_secs(600);
+const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_CONNECTIONS: usize = 128;
+// How long a receiver waits past the test length for the last buffers in flight
+const SLACK: Duration = Duration::from_secs(1);
+
+pub fn run_bench(args: &BenchArgs) -> Result<()> {
+    match &args.command {
+        BenchCommand::Net(net) => {
+            if net.server {
+                run_server(net)
+            } else if let Some(host) = &net.client {
+                run_client(net, host)
+            } else {
+                bail!("choose --server or --client <HOST>")
+            }
+        }
+    }
+}
+
+fn bitrate(bytes: u64, elapsed: Duration) -> String {
+    let bits_per_sec = bytes as f64 * 8.0 / elapsed.as_secs_f64().max(1e-9);
+    if bits_per_sec >= 1e9 {
+        format!("{:.2} Gbit/s", bits_per_sec / 1e9)
+    } else {
+        format!("{:.1} Mbit/s", bits_per_sec / 1e6)
+    }
+}
+
+/// Writes until `duration` has passed, counting bytes into `sent`.
+fn send_for(stream: &mut TcpStream, duration: Duration, sent: &AtomicU64) -> Result<u64> {
+    let buffer = vec![0x5au8; BUFFER];
+    let started = Instant::now();
+    let mut total = 0u64;
+    while started.elapsed() < duration {
+        stream.write_all(&buffer)?;
+        sent.fetch_add(BUFFER as u64, Ordering::Relaxed);
+        total += BUFFER as u64;
+    }
+    Ok(total)
+}
+
+/// Reads until the peer closes its side, counting bytes into `received`.
+/// Past `deadline` the peer is cut off.
+fn receive_all(stream: &mut TcpStream, received: &AtomicU64, deadline: Instant) -> Result<u64> {
+    let mut buffer = vec![0u8; BUFFER];
+    let mut total = 0u64;
+    loop {
+        if Instant::now() > deadline {
+            bail!("the peer kept sending past the test length");
+        }
+        match stream.read(&mut buffer) {
+            Ok(0) => return Ok(total),
+            Ok(n) => {
+                received.fetch_add(n as u64, Ordering::Relaxed);
+                total += n as u64;
+            }
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+fn serve_stream(mut stream: TcpStream) -> Result<()> {
+    let peer = stream.peer_addr()?;
+    stream.set_read_timeout(Some(IDLE_TIMEOUT))?;
+    stream.set_write_timeout(Some(IDLE_TIMEOUT))?;
+    let mut header = [0u8; 9];
+    stream.read_exact(&mut header)?;
+    if &header[..4] != MAGIC {
+        bail!("{} is not a hw-checker bench client", peer);
+    }
+    let duration = Duration::from_millis(u32::from_be_bytes(header[5..9].try_into()?) as u64)
+        .min(MAX_DURATION);
+    let bytes = AtomicU64::new(0);
+    let started = Instant::now();
+    let direction = match header[4] {
+        // The receiving side's byte count and clock are the result, so uploads get a reply.
+        UPLOAD => {
+            receive_all(&mut stream, &bytes, started + duration + SLACK)?;
+            let elapsed = started.elapsed().as_micros() as u64;
+            stream.write_all(&bytes.load(Ordering::Relaxed).to_be_bytes())?;
+            stream.write_all(&elapsed.to_be_bytes())?;
+            t("received")
+        }
+        DOWNLOAD => {
+            send_for(&mut stream, duration, &bytes)?;
+            stream.shutdown(Shutdown::Write)?;
+            t("sent")
+        }
+        other => bail!("unknown direction {} from {}", other, peer),
+    };
+    let elapsed = started.elapsed();
+    let total = bytes.load(Ordering::Relaxed);
+    println!(
+        "{}  {} {:.1} MiB {} {:.1}s: {}",
+        peer,
+        direction,
+        total as f64 / 1024.0 / 1024.0,
+        t("in"),
+        elapsed.as_secs_f64(),
+        bitrate(total, elapsed)
+    );
+    Ok(())
+}
+
+fn run_server(args: &NetBenchArgs) -> Result<()> {
+    let listener = TcpListener::bind((args.bind.as_str(), args.port))
+        .with_context(|| format!("failed to listen on {}:{}", args.bind, args.port))?;
+    println!(
+        "{} {}",
+        t("Bench server listening on").bold().color(theme().title),
+        listener.local_addr()?
+    );
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("{}", err);
+                continue;
+            }
+        };
+        // Dropped right away: the client sees the connection close
+        if active.load(Ordering::SeqCst) >= MAX_CONNECTIONS {
+            eprintln!(
+                "{}: {} {}",
+                stream
+                    .peer_addr()
+                    .map_or_else(|_| "?".to_string(), |peer| peer.to_string()),
+                t("refused, already serving"),
+                MAX_CONNECTIONS
+            );
+            continue;
+        }
+        active.fetch_add(1, Ordering::SeqCst);
+        let active = active.clone();
+        std::thread::spawn(move || {
+            if let Err(err) = serve_stream(stream) {
+                eprintln!("{:#}", err);
+            }
+            active.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+    Ok(())
+}
+
+// Negotiated speed (Mbit/s) of the interface that owns `ip`, from sysfs.
+fn link_speed(ip: IpAddr) -> Option<(String, u64)> {
+    let networks = sysinfo::Networks::new_with_refreshed_list();
+    let (name, _) = networks
+        .iter()
+        .find(|(_, data)| data.ip_networks().iter().any(|n| n.addr == ip))?;
+    let speed = std::fs::read_to_string(format!("/sys/class/net/{}/speed", name))
+        .ok()?
+        .trim()
+        .parse::<i64>()
+        .ok()
+        .filter(|s| *s > 0)?;
+    Some((name.clone(), speed as u64))
+}
+
+fn run_client(args: &NetBenchArgs, host: &str) -> Result<()> {
+    if args.duration > MAX_DURATION {
+        bail!(
+            "--duration can be at most {}",
+            humantime::format_duration(MAX_DURATION)
+        );
+    }
+    let direction = if args.reverse { DOWNLOAD } else { UPLOAD };
+    let mut header = Vec::with_capacity(9);
+    header.extend_from_slice(MAGIC);
+    header.push(direction);
+    header
+        .extend_from_slice(&(args.duration.as_millis().min(u32::MAX as u128) as u32).to_be_bytes());
+
+    let mut streams = Vec::new();
+    for _ in 0..args.streams {
+        let mut stream = TcpStream::connect((host, args.port))
+            .with_context(|| format!("failed to connect to {}:{}", host, args.port))?;
+        stream.set_nodelay(true)?;
+        // A server that stalls or never closes its side must not hang the client
+        stream.set_read_timeout(Some(IDLE_TIMEOUT))?;
+        stream.set_write_timeout(Some(IDLE_TIMEOUT))?;
+        stream.write_all(&header)?;
+        streams.push(stream);
+    }
+    let local_ip = streams[0].local_addr()?.ip();
+    println!(
+        "{} {} ({}, {} {}, {})",
+        t("Testing throughput to").bold().color(theme().title),
+        streams[0].peer_addr()?,
+        if args.reverse {
+            t("download")
+        } else {
+            t("upload")
+        },
+        args.streams,
+        t("streams"),
+        humantime::format_duration(args.duration)
+    );
+
+    let progress = Arc::new(AtomicU64::new(0));
+    let started = Instant::now();
+    let workers: Vec<_> = streams
+        .into_iter()
+        .map(|mut stream| {
+            let progress = Arc::clone(&progress);
+            let duration = args.duration;
+            std::thread::spawn(move || -> Result<(u64, u64)> {
+                if direction == DOWNLOAD {
+                    let started = Instant::now();
+                    let bytes = receive_all(&mut stream, &progress, started + duration + SLACK)?;
+                    return Ok((bytes, started.elapsed().as_micros() as u64));
+                }
+                send_for(&mut stream, duration, &progress)?;
+                stream.shutdown(Shutdown::Write)?;
+                let mut result = [0u8; 16];
+                stream
+                    .read_exact(&mut result)
+                    .context("the server did not report its result")?;
+                Ok((
+                    u64::from_be_bytes(result[..8].try_into()?),
+                    u64::from_be_bytes(result[8..].try_into()?),
+                ))
+            })
+        })
+        .collect();
+
+    let mut last = 0u64;
+    let mut second = 0u64;
+    while workers.iter().any(|w| !w.is_finished()) {
+        std::thread::sleep(Duration::from_millis(100));
+        let elapsed = started.elapsed().as_secs();
+        if elapsed > second && elapsed <= args.duration.as_secs() {
+            second = elapsed;
+            let now = progress.load(Ordering::Relaxed);
+            println!(
+                "{:>4}s  {}",
+                second,
+                bitrate(now - last, Duration::from_secs(1))
+            );
+            last = now;
+        }
+    }
+
+    let mut total = 0u64;
+    let mut longest = Duration::ZERO;
+    for worker in workers {
+        let (bytes, micros) = worker
+            .join()
+            .map_err(|_| anyhow::anyhow!("bench stream panicked"))??;
+        total += bytes;
+        longest = longest.max(Duration::from_micros(micros));
+    }
+
+    println!("\n{}", t("Network Throughput").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![t("Metric"), t("Value")]);
+    table.add_row(vec![
+        t("Transferred").to_string(),
+        format!("{:.1} MiB", total as f64 / 1024.0 / 1024.0),
+    ]);
+    table.add_row(vec![t("Bitrate").to_string(), bitrate(total, longest)]);
+    if let Some((interface, speed)) = link_speed(local_ip) {
+        let measured = total as f64 * 8.0 / longest.as_secs_f64().max(1e-9) / 1e6;
+        table.add_row(vec![
+            t("Link speed").to_string(),
+            format!("{} Mbit/s ({})", speed, interface),
+        ]);
+        table.add_row(vec![
+            t("Link utilization").to_string(),
+            format!("{:.0}%", measured / speed as f64 * 100.0),
+        ]);
+    }
+    println!("{table}");
+    Ok(())
+}
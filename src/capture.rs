@@ -0,0 +1,471 @@
+//! `hw-checker capture`: copies the sysfs/procfs/DMI files discovery reads
+//! into a tarball that `--from-sysfs` can replay, for attaching to bug reports.
+
+use crate::cli::CaptureArgs;
+use crate::config::Config;
+use crate::i18n::t;
+use crate::redact::{RedactConfig, RedactMode};
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+
+// Everything LinuxProbe reads; `*` matches within one path component.
+const PATTERNS: &[&str] = &[
+    "/etc/os-release",
+    "/proc/cpuinfo",
+    "/proc/meminfo",
+    "/proc/sys/vm/overcommit_memory",
+    "/proc/sys/vm/overcommit_ratio",
+    "/sys/kernel/mm/transparent_hugepage/enabled",
+    "/proc/self/limits",
+    "/proc/loadavg",
+    "/proc/pressure/cpu",
+    "/proc/pressure/memory",
+    "/proc/pressure/io",
+    "/proc/sys/fs/file-nr",
+    "/proc/sys/fs/file-max",
+    "/proc/sys/fs/nr_open",
+    "/proc/sys/fs/aio-nr",
+    "/proc/sys/fs/aio-max-nr",
+    "/proc/sys/fs/inotify/max_user_watches",
+    "/proc/sys/kernel/pid_max",
+    "/proc/sys/kernel/threads-max",
+    "/proc/sys/vm/max_map_count",
+    "/proc/self/cgroup",
+    "/sys/fs/cgroup/cpu.max",
+    "/sys/fs/cgroup/cpuset.cpus.effective",
+    "/sys/fs/cgroup/memory.max",
+    "/sys/fs/cgroup/memory.current",
+    "/sys/fs/cgroup/memory.stat",
+    "/sys/fs/cgroup/cpu/cpu.cfs_*",
+    "/sys/fs/cgroup/cpuset/cpuset.cpus",
+    "/sys/fs/cgroup/cpuset/cpuset.effective_cpus",
+    "/sys/fs/cgroup/memory/memory.limit_in_bytes",
+    "/sys/fs/cgroup/memory/memory.usage_in_bytes",
+    "/sys/fs/cgroup/memory/memory.stat",
+    "/sys/devices/system/cpu/online",
+    "/proc/mounts",
+    "/proc/uptime",
+    "/proc/diskstats",
+    "/proc/interrupts",
+    "/proc/irq/*/smp_affinity_list",
+    "/proc/net/route",
+    "/proc/net/ipv6_route",
+    "/etc/resolv.conf",
+    "/run/systemd/resolve/resolv.conf",
+    "/proc/sys/kernel/osrelease",
+    "/proc/sys/kernel/hostname",
+    "/proc/sys/kernel/arch",
+    "/proc/cmdline",
+    "/proc/modules",
+    "/proc/stat",
+    "/sys/fs/selinux/enforce",
+    "/sys/devices/system/clocksource/clocksource0/current_clocksource",
+    "/sys/devices/system/clocksource/clocksource0/available_clocksource",
+    "/sys/class/rtc/rtc0/name",
+    "/sys/module/apparmor/parameters/enabled",
+    "/sys/class/dmi/id/*",
+    "/sys/firmware/dmi/tables/DMI",
+    "/sys/firmware/dmi/tables/smbios_entry_point",
+    "/sys/hypervisor/type",
+    "/sys/bus/virtio/devices/*/device",
+    "/sys/bus/vmbus/drivers/hv_balloon",
+    "/sys/module/vmw_balloon",
+    "/sys/devices/system/xen_memory/xen_memory0",
+    "/sys/bus/i2c/drivers/ee1004/*/eeprom",
+    "/sys/bus/i2c/drivers/spd5118/*/eeprom",
+    "/sys/devices/system/cpu/cpu0/cache/index*/level",
+    "/sys/devices/system/cpu/cpu0/cache/index*/type",
+    "/sys/devices/system/cpu/cpu0/cache/index*/size",
+    "/sys/devices/system/cpu/cpu0/cache/index*/shared_cpu_list",
+    "/sys/devices/system/cpu/cpu*/topology/physical_package_id",
+    "/sys/devices/system/cpu/cpu*/topology/core_id",
+    "/sys/devices/system/cpu/cpu*/topology/cluster_id",
+    "/sys/devices/system/cpu/cpu*/cpu_capacity",
+    "/sys/devices/system/cpu/cpu*/cpufreq/base_frequency",
+    "/sys/devices/system/cpu/cpu*/cpufreq/cpuinfo_max_freq",
+    "/sys/devices/cpu_core/cpus",
+    "/sys/devices/cpu_atom/cpus",
+    "/sys/devices/system/cpu/cpu*/thermal_throttle/core_throttle_count",
+    "/sys/devices/system/cpu/cpu*/thermal_throttle/package_throttle_count",
+    "/proc/acpi/button/lid/*/state",
+    "/sys/devices/platform/dock.*/docked",
+    "/sys/class/thermal/cooling_device*/type",
+    "/sys/class/thermal/cooling_device*/cur_state",
+    "/sys/block/*/device/model",
+    "/sys/block/*/device/serial",
+    "/sys/block/*/device/vendor",
+    "/sys/block/*/device/type",
+    "/sys/block/*/device",
+    "/sys/block/*/size",
+    "/sys/block/*/queue/rotational",
+    "/sys/block/*/*/partition",
+    "/sys/class/block/*/size",
+    "/sys/class/block/*/queue/rotational",
+    "/sys/class/ata_link/*/sata_spd",
+    "/sys/class/sas_port/*/device/phy-*/sas_phy/phy-*/negotiated_linkrate",
+    "/sys/class/net/*/address",
+    "/sys/class/net/*/statistics/rx_bytes",
+    "/sys/class/net/*/statistics/tx_bytes",
+    "/sys/class/net/*/type",
+    "/sys/class/net/*/uevent",
+    "/sys/class/net/*/ifindex",
+    "/sys/class/net/*/iflink",
+    "/sys/class/net/*/tun_flags",
+    "/sys/class/net/*/bridge",
+    "/sys/class/net/*/bonding",
+    "/sys/class/net/*/device",
+    "/sys/class/net/*/lower_*",
+    "/sys/class/net/*/operstate",
+    "/sys/class/net/*/bonding/mode",
+    "/sys/class/net/*/bonding/slaves",
+    "/sys/class/net/*/bonding/active_slave",
+    "/sys/class/net/*/bonding_slave/mii_status",
+    "/sys/class/net/*/brif/*",
+    "/sys/bus/usb/devices/*/busnum",
+    "/sys/bus/usb/devices/*/devnum",
+    "/sys/bus/usb/devices/*/idVendor",
+    "/sys/bus/usb/devices/*/idProduct",
+    "/sys/bus/usb/devices/*/manufacturer",
+    "/sys/bus/usb/devices/*/product",
+    "/sys/bus/usb/devices/*/serial",
+    "/sys/bus/usb/devices/*/speed",
+    "/sys/bus/pci/devices/*/vendor",
+    "/sys/bus/pci/devices/*/device",
+    "/sys/bus/pci/devices/*/class",
+    "/sys/bus/pci/devices/*/revision",
+    "/sys/bus/pci/devices/*/subsystem_vendor",
+    "/sys/bus/pci/devices/*/subsystem_device",
+    "/sys/bus/pci/devices/*/numa_node",
+    "/sys/bus/pci/devices/*/iommu_group",
+    "/sys/bus/pci/devices/*/driver",
+    "/sys/class/iommu/*",
+    "/sys/class/misc/kvm",
+    "/sys/module/kvm",
+    "/sys/module/kvm_intel/parameters/nested",
+    "/sys/module/kvm_amd/parameters/nested",
+    "/sys/kernel/iommu_groups/*/type",
+    "/sys/kernel/iommu_groups/*/devices/*",
+    "/sys/bus/pci/devices/*/0000:*/class",
+    "/sys/bus/pci/devices/*/current_link_speed",
+    "/sys/bus/pci/devices/*/current_link_width",
+    "/sys/bus/thunderbolt/devices/*/device_name",
+    "/sys/bus/thunderbolt/devices/*/vendor_name",
+    "/sys/bus/thunderbolt/devices/*/rx_speed",
+    "/sys/class/power_supply/*/status",
+    "/sys/class/power_supply/*/capacity",
+    "/sys/class/power_supply/*/power_now",
+    "/sys/class/power_supply/*/current_now",
+    "/sys/class/power_supply/*/voltage_now",
+    "/sys/class/power_supply/*/cycle_count",
+    "/sys/class/power_supply/*/energy_full",
+    "/sys/class/power_supply/*/energy_full_design",
+    "/sys/class/power_supply/*/charge_full",
+    "/sys/class/power_supply/*/charge_full_design",
+    "/sys/class/thermal/thermal_zone*/temp",
+    "/sys/class/thermal/thermal_zone*/type",
+    "/sys/class/hwmon/*/name",
+    "/sys/class/hwmon/*/temp*_input",
+    "/sys/class/hwmon/*/temp*_label",
+    "/sys/class/hwmon/*/temp*_max",
+    "/sys/class/hwmon/*/temp*_crit",
+    "/sys/class/hwmon/*/fan*_input",
+    "/sys/class/hwmon/*/fan*_label",
+    "/sys/class/drm/card*/device/vendor",
+    "/sys/class/drm/card*/device/device",
+    "/sys/class/drm/card*/device/mem_info_vram_total",
+    "/sys/class/drm/card*/device/driver",
+];
+
+/// Contents of `capture.json` at the top of the bundle.
+#[derive(Debug, Serialize)]
+struct CaptureInfo {
+    hw_checker_version: &'static str,
+    captured_at: String,
+    redacted: Option<RedactMode>,
+    files: usize,
+    skipped: Vec<String>,
+}
+
+fn glob_match(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => {
+            name.len() >= prefix.len() + suffix.len()
+                && name.starts_with(prefix)
+                && name.ends_with(suffix)
+        }
+        None => pattern == name,
+    }
+}
+
+fn expand(pattern: &str) -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::from("/")];
+    for part in pattern.trim_start_matches('/').split('/') {
+        paths = paths
+            .into_iter()
+            .flat_map(|dir| {
+                if !part.contains('*') {
+                    return vec![dir.join(part)];
+                }
+                let Ok(entries) = fs::read_dir(&dir) else {
+                    return Vec::new();
+                };
+                let mut matched: Vec<PathBuf> = entries
+                    .flatten()
+                    .filter(|e| glob_match(part, &e.file_name().to_string_lossy()))
+                    .map(|e| dir.join(e.file_name()))
+                    .collect();
+                matched.sort();
+                matched
+            })
+            .collect();
+    }
+    paths
+}
+
+/// Files holding serial numbers, UUIDs, MAC addresses or the hostname.
+fn is_identifier(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    name == "serial"
+        || name.ends_with("_serial")
+        || name == "product_uuid"
+        || (name == "address" && path.starts_with("/sys/class/net"))
+        || path == Path::new("/proc/sys/kernel/hostname")
+}
+
+#[cfg(unix)]
+fn symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(not(unix))]
+fn symlink(_target: &Path, _link: &Path) -> std::io::Result<()> {
+    Err(std::io::ErrorKind::Unsupported.into())
+}
+
+struct Bundle {
+    dir: PathBuf,
+    redact: RedactConfig,
+    files: usize,
+    skipped: Vec<String>,
+}
+
+impl Bundle {
+    fn staged(&self, path: &Path) -> PathBuf {
+        self.dir.join(path.strip_prefix("/").unwrap_or(path))
+    }
+
+    /// Resolves `path` like the kernel would, recreating each symlink met on
+    /// the way (also inside link targets) so the relative links sysfs is built
+    /// from resolve the same way inside the bundle.
+    fn resolve(&self, path: &Path, depth: usize) -> Result<Option<PathBuf>> {
+        if depth > 40 {
+            bail!("too many levels of symbolic links at {}", path.display());
+        }
+        let mut current = PathBuf::from("/");
+        for component in path.components() {
+            let next = match component {
+                Component::Normal(name) => current.join(name),
+                Component::ParentDir => {
+                    current.pop();
+                    continue;
+                }
+                _ => continue,
+            };
+            let Ok(meta) = fs::symlink_metadata(&next) else {
+                return Ok(None);
+            };
+            if !meta.file_type().is_symlink() {
+                current = next;
+                continue;
+            }
+            let target = fs::read_link(&next)?;
+            let mut stored = target.clone();
+            if target.is_absolute() {
+                // Point absolute links at the copy, not at the replaying host
+                let levels = current.components().count() - 1;
+                stored = Path::new(&"../".repeat(levels)).join(target.strip_prefix("/")?);
+            }
+            let link = self.staged(&next);
+            if fs::symlink_metadata(&link).is_err() {
+                fs::create_dir_all(link.parent().unwrap_or(&self.dir))?;
+                symlink(&stored, &link)
+                    .with_context(|| format!("failed to create {}", link.display()))?;
+            }
+            let Some(resolved) = self.resolve(&current.join(target), depth + 1)? else {
+                return Ok(None);
+            };
+            current = resolved;
+        }
+        Ok(Some(current))
+    }
+
+    fn add(&mut self, path: &Path) -> Result<()> {
+        let Some(current) = self.resolve(path, 0)? else {
+            return Ok(());
+        };
+        let staged = self.staged(&current);
+        if current.is_dir() {
+            fs::create_dir_all(&staged)?;
+            return Ok(());
+        }
+        // Some attributes fail to read on some hardware; the replay just lacks them
+        let Ok(mut contents) = fs::read(&current) else {
+            return Ok(());
+        };
+        if self.redact.enabled {
+            if path.starts_with("/sys/firmware/dmi/tables") {
+                self.skipped.push(format!(
+                    "{} (binary SMBIOS data with serial numbers)",
+                    path.display()
+                ));
+                return Ok(());
+            }
+            if path.starts_with("/sys/bus/i2c/drivers") {
+                self.skipped.push(format!(
+                    "{} (SPD EEPROM with the module serial number)",
+                    path.display()
+                ));
+                return Ok(());
+            }
+            if path == Path::new("/proc/cmdline") {
+                let cmdline = String::from_utf8_lossy(&contents);
+                contents = format!("{}\n", self.redact.redact_cmdline(&cmdline)).into_bytes();
+            } else if is_identifier(path) {
+                let value = String::from_utf8_lossy(&contents).trim().to_string();
+                match self.redact.mode {
+                    RedactMode::Hash if !value.is_empty() => {
+                        contents = format!("{}\n", self.redact.redact(&value)).into_bytes();
+                    }
+                    RedactMode::Hash => {}
+                    RedactMode::Remove => {
+                        self.skipped.push(format!("{} (redacted)", path.display()));
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        fs::create_dir_all(staged.parent().unwrap_or(&self.dir))?;
+        fs::write(&staged, contents)
+            .with_context(|| format!("failed to write {}", staged.display()))?;
+        self.files += 1;
+        Ok(())
+    }
+}
+
+fn build_bundle(dir: &Path, redact: &RedactConfig) -> Result<usize> {
+    let mut bundle = Bundle {
+        dir: dir.to_path_buf(),
+        redact: redact.clone(),
+        files: 0,
+        skipped: Vec::new(),
+    };
+    for pattern in PATTERNS {
+        for path in expand(pattern) {
+            bundle.add(&path)?;
+        }
+    }
+    let info = CaptureInfo {
+        hw_checker_version: env!("CARGO_PKG_VERSION"),
+        captured_at: chrono::Local::now().to_rfc3339(),
+        redacted: redact.enabled.then_some(redact.mode),
+        files: bundle.files,
+        skipped: bundle.skipped,
+    };
+    fs::write(
+        dir.join("capture.json"),
+        serde_json::to_string_pretty(&info)?,
+    )?;
+    Ok(bundle.files)
+}
+
+pub fn run_capture(args: &CaptureArgs, config: &Config) -> Result<()> {
+    if !cfg!(target_os = "linux") {
+        bail!("capture reads sysfs and procfs, which only exist on Linux");
+    }
+    let mut redact = config.redact.clone();
+    if let Some(mode) = args.redact {
+        redact.enabled = true;
+        if let Some(mode) = mode {
+            redact.mode = mode;
+        }
+    }
+
+    let output = args.output.clone().unwrap_or_else(|| {
+        PathBuf::from(format!(
+            "hw-checker-capture-{}.tar.gz",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ))
+    });
+    let file_name = output
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    // The archive holds one directory named like the archive itself
+    let stem = file_name
+        .strip_suffix(".tar.gz")
+        .or_else(|| file_name.strip_suffix(".tgz"))
+        .unwrap_or(&file_name)
+        .to_string();
+    if stem.is_empty() {
+        bail!("invalid output file name '{}'", output.display());
+    }
+
+    let staging = staging_dir()?;
+    let result = (|| {
+        let files = build_bundle(&staging.join(&stem), &redact)?;
+        let status = Command::new("tar")
+            .arg("-czf")
+            .arg(std::path::absolute(&output)?)
+            .arg("-C")
+            .arg(&staging)
+            .arg(&stem)
+            .status()
+            .context("failed to run tar")?;
+        if !status.success() {
+            bail!("tar exited with {}", status);
+        }
+        Ok(files)
+    })();
+    let _ = fs::remove_dir_all(&staging);
+    let files = result?;
+
+    println!(
+        "{} {} ({} {})",
+        t("Wrote"),
+        output.display(),
+        files,
+        t("files")
+    );
+    println!(
+        "{}: tar -xzf {} && hw-checker --full --from-sysfs {}",
+        t("Replay with"),
+        output.display(),
+        stem
+    );
+    Ok(())
+}
+
+/// A new directory under the system temp dir with an unguessable name, so
+/// another local user cannot plant one (or a symlink) there beforehand.
+/// Creation fails rather than reuse anything already at that path.
+fn staging_dir() -> Result<PathBuf> {
+    let mut suffix = [0u8; 16];
+    ring::rand::SecureRandom::fill(&ring::rand::SystemRandom::new(), &mut suffix)
+        .map_err(|_| anyhow::anyhow!("failed to generate a random directory name"))?;
+    let name: String = suffix.iter().map(|byte| format!("{:02x}", byte)).collect();
+    let staging = std::env::temp_dir().join(format!("hw-checker-capture-{}", name));
+
+    let mut builder = fs::DirBuilder::new();
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder
+        .create(&staging)
+        .with_context(|| format!("failed to create {}", staging.display()))?;
+    Ok(staging)
+}
@@ -1,13 +1,32 @@
-use clap::{Parser, ValueEnum};
+use crate::alerts::AlertTarget;
+use crate::i18n::Locale;
+use crate::redact::RedactMode;
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Output format
     #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
     pub format: OutputFormat,
 
+    /// Render the report through a Tera template instead of a built-in format
+    #[arg(long, value_name = "FILE", conflicts_with = "format")]
+    pub template: Option<PathBuf>,
+
+    /// Show how long each discovery step took (`diagnostics.timings` in exports)
+    #[arg(long)]
+    pub timings: bool,
+
+    /// Draw the OS logo next to `--format summary`
+    #[arg(long)]
+    pub logo: bool,
+
     /// Show only CPU info
     #[arg(long)]
     pub cpu: bool,
@@ -16,14 +35,57 @@ pub struct Args {
     #[arg(long)]
     pub ram: bool,
 
+    /// Read the memory modules' SPD EEPROMs for timings, XMP/EXPO profiles and
+    /// the DRAM vendor (needs root and the ee1004 or spd5118 driver)
+    #[arg(long)]
+    pub spd: bool,
+
     /// Show only Storage info
     #[arg(long)]
     pub storage: bool,
 
+    /// Include pseudo filesystems, loop devices and bind mounts in the storage section
+    #[arg(long)]
+    pub all_mounts: bool,
+
+    /// Show disk I/O throughput and IOPS (sampled over one second)
+    #[arg(long)]
+    pub io: bool,
+
+    /// Show the busiest interrupt sources with their rates, the CPUs handling
+    /// them and their affinity (sampled over one second)
+    #[arg(long)]
+    pub irq: bool,
+
+    /// Show whether VT-d/AMD-Vi is on, the IOMMU groups and their devices, and
+    /// which GPUs could be passed through to a VM
+    #[arg(long)]
+    pub iommu: bool,
+
     /// Show only Network info
     #[arg(long)]
     pub network: bool,
 
+    /// Include virtual interfaces (loopback, bridges, bonds, VLANs, veth, tun/tap, WireGuard)
+    #[arg(long)]
+    pub all_interfaces: bool,
+
+    /// Ping the default gateway and a target host and check DNS resolution
+    #[arg(long)]
+    pub connectivity: bool,
+
+    /// Add the cloud instance type, region and ID (AWS, GCP, Azure) to the summary
+    #[arg(long)]
+    pub cloud: bool,
+
+    /// Show open files, PIDs and other kernel limits against current usage
+    #[arg(long)]
+    pub limits: bool,
+
+    /// Show the clock source, NTP synchronization and RTC drift
+    #[arg(long)]
+    pub time: bool,
+
     /// Show only USB devices
     #[arg(long)]
     pub usb: bool,
@@ -32,14 +94,33 @@ pub struct Args {
     #[arg(long)]
     pub pci: bool,
 
+    /// Show GPUs and the processes using them, with their VRAM
+    #[arg(long)]
+    pub gpu: bool,
+
     /// Show System Health (Motherboard, BIOS, Battery)
     #[arg(long)]
     pub health: bool,
 
-    /// Path to configuration file (YAML)
+    /// Show the expansion slot map: each slot's generation and width, what is
+    /// installed in it and which slots are free
+    #[arg(long)]
+    pub slots: bool,
+
+    /// Path to configuration file (YAML, or TOML if it ends in .toml); defaults to
+    /// $XDG_CONFIG_HOME/hw-checker/config.{toml,yaml}, then /etc/hw-checker/config.{toml,yaml}
     #[arg(short, long)]
     pub config: Option<PathBuf>,
 
+    /// Config profile to apply on top of the base settings, e.g. `laptop` (see `profiles:`)
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Override a config key, e.g. `--set cpu_thresholds.warning=80`; repeatable.
+    /// Wins over HW_CHECKER_* variables (`HW_CHECKER_CPU_THRESHOLDS__WARNING=80`) and the file
+    #[arg(long = "set", value_name = "KEY=VALUE")]
+    pub set: Vec<String>,
+
     /// Show full information (all filters enabled)
     #[arg(long, aliases = ["all"])]
     pub full: bool,
@@ -47,6 +128,65 @@ pub struct Args {
     /// Launch interactive Terminal User Interface (TUI)
     #[arg(long)]
     pub tui: bool,
+
+    /// TUI refresh interval in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 1000, value_parser = clap::value_parser!(u64).range(100..))]
+    pub interval: u64,
+
+    /// Language for labels and headers (defaults to config, then LANG)
+    #[arg(long, value_enum)]
+    pub lang: Option<Locale>,
+
+    /// Hash (default) or remove serial numbers, MAC addresses and the hostname in all output
+    #[arg(long, value_enum, value_name = "MODE", num_args = 0..=1)]
+    pub redact: Option<Option<RedactMode>>,
+
+    /// Read sysfs/procfs from a captured tree instead of the live system (Linux)
+    #[arg(long, value_name = "DIR")]
+    pub from_sysfs: Option<PathBuf>,
+
+    /// Use the report of a running `hw-checker daemon` instead of collecting one
+    /// (also for `get`); `--connect=PATH` for a socket other than the default
+    #[arg(long, value_name = "SOCKET", num_args = 0..=1, require_equals = true)]
+    pub connect: Option<Option<PathBuf>>,
+
+    /// Re-run under sudo when some data needs root (SMBIOS RAM details, USB names)
+    #[arg(long)]
+    pub elevate: bool,
+
+    /// Compare the hardware with the last `--audit` run and log what changed
+    /// (read back with `audit log`)
+    #[arg(long)]
+    pub audit: bool,
+
+    /// Sign json/yaml output with an ed25519 private key (PKCS#8 PEM); check with `verify-report`
+    #[arg(long, value_name = "KEY")]
+    pub sign: Option<PathBuf>,
+
+    /// Asset tag recorded in the report (overrides `asset.asset_tag` in the config)
+    #[arg(long)]
+    pub asset_tag: Option<String>,
+
+    /// Location recorded in the report, e.g. a rack or room
+    #[arg(long)]
+    pub location: Option<String>,
+
+    /// Owner recorded in the report
+    #[arg(long)]
+    pub owner: Option<String>,
+
+    /// Custom metadata recorded in the report, e.g. `--meta cost_center=R&D` (repeatable)
+    #[arg(long, value_name = "KEY=VALUE", value_parser = parse_key_value)]
+    pub meta: Vec<(String, String)>,
+}
+
+fn parse_key_value(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_string(), value.to_string()))
+        }
+        _ => Err(format!("expected KEY=VALUE, got '{}'", s)),
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
@@ -54,4 +194,479 @@ pub enum OutputFormat {
     Table,
     Json,
     Yaml,
+    Html,
+    /// InfluxDB line protocol, e.g. for telegraf `exec`
+    Influx,
+    /// Flat `key=value` lines with Zabbix item keys (`hw.fs.pused[/]=42.10`)
+    Kv,
+    /// `HW_CPU_MODEL="..."` shell variables for `eval`; section flags pick which are set
+    Env,
+    /// Zabbix low-level discovery JSON for cpus, filesystems, interfaces and sensors
+    ZabbixLld,
+    /// The node tree of `lshw -json`, for tools written against lshw
+    LshwJson,
+    /// Condensed per-device text in the layout of `inxi -b`
+    Inxi,
+    /// One-screen neofetch-style overview (host, CPU, RAM, GPU, disks)
+    Summary,
+    /// Elements mirroring the JSON report, for asset databases that only ingest XML
+    Xml,
+    /// MessagePack map of the JSON report (binary; redirect to a file)
+    Msgpack,
+    /// CBOR map of the JSON report (binary; redirect to a file)
+    Cbor,
+    /// Paginated A4 document with an acceptance block (binary; redirect to a file)
+    Pdf,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Watch udev hotplug events (device added/removed) and print them as they happen (Linux only)
+    Monitor(MonitorArgs),
+    /// Print CPU, RAM, temperature and network metrics every interval
+    Watch(WatchArgs),
+    /// Publish metrics to an MQTT broker every interval, with Home Assistant discovery
+    Mqtt(MqttArgs),
+    /// Serve CPU, RAM, disk and temperature metrics to snmpd as an AgentX subagent
+    #[cfg(feature = "snmp")]
+    Snmp(SnmpArgs),
+    /// Collect a report from remote hosts over SSH and render it locally
+    Remote(RemoteArgs),
+    /// Summarize a directory of saved JSON reports: CPU models, RAM, full disks, BIOS spread, outliers
+    Fleet(FleetArgs),
+    /// Check this machine against a requirements.yaml spec; exits 1 if any rule fails
+    Verify(VerifyArgs),
+    /// Check the signature of a report exported with --sign; exits 1 if it was modified
+    VerifyReport(VerifyReportArgs),
+    /// Burn-in test: load the CPU (and optionally memory) while watching temperatures and clocks
+    Stress(StressArgs),
+    /// Field checks, e.g. `validate usb` for storage devices stuck at a slower USB speed
+    Validate(ValidateArgs),
+    /// Bundle the sysfs/procfs/DMI files discovery reads into a tarball for bug reports
+    Capture(CaptureArgs),
+    /// Write a commented default config (`config init`) or show which config file is used
+    Config(ConfigArgs),
+    /// Benchmarks, e.g. `bench net --server` / `bench net --client <HOST>`
+    Bench(BenchArgs),
+    /// Sample metrics once, report threshold breaches, and exit with 1 (warning) or 2 (critical)
+    Check(CheckArgs),
+    /// Sample CPU, RAM, disk usage, temperatures and network counters into a SQLite database
+    Record(RecordArgs),
+    /// Show min/avg/max and trends of recorded metrics
+    History(HistoryArgs),
+    /// Plot recorded metrics as terminal charts, e.g. `graph cpu --since 6h`
+    Graph(GraphArgs),
+    /// Serve recorded metrics to Grafana's JSON datasource plugin (/search, /query, /annotations)
+    Grafana(GrafanaArgs),
+    /// Print single report fields by dotted path, e.g. `get cpu.0.model ram.total`
+    Get(GetArgs),
+    /// Hardware changes recorded by `--audit` runs, e.g. `audit log --since 30d`
+    Audit(AuditArgs),
+    /// Run `check` on a systemd timer (or cron) every interval, or `record` as a service
+    InstallService(InstallServiceArgs),
+    /// Keep a report in memory and answer `--connect` queries on a Unix socket
+    Daemon(DaemonArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct MonitorArgs {
+    /// Only show events from this subsystem (e.g. usb, block, net); repeatable
+    #[arg(long, value_name = "NAME")]
+    pub subsystem: Vec<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = StreamFormat::Table)]
+    pub format: StreamFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct WatchArgs {
+    /// Sampling interval, e.g. 500ms, 5s or 1m
+    #[arg(long, value_name = "DURATION", default_value = "1s", value_parser = humantime::parse_duration)]
+    pub interval: Duration,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = StreamFormat::Table)]
+    pub format: StreamFormat,
+
+    /// Also send threshold crossings here (added to `alerts.targets` in the config); repeatable
+    #[arg(long, value_enum, value_name = "TARGET")]
+    pub alert_target: Vec<AlertTarget>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct MqttArgs {
+    /// Broker host name or address (defaults to `mqtt.broker` in the config)
+    #[arg(long, value_name = "HOST")]
+    pub broker: Option<String>,
+
+    /// Broker port (defaults to `mqtt.port`, 1883)
+    #[arg(long)]
+    pub port: Option<u16>,
+
+    /// Publishing interval, e.g. 10s or 1m (defaults to `mqtt.interval_secs`, 30s)
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub interval: Option<Duration>,
+
+    /// Do not publish Home Assistant discovery payloads
+    #[arg(long)]
+    pub no_discovery: bool,
+}
+
+#[cfg(feature = "snmp")]
+#[derive(clap::Args, Debug)]
+pub struct SnmpArgs {
+    /// AgentX master socket path or tcp:host:port (defaults to `snmp.master`, /var/agentx/master)
+    #[arg(long, value_name = "SOCKET")]
+    pub master: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct RemoteArgs {
+    /// Host to collect from, as given to ssh ([user@]host or an ssh_config alias)
+    #[arg(required_unless_present = "hosts", conflicts_with = "hosts")]
+    pub host: Option<String>,
+
+    /// File with one host per line (`#` starts a comment); hosts are queried in parallel
+    #[arg(long, value_name = "FILE")]
+    pub hosts: Option<PathBuf>,
+
+    /// Copy a binary to the remote host for the run (this one, or e.g. a static musl build)
+    #[arg(long, value_name = "BINARY", num_args = 0..=1)]
+    pub copy: Option<Option<PathBuf>>,
+
+    /// Path of an installed hw-checker on the remote host
+    #[arg(long, value_name = "PATH", default_value = "hw-checker")]
+    pub remote_path: String,
+
+    /// Run the remote side through `sudo -n` for RAM/DMI details
+    #[arg(long)]
+    pub sudo: bool,
+
+    /// Extra ssh option, e.g. `--ssh-option Port=2222` (repeatable)
+    #[arg(long, value_name = "OPTION")]
+    pub ssh_option: Vec<String>,
+
+    /// Maximum number of hosts queried at once
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u16).range(1..))]
+    pub parallel: u16,
+
+    /// Also save each report as `<DIR>/<host>.json`
+    #[arg(long, value_name = "DIR")]
+    pub save_dir: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct FleetArgs {
+    /// Directory of reports saved with `--format json` (e.g. by `remote --save-dir`)
+    pub dir: PathBuf,
+
+    /// Write the result to a .csv (one row per host) or .html file instead of printing it
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct VerifyArgs {
+    /// Requirements spec (min_cores, min_threads, min_ram_gib, cpu_flags, disks, min_gpu_vram_gib)
+    pub spec: PathBuf,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct VerifyReportArgs {
+    /// Signed JSON or YAML report
+    pub file: PathBuf,
+
+    /// Require this ed25519 public key (PEM, e.g. from `openssl pkey -pubout`)
+    #[arg(long)]
+    pub key: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct StressArgs {
+    /// Load every CPU with floating-point work (the default when no load is chosen)
+    #[arg(long)]
+    pub cpu: bool,
+
+    /// Also stress memory bandwidth with large buffer copies
+    #[arg(long)]
+    pub memory: bool,
+
+    /// How long to run, e.g. 30s, 10m, 1h
+    #[arg(long, default_value = "1m", value_parser = humantime::parse_duration)]
+    pub duration: Duration,
+
+    /// Number of CPU load threads (defaults to the number of logical CPUs)
+    #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+    pub threads: Option<u16>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ValidateArgs {
+    #[command(subcommand)]
+    pub command: ValidateCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ValidateCommand {
+    /// Compare each USB mass-storage device's negotiated speed with what the port and device support
+    Usb,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct CaptureArgs {
+    /// Archive to write (default: hw-checker-capture-<timestamp>.tar.gz)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Hash (default) or remove serial numbers, UUIDs, MAC addresses and the hostname
+    #[arg(long, value_enum, value_name = "MODE", num_args = 0..=1)]
+    pub redact: Option<Option<RedactMode>>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Write the default config, with every option documented
+    Init(ConfigInitArgs),
+    /// Check the config (with --profile, or else the base settings and every profile); exits 1 on errors
+    Validate,
+    /// Print the effective config after profiles, HW_CHECKER_* variables and --set
+    Show,
+    /// Print the config file loaded when --config is not given
+    Path,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct ConfigInitArgs {
+    /// Where to write it (default: $XDG_CONFIG_HOME/hw-checker/config.yaml); TOML if it ends in .toml
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Overwrite an existing file
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct BenchArgs {
+    #[command(subcommand)]
+    pub command: BenchCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum BenchCommand {
+    /// TCP throughput between two hosts running hw-checker (iperf-like)
+    Net(NetBenchArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct NetBenchArgs {
+    /// Wait for clients (runs until interrupted)
+    #[arg(long, conflicts_with = "client")]
+    pub server: bool,
+
+    /// Measure throughput to a host running `bench net --server`
+    #[arg(long, value_name = "HOST")]
+    pub client: Option<String>,
+
+    /// TCP port
+    #[arg(long, default_value_t = 5299)]
+    pub port: u16,
+
+    /// Address the server listens on; use 0.0.0.0 to accept clients from other hosts
+    #[arg(long, default_value = "127.0.0.1", requires = "server")]
+    pub bind: String,
+
+    /// Length of the test (servers cut tests off after 10m)
+    #[arg(long, default_value = "10s", value_parser = humantime::parse_duration)]
+    pub duration: Duration,
+
+    /// Number of parallel TCP streams
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..=128))]
+    pub streams: u16,
+
+    /// Measure server-to-client (download) instead of client-to-server
+    #[arg(short = 'R', long)]
+    pub reverse: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct CheckArgs {
+    /// Also send threshold breaches here (added to `alerts.targets` in the config); repeatable
+    #[arg(long, value_enum, value_name = "TARGET")]
+    pub alert_target: Vec<AlertTarget>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum StreamFormat {
+    Table,
+    /// One JSON object per line
+    Jsonl,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct RecordArgs {
+    /// SQLite database to write to (defaults to `record.database` in the config)
+    #[arg(long, value_name = "PATH")]
+    pub database: Option<PathBuf>,
+
+    /// Sampling interval, e.g. 30s or 5m (defaults to `record.interval_secs` in the config)
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub interval: Option<Duration>,
+
+    /// Also print every sample to stdout in this format
+    #[arg(short, long, value_enum)]
+    pub format: Option<StreamFormat>,
+
+    /// Also send threshold crossings here (added to `alerts.targets` in the config); repeatable
+    #[arg(long, value_enum, value_name = "TARGET")]
+    pub alert_target: Vec<AlertTarget>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct HistoryArgs {
+    /// SQLite database to read from (defaults to `record.database` in the config)
+    #[arg(long, value_name = "PATH")]
+    pub database: Option<PathBuf>,
+
+    /// How far back to look, e.g. 30m, 24h or 7d
+    #[arg(long, value_name = "DURATION", default_value = "24h", value_parser = humantime::parse_duration)]
+    pub since: Duration,
+
+    /// Only show metrics whose name starts with this prefix (e.g. cpu, temp, disk:/home)
+    #[arg(long, value_name = "PREFIX")]
+    pub metric: Option<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = HistoryFormat::Table)]
+    pub format: HistoryFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct GraphArgs {
+    /// Metric name or prefix: cpu, ram, swap, disk, temp, net, or a full name like disk:/home
+    pub metric: String,
+
+    /// SQLite database to read from (defaults to `record.database` in the config)
+    #[arg(long, value_name = "PATH")]
+    pub database: Option<PathBuf>,
+
+    /// How far back to plot, e.g. 30m, 6h or 7d
+    #[arg(long, value_name = "DURATION", default_value = "6h", value_parser = humantime::parse_duration)]
+    pub since: Duration,
+
+    /// Chart width in columns, including the axis labels (defaults to the terminal width)
+    #[arg(long, value_parser = clap::value_parser!(u16).range(20..))]
+    pub width: Option<u16>,
+
+    /// Chart height in rows
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u16).range(2..))]
+    pub height: u16,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct GrafanaArgs {
+    /// Address and port to listen on; use 0.0.0.0 to reach it from another host
+    #[arg(long, value_name = "ADDR", default_value = "127.0.0.1:3003")]
+    pub bind: String,
+
+    /// SQLite database to read from (defaults to `record.database` in the config)
+    #[arg(long, value_name = "PATH")]
+    pub database: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct GetArgs {
+    /// Field paths into the JSON report; array items by index, e.g. `disks.0.serial_number`
+    #[arg(required = true, value_name = "PATH")]
+    pub paths: Vec<String>,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct AuditArgs {
+    #[command(subcommand)]
+    pub command: AuditCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AuditCommand {
+    /// Print the change log, oldest first
+    Log(AuditLogArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub struct AuditLogArgs {
+    /// Only changes from this far back, e.g. 24h or 30d
+    #[arg(long, value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub since: Option<Duration>,
+
+    /// Only this component: cpu, memory, disk, motherboard, network, pci or gpu
+    #[arg(long, value_name = "NAME")]
+    pub component: Option<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = StreamFormat::Table)]
+    pub format: StreamFormat,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct InstallServiceArgs {
+    /// How often to run `check`, or the sampling interval of `record`
+    #[arg(long, value_name = "DURATION", default_value = "15m", value_parser = humantime::parse_duration)]
+    pub interval: Duration,
+
+    /// What to run
+    #[arg(long, value_enum, default_value_t = ServiceCommand::Check)]
+    pub command: ServiceCommand,
+
+    /// Write a cron entry (/etc/cron.d) instead of systemd units
+    #[arg(long)]
+    pub cron: bool,
+
+    /// A systemd user unit (~/.config/systemd/user) instead of a system one
+    #[arg(long)]
+    pub user: bool,
+
+    /// Write the units but leave enabling them to you
+    #[arg(long)]
+    pub no_enable: bool,
+
+    /// Print what would be written instead of writing it
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct DaemonArgs {
+    /// Socket to listen on (defaults to $XDG_RUNTIME_DIR/hw-checker.sock)
+    #[arg(long, value_name = "PATH")]
+    pub socket: Option<PathBuf>,
+
+    /// How often the report is collected again
+    #[arg(long, value_name = "DURATION", default_value = "5m", value_parser = humantime::parse_duration)]
+    pub refresh: Duration,
+
+    /// How often metrics are checked against the thresholds for `SUBSCRIBE alerts`
+    #[arg(long, value_name = "DURATION", default_value = "10s", value_parser = humantime::parse_duration)]
+    pub interval: Duration,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum ServiceCommand {
+    /// Threshold check every interval, alerting through `alerts.targets`
+    Check,
+    /// Metrics recorder for `history` and `graph`
+    Record,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum, Debug)]
+pub enum HistoryFormat {
+    Table,
+    Json,
 }
@@ -0,0 +1,195 @@
+//! `--cloud`: instance type, region and ID from the AWS, GCP or Azure
+//! instance metadata service, asked only when the DMI tables name the
+//! provider so bare-metal machines never wait on a link-local timeout.
+
+use crate::model::{CloudInstance, CloudProvider};
+use serde_json::Value;
+use std::time::Duration;
+
+const METADATA_HOST: &str = "http://169.254.169.254";
+const TIMEOUT: Duration = Duration::from_secs(2);
+// Chassis asset tag Hyper-V sets on every Azure VM
+const AZURE_ASSET_TAG: &str = "7783-7084-3265-9085-8269-3286-77";
+
+/// The provider named by DMI system/BIOS vendor, product or asset tag
+/// strings: "Amazon EC2" (or "4.11.amazon" BIOS versions on older Xen
+/// instances), "Google Compute Engine", or Azure's chassis asset tag.
+pub fn provider_from_dmi(fields: &[&str]) -> Option<CloudProvider> {
+    let has = |needle: &str| fields.iter().any(|f| f.to_lowercase().contains(needle));
+    if has("amazon") {
+        Some(CloudProvider::Aws)
+    } else if has("google") {
+        Some(CloudProvider::Gcp)
+    } else if fields.contains(&AZURE_ASSET_TAG) {
+        Some(CloudProvider::Azure)
+    } else {
+        None
+    }
+}
+
+pub fn query_instance(provider: CloudProvider) -> CloudInstance {
+    let agent = ureq::Agent::new_with_config(
+        ureq::Agent::config_builder()
+            .timeout_global(Some(TIMEOUT))
+            // The metadata service is link-local; an HTTP_PROXY cannot reach it
+            .proxy(None)
+            .build(),
+    );
+    let mut instance = CloudInstance::unknown(provider);
+    match provider {
+        CloudProvider::Aws => {
+            // IMDSv2 wants a session token; IMDSv1-only instances answer without one
+            let token = agent
+                .put(format!("{}/latest/api/token", METADATA_HOST))
+                .header("X-aws-ec2-metadata-token-ttl-seconds", "60")
+                .send_empty()
+                .and_then(|mut r| r.body_mut().read_to_string())
+                .ok();
+            let get = |path: &str| {
+                let mut request = agent.get(format!("{}/latest/meta-data/{}", METADATA_HOST, path));
+                if let Some(token) = &token {
+                    request = request.header("X-aws-ec2-metadata-token", token);
+                }
+                request
+                    .call()
+                    .and_then(|mut r| r.body_mut().read_to_string())
+                    .ok()
+            };
+            instance.instance_id = get("instance-id");
+            instance.instance_type = get("instance-type");
+            instance.region = get("placement/region");
+            instance.zone = get("placement/availability-zone");
+        }
+        CloudProvider::Gcp => {
+            let get = |path: &str| {
+                agent
+                    .get(format!(
+                        "{}/computeMetadata/v1/instance/{}",
+                        METADATA_HOST, path
+                    ))
+                    .header("Metadata-Flavor", "Google")
+                    .call()
+                    .and_then(|mut r| r.body_mut().read_to_string())
+                    .ok()
+            };
+            instance = gcp_instance(get("id"), get("machine-type"), get("zone"));
+        }
+        CloudProvider::Azure => {
+            let body = agent
+                .get(format!(
+                    "{}/metadata/instance/compute?api-version=2021-02-01",
+                    METADATA_HOST
+                ))
+                .header("Metadata", "true")
+                .call()
+                .and_then(|mut r| r.body_mut().read_to_string());
+            if let Ok(body) = body {
+                instance = azure_instance(&body);
+            }
+        }
+    }
+    instance
+}
+
+/// GCP answers with full resource paths: "projects/123/machineTypes/e2-medium"
+/// and "projects/123/zones/us-central1-a".
+fn gcp_instance(
+    id: Option<String>,
+    machine_type: Option<String>,
+    zone: Option<String>,
+) -> CloudInstance {
+    let last = |value: String| value.rsplit('/').next().map(str::to_string);
+    let zone = zone.and_then(last);
+    CloudInstance {
+        provider: CloudProvider::Gcp,
+        instance_type: machine_type.and_then(last),
+        region: zone
+            .as_deref()
+            .and_then(|zone| zone.rsplit_once('-'))
+            .map(|(region, _)| region.to_string()),
+        zone,
+        instance_id: id,
+    }
+}
+
+/// The `compute` object of the Azure instance metadata document.
+fn azure_instance(body: &str) -> CloudInstance {
+    let mut instance = CloudInstance::unknown(CloudProvider::Azure);
+    let Ok(compute) = serde_json::from_str::<Value>(body) else {
+        return instance;
+    };
+    let field = |key: &str| {
+        compute[key]
+            .as_str()
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+    instance.instance_id = field("vmId");
+    instance.instance_type = field("vmSize");
+    instance.region = field("location");
+    instance.zone = field("zone");
+    instance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provider_from_dmi_strings() {
+        assert_eq!(
+            provider_from_dmi(&["Amazon EC2", "m6i.large"]),
+            Some(CloudProvider::Aws)
+        );
+        assert_eq!(
+            provider_from_dmi(&["Xen", "HVM domU", "4.11.amazon"]),
+            Some(CloudProvider::Aws)
+        );
+        assert_eq!(
+            provider_from_dmi(&["Google", "Google Compute Engine"]),
+            Some(CloudProvider::Gcp)
+        );
+        assert_eq!(
+            provider_from_dmi(&["Microsoft Corporation", "Virtual Machine", AZURE_ASSET_TAG]),
+            Some(CloudProvider::Azure)
+        );
+        // Plain Hyper-V is not Azure
+        assert_eq!(
+            provider_from_dmi(&["Microsoft Corporation", "Virtual Machine"]),
+            None
+        );
+        assert_eq!(provider_from_dmi(&["Dell Inc.", "PowerEdge R740"]), None);
+    }
+
+    #[test]
+    fn gcp_resource_paths() {
+        let instance = gcp_instance(
+            Some("4520006452781813313".to_string()),
+            Some("projects/123/machineTypes/e2-standard-4".to_string()),
+            Some("projects/123/zones/europe-west1-b".to_string()),
+        );
+        assert_eq!(instance.instance_type.as_deref(), Some("e2-standard-4"));
+        assert_eq!(instance.zone.as_deref(), Some("europe-west1-b"));
+        assert_eq!(instance.region.as_deref(), Some("europe-west1"));
+        assert_eq!(instance.instance_id.as_deref(), Some("4520006452781813313"));
+    }
+
+    #[test]
+    fn azure_compute_document() {
+        let instance = azure_instance(
+            r#"{"vmId": "02aab8a4-74ef-476e-8182-f6d2ba4166a6", "vmSize": "Standard_D4s_v5",
+                "location": "westeurope", "zone": "", "name": "web-1"}"#,
+        );
+        assert_eq!(instance.provider, CloudProvider::Azure);
+        assert_eq!(instance.instance_type.as_deref(), Some("Standard_D4s_v5"));
+        assert_eq!(instance.region.as_deref(), Some("westeurope"));
+        assert_eq!(instance.zone, None);
+        assert_eq!(
+            instance.instance_id.as_deref(),
+            Some("02aab8a4-74ef-476e-8182-f6d2ba4166a6")
+        );
+
+        let instance = azure_instance("<html>blocked</html>");
+        assert!(instance.instance_type.is_none());
+    }
+}
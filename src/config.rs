@@ -1,8 +1,25 @@
-use anyhow::Result;
+use crate::alerts::AlertsConfig;
+use crate::audit::AuditConfig;
+use crate::cli::{ConfigArgs, ConfigCommand};
+use crate::connectivity::ConnectivityConfig;
+use crate::i18n::{Locale, t};
+use crate::model::AssetInfo;
+use crate::mounts::MountsConfig;
+use crate::mqtt::MqttConfig;
+use crate::plugins::{CollectorConfig, PluginConfig};
+use crate::redact::RedactConfig;
+#[cfg(feature = "snmp")]
+use crate::snmp::SnmpConfig;
+use crate::theme::ThemeConfig;
+use anyhow::{Context, Result, anyhow, bail};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use serde_yaml::{Mapping, Value};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
 pub struct Thresholds {
     pub warning: f32,
     pub critical: f32,
@@ -17,23 +34,983 @@ impl Default for Thresholds {
     }
 }
 
+/// The `record:` config section used by `hw-checker record` and `history`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct RecordConfig {
+    /// SQLite database path (defaults to the user data directory).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<PathBuf>,
+    #[serde(default = "RecordConfig::default_interval")]
+    pub interval_secs: u64,
+    /// Samples older than this are pruned while recording; 0 keeps everything.
+    #[serde(default = "RecordConfig::default_retention")]
+    pub retention_days: u64,
+}
+
+impl RecordConfig {
+    fn default_interval() -> u64 {
+        60
+    }
+
+    fn default_retention() -> u64 {
+        30
+    }
+}
+
+impl Default for RecordConfig {
+    fn default() -> Self {
+        Self {
+            database: None,
+            interval_secs: Self::default_interval(),
+            retention_days: Self::default_retention(),
+        }
+    }
+}
+
+/// The `battery:` config section. Unlike the usage thresholds, charge levels
+/// are lower limits: a battery below `warning`/`critical` percent is flagged.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BatteryConfig {
+    #[serde(default = "BatteryConfig::default_warning")]
+    pub warning: u8,
+    #[serde(default = "BatteryConfig::default_critical")]
+    pub critical: u8,
+    /// Wear (capacity lost against the design capacity) above this is a warning.
+    #[serde(default = "BatteryConfig::default_max_wear")]
+    pub max_wear: f32,
+}
+
+impl BatteryConfig {
+    fn default_warning() -> u8 {
+        50
+    }
+
+    fn default_critical() -> u8 {
+        20
+    }
+
+    fn default_max_wear() -> f32 {
+        20.0
+    }
+}
+
+impl Default for BatteryConfig {
+    fn default() -> Self {
+        Self {
+            warning: Self::default_warning(),
+            critical: Self::default_critical(),
+            max_wear: Self::default_max_wear(),
+        }
+    }
+}
+
+/// The `pressure:` config section: limits on the share of the last 10
+/// seconds in which some tasks stalled waiting for CPU, memory or I/O (PSI).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields, default)]
+pub struct PressureConfig {
+    pub cpu: Thresholds,
+    pub memory: Thresholds,
+    pub io: Thresholds,
+}
+
+impl Default for PressureConfig {
+    fn default() -> Self {
+        let thresholds = Thresholds {
+            warning: 10.0,
+            critical: 40.0,
+        };
+        Self {
+            cpu: thresholds.clone(),
+            memory: thresholds.clone(),
+            io: thresholds,
+        }
+    }
+}
+
+impl PressureConfig {
+    /// Thresholds for a [`Pressure`](crate::model::Pressure) resource name.
+    pub fn thresholds(&self, resource: &str) -> &Thresholds {
+        match resource {
+            "cpu" => &self.cpu,
+            "memory" => &self.memory,
+            _ => &self.io,
+        }
+    }
+}
+
+/// The `sections:` config map: which probes run and which sections are shown
+/// by default. A section asked for on the command line (e.g. `--usb`) still runs.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields, default)]
+pub struct Sections {
+    pub cpu: bool,
+    pub ram: bool,
+    pub storage: bool,
+    pub network: bool,
+    pub usb: bool,
+    pub thunderbolt: bool,
+    pub pci: bool,
+    pub gpu: bool,
+    /// Open files, PIDs and other kernel limits against current usage
+    pub limits: bool,
+    /// Clock source, NTP sync and RTC drift
+    pub time: bool,
+    pub motherboard: bool,
+    pub battery: bool,
+    /// Temperatures and fans
+    pub sensors: bool,
+}
+
+impl Default for Sections {
+    fn default() -> Self {
+        Self {
+            cpu: true,
+            ram: true,
+            storage: true,
+            network: true,
+            usb: true,
+            thunderbolt: true,
+            pci: true,
+            gpu: true,
+            limits: true,
+            time: true,
+            motherboard: true,
+            battery: true,
+            sensors: true,
+        }
+    }
+}
+
+/// The `probe_timeouts:` config section: how long a discovery step may run
+/// before its section is left empty and listed under `diagnostics.timed_out`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields, default)]
+pub struct ProbeTimeouts {
+    /// Seconds for every step; 0 waits forever
+    pub default_secs: u64,
+    /// Per-step overrides, keyed by the report field the step fills
+    pub steps: BTreeMap<String, u64>,
+}
+
+impl Default for ProbeTimeouts {
+    fn default() -> Self {
+        Self {
+            default_secs: 15,
+            steps: BTreeMap::new(),
+        }
+    }
+}
+
+impl ProbeTimeouts {
+    pub fn for_step(&self, step: &str) -> Option<Duration> {
+        let secs = self.steps.get(step).copied().unwrap_or(self.default_secs);
+        (secs > 0).then(|| Duration::from_secs(secs))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
     pub cpu_thresholds: Thresholds,
     pub ram_thresholds: Thresholds,
     pub storage_thresholds: Thresholds,
+    /// Share of a filesystem's inodes in use
+    #[serde(default)]
+    pub inode_thresholds: Thresholds,
+    #[serde(default)]
+    pub temperature_thresholds: Thresholds,
+    #[serde(default)]
+    pub battery: BatteryConfig,
+    #[serde(default)]
+    pub pressure: PressureConfig,
+    #[serde(default)]
+    pub sections: Sections,
+    #[serde(default)]
+    pub probe_timeouts: ProbeTimeouts,
+    #[serde(default)]
+    pub mounts: MountsConfig,
+    /// Show bridges, bonds, VLANs, veth/tun/tap, WireGuard and loopback
+    /// interfaces in the network section (also `--all-interfaces`).
+    #[serde(default)]
+    pub all_interfaces: bool,
+    #[serde(default)]
+    pub connectivity: ConnectivityConfig,
+    /// Ask the cloud instance metadata service for the instance type, region
+    /// and ID when the firmware names AWS, GCP or Azure (also `--cloud`).
+    #[serde(default)]
+    pub cloud: bool,
+    #[serde(default)]
+    pub locale: Option<Locale>,
+    #[serde(default)]
+    pub theme: ThemeConfig,
+    #[serde(default)]
+    pub record: RecordConfig,
+    #[serde(default)]
+    pub alerts: AlertsConfig,
+    #[serde(default)]
+    pub mqtt: MqttConfig,
+    #[serde(default)]
+    pub asset: AssetInfo,
+    #[serde(default)]
+    pub redact: RedactConfig,
+    #[serde(default)]
+    pub audit: AuditConfig,
+    #[serde(default)]
+    pub plugins: Vec<PluginConfig>,
+    #[serde(default)]
+    pub collectors: BTreeMap<String, CollectorConfig>,
+    #[cfg(feature = "snmp")]
+    #[serde(default)]
+    pub snmp: SnmpConfig,
+}
+
+/// Written by `hw-checker config init`: the built-in defaults, with every
+/// optional section present but commented out.
+pub const DEFAULT_CONFIG: &str = r##"# hw-checker configuration
+#
+# Read from --config, or else from $XDG_CONFIG_HOME/hw-checker/config.yaml
+# (~/.config/hw-checker/config.yaml) and then /etc/hw-checker/config.yaml.
+# A config.toml next to either is read instead when present.
+# Any key can be overridden with HW_CHECKER_* variables (HW_CHECKER_CPU_THRESHOLDS__WARNING=80)
+# and, above those, with --set cpu_thresholds.warning=80.
+
+# Usage percentages at which values turn yellow (warning) and red (critical).
+cpu_thresholds:
+  warning: 70.0
+  critical: 90.0
+ram_thresholds:
+  warning: 70.0
+  critical: 90.0
+storage_thresholds:
+  warning: 70.0
+  critical: 90.0
+# Inodes in use; a filesystem full of small files runs out of these first.
+inode_thresholds:
+  warning: 70.0
+  critical: 90.0
+# Degrees Celsius.
+temperature_thresholds:
+  warning: 70.0
+  critical: 90.0
+
+# Battery charge levels (percent) below which a battery is flagged, and the
+# wear against its design capacity above which it is due for replacement.
+battery:
+  warning: 50
+  critical: 20
+  max_wear: 20.0
+
+# Pressure stall information (Linux): percent of the last 10 seconds in which
+# some tasks waited for CPU, memory or I/O. Unlike usage, any sustained
+# memory or I/O stall means work is being delayed.
+pressure:
+  cpu:
+    warning: 10.0
+    critical: 40.0
+  memory:
+    warning: 10.0
+    critical: 40.0
+  io:
+    warning: 10.0
+    critical: 40.0
+
+# Probes to run and sections to show by default; a section requested with a
+# flag such as --usb still runs. `sensors` covers temperatures and fans.
+# sections:
+#   cpu: true
+#   ram: true
+#   storage: true
+#   network: true
+#   usb: false
+#   thunderbolt: false
+#   pci: true
+#   gpu: true
+#   limits: true
+#   time: true
+#   motherboard: true
+#   battery: false
+#   sensors: true
+
+# Seconds a discovery step may take before it is abandoned, so a hung USB
+# device or NVMe admin command cannot stall the report. The section is left
+# empty and named under `diagnostics.timed_out`. Steps are named after report
+# fields (system, os_details, cpu, ram, disks, filesystems, network, usb, pci,
+# gpus, motherboard, battery, temperatures, ...); 0 waits forever.
+# probe_timeouts:
+#   default_secs: 15
+#   steps:
+#     disks: 60
+
+# Filesystems left out of the storage section unless --all-mounts is given:
+# pseudo filesystems, loop devices (snap packages) and repeated mounts of the
+# same device (bind mounts), which are shown once under the shortest path.
+# mounts:
+#   all: false
+#   hide_types: [tmpfs, devtmpfs, ramfs, overlay, squashfs, fuse.snapfuse, autofs, devfs, fdescfs, nullfs]
+#   hide_loop: true
+#   collapse_binds: true
+
+# Virtual network interfaces (loopback, bridges, bonds, VLANs, veth, tun/tap,
+# WireGuard) are left out of the network table unless this or
+# --all-interfaces is set.
+# all_interfaces: false
+
+# What --connectivity pings (besides the default gateway) and resolves.
+# connectivity:
+#   target: 1.1.1.1
+#   dns_name: example.com
+#   count: 3
+
+# On AWS, GCP and Azure VMs, add the instance type, region and instance ID from
+# the instance metadata service to the System Summary (also --cloud).
+# cloud: false
+
+# Label language (en, vi); defaults to LANG.
+# locale: en
+
+# Colors for the TUI and the CLI tables: a preset (dark, light, solarized) plus
+# per-role overrides as ANSI names or #rrggbb.
+# theme:
+#   preset: dark
+#   critical: "#ff0000"
+
+# `hw-checker record` / `history` / `graph`.
+# record:
+#   database: /var/lib/hw-checker/history.db   # default: $XDG_DATA_HOME/hw-checker/history.db
+#   interval_secs: 60
+#   retention_days: 30                         # 0 keeps everything
+
+# Where `watch`, `record` and `check` send threshold crossings.
+# alerts:
+#   targets: [syslog, webhook, email]
+#   webhooks:
+#     - url: https://hooks.slack.com/services/...
+#       format: slack                          # json, slack, discord
+#   email:
+#     server: smtp.example.com
+#     port: 587
+#     security: starttls                       # starttls, tls, none
+#     from: hw-checker@example.com
+#     to: [ops@example.com]
+#     username_env: HW_CHECKER_SMTP_USERNAME
+#     password_env: HW_CHECKER_SMTP_PASSWORD
+#   # Debouncing for watch/record, per metric kind (cpu, ram, swap, disk, temp)
+#   # or metric (disk:/home): raise a level only after it held for_secs, and
+#   # clear it only once the value is clear_margin below its threshold.
+#   rules:
+#     cpu: { for_secs: 300, clear_margin: 20.0 }   # 5 min past a threshold; clears 20 points under it
+#     temp: { for_secs: 60 }
+
+# `hw-checker mqtt`.
+# mqtt:
+#   broker: mqtt.example.com
+#   port: 1883
+#   username: hw-checker
+#   password_env: HW_CHECKER_MQTT_PASSWORD
+#   topic_prefix: hwchecker
+#   interval_secs: 30
+#   discovery: true                            # Home Assistant MQTT discovery
+#   discovery_prefix: homeassistant
+
+# `hw-checker snmp` (builds with the `snmp` feature).
+# snmp:
+#   master: /var/agentx/master                 # or tcp:host:port
+#   base_oid: 1.3.6.1.4.1.8072.9999.9999.7
+#   refresh_secs: 10
+
+# Operator metadata added to reports.
+# asset:
+#   asset_tag: IT-00123
+#   location: Rack 4, DC1
+#   owner: ops
+#   custom:
+#     cost_center: "4711"
+
+# Hash (or remove) serial numbers, MACs, cloud instance IDs and hostnames in
+# reports.
+# redact:
+#   enabled: false
+#   mode: hash                                 # hash, remove
+#   salt: change-me
+
+# `--audit` state (last-seen hardware) and its append-only change log.
+# audit:
+#   state: /var/lib/hw-checker/audit-state.json  # default: $XDG_DATA_HOME/hw-checker/audit-state.json
+#   log: /var/log/hw-checker-audit.log           # default: $XDG_DATA_HOME/hw-checker/audit.log
+
+# Site-specific probes run during discovery. Each prints one JSON document,
+# added to the report under `custom.<name>`; failures are listed under
+# `diagnostics.plugin_errors`. Commands run directly, not through a shell.
+# plugins:
+#   - name: raid
+#     command: /usr/local/lib/hw-checker/raid-status
+#     args: [--json]
+#     timeout_secs: 30                         # default: probe_timeouts.default_secs
+
+# Shell one-liners whose output is added under `custom.<name>`, as text
+# (raw) or parsed (json). Same timeouts and failure reporting as plugins.
+# collectors:
+#   sel_entries:
+#     command: ipmitool sel info | awk '/^Entries/ {print $3}'
+#   raid:
+#     command: storcli /c0 show J
+#     format: json                             # raw, json
+#     timeout_secs: 30
+
+# Named overrides selected with --profile; `inherits` names another profile.
+# profiles:
+#   server:
+#     cpu_thresholds: { warning: 85.0, critical: 95.0 }
+#   ci:
+#     inherits: server
+#     locale: en
+
+# Other config files merged underneath this one, relative to it.
+# include: [common.yaml]
+"##;
+
+/// Where `config init` writes and the first place looked for a config:
+/// `$XDG_CONFIG_HOME/hw-checker/config.yaml` (or the platform equivalent).
+pub fn user_config_path() -> PathBuf {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("hw-checker")
+        .join("config.yaml")
+}
+
+/// The config used when `--config` is not given: the user's, else the
+/// system-wide `/etc/hw-checker/config.yaml`.
+pub fn find_default_config() -> Option<PathBuf> {
+    let mut candidates = vec![user_config_path()];
+    if cfg!(unix) {
+        candidates.push(PathBuf::from("/etc/hw-checker/config.yaml"));
+    }
+    candidates
+        .into_iter()
+        .flat_map(|yaml| [yaml.with_extension("toml"), yaml])
+        .find(|path| path.is_file())
+}
+
+fn is_toml(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+}
+
+/// TOML for `.toml` files and YAML otherwise, as one YAML value so the two
+/// formats can include each other.
+fn parse_file(path: &Path, content: &str) -> Result<Value> {
+    if is_toml(path) {
+        let table: toml::Table = toml::from_str(content)
+            .with_context(|| format!("invalid TOML in {}", path.display()))?;
+        return Ok(serde_yaml::to_value(table)?);
+    }
+    serde_yaml::from_str(content).with_context(|| format!("invalid YAML in {}", path.display()))
+}
+
+/// Merges `overlay` into `base`: mappings key by key, anything else replaced.
+fn merge(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Mapping(base), Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+fn take_key(value: &mut Value, key: &str) -> Option<Value> {
+    value.as_mapping_mut()?.remove(key)
+}
+
+/// Reads one config file with the files named in its `include:` key (relative
+/// to it) merged underneath, so the including file wins.
+fn load_file(path: &Path, stack: &mut Vec<PathBuf>) -> Result<Value> {
+    let canonical = path
+        .canonicalize()
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    if stack.contains(&canonical) {
+        bail!("{} includes itself", path.display());
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let mut value = parse_file(path, &content)?;
+    if value.is_null() {
+        value = Value::Mapping(Mapping::new());
+    }
+
+    let includes = match take_key(&mut value, "include") {
+        None => Vec::new(),
+        Some(Value::String(file)) => vec![file],
+        Some(Value::Sequence(files)) => files
+            .into_iter()
+            .map(|f| match f {
+                Value::String(file) => Ok(file),
+                _ => bail!("`include` in {} must list file names", path.display()),
+            })
+            .collect::<Result<_>>()?,
+        Some(_) => bail!(
+            "`include` in {} must be a file name or a list",
+            path.display()
+        ),
+    };
+    let dir = path.parent().unwrap_or(Path::new("."));
+    stack.push(canonical);
+    let mut merged = Value::Mapping(Mapping::new());
+    for include in includes {
+        merge(&mut merged, load_file(&dir.join(include), stack)?);
+    }
+    stack.pop();
+    merge(&mut merged, value);
+    Ok(merged)
+}
+
+/// The settings of profile `name`, with the profile it `inherits:` from merged underneath.
+fn resolve_profile(profiles: &Mapping, name: &str, chain: &mut Vec<String>) -> Result<Value> {
+    if chain.iter().any(|n| n == name) {
+        bail!(
+            "profile inheritance loops: {} -> {}",
+            chain.join(" -> "),
+            name
+        );
+    }
+    let Some(profile) = profiles.get(name) else {
+        let mut available: Vec<&str> = profiles.keys().filter_map(Value::as_str).collect();
+        available.sort_unstable();
+        if available.is_empty() {
+            bail!("unknown profile '{}': the config defines no profiles", name);
+        }
+        bail!(
+            "unknown profile '{}' (available: {})",
+            name,
+            available.join(", ")
+        );
+    };
+    let mut profile = profile.clone();
+    let parent = match take_key(&mut profile, "inherits") {
+        None => None,
+        Some(Value::String(parent)) => Some(parent),
+        Some(_) => bail!("`inherits` of profile '{}' must be a profile name", name),
+    };
+    let Some(parent) = parent else {
+        return Ok(profile);
+    };
+    chain.push(name.to_string());
+    let mut base = resolve_profile(profiles, &parent, chain)?;
+    chain.pop();
+    merge(&mut base, profile);
+    Ok(base)
+}
+
+/// Top-level config keys, which tell `HW_CHECKER_*` overrides apart from
+/// other variables such as `HW_CHECKER_MQTT_PASSWORD`. Every field of
+/// [`Config`] is serialized, even when empty, so none is left out.
+fn top_level_keys() -> Vec<String> {
+    match serde_yaml::to_value(Config::default()) {
+        Ok(Value::Mapping(map)) => map
+            .keys()
+            .filter_map(Value::as_str)
+            .map(String::from)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Reads an override the way YAML would, so `80`, `true` and `[a, b]` keep their types.
+fn parse_scalar(raw: &str) -> Value {
+    serde_yaml::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()))
+}
+
+/// `HW_CHECKER_<KEY>` overrides a config key, with `__` between nesting
+/// levels: `HW_CHECKER_CPU_THRESHOLDS__WARNING=80`.
+fn env_overrides(vars: impl Iterator<Item = (String, String)>) -> Vec<(Vec<String>, Value)> {
+    let keys = top_level_keys();
+    let mut vars: Vec<(String, String)> = vars
+        .filter_map(|(name, value)| {
+            let key = name.strip_prefix("HW_CHECKER_")?.to_lowercase();
+            Some((key, value))
+        })
+        .filter(|(key, _)| {
+            keys.iter()
+                .any(|k| k == key.split("__").next().unwrap_or_default())
+        })
+        .collect();
+    vars.sort();
+    vars.into_iter()
+        .map(|(key, value)| {
+            (
+                key.split("__").map(String::from).collect(),
+                parse_scalar(&value),
+            )
+        })
+        .collect()
+}
+
+fn set_key(value: &mut Value, key: &[String], new: Value) {
+    let Some((first, rest)) = key.split_first() else {
+        *value = new;
+        return;
+    };
+    if !value.is_mapping() {
+        *value = Value::Mapping(Mapping::new());
+    }
+    if let Value::Mapping(map) = value {
+        let entry = map
+            .entry(Value::String(first.clone()))
+            .or_insert(Value::Null);
+        set_key(entry, rest, new);
+    }
+}
+
+/// Reads `path` and its includes, split into the base settings and the `profiles:` map.
+fn read_config(path: &Path) -> Result<(Value, Mapping)> {
+    let mut value = load_file(path, &mut Vec::new())?;
+    let profiles = match take_key(&mut value, "profiles") {
+        None => Mapping::new(),
+        Some(Value::Mapping(profiles)) => profiles,
+        Some(_) => bail!("`profiles` in {} must be a map", path.display()),
+    };
+    // Accepted, but unused, by builds without SNMP support
+    #[cfg(not(feature = "snmp"))]
+    take_key(&mut value, "snmp");
+    Ok((value, profiles))
+}
+
+fn check_thresholds(
+    path: &str,
+    thresholds: &Thresholds,
+    percent: bool,
+    problems: &mut Vec<String>,
+) {
+    for (key, value) in [
+        ("warning", thresholds.warning),
+        ("critical", thresholds.critical),
+    ] {
+        if percent && !(0.0..=100.0).contains(&value) {
+            problems.push(format!(
+                "{}.{}: must be between 0 and 100, got {}",
+                path, key, value
+            ));
+        }
+    }
+    if thresholds.warning >= thresholds.critical {
+        problems.push(format!(
+            "{}: warning ({}) must be below critical ({})",
+            path, thresholds.warning, thresholds.critical
+        ));
+    }
 }
 
 impl Config {
-    pub fn load_from_file(path: PathBuf) -> Result<Self> {
-        let content = std::fs::read_to_string(path)?;
-        let config: Config = serde_yaml::from_str(&content)?;
+    /// Loads the effective config. Later sources win: built-in defaults, the
+    /// config file (with `profile` applied), `HW_CHECKER_*` environment
+    /// variables, then `--set KEY=VALUE` options.
+    pub fn load(path: Option<PathBuf>, profile: Option<&str>, set: &[String]) -> Result<Self> {
+        let mut overrides = env_overrides(std::env::vars());
+        for item in set {
+            let Some((key, value)) = item.split_once('=') else {
+                bail!("--set {}: expected KEY=VALUE", item);
+            };
+            if key.is_empty() {
+                bail!("--set {}: missing key", item);
+            }
+            overrides.push((
+                key.split('.').map(String::from).collect(),
+                parse_scalar(value),
+            ));
+        }
+        Self::build(path.as_deref(), profile, overrides)
+    }
+
+    /// Loads `path` with its `include:` files merged underneath and, if given,
+    /// the named entry of its `profiles:` map merged on top. Environment and
+    /// `--set` overrides are not applied.
+    pub fn load_from_file(path: PathBuf, profile: Option<&str>) -> Result<Self> {
+        Self::build(Some(&path), profile, Vec::new())
+    }
+
+    fn build(
+        path: Option<&Path>,
+        profile: Option<&str>,
+        overrides: Vec<(Vec<String>, Value)>,
+    ) -> Result<Self> {
+        let mut value = serde_yaml::to_value(Config::default())?;
+        match path {
+            Some(path) => {
+                let (file, profiles) = read_config(path)?;
+                merge(&mut value, file);
+                if let Some(name) = profile {
+                    merge(
+                        &mut value,
+                        resolve_profile(&profiles, name, &mut Vec::new())?,
+                    );
+                }
+            }
+            None if profile.is_some() => bail!("--profile needs a config file (--config)"),
+            None => {}
+        }
+        for (key, override_value) in overrides {
+            set_key(&mut value, &key, override_value);
+        }
+
+        let source = match path {
+            Some(path) => format!("invalid config in {}", path.display()),
+            None => "invalid config".to_string(),
+        };
+        let config: Config = serde_path_to_error::deserialize(value)
+            .map_err(|e| anyhow!("{}: {}: {}", source, e.path(), e.inner()))?;
+        let problems = config.problems();
+        if !problems.is_empty() {
+            bail!("{}:\n  {}", source, problems.join("\n  "));
+        }
         Ok(config)
     }
 
+    /// Names of the profiles defined in `path`, sorted.
+    pub fn profile_names(path: &Path) -> Result<Vec<String>> {
+        let (_, profiles) = read_config(path)?;
+        let mut names: Vec<String> = profiles
+            .keys()
+            .filter_map(Value::as_str)
+            .map(String::from)
+            .collect();
+        names.sort_unstable();
+        Ok(names)
+    }
+
+    /// Values that parse but make no sense, as `path: problem` lines.
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        check_thresholds("cpu_thresholds", &self.cpu_thresholds, true, &mut problems);
+        check_thresholds("ram_thresholds", &self.ram_thresholds, true, &mut problems);
+        check_thresholds(
+            "storage_thresholds",
+            &self.storage_thresholds,
+            true,
+            &mut problems,
+        );
+        check_thresholds(
+            "inode_thresholds",
+            &self.inode_thresholds,
+            true,
+            &mut problems,
+        );
+        check_thresholds(
+            "temperature_thresholds",
+            &self.temperature_thresholds,
+            false,
+            &mut problems,
+        );
+        if self.battery.warning > 100 || self.battery.critical > 100 {
+            problems.push(format!(
+                "battery: charge levels must be between 0 and 100, got warning {} and critical {}",
+                self.battery.warning, self.battery.critical
+            ));
+        }
+        if self.battery.critical >= self.battery.warning {
+            problems.push(format!(
+                "battery: critical ({}) must be below warning ({})",
+                self.battery.critical, self.battery.warning
+            ));
+        }
+        if !(0.0..=100.0).contains(&self.battery.max_wear) {
+            problems.push(format!(
+                "battery.max_wear: must be between 0 and 100, got {}",
+                self.battery.max_wear
+            ));
+        }
+        if self.record.interval_secs == 0 {
+            problems.push("record.interval_secs: must be at least 1".to_string());
+        }
+        if self.mqtt.interval_secs == 0 {
+            problems.push("mqtt.interval_secs: must be at least 1".to_string());
+        }
+        let mut plugin_names = std::collections::HashSet::new();
+        for (i, plugin) in self.plugins.iter().enumerate() {
+            if plugin.name.trim().is_empty() {
+                problems.push(format!("plugins[{}].name: must not be empty", i));
+            } else if !plugin_names.insert(plugin.name.as_str()) {
+                problems.push(format!(
+                    "plugins[{}].name: '{}' is used by an earlier plugin",
+                    i, plugin.name
+                ));
+            }
+        }
+        for (name, collector) in &self.collectors {
+            if plugin_names.contains(name.as_str()) {
+                problems.push(format!(
+                    "collectors.{}: a plugin already reports under this name",
+                    name
+                ));
+            }
+            if collector.command.trim().is_empty() {
+                problems.push(format!("collectors.{}.command: must not be empty", name));
+            }
+        }
+        #[cfg(feature = "snmp")]
+        if self.snmp.refresh_secs == 0 {
+            problems.push("snmp.refresh_secs: must be at least 1".to_string());
+        }
+        problems
+    }
+
+    /// Writes TOML to `.toml` files and YAML otherwise.
     pub fn save_to_file(&self, path: PathBuf) -> Result<()> {
-        let yaml = serde_yaml::to_string(self)?;
-        std::fs::write(path, yaml)?;
+        let text = if is_toml(&path) {
+            toml::to_string_pretty(self)?
+        } else {
+            serde_yaml::to_string(self)?
+        };
+        std::fs::write(path, text)?;
         Ok(())
     }
 }
+
+pub fn run_config(
+    args: &ConfigArgs,
+    config_path: Option<&Path>,
+    profile: Option<&str>,
+    set: &[String],
+) -> Result<()> {
+    match &args.command {
+        ConfigCommand::Show => {
+            let path = config_path
+                .map(Path::to_path_buf)
+                .or_else(find_default_config);
+            let config = Config::load(path, profile, set)?;
+            print!("{}", serde_yaml::to_string(&config)?);
+            Ok(())
+        }
+        ConfigCommand::Init(init) => {
+            let path = init.output.clone().unwrap_or_else(user_config_path);
+            if path.exists() && !init.force {
+                bail!(
+                    "{} already exists (use --force to overwrite it)",
+                    path.display()
+                );
+            }
+            if let Some(dir) = path.parent()
+                && !dir.as_os_str().is_empty()
+            {
+                std::fs::create_dir_all(dir)
+                    .with_context(|| format!("failed to create {}", dir.display()))?;
+            }
+            // The commented template is YAML; TOML gets the bare defaults
+            let text = if is_toml(&path) {
+                format!(
+                    "# hw-checker configuration; see `hw-checker config init` without .toml\n# for a commented YAML version listing every option.\n\n{}",
+                    toml::to_string_pretty(&Config::default())?
+                )
+            } else {
+                DEFAULT_CONFIG.to_string()
+            };
+            std::fs::write(&path, text)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            println!("{} {}", t("Wrote"), path.display());
+            Ok(())
+        }
+        ConfigCommand::Validate => {
+            let Some(path) = config_path
+                .map(Path::to_path_buf)
+                .or_else(find_default_config)
+            else {
+                bail!("no config file to validate (pass --config or run `config init`)");
+            };
+            // Without --profile, the base settings and every profile are checked
+            let profiles = match profile {
+                Some(name) => vec![Some(name.to_string())],
+                None => std::iter::once(None)
+                    .chain(Config::profile_names(&path)?.into_iter().map(Some))
+                    .collect(),
+            };
+            let mut failed = false;
+            for name in profiles {
+                let label = match &name {
+                    Some(name) => format!("{} ({} {})", path.display(), t("profile"), name),
+                    None => path.display().to_string(),
+                };
+                match Config::load_from_file(path.clone(), name.as_deref()) {
+                    Ok(_) => println!("{} {}", t("OK"), label),
+                    Err(e) => {
+                        failed = true;
+                        println!("{} {}", t("FAIL"), label);
+                        for line in format!("{:#}", e).lines() {
+                            println!("  {}", line);
+                        }
+                    }
+                }
+            }
+            if failed {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        ConfigCommand::Path => {
+            match find_default_config() {
+                Some(path) => println!("{}", path.display()),
+                None => println!(
+                    "{} ({})",
+                    t("No config file found"),
+                    user_config_path().display()
+                ),
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_overrides_cover_every_key() {
+        let defaults = serde_yaml::to_value(Config::default()).unwrap();
+        let Value::Mapping(defaults) = defaults else {
+            panic!("the config is not a map");
+        };
+        // Each key set to its default, as YAML
+        let vars: Vec<(String, String)> = defaults
+            .iter()
+            .map(|(key, value)| {
+                let key = key.as_str().unwrap();
+                (
+                    format!("HW_CHECKER_{}", key.to_uppercase()),
+                    serde_yaml::to_string(value).unwrap(),
+                )
+            })
+            .chain([("HW_CHECKER_MQTT_PASSWORD".to_string(), "secret".to_string())])
+            .collect();
+        let overrides = env_overrides(vars.into_iter());
+        let keys: Vec<&str> = overrides.iter().map(|(key, _)| key[0].as_str()).collect();
+        for key in [
+            "pressure",
+            "probe_timeouts",
+            "audit",
+            "plugins",
+            "collectors",
+        ] {
+            assert!(keys.contains(&key), "{} is not overridable", key);
+        }
+        assert_eq!(overrides.len(), defaults.len());
+        Config::build(None, None, overrides).unwrap();
+
+        let nested = [(
+            "HW_CHECKER_PRESSURE__CPU__WARNING".to_string(),
+            "20".to_string(),
+        )];
+        let config = Config::build(None, None, env_overrides(nested.into_iter())).unwrap();
+        assert_eq!(config.pressure.cpu.warning, 20.0);
+    }
+}
@@ -0,0 +1,142 @@
+//! `--connectivity`: pings the default gateway and a target host and resolves
+//! a name, for a quick "is networking actually working" verdict.
+
+use crate::model::{Connectivity, ConnectivityVerdict, DnsCheck, NetworkConfig, PingResult};
+use serde::{Deserialize, Serialize};
+use std::net::ToSocketAddrs;
+use std::process::Command;
+use std::time::Instant;
+
+/// The `connectivity:` config section.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields, default)]
+pub struct ConnectivityConfig {
+    /// Host pinged beyond the gateway
+    pub target: String,
+    /// Name looked up through the system resolver
+    pub dns_name: String,
+    /// Echo requests per host
+    pub count: u32,
+}
+
+impl Default for ConnectivityConfig {
+    fn default() -> Self {
+        Self {
+            target: "1.1.1.1".to_string(),
+            dns_name: "example.com".to_string(),
+            count: 3,
+        }
+    }
+}
+
+pub fn check_connectivity(
+    config: &ConnectivityConfig,
+    network_config: Option<&NetworkConfig>,
+) -> Connectivity {
+    let gateway = network_config.and_then(|network| {
+        let gateway = network
+            .gateway_v4
+            .as_ref()
+            .or(network.gateway_v6.as_ref())?;
+        // Link-local next hops only mean something on their interface
+        Some(if gateway.address.starts_with("fe80:") {
+            format!("{}%{}", gateway.address, gateway.interface)
+        } else {
+            gateway.address.clone()
+        })
+    });
+    let gateway = gateway.map(|host| ping(&host, config.count));
+    let target = ping(&config.target, config.count);
+    let dns = resolve(&config.dns_name);
+
+    // A ping that could not run says nothing either way
+    let reachable = |ping: &PingResult| ping.received > 0 || ping.error.is_some();
+    let verdict = if !reachable(&target) && !dns.resolved {
+        ConnectivityVerdict::Offline
+    } else if !reachable(&target)
+        || !dns.resolved
+        || target.loss_percent > 0.0
+        || gateway
+            .as_ref()
+            .is_some_and(|gateway| gateway.loss_percent > 0.0)
+    {
+        ConnectivityVerdict::Degraded
+    } else {
+        ConnectivityVerdict::Online
+    };
+    Connectivity {
+        gateway,
+        target,
+        dns,
+        verdict,
+    }
+}
+
+/// Runs the system `ping`, which holds the privileges raw ICMP sockets need,
+/// and reads its summary lines:
+/// "3 packets transmitted, 3 received, 0% packet loss" and
+/// "rtt min/avg/max/mdev = 0.1/0.2/0.3/0.1 ms" (or "round-trip ..." on macOS/BSD).
+fn ping(host: &str, count: u32) -> PingResult {
+    let count = count.max(1);
+    let mut command = Command::new("ping");
+    command.args(["-c", &count.to_string()]);
+    // Per-reply timeout: seconds on Linux, milliseconds on macOS
+    if cfg!(target_os = "linux") {
+        command.args(["-W", "1"]);
+    } else if cfg!(target_os = "macos") {
+        command.args(["-W", "1000"]);
+    }
+    let output = match command.arg(host).output() {
+        Ok(output) => String::from_utf8_lossy(&output.stdout).to_string(),
+        Err(err) => {
+            return PingResult {
+                host: host.to_string(),
+                sent: 0,
+                received: 0,
+                loss_percent: 0.0,
+                avg_latency_ms: None,
+                error: Some(format!("failed to run ping: {}", err)),
+            };
+        }
+    };
+
+    let received = output
+        .lines()
+        .find(|l| l.contains("packets transmitted"))
+        .and_then(|l| {
+            l.split(',')
+                .nth(1)?
+                .split_whitespace()
+                .next()?
+                .parse::<u32>()
+                .ok()
+        })
+        .unwrap_or(0);
+    let avg_latency_ms = output
+        .lines()
+        .find(|l| l.contains("min/avg/max"))
+        .and_then(|l| l.split('=').nth(1)?.trim().split('/').nth(1)?.parse().ok());
+    PingResult {
+        host: host.to_string(),
+        sent: count,
+        received,
+        loss_percent: (count - received.min(count)) as f32 * 100.0 / count as f32,
+        avg_latency_ms,
+        error: None,
+    }
+}
+
+fn resolve(name: &str) -> DnsCheck {
+    let started = Instant::now();
+    let addresses: Vec<String> = (name, 0)
+        .to_socket_addrs()
+        .map(|addrs| addrs.map(|a| a.ip().to_string()).collect())
+        .unwrap_or_default();
+    let resolved = !addresses.is_empty();
+    DnsCheck {
+        name: name.to_string(),
+        resolved,
+        latency_ms: resolved.then(|| started.elapsed().as_secs_f64() * 1000.0),
+        addresses,
+    }
+}
@@ -0,0 +1,438 @@
+//! `hw-checker daemon`: keeps a collected report in memory and answers on a
+//! Unix socket, so scripts that query the same machine over and over do not
+//! pay for discovery each time. Requests are one line per connection:
+//!
+//! - `GET report`: the whole report as one line of JSON
+//! - `GET <path>`: one field by dotted path, as `hw-checker get` takes it
+//! - `SUBSCRIBE alerts`: threshold events as JSON lines until the client leaves
+//!
+//! Failures come back as `{"error": "..."}`. `--connect` makes the report
+//! commands (and `get`) read the daemon's report instead of collecting one.
+
+use crate::alerts::Alerter;
+use crate::cli::DaemonArgs;
+use crate::config::Config;
+use crate::discovery::{MetricsSampler, get_hardware_report};
+use crate::history::data_dir;
+use crate::i18n::t;
+use crate::model::HardwareReport;
+use crate::plugins::run_plugins;
+use crate::query::lookup;
+use crate::redact::redact_report;
+use anyhow::{Context, Result, bail};
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+// Each subscriber's alert lines; a tokio channel under the `async` feature,
+// so its connection task can wait on them
+#[cfg(not(feature = "async"))]
+use std::sync::mpsc::{Receiver, Sender, channel};
+#[cfg(feature = "async")]
+use tokio::sync::mpsc::{
+    UnboundedReceiver as Receiver, UnboundedSender as Sender, unbounded_channel as channel,
+};
+
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+// Subscribers hold their connection (and thread) until they leave
+const MAX_CONNECTIONS: usize = 64;
+
+/// `$XDG_RUNTIME_DIR/hw-checker.sock`, or `daemon.sock` in the data directory.
+pub fn default_socket_path() -> PathBuf {
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .map(|dir| PathBuf::from(dir).join("hw-checker.sock"))
+        .unwrap_or_else(|| data_dir().join("daemon.sock"))
+}
+
+/// What the connection handlers share with the refresh and alert loops.
+struct State {
+    report: RwLock<Value>,
+    subscribers: Mutex<Vec<Sender<String>>>,
+}
+
+pub fn run_daemon(args: &DaemonArgs, config: &Config) -> Result<()> {
+    let path = args.socket.clone().unwrap_or_else(default_socket_path);
+    let state = Arc::new(State {
+        report: RwLock::new(collect(config)?),
+        subscribers: Mutex::new(Vec::new()),
+    });
+    let interval = args.interval.max(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+
+    // The alert targets are set up on the sampling thread; a broken one
+    // stops the daemon before it starts listening
+    let (ready, started) = std::sync::mpsc::channel();
+    std::thread::spawn({
+        let (config, state) = (config.clone(), state.clone());
+        move || -> Result<()> {
+            let mut sampler = MetricsSampler::new();
+            let mut alerter = match Alerter::new(&[], &config) {
+                Ok(alerter) => {
+                    let _ = ready.send(Ok(()));
+                    alerter
+                }
+                Err(err) => {
+                    let _ = ready.send(Err(err));
+                    return Ok(());
+                }
+            };
+            loop {
+                std::thread::sleep(interval);
+                let events = alerter.check(&sampler.sample());
+                let lines = events
+                    .iter()
+                    .map(serde_json::to_string)
+                    .collect::<serde_json::Result<Vec<_>>>()?;
+                // A subscriber that hung up drops its receiver, and with it
+                // its place in the list
+                state.subscribers.lock().unwrap().retain(|subscriber| {
+                    lines
+                        .iter()
+                        .all(|line| subscriber.send(line.clone()).is_ok())
+                });
+            }
+        }
+    });
+    started.recv()??;
+    std::thread::spawn({
+        let (config, state, refresh) = (config.clone(), state.clone(), args.refresh);
+        move || {
+            loop {
+                std::thread::sleep(refresh);
+                match collect(&config) {
+                    Ok(report) => *state.report.write().unwrap() = report,
+                    Err(err) => eprintln!("{}: {:#}", t("Refresh failed"), err),
+                }
+            }
+        }
+    });
+    listen(&path, state)
+}
+
+fn collect(config: &Config) -> Result<Value> {
+    let mut report = get_hardware_report(&config.sections, &config.probe_timeouts);
+    run_plugins(
+        &config.plugins,
+        &config.collectors,
+        &config.probe_timeouts,
+        &mut report,
+    );
+    redact_report(&mut report, &config.redact);
+    Ok(serde_json::to_value(&report)?)
+}
+
+/// What a request line gets back.
+enum Reply {
+    Line(Value),
+    Stream(Receiver<String>),
+}
+
+fn respond(request: &str, state: &State) -> Reply {
+    let error = |message: String| Reply::Line(json!({ "error": message }));
+    match request.split_once(' ').unwrap_or((request, "")) {
+        ("GET", "report") => Reply::Line(state.report.read().unwrap().clone()),
+        ("GET", path) if !path.is_empty() => match lookup(&state.report.read().unwrap(), path) {
+            Some(value) => Reply::Line(value.clone()),
+            None => error(format!("no field `{}` in the report", path)),
+        },
+        ("SUBSCRIBE", "alerts") => {
+            let (sender, receiver) = channel();
+            state.subscribers.lock().unwrap().push(sender);
+            Reply::Stream(receiver)
+        }
+        _ => error(format!(
+            "unknown request `{}` (GET report, GET <path> or SUBSCRIBE alerts)",
+            request
+        )),
+    }
+}
+
+/// Sends `request` to the daemon listening on `socket` and returns its reply.
+pub fn request(socket: &Path, request: &str) -> Result<Value> {
+    let line = connect(socket, request)?;
+    let value: Value =
+        serde_json::from_str(&line).context("the daemon sent something other than JSON")?;
+    if let Some(Value::String(message)) = value.get("error") {
+        bail!("hw-checker daemon: {}", message);
+    }
+    Ok(value)
+}
+
+/// The running daemon's report, in place of collecting one.
+pub fn fetch_report(socket: &Path) -> Result<HardwareReport> {
+    Ok(serde_json::from_value(request(socket, "GET report")?)?)
+}
+
+/// The line a client gets when `MAX_CONNECTIONS` are already connected.
+fn busy() -> String {
+    format!(
+        "{}\n",
+        json!({ "error": format!("already serving {} clients", MAX_CONNECTIONS) })
+    )
+}
+
+#[cfg(unix)]
+fn bind(path: &Path) -> Result<std::os::unix::net::UnixListener> {
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+    use std::os::unix::net::{UnixListener, UnixStream};
+
+    if path.exists() {
+        if UnixStream::connect(path).is_ok() {
+            bail!("a daemon is already listening on {}", path.display());
+        }
+        // Left behind by a daemon that did not shut down cleanly
+        std::fs::remove_file(path)
+            .with_context(|| format!("failed to remove stale socket {}", path.display()))?;
+    }
+    if let Some(dir) = path.parent() {
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    // The report carries serials and MAC addresses unless `redact` is on, so
+    // the socket is created private rather than opened up until a chmod
+    let umask = unsafe { libc::umask(0o077) };
+    let listener = UnixListener::bind(path);
+    unsafe { libc::umask(umask) };
+    let listener = listener.with_context(|| format!("failed to listen on {}", path.display()))?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    eprintln!("{} {}", t("Listening on"), path.display());
+    Ok(listener)
+}
+
+#[cfg(all(unix, not(feature = "async")))]
+fn listen(path: &Path, state: Arc<State>) -> Result<()> {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::os::unix::net::UnixStream;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc::RecvTimeoutError;
+
+    let listener = bind(path)?;
+    let serve = |stream: UnixStream| -> Result<()> {
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+        let mut line = String::new();
+        BufReader::new(&stream).read_line(&mut line)?;
+        let mut stream = &stream;
+        match respond(line.trim(), &state) {
+            Reply::Line(value) => writeln!(stream, "{}", value)?,
+            Reply::Stream(events) => loop {
+                match events.recv_timeout(IO_TIMEOUT) {
+                    Ok(event) => writeln!(stream, "{}", event)?,
+                    // Between alerts, look for a client that hung up so it
+                    // does not keep its place until the next one
+                    Err(RecvTimeoutError::Timeout) => {
+                        stream.set_nonblocking(true)?;
+                        let hung_up = matches!(stream.read(&mut [0; 1]), Ok(0));
+                        stream.set_nonblocking(false)?;
+                        if hung_up {
+                            break;
+                        }
+                    }
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            },
+        }
+        Ok(())
+    };
+    let active = AtomicUsize::new(0);
+    std::thread::scope(|scope| {
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(err) => {
+                    eprintln!("{}: {}", t("Connection failed"), err);
+                    continue;
+                }
+            };
+            if active.load(Ordering::SeqCst) >= MAX_CONNECTIONS {
+                let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+                let _ = stream.write_all(busy().as_bytes());
+                continue;
+            }
+            // Subscribers stay connected, so each client gets a thread
+            active.fetch_add(1, Ordering::SeqCst);
+            let (serve, active) = (&serve, &active);
+            scope.spawn(move || {
+                let result = serve(stream);
+                active.fetch_sub(1, Ordering::SeqCst);
+                result
+            });
+        }
+    });
+    Ok(())
+}
+
+/// The same server as tasks on the shared runtime: a subscriber waits on its
+/// channel instead of holding a thread.
+#[cfg(all(unix, feature = "async"))]
+fn listen(path: &Path, state: Arc<State>) -> Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::UnixStream;
+    use tokio::time::timeout;
+
+    async fn serve(stream: UnixStream, state: &State) -> Result<()> {
+        let (reader, mut writer) = stream.into_split();
+        let mut reader = BufReader::new(reader);
+        let mut line = String::new();
+        timeout(IO_TIMEOUT, reader.read_line(&mut line)).await??;
+        match respond(line.trim(), state) {
+            Reply::Line(value) => {
+                timeout(
+                    IO_TIMEOUT,
+                    writer.write_all(format!("{}\n", value).as_bytes()),
+                )
+                .await??
+            }
+            Reply::Stream(mut events) => loop {
+                // The client sends nothing more, so a read returns once it
+                // hangs up, freeing its place without waiting for an alert
+                let event = tokio::select! {
+                    event = events.recv() => event,
+                    _ = reader.read_line(&mut line) => None,
+                };
+                let Some(event) = event else {
+                    break;
+                };
+                timeout(
+                    IO_TIMEOUT,
+                    writer.write_all(format!("{}\n", event).as_bytes()),
+                )
+                .await??;
+            },
+        }
+        Ok(())
+    }
+
+    let listener = bind(path)?;
+    listener.set_nonblocking(true)?;
+    crate::runtime::runtime().block_on(async move {
+        let listener = tokio::net::UnixListener::from_std(listener)?;
+        let active = Arc::new(AtomicUsize::new(0));
+        loop {
+            let mut stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(err) => {
+                    eprintln!("{}: {}", t("Connection failed"), err);
+                    continue;
+                }
+            };
+            if active.load(Ordering::SeqCst) >= MAX_CONNECTIONS {
+                let _ = timeout(IO_TIMEOUT, stream.write_all(busy().as_bytes())).await;
+                continue;
+            }
+            active.fetch_add(1, Ordering::SeqCst);
+            let (state, active) = (state.clone(), active.clone());
+            tokio::spawn(async move {
+                let _ = serve(stream, &state).await;
+                active.fetch_sub(1, Ordering::SeqCst);
+            });
+        }
+    })
+}
+
+#[cfg(unix)]
+fn connect(socket: &Path, request: &str) -> Result<String> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(socket).with_context(|| {
+        format!(
+            "no daemon on {} (start one with `hw-checker daemon`)",
+            socket.display()
+        )
+    })?;
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    writeln!(stream, "{}", request)?;
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line)?;
+    Ok(line)
+}
+
+#[cfg(not(unix))]
+fn listen(_path: &Path, _state: Arc<State>) -> Result<()> {
+    bail!("the daemon listens on a Unix socket and is not available on Windows")
+}
+
+#[cfg(not(unix))]
+fn connect(_socket: &Path, _request: &str) -> Result<String> {
+    bail!("--connect talks to the daemon's Unix socket and is not available on Windows")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(reply: Reply) -> Value {
+        match reply {
+            Reply::Line(value) => value,
+            Reply::Stream(_) => panic!("expected a single line"),
+        }
+    }
+
+    // `try_recv` takes `&self` on std's receiver and `&mut self` on tokio's
+    fn next(events: &mut Receiver<String>) -> String {
+        events.try_recv().unwrap()
+    }
+
+    #[test]
+    fn requests() {
+        let state = State {
+            report: RwLock::new(json!({"hostname": "db-1", "cpu": [{"brand": "GenuineIntel"}]})),
+            subscribers: Mutex::new(Vec::new()),
+        };
+        assert_eq!(line(respond("GET report", &state))["hostname"], "db-1");
+        assert_eq!(line(respond("GET cpu.0.brand", &state)), "GenuineIntel");
+        assert_eq!(
+            line(respond("GET ram.total", &state))["error"],
+            "no field `ram.total` in the report"
+        );
+        assert!(line(respond("GET", &state))["error"].is_string());
+
+        let Reply::Stream(mut events) = respond("SUBSCRIBE alerts", &state) else {
+            panic!("expected a stream");
+        };
+        state.subscribers.lock().unwrap()[0]
+            .send("{}".to_string())
+            .unwrap();
+        assert_eq!(next(&mut events), "{}");
+    }
+
+    #[test]
+    fn redacted_reply() {
+        let mut config = Config::default();
+        config.sections = crate::config::Sections {
+            cpu: false,
+            ram: false,
+            storage: false,
+            network: true,
+            usb: false,
+            thunderbolt: false,
+            pci: false,
+            gpu: false,
+            limits: false,
+            time: false,
+            motherboard: false,
+            battery: false,
+            sensors: false,
+        };
+        let plain = collect(&config).unwrap();
+        config.redact.enabled = true;
+        config.redact.mode = crate::redact::RedactMode::Remove;
+        let state = State {
+            report: RwLock::new(collect(&config).unwrap()),
+            subscribers: Mutex::new(Vec::new()),
+        };
+        let hostname = plain["hostname"].as_str().unwrap();
+        assert_eq!(
+            line(respond("GET hostname", &state)),
+            config.redact.hostname(hostname)
+        );
+        let network = line(respond("GET network", &state));
+        for net in network.as_array().unwrap() {
+            assert_eq!(net["mac_address"], "");
+        }
+    }
+}
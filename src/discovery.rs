@@ -1,343 +1,943 @@
+//! Hardware discovery. Each platform implements [`Probe`]; the trait's default
+//! methods cover what sysinfo, libusb and pci-info report everywhere, and the
+//! backends override the sections they can read natively. [`NativeProbe`] is
+//! the backend for the target OS, and the free functions below use it.
+
+#[cfg(any(target_os = "freebsd", target_os = "openbsd"))]
+mod bsd;
+mod generic;
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "macos")]
+mod macos;
+mod mock;
+mod timed;
+
+#[cfg(any(target_os = "freebsd", target_os = "openbsd"))]
+pub use bsd::BsdProbe;
+pub use generic::GenericProbe;
+#[cfg(target_os = "linux")]
+pub use linux::LinuxProbe;
+#[cfg(target_os = "macos")]
+pub use macos::MacProbe;
+pub use mock::MockProbe;
+use timed::TimedProbe;
+
+use crate::config::{ProbeTimeouts, Sections};
 use crate::model::{
-    BatteryInfo, CpuInfo, HardwareReport, MotherboardInfo, NetworkInfo, PciDevice, RamInfo,
-    RamStick, StorageInfo, UsbDevice,
+    AssetInfo, BatteryInfo, BoardLayout, CacheKind, CloudProvider, ContainerLimits, CoreMetrics,
+    CpuCache, CpuLoad, CpuPackage, CpuTimes, Diagnostics, DiskIoInfo, DiskUsageSample, FanInfo,
+    Filesystem, GpuInfo, GpuProcess, HardwareReport, InodeUsage, IommuInfo, IrqInfo, KernelLimit,
+    Limitation, MemorySlots, MetricsSample, MotherboardInfo, NetworkConfig, NetworkCounterSample,
+    NetworkInfo, OsDetails, PciDevice, PhysicalDisk, PlatformState, Pressure, RamInfo, RamStick,
+    SmartHealth, SpdInfo, TemperatureSample, TemperatureSensor, ThunderboltDevice, TimeHealth,
+    UsbDevice, VirtualizationSupport,
 };
-use raw_cpuid::{CpuId, CpuIdReaderNative};
+use crate::progress::step;
+use crate::sysfs::SysfsRoot;
 use rusb::UsbContext;
-use smbioslib::table_load_from_device;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::io::{BufRead, BufReader};
-use sysinfo::{CpuRefreshKind, Disks, Networks, RefreshKind, System};
+use std::time::{Duration, Instant};
+use sysinfo::{Components, CpuRefreshKind, Disks, Networks, RefreshKind, System};
+
+#[cfg(target_os = "linux")]
+pub type NativeProbe = LinuxProbe;
+#[cfg(target_os = "macos")]
+pub type NativeProbe = MacProbe;
+#[cfg(any(target_os = "freebsd", target_os = "openbsd"))]
+pub type NativeProbe = BsdProbe;
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "freebsd",
+    target_os = "openbsd"
+)))]
+pub type NativeProbe = GenericProbe;
+
+pub fn native_probe() -> NativeProbe {
+    NativeProbe::default()
+}
+
+/// OS and host identification for the top of a report.
+#[derive(Debug, Clone, Default)]
+pub struct SystemIdentity {
+    pub os_name: String,
+    pub os_version: String,
+    pub kernel_version: String,
+    pub hostname: String,
+    pub uptime: u64,
+}
+
+pub trait Probe {
+    fn system(&self) -> SystemIdentity {
+        sysinfo_system()
+    }
+
+    /// Architecture, boot time and whatever else of the OS the backend knows.
+    fn os_details(&self) -> Option<OsDetails> {
+        Some(OsDetails {
+            architecture: std::env::consts::ARCH.to_string(),
+            boot_time: boot_time(System::boot_time() as i64),
+            ..OsDetails::default()
+        })
+    }
+
+    fn cpus(&self) -> Vec<CpuPackage> {
+        sysinfo_cpus()
+    }
+
+    fn memory(&self) -> RamInfo {
+        RamInfo {
+            slots: self.memory_slots(),
+            ..sysinfo_memory(self.ram_sticks())
+        }
+    }
+
+    /// Limits of the cgroup this process runs in, when there are any.
+    fn container_limits(&self) -> Option<ContainerLimits> {
+        None
+    }
+
+    /// Open files, PIDs and other kernel limits against current usage.
+    fn kernel_limits(&self) -> Vec<KernelLimit> {
+        Vec::new()
+    }
+
+    /// Clock source, NTP synchronization and RTC drift.
+    fn time_health(&self) -> Option<TimeHealth> {
+        None
+    }
+
+    /// Installed modules, where the firmware tables can be read.
+    fn ram_sticks(&self) -> Vec<RamStick> {
+        Vec::new()
+    }
+
+    /// Memory slots, filled or not, and the board's maximum capacity.
+    fn memory_slots(&self) -> Option<MemorySlots> {
+        None
+    }
+
+    /// Module SPD EEPROMs the kernel exposes; reading them needs root.
+    fn spd_modules(&self) -> Vec<SpdInfo> {
+        Vec::new()
+    }
+
+    /// Drives, whether or not anything on them is mounted.
+    fn disks(&self) -> Vec<PhysicalDisk> {
+        Vec::new()
+    }
+
+    fn filesystems(&self) -> Vec<Filesystem> {
+        sysinfo_filesystems()
+    }
+
+    fn network(&self) -> Vec<NetworkInfo> {
+        sysinfo_network()
+    }
+
+    /// Default gateways, name servers and the interface the default route uses.
+    fn network_config(&self) -> Option<NetworkConfig> {
+        NetworkConfig::new(None, None, resolv_conf_servers("/etc/resolv.conf"))
+    }
+
+    /// (bus, address, vendor, product) of every attached USB device, without opening them.
+    fn usb_ids(&self) -> Vec<(u8, u8, u16, u16)> {
+        libusb_ids()
+    }
+
+    fn usb_devices(&self) -> Vec<UsbDevice> {
+        libusb_devices()
+    }
+
+    /// Devices attached over Thunderbolt/USB4, not counting the host's own controllers.
+    fn thunderbolt_devices(&self) -> Vec<ThunderboltDevice> {
+        Vec::new()
+    }
+
+    fn pci_devices(&self) -> Vec<PciDevice> {
+        enumerate_pci()
+    }
+
+    /// IOMMU groups and whether DMA remapping is on.
+    fn iommu(&self) -> Option<IommuInfo> {
+        None
+    }
+
+    fn motherboard(&self) -> Option<MotherboardInfo> {
+        None
+    }
+
+    /// The cloud provider the firmware strings name, on a cloud VM.
+    fn cloud_provider(&self) -> Option<CloudProvider> {
+        None
+    }
+
+    fn batteries(&self) -> Vec<BatteryInfo> {
+        Vec::new()
+    }
+
+    /// Lid, dock and thermal throttling state, where the firmware exposes them.
+    fn platform_state(&self) -> Option<PlatformState> {
+        None
+    }
+
+    /// CPU sockets, caches, port connectors and expansion slots from SMBIOS.
+    fn board_layout(&self) -> Option<BoardLayout> {
+        None
+    }
+
+    fn temperatures(&self) -> Vec<TemperatureSensor> {
+        sysinfo_temperatures()
+    }
+
+    fn fans(&self) -> Vec<FanInfo> {
+        Vec::new()
+    }
+
+    fn gpus(&self) -> Vec<GpuInfo> {
+        Vec::new()
+    }
+
+    /// Processes using each GPU, with their VRAM where the driver reports it.
+    fn gpu_processes(&self) -> Vec<GpuProcess> {
+        Vec::new()
+    }
+
+    /// CPU feature flags, named as in Linux `/proc/cpuinfo` (e.g. `avx2`, `sse4_2`).
+    fn cpu_flags(&self) -> Vec<String> {
+        cpuid_flags()
+    }
+
+    /// VT-x/AMD-V, nested paging and, on Linux, KVM.
+    fn virtualization(&self) -> Option<VirtualizationSupport> {
+        cpuid_virtualization()
+    }
+
+    /// CPU, memory and I/O stall averages, where the kernel tracks them.
+    fn pressure(&self) -> Option<Pressure> {
+        None
+    }
+
+    /// 1, 5 and 15 minute load averages.
+    fn load_average(&self) -> Option<[f64; 3]> {
+        if cfg!(windows) {
+            return None;
+        }
+        let load = System::load_average();
+        Some([load.one, load.five, load.fifteen])
+    }
+
+    /// Cumulative CPU time counters, for the time split between two reads.
+    fn cpu_ticks(&self) -> Option<CpuTicks> {
+        None
+    }
+
+    /// Load averages and the CPU time split over a short sample; from boot on
+    /// when the counters do not move (a captured tree).
+    fn cpu_load(&self) -> Option<CpuLoad> {
+        let before = self.cpu_ticks();
+        if before.is_some() {
+            std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        }
+        let times = self.cpu_ticks().and_then(|after| {
+            cpu_time_shares(&before.unwrap_or_default(), &after)
+                .or_else(|| cpu_time_shares(&CpuTicks::default(), &after))
+        });
+        let load_average = self.load_average();
+        (load_average.is_some() || times.is_some()).then_some(CpuLoad {
+            load_average,
+            times,
+        })
+    }
+
+    /// Total thermal throttling events since boot, where the OS counts them.
+    fn throttle_count(&self) -> Option<u64> {
+        None
+    }
+
+    /// Cumulative per-disk I/O counters, for computing rates between two reads.
+    fn diskstats(&self) -> HashMap<String, DiskStats> {
+        HashMap::new()
+    }
+
+    /// Cumulative per-CPU counts of every interrupt source, for computing rates
+    /// between two reads.
+    fn interrupts(&self) -> HashMap<String, IrqStats> {
+        HashMap::new()
+    }
+
+    /// Enabled sections that would have more data if run as root.
+    fn limitations(&self, sections: &Sections) -> Vec<Limitation> {
+        if !sections.usb || crate::privilege::is_elevated() {
+            return Vec::new();
+        }
+        libusb_limitations()
+    }
+}
+
+/// Builds a full report from any backend.
+pub fn collect_report(probe: &dyn Probe) -> HardwareReport {
+    collect_sections(probe, &Sections::default())
+}
+
+/// Like [`collect_report`], but leaves disabled sections empty without probing them.
+pub fn collect_sections(probe: &dyn Probe, sections: &Sections) -> HardwareReport {
+    let system = step("system", || probe.system());
+    HardwareReport {
+        os_name: system.os_name,
+        os_version: system.os_version,
+        kernel_version: system.kernel_version,
+        hostname: system.hostname,
+        uptime: system.uptime,
+        os_details: step("os_details", || probe.os_details()),
+        asset: AssetInfo::default(),
+        cloud: None,
+        cpu: run_if(sections.cpu, "cpu", || probe.cpus()),
+        cpu_load: run_if(sections.cpu, "cpu_load", || probe.cpu_load()),
+        virtualization: run_if(sections.cpu, "virtualization", || probe.virtualization()),
+        ram: run_if(sections.ram, "ram", || probe.memory()),
+        container: run_if(sections.cpu || sections.ram, "container", || {
+            probe.container_limits()
+        }),
+        pressure: run_if(
+            sections.cpu || sections.ram || sections.storage,
+            "pressure",
+            || probe.pressure(),
+        ),
+        limits: run_if(sections.limits, "limits", || probe.kernel_limits()),
+        time: run_if(sections.time, "time", || probe.time_health()),
+        disks: run_if(sections.storage, "disks", || probe.disks()),
+        filesystems: run_if(sections.storage, "filesystems", || probe.filesystems()),
+        disk_io: Vec::new(),
+        irqs: Vec::new(),
+        network: run_if(sections.network, "network", || probe.network()),
+        network_config: run_if(sections.network, "network_config", || {
+            probe.network_config()
+        }),
+        connectivity: None,
+        usb: run_if(sections.usb, "usb", || probe.usb_devices()),
+        thunderbolt: run_if(sections.thunderbolt, "thunderbolt", || {
+            probe.thunderbolt_devices()
+        }),
+        pci: run_if(sections.pci, "pci", || probe.pci_devices()),
+        iommu: None,
+        gpus: run_if(sections.gpu, "gpus", || probe.gpus()),
+        gpu_processes: Vec::new(),
+        motherboard: run_if(sections.motherboard, "motherboard", || probe.motherboard()),
+        battery: run_if(sections.battery, "battery", || probe.batteries()),
+        platform: run_if(sections.motherboard || sections.battery, "platform", || {
+            probe.platform_state()
+        }),
+        board_layout: run_if(sections.motherboard, "board_layout", || {
+            probe.board_layout()
+        }),
+        temperatures: run_if(sections.sensors, "temperatures", || probe.temperatures()),
+        fans: run_if(sections.sensors, "fans", || probe.fans()),
+        custom: BTreeMap::new(),
+        limitations: probe.limitations(sections),
+        diagnostics: Diagnostics::default(),
+    }
+}
+
+/// Steps are named after the report field they fill.
+fn run_if<T: Default>(enabled: bool, name: &str, probe: impl FnOnce() -> T) -> T {
+    if enabled {
+        step(name, probe)
+    } else {
+        T::default()
+    }
+}
+
+/// Collects the native report, giving up on steps that outlast `timeouts`.
+pub fn get_hardware_report(sections: &Sections, timeouts: &ProbeTimeouts) -> HardwareReport {
+    let probe = TimedProbe::new(timeouts);
+    let mut report = collect_sections(&probe, sections);
+    report.diagnostics.timed_out = probe.timed_out.into_inner();
+    report
+}
+
+pub fn get_disks() -> Vec<PhysicalDisk> {
+    native_probe().disks()
+}
+
+pub fn get_filesystems() -> Vec<Filesystem> {
+    native_probe().filesystems()
+}
+
+pub fn get_network() -> Vec<NetworkInfo> {
+    native_probe().network()
+}
+
+pub fn get_network_config() -> Option<NetworkConfig> {
+    native_probe().network_config()
+}
+
+pub fn get_cloud_provider() -> Option<CloudProvider> {
+    native_probe().cloud_provider()
+}
+
+pub fn get_usb_ids() -> Vec<(u8, u8, u16, u16)> {
+    native_probe().usb_ids()
+}
+
+pub fn get_usb_devices() -> Vec<UsbDevice> {
+    native_probe().usb_devices()
+}
+
+pub fn get_thunderbolt_devices() -> Vec<ThunderboltDevice> {
+    native_probe().thunderbolt_devices()
+}
+
+pub fn get_cpu_flags() -> Vec<String> {
+    native_probe().cpu_flags()
+}
+
+pub fn get_gpus() -> Vec<GpuInfo> {
+    native_probe().gpus()
+}
+
+pub fn get_gpu_processes() -> Vec<GpuProcess> {
+    native_probe().gpu_processes()
+}
+
+pub fn get_spd_modules() -> Vec<SpdInfo> {
+    native_probe().spd_modules()
+}
+
+pub fn get_iommu() -> Option<IommuInfo> {
+    native_probe().iommu()
+}
+
+pub fn read_throttle_count() -> Option<u64> {
+    native_probe().throttle_count()
+}
+
+pub fn get_battery_info() -> Vec<BatteryInfo> {
+    native_probe().batteries()
+}
+
+pub fn get_temperatures() -> Vec<TemperatureSensor> {
+    native_probe().temperatures()
+}
+
+pub fn get_fans() -> Vec<FanInfo> {
+    native_probe().fans()
+}
+
+pub fn read_diskstats() -> HashMap<String, DiskStats> {
+    native_probe().diskstats()
+}
+
+pub fn get_pressure() -> Option<Pressure> {
+    native_probe().pressure()
+}
+
+pub fn read_interrupts() -> HashMap<String, IrqStats> {
+    native_probe().interrupts()
+}
+
+pub fn read_cpu_ticks() -> Option<CpuTicks> {
+    native_probe().cpu_ticks()
+}
 
-pub fn get_hardware_report() -> HardwareReport {
+pub fn read_load_average() -> Option<[f64; 3]> {
+    native_probe().load_average()
+}
+
+fn sysinfo_system() -> SystemIdentity {
+    SystemIdentity {
+        os_name: System::name().unwrap_or_default(),
+        os_version: System::os_version().unwrap_or_default(),
+        kernel_version: System::kernel_version().unwrap_or_default(),
+        hostname: System::host_name().unwrap_or_default(),
+        uptime: System::uptime(),
+    }
+}
+
+/// Seconds since the epoch as RFC 3339 in local time.
+fn boot_time(epoch: i64) -> Option<String> {
+    chrono::DateTime::from_timestamp(epoch, 0)
+        .filter(|_| epoch > 0)
+        .map(|dt| dt.with_timezone(&chrono::Local).to_rfc3339())
+}
+
+fn sysinfo_memory(sticks: Vec<RamStick>) -> RamInfo {
     let mut sys = System::new_with_specifics(
-        RefreshKind::nothing()
-            .with_cpu(CpuRefreshKind::everything())
-            .with_memory(sysinfo::MemoryRefreshKind::everything()),
+        RefreshKind::nothing().with_memory(sysinfo::MemoryRefreshKind::everything()),
     );
+    sys.refresh_memory();
+    RamInfo {
+        total: sys.total_memory(),
+        used: sys.used_memory(),
+        free: sys.free_memory(),
+        swap_total: sys.total_swap(),
+        swap_used: sys.used_swap(),
+        sticks,
+        settings: None,
+        slots: None,
+        spd: Vec::new(),
+        virtualized: None,
+    }
+}
+
+fn sysinfo_cpus() -> Vec<CpuPackage> {
+    let mut sys =
+        System::new_with_specifics(RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()));
 
     // Initial refresh to get valid CPU usage
     sys.refresh_cpu_all();
     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
     sys.refresh_cpu_all();
 
-    let cpuid = CpuId::new();
+    let Some(first) = sys.cpus().first() else {
+        return Vec::new();
+    };
+    // sysinfo does not tell sockets apart; backends that can split this up do
+    vec![CpuPackage {
+        model: first.brand().trim().to_string(),
+        vendor: first.vendor_id().to_string(),
+        caches: {
+            let caches = cpuid_caches();
+            if caches.is_empty() {
+                sysfs_caches(&SysfsRoot::live())
+            } else {
+                caches
+            }
+        },
+        physical_cores: System::physical_core_count().unwrap_or(0),
+        logical_cores: sys.cpus().len(),
+        core_groups: Vec::new(),
+        cores: sys
+            .cpus()
+            .iter()
+            .enumerate()
+            .map(|(id, cpu)| CoreMetrics {
+                id,
+                kind: None,
+                cluster: None,
+                frequency: cpu.frequency(),
+                usage: cpu.cpu_usage(),
+            })
+            .collect(),
+    }]
+}
 
-    // Cache discovery
-    let (l1, l2, l3) = get_cpu_caches(&cpuid);
+/// Caches as listed by CPUID leaf 4 (Intel) or 0x8000001D (AMD).
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn cpuid_caches() -> Vec<CpuCache> {
+    let Some(cache_params) = raw_cpuid::CpuId::new().get_cache_parameters() else {
+        return Vec::new();
+    };
+    let logical_cores = std::thread::available_parallelism().map_or(usize::MAX, |n| n.get());
+    cache_params
+        .filter_map(|cache| {
+            let kind = match cache.cache_type() {
+                raw_cpuid::CacheType::Data => CacheKind::Data,
+                raw_cpuid::CacheType::Instruction => CacheKind::Instruction,
+                raw_cpuid::CacheType::Unified => CacheKind::Unified,
+                _ => return None,
+            };
+            // raw-cpuid already adds the 1 the spec stores each field minus:
+            // size = ways * partitions * line size * sets.
+            let size = cache.associativity()
+                * cache.physical_line_partitions()
+                * cache.coherency_line_size()
+                * cache.sets();
+            Some(CpuCache {
+                level: cache.level(),
+                kind,
+                size_kb: size as u64 / 1024,
+                // An upper bound (addressable IDs), so cap it at what is present
+                shared_by: cache.max_cores_for_cache().min(logical_cores),
+            })
+        })
+        .collect()
+}
 
-    let info = cpuid.get_vendor_info();
-    let vendor_name = info.as_ref().map(|v| v.as_str()).unwrap_or("Unknown");
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn cpuid_caches() -> Vec<CpuCache> {
+    Vec::new()
+}
 
-    let cpu_info = sys
-        .cpus()
-        .iter()
-        .map(|cpu| CpuInfo {
-            model: cpu.brand().to_string(),
-            vendor_id: cpu.vendor_id().to_string(),
-            brand: vendor_name.to_string(),
-            cores: System::physical_core_count().unwrap_or(0),
-            frequency: cpu.frequency(),
-            usage: cpu.cpu_usage(),
-            l1_cache: l1.clone(),
-            l2_cache: l2.clone(),
-            l3_cache: l3.clone(),
+/// Caches of cpu0 as published under `/sys/devices/system/cpu/cpu0/cache`,
+/// for CPUs without CPUID (ARM) and for captured trees.
+fn sysfs_caches(root: &SysfsRoot) -> Vec<CpuCache> {
+    let Ok(entries) = root.read_dir("/sys/devices/system/cpu/cpu0/cache/") else {
+        return Vec::new();
+    };
+    let mut caches: Vec<CpuCache> = entries
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("index"))
+        .filter_map(|entry| {
+            let read = |file: &str| {
+                fs::read_to_string(entry.path().join(file))
+                    .ok()
+                    .map(|s| s.trim().to_string())
+            };
+            let kind = match read("type")?.as_str() {
+                "Data" => CacheKind::Data,
+                "Instruction" => CacheKind::Instruction,
+                "Unified" => CacheKind::Unified,
+                _ => return None,
+            };
+            // e.g. "32K", "2048K" or "32M"
+            let size = read("size")?;
+            let size_kb = match size.strip_suffix('M') {
+                Some(mb) => mb.parse::<u64>().ok()? * 1024,
+                None => size.trim_end_matches('K').parse().ok()?,
+            };
+            Some(CpuCache {
+                level: read("level")?.parse().ok()?,
+                kind,
+                size_kb,
+                shared_by: read("shared_cpu_list").map_or(0, |list| cpu_list(&list).len()),
+            })
         })
         .collect();
+    caches.sort_by_key(|c| (c.level, c.kind));
+    caches
+}
 
-    let ram_sticks = get_ram_details();
-    let ram_info = RamInfo {
-        total: sys.total_memory(),
-        used: sys.used_memory(),
-        free: sys.free_memory(),
-        swap_total: sys.total_swap(),
-        swap_used: sys.used_swap(),
-        sticks: ram_sticks,
+/// The CPUs in a kernel cpu list such as "0-3,8-11".
+fn cpu_list(list: &str) -> Vec<usize> {
+    list.split(',')
+        .filter_map(|range| match range.split_once('-') {
+            Some((start, end)) => Some(start.parse().ok()?..=end.parse().ok()?),
+            None => range.parse().ok().map(|id| id..=id),
+        })
+        .flatten()
+        .collect()
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn cpuid_flags() -> Vec<String> {
+    let cpuid = raw_cpuid::CpuId::new();
+    let mut flags = Vec::new();
+    if let Some(f) = cpuid.get_feature_info() {
+        for (name, present) in [
+            ("sse", f.has_sse()),
+            ("sse2", f.has_sse2()),
+            ("pni", f.has_sse3()),
+            ("ssse3", f.has_ssse3()),
+            ("sse4_1", f.has_sse41()),
+            ("sse4_2", f.has_sse42()),
+            ("popcnt", f.has_popcnt()),
+            ("aes", f.has_aesni()),
+            ("avx", f.has_avx()),
+            ("fma", f.has_fma()),
+            ("f16c", f.has_f16c()),
+        ] {
+            if present {
+                flags.push(name.to_string());
+            }
+        }
+    }
+    if let Some(f) = cpuid.get_extended_feature_info() {
+        for (name, present) in [
+            ("avx2", f.has_avx2()),
+            ("bmi1", f.has_bmi1()),
+            ("bmi2", f.has_bmi2()),
+            ("sha_ni", f.has_sha()),
+            ("avx512f", f.has_avx512f()),
+            ("avx512bw", f.has_avx512bw()),
+            ("avx512vl", f.has_avx512vl()),
+        ] {
+            if present {
+                flags.push(name.to_string());
+            }
+        }
+    }
+    flags
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn cpuid_flags() -> Vec<String> {
+    Vec::new()
+}
+
+/// What the CPU itself reports, whether or not firmware left it usable.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn cpuid_virtualization() -> Option<VirtualizationSupport> {
+    let cpuid = raw_cpuid::CpuId::new();
+    let vmx = cpuid.get_feature_info().is_some_and(|f| f.has_vmx());
+    let svm = cpuid
+        .get_extended_processor_and_feature_identifiers()
+        .is_some_and(|f| f.has_svm());
+    let extension = match (vmx, svm) {
+        (true, _) => Some("VT-x".to_string()),
+        (_, true) => Some("AMD-V".to_string()),
+        _ => None,
     };
+    Some(VirtualizationSupport {
+        extension,
+        disabled_in_firmware: false,
+        // Intel lists EPT in an MSR, out of reach from user space
+        nested_paging: svm.then(|| cpuid.get_svm_info().is_some_and(|s| s.has_nested_paging())),
+        kvm: None,
+        nested_virtualization: None,
+    })
+}
 
-    let disks = Disks::new_with_refreshed_list();
-    let storage_info = disks
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn cpuid_virtualization() -> Option<VirtualizationSupport> {
+    None
+}
+
+fn sysinfo_filesystems() -> Vec<Filesystem> {
+    Disks::new_with_refreshed_list()
         .iter()
-        .map(|disk| {
-            let name = disk.name().to_string_lossy().to_string();
-            let (vendor, model, sn, interface) = get_disk_metadata(&name);
-            StorageInfo {
-                name: name.clone(),
-                mount_point: disk.mount_point().to_string_lossy().to_string(),
-                total: disk.total_space(),
-                used: disk.total_space() - disk.available_space(),
-                free: disk.available_space(),
-                filesystem: disk.file_system().to_string_lossy().to_string(),
-                vendor,
-                model_name: model,
-                serial_number: sn,
-                disk_type: Some(format!("{:?}", disk.kind())),
-                interface,
-            }
+        .map(|disk| Filesystem {
+            name: disk.name().to_string_lossy().to_string(),
+            mount_point: disk.mount_point().to_string_lossy().to_string(),
+            total: disk.total_space(),
+            used: disk.total_space() - disk.available_space(),
+            free: disk.available_space(),
+            filesystem: disk.file_system().to_string_lossy().to_string(),
+            disk: None,
+            container: None,
+            inodes: inode_usage(disk.mount_point()),
         })
-        .collect();
+        .collect()
+}
+
+#[cfg(unix)]
+fn inode_usage(mount_point: &std::path::Path) -> Option<InodeUsage> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = std::ffi::CString::new(mount_point.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 || stat.f_files == 0 {
+        return None;
+    }
+    let total = stat.f_files as u64;
+    let free = stat.f_ffree as u64;
+    Some(InodeUsage {
+        total,
+        used: total.saturating_sub(free),
+        free,
+    })
+}
 
-    let networks = Networks::new_with_refreshed_list();
-    let network_info = networks
+#[cfg(not(unix))]
+fn inode_usage(_mount_point: &std::path::Path) -> Option<InodeUsage> {
+    None
+}
+
+/// Health of the drive at `device` (e.g. `/dev/sda`) from `smartctl --json`.
+/// `None` when smartctl is missing, cannot open the drive (it needs root) or
+/// the drive does not support SMART.
+fn smart_health(device: &str) -> Option<SmartHealth> {
+    let output = std::process::Command::new("smartctl")
+        .args(["--json", "-H", "-A", device])
+        .output()
+        .ok()?;
+    // The exit status is a bit mask that is non-zero for failing drives too
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+    Some(SmartHealth {
+        passed: json["smart_status"]["passed"].as_bool()?,
+        temperature: json["temperature"]["current"].as_f64().map(|t| t as f32),
+        power_on_hours: json["power_on_time"]["hours"].as_u64(),
+    })
+}
+
+/// Whether smartctl is on the PATH, so a missing SMART verdict can be blamed
+/// on privileges.
+fn smartctl_installed() -> bool {
+    std::env::var_os("PATH")
+        .is_some_and(|path| std::env::split_paths(&path).any(|dir| dir.join("smartctl").is_file()))
+}
+
+fn sysinfo_network() -> Vec<NetworkInfo> {
+    Networks::new_with_refreshed_list()
         .iter()
         .map(|(name, data)| NetworkInfo {
             name: name.clone(),
             received: data.total_received(),
             transmitted: data.total_transmitted(),
             mac_address: data.mac_address().to_string(),
+            kind: None,
+            members: None,
         })
-        .collect();
+        .collect()
+}
 
-    let usb_devices = get_usb_devices();
-    let pci_devices = get_pci_devices();
-    let motherboard = get_motherboard_info();
-    let battery = get_battery_info();
+/// The `nameserver` lines of a resolv.conf.
+fn resolv_conf_servers(path: impl AsRef<std::path::Path>) -> Vec<String> {
+    let Ok(text) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    text.lines()
+        .filter_map(
+            |line| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                ["nameserver", server, ..] => Some(server.to_string()),
+                _ => None,
+            },
+        )
+        .collect()
+}
 
-    HardwareReport {
-        os_name: System::name().unwrap_or_default(),
-        os_version: System::os_version().unwrap_or_default(),
-        kernel_version: System::kernel_version().unwrap_or_default(),
-        hostname: System::host_name().unwrap_or_default(),
-        uptime: System::uptime(),
-        cpu: cpu_info,
-        ram: ram_info,
-        storage: storage_info,
-        network: network_info,
-        usb: usb_devices,
-        pci: pci_devices,
-        motherboard,
-        battery,
-    }
-}
-
-fn get_cpu_caches(
-    cpuid: &CpuId<CpuIdReaderNative>,
-) -> (Option<String>, Option<String>, Option<String>) {
-    let mut l1 = None;
-    let mut l2 = None;
-    let mut l3 = None;
-
-    if let Some(cache_params) = cpuid.get_cache_parameters() {
-        for cache in cache_params {
-            // raw-cpuid levels: 1 = L1, 2 = L2, etc.
-            // associativity() etc. return raw values which might need +1 if they follow the CPUID spec literally.
-            // But let's check if we can get a cleaner value.
-            // Re-calculating with +1 only where necessary.
-            let ways = cache.associativity() as u64;
-            let partitions = cache.physical_line_partitions() as u64;
-            let line_size = cache.coherency_line_size() as u64;
-            let sets = cache.sets() as u64;
-
-            // Intel docs say: Size = (Ways + 1) * (Partitions + 1) * (LineSize + 1) * (Sets + 1)
-            // But raw-cpuid might already add them.
-            // Let's test if the values are raw.
-            // If they are raw, 66KB (ways=8+1=9?) -> 64KB should be ways=8.
-            let size_kb = (ways * partitions * line_size * sets) / 1024;
-
-            let level = cache.level();
-            let label = format!("{} KB", size_kb);
-            match level {
-                1 => l1 = Some(label),
-                2 => l2 = Some(label),
-                3 => l3 = Some(label),
-                _ => {}
-            }
-        }
-    }
+/// `route -n get default` on macOS and the BSDs prints the route as
+/// "gateway: 192.168.1.1" and "interface: en0" lines.
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "openbsd"))]
+fn route_network_config() -> Option<NetworkConfig> {
+    let default_route = |family: Option<&str>| {
+        let output = std::process::Command::new("route")
+            .args(["-n", "get"])
+            .args(family)
+            .arg("default")
+            .output()
+            .ok()?;
+        let text = String::from_utf8_lossy(&output.stdout);
+        let field = |key: &str| {
+            text.lines()
+                .find_map(|l| l.trim().strip_prefix(key))
+                .map(|v| v.trim().to_string())
+        };
+        Some(crate::model::Gateway {
+            address: field("gateway:")?,
+            interface: field("interface:")?,
+        })
+    };
+    NetworkConfig::new(
+        default_route(None),
+        default_route(Some("-inet6")),
+        resolv_conf_servers("/etc/resolv.conf"),
+    )
+}
 
-    (l1, l2, l3)
-}
-
-fn get_ram_details() -> Vec<RamStick> {
-    let mut sticks = Vec::new();
-    #[cfg(target_os = "linux")]
-    {
-        use smbioslib::{SMBiosMemoryDevice, SMBiosStruct};
-
-        if let Ok(data) = table_load_from_device() {
-            for sm_struct in data.iter() {
-                if sm_struct.header.struct_type() == 17 {
-                    let dev = SMBiosMemoryDevice::new(sm_struct);
-
-                    let manufacturer_raw = format!("{}", dev.manufacturer());
-                    let part_number = format!("{}", dev.part_number());
-                    let serial_number = format!("{}", dev.serial_number());
-
-                    let speed = dev.configured_memory_speed().map(|s| {
-                        let s_str = format!("{:?}", s);
-                        s_str
-                            .chars()
-                            .filter(|c| c.is_digit(10))
-                            .collect::<String>()
-                            .parse::<u16>()
-                            .unwrap_or(0)
-                    });
-
-                    let clean = |s: String| {
-                        let t = s.trim();
-                        if t.is_empty()
-                            || t.to_lowercase() == "unknown"
-                            || t.to_lowercase() == "none"
-                            || t.to_lowercase() == "not specified"
-                            || t.to_lowercase().contains("empty")
-                            || t == "0"
-                        {
-                            None
-                        } else {
-                            Some(t.to_string())
-                        }
-                    };
-
-                    if let Some(m) = clean(manufacturer_raw) {
-                        sticks.push(RamStick {
-                            manufacturer: Some(map_ram_manufacturer(&m)),
-                            part_number: clean(part_number),
-                            serial_number: clean(serial_number),
-                            speed: speed.and_then(|s| if s > 0 { Some(s) } else { None }),
-                        });
-                    }
-                }
-            }
-        }
-    }
-    sticks
-}
-
-fn map_ram_manufacturer(id: &str) -> String {
-    let id_upper = id.to_uppercase();
-    if id_upper.contains("0198") {
-        "Kingston".to_string()
-    } else if id_upper.contains("04CB") {
-        "ADATA".to_string()
-    } else if id_upper.contains("00AD") || id_upper.contains("80AD") {
-        "SK Hynix".to_string()
-    } else if id_upper.contains("00CE") || id_upper.contains("80CE") {
-        "Samsung".to_string()
-    } else if id_upper.contains("012F") || id_upper.contains("812F") {
-        "Micron".to_string()
-    } else if id_upper.contains("029E") || id_upper.contains("829E") {
-        "Corsair".to_string()
-    } else if id_upper.contains("0423") || id_upper.contains("8423") {
-        "Crucial".to_string()
-    } else if id_upper.contains("059B") || id_upper.contains("859B") {
-        "Crucial".to_string()
-    } else {
-        id.to_string()
-    }
-}
-
-fn get_disk_metadata(
-    name: &str,
-) -> (
-    Option<String>,
-    Option<String>,
-    Option<String>,
-    Option<String>,
-) {
-    #[cfg(target_os = "linux")]
-    {
-        let mut parent_name = name.to_string();
-        if name.starts_with("sd") || name.starts_with("hd") {
-            parent_name = name.trim_end_matches(char::is_numeric).to_string();
-        } else if name.contains('p') && name.starts_with("nvme") {
-            if let Some(pos) = name.rfind('p') {
-                parent_name = name[..pos].to_string();
-            }
-        }
+fn sysinfo_temperatures() -> Vec<TemperatureSensor> {
+    Components::new_with_refreshed_list()
+        .list()
+        .iter()
+        .map(|c| TemperatureSensor {
+            label: c.label().to_string(),
+            temperature: c.temperature(),
+            max: c.max(),
+            critical: c.critical(),
+        })
+        .collect()
+}
+
+fn libusb_ids() -> Vec<(u8, u8, u16, u16)> {
+    let Ok(context) = rusb::Context::new() else {
+        return Vec::new();
+    };
+    let Ok(list) = context.devices() else {
+        return Vec::new();
+    };
+    list.iter()
+        .filter_map(|device| {
+            let desc = device.device_descriptor().ok()?;
+            Some((
+                device.bus_number(),
+                device.address(),
+                desc.vendor_id(),
+                desc.product_id(),
+            ))
+        })
+        .collect()
+}
 
-        let model = fs::read_to_string(format!("/sys/block/{}/device/model", parent_name))
-            .ok()
-            .map(|s| s.trim().to_string());
-        let sn = fs::read_to_string(format!("/sys/block/{}/device/serial", parent_name))
-            .ok()
-            .map(|s| s.trim().to_string());
-        let vendor = fs::read_to_string(format!("/sys/block/{}/device/vendor", parent_name))
-            .ok()
-            .map(|s| s.trim().to_string());
-
-        let interface = if parent_name.starts_with("nvme") {
-            Some("NVMe".to_string())
-        } else if parent_name.starts_with("sd") {
-            Some("SATA/SAS".to_string())
+fn libusb_devices() -> Vec<UsbDevice> {
+    let mut devices = Vec::new();
+    let Ok(context) = rusb::Context::new() else {
+        return devices;
+    };
+    let Ok(list) = context.devices() else {
+        return devices;
+    };
+    for device in list.iter() {
+        let Ok(desc) = device.device_descriptor() else {
+            continue;
+        };
+        let (m_string, p_string) = if let Ok(h) = device.open() {
+            let m = h.read_manufacturer_string_ascii(&desc).ok();
+            let p = h.read_product_string_ascii(&desc).ok();
+            (m, p)
         } else {
-            None
+            (None, None)
         };
 
-        (vendor, model, sn, interface)
-    }
-    #[cfg(not(target_os = "linux"))]
-    {
-        (None, None, None, None)
+        devices.push(UsbDevice {
+            bus: device.bus_number(),
+            address: device.address(),
+            vendor_id: desc.vendor_id(),
+            product_id: desc.product_id(),
+            manufacturer: m_string,
+            product: p_string,
+        });
     }
+    devices
 }
 
-fn get_usb_devices() -> Vec<UsbDevice> {
-    let mut devices = Vec::new();
-    if let Ok(context) = rusb::Context::new() {
-        if let Ok(list) = context.devices() {
-            for device in list.iter() {
-                if let Ok(desc) = device.device_descriptor() {
-                    let handle = device.open();
-                    let (m_string, p_string) = if let Ok(h) = handle {
-                        let m = h.read_manufacturer_string_ascii(&desc).ok();
-                        let p = h.read_product_string_ascii(&desc).ok();
-                        (m, p)
-                    } else {
-                        (None, None)
-                    };
-
-                    devices.push(UsbDevice {
-                        bus: device.bus_number(),
-                        address: device.address(),
-                        vendor_id: desc.vendor_id(),
-                        product_id: desc.product_id(),
-                        manufacturer: m_string,
-                        product: p_string,
-                    });
-                }
-            }
-        }
+// Devices without a udev rule granting access can be listed but not opened,
+// so their manufacturer and product strings are missing.
+fn libusb_limitations() -> Vec<Limitation> {
+    let Ok(context) = rusb::Context::new() else {
+        return Vec::new();
+    };
+    let Ok(list) = context.devices() else {
+        return Vec::new();
+    };
+    let denied = list
+        .iter()
+        .filter(|device| matches!(device.open(), Err(rusb::Error::Access)))
+        .count();
+    if denied == 0 {
+        return Vec::new();
     }
-    devices
+    vec![Limitation {
+        probe: "USB device names".to_string(),
+        reason: format!("{} device(s) can only be opened by root", denied),
+    }]
 }
 
-fn get_pci_devices() -> Vec<PciDevice> {
+fn enumerate_pci() -> Vec<PciDevice> {
     let mut devices = Vec::new();
     let pci_db = load_pci_db();
 
     if let Ok(pci) = pci_info::PciInfo::enumerate_pci() {
-        for function_res in pci {
-            if let Ok(function) = function_res {
-                let v_id = function.vendor_id();
-                let d_id = function.device_id();
-
-                let (v_name, d_name) = pci_db
-                    .get(&(v_id, d_id))
-                    .map(|(v, d)| (v.clone(), d.clone()))
-                    .unwrap_or_else(|| {
-                        let v_only = pci_db
-                            .get(&(v_id, 0xFFFF))
-                            .map(|(v, _)| (v.clone(), None))
-                            .unwrap_or((None, None));
-                        v_only
-                    });
-
-                devices.push(PciDevice {
-                    slot: format!("{:?}", function.location()),
-                    vendor_id: v_id,
-                    device_id: d_id,
-                    vendor_name: v_name,
-                    device_name: d_name,
-                    class_name: None,
-                });
-            }
+        for function in pci.into_iter().flatten() {
+            let v_id = function.vendor_id();
+            let d_id = function.device_id();
+
+            let (v_name, d_name) = pci_names(&pci_db, v_id, d_id);
+
+            devices.push(PciDevice {
+                slot: format!("{:?}", function.location()),
+                vendor_id: v_id,
+                device_id: d_id,
+                vendor_name: v_name,
+                device_name: d_name,
+                class_name: None,
+                revision: function.revision().ok(),
+                subsystem_vendor_id: function.subsystem_vendor_id().ok().flatten(),
+                subsystem_device_id: function.subsystem_device_id().ok().flatten(),
+                numa_node: None,
+                iommu_group: None,
+            });
         }
     }
     devices
 }
 
-fn load_pci_db() -> HashMap<(u16, u16), (Option<String>, Option<String>)> {
+type PciDb = HashMap<(u16, u16), (Option<String>, Option<String>)>;
+
+/// (vendor, device) names from pci.ids, or just the vendor for unlisted devices.
+fn pci_names(db: &PciDb, vendor_id: u16, device_id: u16) -> (Option<String>, Option<String>) {
+    db.get(&(vendor_id, device_id))
+        .map(|(v, d)| (v.clone(), d.clone()))
+        .unwrap_or_else(|| {
+            db.get(&(vendor_id, 0xFFFF))
+                .map(|(v, _)| (v.clone(), None))
+                .unwrap_or((None, None))
+        })
+}
+
+fn load_pci_db() -> PciDb {
     let mut db = HashMap::new();
     let paths = ["/usr/share/misc/pci.ids", "/var/lib/pciutils/pci.ids"];
 
@@ -347,7 +947,7 @@ fn load_pci_db() -> HashMap<(u16, u16), (Option<String>, Option<String>)> {
             let mut current_vendor_id: Option<u16> = None;
             let mut current_vendor_name: Option<String> = None;
 
-            for line in reader.lines().flatten() {
+            for line in reader.lines().map_while(Result::ok) {
                 if line.trim().is_empty() || line.starts_with('#') || line.starts_with('C') {
                     continue;
                 }
@@ -357,33 +957,26 @@ fn load_pci_db() -> HashMap<(u16, u16), (Option<String>, Option<String>)> {
                 }
 
                 if line.starts_with('\t') {
-                    if let Some(v_id) = current_vendor_id {
-                        let content = line.trim();
-                        let mut parts = content.splitn(2, ' ');
-                        if let Some(id_str) = parts.next() {
-                            if let Ok(d_id) = u16::from_str_radix(id_str, 16) {
-                                if let Some(name) = parts.next() {
-                                    db.insert(
-                                        (v_id, d_id),
-                                        (
-                                            current_vendor_name.clone(),
-                                            Some(name.trim().to_string()),
-                                        ),
-                                    );
-                                }
-                            }
-                        }
+                    let mut parts = line.trim().splitn(2, ' ');
+                    if let Some(v_id) = current_vendor_id
+                        && let Some(id_str) = parts.next()
+                        && let Ok(d_id) = u16::from_str_radix(id_str, 16)
+                        && let Some(name) = parts.next()
+                    {
+                        db.insert(
+                            (v_id, d_id),
+                            (current_vendor_name.clone(), Some(name.trim().to_string())),
+                        );
                     }
                 } else {
                     let mut parts = line.splitn(2, ' ');
-                    if let Some(id_str) = parts.next() {
-                        if let Ok(v_id) = u16::from_str_radix(id_str, 16) {
-                            if let Some(name) = parts.next() {
-                                current_vendor_id = Some(v_id);
-                                current_vendor_name = Some(name.trim().to_string());
-                                db.insert((v_id, 0xFFFF), (current_vendor_name.clone(), None));
-                            }
-                        }
+                    if let Some(id_str) = parts.next()
+                        && let Ok(v_id) = u16::from_str_radix(id_str, 16)
+                        && let Some(name) = parts.next()
+                    {
+                        current_vendor_id = Some(v_id);
+                        current_vendor_name = Some(name.trim().to_string());
+                        db.insert((v_id, 0xFFFF), (current_vendor_name.clone(), None));
                     }
                 }
             }
@@ -393,52 +986,216 @@ fn load_pci_db() -> HashMap<(u16, u16), (Option<String>, Option<String>)> {
     db
 }
 
-fn get_motherboard_info() -> Option<MotherboardInfo> {
-    #[cfg(target_os = "linux")]
-    {
-        let read_sys = |path: &str| {
-            fs::read_to_string(format!("/sys/class/dmi/id/{}", path))
-                .map(|s| s.trim().to_string())
-                .unwrap_or_else(|_| "Unknown".to_string())
-        };
+// Cumulative counters from one /proc/diskstats line.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DiskStats {
+    pub reads_completed: u64,
+    pub sectors_read: u64,
+    pub writes_completed: u64,
+    pub sectors_written: u64,
+}
+
+// Cumulative counts from one /proc/interrupts line.
+#[derive(Debug, Clone, Default)]
+pub struct IrqStats {
+    pub name: String,
+    pub per_cpu: Vec<u64>,
+    pub affinity: Option<String>,
+}
 
-        Some(MotherboardInfo {
-            vendor: read_sys("board_vendor"),
-            product: read_sys("board_name"),
-            bios_vendor: read_sys("bios_vendor"),
-            bios_version: read_sys("bios_version"),
-            bios_date: read_sys("bios_date"),
+/// Interrupt rates between two reads, busiest first; sources that did not
+/// fire in between are left out.
+pub fn irq_rates(
+    prev: &HashMap<String, IrqStats>,
+    curr: &HashMap<String, IrqStats>,
+    elapsed: Duration,
+) -> Vec<IrqInfo> {
+    let secs = elapsed.as_secs_f64().max(0.001);
+    let mut rates: Vec<IrqInfo> = curr
+        .iter()
+        .filter_map(|(irq, now)| {
+            let before = prev.get(irq)?;
+            let per_cpu: Vec<f64> = now
+                .per_cpu
+                .iter()
+                .enumerate()
+                .map(|(cpu, count)| {
+                    let before = before.per_cpu.get(cpu).copied().unwrap_or(0);
+                    count.saturating_sub(before) as f64 / secs
+                })
+                .collect();
+            let rate: f64 = per_cpu.iter().sum();
+            (rate > 0.0).then(|| IrqInfo {
+                irq: irq.clone(),
+                name: now.name.clone(),
+                rate,
+                per_cpu,
+                affinity: now.affinity.clone(),
+            })
+        })
+        .collect();
+    rates.sort_by(|a, b| b.rate.total_cmp(&a.rate).then_with(|| a.irq.cmp(&b.irq)));
+    rates
+}
+
+pub fn get_irqs(interval: Duration) -> Vec<IrqInfo> {
+    let start = Instant::now();
+    let prev = read_interrupts();
+    std::thread::sleep(interval);
+    let curr = read_interrupts();
+    irq_rates(&prev, &curr, start.elapsed())
+}
+
+// Cumulative jiffies from the `cpu` line of /proc/stat.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CpuTicks {
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+    pub irq: u64,
+    pub softirq: u64,
+    pub steal: u64,
+}
+
+/// The CPU time split between two reads of the counters; `None` if no time passed.
+pub fn cpu_time_shares(prev: &CpuTicks, curr: &CpuTicks) -> Option<CpuTimes> {
+    let delta = |now: u64, before: u64| now.saturating_sub(before) as f32;
+    let user = delta(curr.user, prev.user) + delta(curr.nice, prev.nice);
+    let system = delta(curr.system, prev.system)
+        + delta(curr.irq, prev.irq)
+        + delta(curr.softirq, prev.softirq);
+    let iowait = delta(curr.iowait, prev.iowait);
+    let steal = delta(curr.steal, prev.steal);
+    let idle = delta(curr.idle, prev.idle);
+    let total = user + system + iowait + steal + idle;
+    (total > 0.0).then(|| CpuTimes {
+        user: user * 100.0 / total,
+        system: system * 100.0 / total,
+        iowait: iowait * 100.0 / total,
+        steal: steal * 100.0 / total,
+        idle: idle * 100.0 / total,
+    })
+}
+
+pub fn disk_io_rates(
+    prev: &HashMap<String, DiskStats>,
+    curr: &HashMap<String, DiskStats>,
+    elapsed: Duration,
+) -> Vec<DiskIoInfo> {
+    // diskstats always counts in 512-byte sectors, regardless of the device block size
+    const SECTOR_SIZE: f64 = 512.0;
+    let secs = elapsed.as_secs_f64().max(0.001);
+
+    let mut rates: Vec<DiskIoInfo> = curr
+        .iter()
+        .filter_map(|(name, now)| {
+            let before = prev.get(name)?;
+            Some(DiskIoInfo {
+                name: name.clone(),
+                read_bytes_per_sec: now.sectors_read.saturating_sub(before.sectors_read) as f64
+                    * SECTOR_SIZE
+                    / secs,
+                write_bytes_per_sec: now.sectors_written.saturating_sub(before.sectors_written)
+                    as f64
+                    * SECTOR_SIZE
+                    / secs,
+                read_iops: now.reads_completed.saturating_sub(before.reads_completed) as f64 / secs,
+                write_iops: now.writes_completed.saturating_sub(before.writes_completed) as f64
+                    / secs,
+            })
         })
+        .collect();
+    rates.sort_by(|a, b| a.name.cmp(&b.name));
+    rates
+}
+
+pub fn get_disk_io(interval: Duration) -> Vec<DiskIoInfo> {
+    let start = Instant::now();
+    let prev = read_diskstats();
+    std::thread::sleep(interval);
+    let curr = read_diskstats();
+    disk_io_rates(&prev, &curr, start.elapsed())
+}
+
+/// Keeps the sysinfo handles alive between samples so CPU usage is measured
+/// over the interval since the previous call.
+pub struct MetricsSampler {
+    sys: System,
+    disks: Disks,
+    networks: Networks,
+}
+
+impl MetricsSampler {
+    pub fn new() -> Self {
+        let mut sys = System::new_with_specifics(
+            RefreshKind::nothing()
+                .with_cpu(CpuRefreshKind::nothing().with_cpu_usage())
+                .with_memory(sysinfo::MemoryRefreshKind::everything()),
+        );
+        sys.refresh_cpu_usage();
+        Self {
+            sys,
+            disks: Disks::new_with_refreshed_list(),
+            networks: Networks::new_with_refreshed_list(),
+        }
     }
-    #[cfg(not(target_os = "linux"))]
-    {
-        None
+
+    pub fn sample(&mut self) -> MetricsSample {
+        self.sys.refresh_cpu_usage();
+        self.sys.refresh_memory();
+        self.disks.refresh(true);
+        self.networks.refresh(true);
+
+        let mut disks: Vec<DiskUsageSample> = self
+            .disks
+            .list()
+            .iter()
+            .map(|d| DiskUsageSample {
+                mount_point: d.mount_point().to_string_lossy().into_owned(),
+                used: d.total_space().saturating_sub(d.available_space()),
+                total: d.total_space(),
+            })
+            .collect();
+        disks.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+        disks.dedup_by(|a, b| a.mount_point == b.mount_point);
+
+        let mut network: Vec<NetworkCounterSample> = self
+            .networks
+            .iter()
+            .map(|(name, data)| NetworkCounterSample {
+                name: name.clone(),
+                received: data.total_received(),
+                transmitted: data.total_transmitted(),
+            })
+            .collect();
+        network.sort_by(|a, b| a.name.cmp(&b.name));
+
+        MetricsSample {
+            timestamp: chrono::Local::now().to_rfc3339(),
+            cpu_usage: self.sys.global_cpu_usage(),
+            ram_used: self.sys.used_memory(),
+            ram_total: self.sys.total_memory(),
+            swap_used: self.sys.used_swap(),
+            swap_total: self.sys.total_swap(),
+            disks,
+            temperatures: get_temperatures()
+                .into_iter()
+                .filter_map(|s| {
+                    Some(TemperatureSample {
+                        celsius: s.temperature?,
+                        label: s.label,
+                    })
+                })
+                .collect(),
+            network,
+        }
     }
 }
 
-fn get_battery_info() -> Vec<BatteryInfo> {
-    let mut batteries = Vec::new();
-    #[cfg(target_os = "linux")]
-    {
-        if let Ok(entries) = fs::read_dir("/sys/class/power_supply/") {
-            for entry in entries.flatten() {
-                let name = entry.file_name().to_string_lossy().to_string();
-                if name.starts_with("BAT") {
-                    let status = fs::read_to_string(entry.path().join("status"))
-                        .map(|s| s.trim().to_string())
-                        .unwrap_or_else(|_| "Unknown".to_string());
-                    let capacity = fs::read_to_string(entry.path().join("capacity"))
-                        .map(|s| s.trim().parse::<u8>().unwrap_or(0))
-                        .unwrap_or(0);
-
-                    batteries.push(BatteryInfo {
-                        name,
-                        status,
-                        capacity,
-                    });
-                }
-            }
-        }
+impl Default for MetricsSampler {
+    fn default() -> Self {
+        Self::new()
     }
-    batteries
 }
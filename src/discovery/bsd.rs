@@ -0,0 +1,329 @@
+//! FreeBSD and OpenBSD discovery through `sysctl`, `kenv`, `geom` and `apm`,
+//! for appliances (pfSense, TrueNAS CORE, OpenBSD firewalls) where sysfs and
+//! DMI files do not exist.
+
+use super::Probe;
+use crate::model::{
+    BatteryInfo, CloudProvider, CoreMetrics, CpuPackage, Filesystem, MotherboardInfo,
+    NetworkConfig, PhysicalDisk,
+};
+use std::process::Command;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BsdProbe;
+
+impl Probe for BsdProbe {
+    fn cpus(&self) -> Vec<CpuPackage> {
+        let cpus = super::sysinfo_cpus();
+        if cpus.is_empty() { cpu_info() } else { cpus }
+    }
+
+    fn disks(&self) -> Vec<PhysicalDisk> {
+        disk_names()
+            .iter()
+            .map(|name| physical_disk(name))
+            .collect()
+    }
+
+    fn filesystems(&self) -> Vec<Filesystem> {
+        let mut filesystems = super::sysinfo_filesystems();
+        for fs in &mut filesystems {
+            fs.disk = disk_of(&fs.name);
+        }
+        filesystems
+    }
+
+    fn network_config(&self) -> Option<NetworkConfig> {
+        super::route_network_config()
+    }
+
+    fn motherboard(&self) -> Option<MotherboardInfo> {
+        motherboard_info()
+    }
+
+    fn cloud_provider(&self) -> Option<CloudProvider> {
+        let fields: Vec<String> = [
+            "smbios.system.maker",
+            "smbios.system.product",
+            "smbios.bios.vendor",
+            "smbios.bios.version",
+            "smbios.chassis.tag",
+        ]
+        .iter()
+        .filter_map(|name| kenv(name))
+        .collect();
+        let fields: Vec<&str> = fields.iter().map(String::as_str).collect();
+        crate::cloud::provider_from_dmi(&fields)
+    }
+
+    fn batteries(&self) -> Vec<BatteryInfo> {
+        battery_info()
+    }
+}
+
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+fn sysctl(name: &str) -> Option<String> {
+    run("sysctl", &["-n", name])
+}
+
+/// The CPU from `hw.model`/`hw.ncpu`, for systems where sysinfo lists no
+/// CPUs (it does not support OpenBSD).
+fn cpu_info() -> Vec<CpuPackage> {
+    let Some(model) = sysctl("hw.model") else {
+        return Vec::new();
+    };
+    let count = sysctl("hw.ncpuonline")
+        .or_else(|| sysctl("hw.ncpu"))
+        .and_then(|n| n.parse::<usize>().ok())
+        .unwrap_or(1);
+    // FreeBSD exposes the current clock per CPU, OpenBSD one value for all
+    let frequency = sysctl("dev.cpu.0.freq")
+        .or_else(|| sysctl("hw.cpuspeed"))
+        .and_then(|f| f.parse::<u64>().ok())
+        .unwrap_or(0);
+    let cores = sysctl("kern.smp.cores")
+        .and_then(|c| c.parse::<usize>().ok())
+        .unwrap_or(count);
+    vec![CpuPackage {
+        model,
+        vendor: String::new(),
+        caches: Vec::new(),
+        physical_cores: cores,
+        logical_cores: count,
+        core_groups: Vec::new(),
+        cores: (0..count)
+            .map(|id| CoreMetrics {
+                id,
+                kind: None,
+                cluster: None,
+                frequency,
+                usage: 0.0,
+            })
+            .collect(),
+    }]
+}
+
+fn kenv(name: &str) -> Option<String> {
+    run("kenv", &["-q", name]).filter(|v| !v.eq_ignore_ascii_case("To be filled by O.E.M."))
+}
+
+/// Board and BIOS strings: FreeBSD's loader copies SMBIOS into the kernel
+/// environment; OpenBSD publishes the system vendor and product as sysctls.
+fn motherboard_info() -> Option<MotherboardInfo> {
+    let unknown = || "Unknown".to_string();
+    if let Some(vendor) = kenv("smbios.planar.maker").or_else(|| kenv("smbios.system.maker")) {
+        return Some(MotherboardInfo {
+            vendor,
+            product: kenv("smbios.planar.product")
+                .or_else(|| kenv("smbios.system.product"))
+                .unwrap_or_else(unknown),
+            bios_vendor: kenv("smbios.bios.vendor").unwrap_or_else(unknown),
+            bios_version: kenv("smbios.bios.version").unwrap_or_else(unknown),
+            bios_date: kenv("smbios.bios.reldate").unwrap_or_else(unknown),
+        });
+    }
+    let vendor = sysctl("hw.vendor")?;
+    Some(MotherboardInfo {
+        vendor,
+        product: sysctl("hw.product").unwrap_or_else(unknown),
+        bios_vendor: unknown(),
+        bios_version: sysctl("hw.version").unwrap_or_else(unknown),
+        bios_date: unknown(),
+    })
+}
+
+/// The whole-disk device behind a partition or slice: ada0p2 -> ada0, da1s1a -> da1.
+fn parent_disk(name: &str) -> String {
+    let name = name.trim_start_matches("/dev/");
+    let letters = name.chars().take_while(|c| c.is_ascii_alphabetic()).count();
+    let digits = name[letters..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    name[..letters + digits].to_string()
+}
+
+fn interface_for(disk: &str) -> Option<String> {
+    let driver = disk.trim_end_matches(|c: char| c.is_ascii_digit());
+    match driver {
+        "nvd" | "nda" | "nvme" => Some("NVMe".to_string()),
+        "ada" | "wd" => Some("SATA".to_string()),
+        "da" | "sd" => Some("SCSI/SAS/USB".to_string()),
+        "mmcsd" => Some("SD/MMC".to_string()),
+        _ => None,
+    }
+}
+
+/// The disk holding `name`. ZFS datasets such as `zroot/ROOT/default` do
+/// not name one.
+fn disk_of(name: &str) -> Option<String> {
+    if name.contains('/') && !name.starts_with("/dev/") {
+        return None;
+    }
+    Some(parent_disk(name)).filter(|disk| !disk.is_empty())
+}
+
+/// Disk devices from `kern.disks` (FreeBSD, e.g. "ada0 nvd0 cd0") or
+/// `hw.disknames` (OpenBSD, e.g. "sd0:1e2f3a4b5c6d7e8f,cd0:"), without
+/// optical and memory disks.
+fn disk_names() -> Vec<String> {
+    let list = sysctl("kern.disks")
+        .or_else(|| sysctl("hw.disknames"))
+        .unwrap_or_default();
+    let mut names: Vec<String> = list
+        .split([' ', ','])
+        .filter_map(|entry| entry.split(':').next())
+        .filter(|name| !name.is_empty())
+        .filter(|name| {
+            let driver = name.trim_end_matches(|c: char| c.is_ascii_digit());
+            !matches!(driver, "cd" | "md" | "rd" | "vnd")
+        })
+        .map(str::to_string)
+        .collect();
+    names.sort();
+    names
+}
+
+fn physical_disk(disk: &str) -> PhysicalDisk {
+    let mut info = PhysicalDisk {
+        name: disk.to_string(),
+        size: 0,
+        vendor: None,
+        model_name: None,
+        serial_number: None,
+        disk_type: None,
+        rotational: None,
+        interface: interface_for(disk),
+        link_speed: None,
+        smart: super::smart_health(&format!("/dev/{}", disk)),
+    };
+
+    // FreeBSD: `geom disk list ada0` prints "Mediasize: <bytes> (466G)",
+    // "descr: <model>", "ident: <serial>" and "rotationrate: <rpm, 0 for SSD>"
+    if let Some(list) = run("geom", &["disk", "list", disk]) {
+        let field = |key: &str| {
+            list.lines()
+                .find_map(|l| l.trim().strip_prefix(key))
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty() && v != "(null)")
+        };
+        info.size = field("Mediasize:")
+            .and_then(|m| m.split_whitespace().next()?.parse().ok())
+            .unwrap_or(0);
+        info.model_name = field("descr:");
+        info.serial_number = field("ident:");
+        info.rotational = match field("rotationrate:").as_deref() {
+            Some("0") => Some(false),
+            Some("unknown") | None => None,
+            Some(_) => Some(true),
+        };
+        info.disk_type = info
+            .rotational
+            .map(|rotational| if rotational { "HDD" } else { "SSD" }.to_string());
+        return info;
+    }
+
+    // OpenBSD: the attach line in dmesg, e.g. "sd0 at scsibus1 ...: <ATA, Samsung SSD 860, RVT0>",
+    // followed by "sd0: 476940MB, 512 bytes/sector, ..."
+    if let Ok(dmesg) = std::fs::read_to_string("/var/run/dmesg.boot") {
+        if let Some(line) = dmesg
+            .lines()
+            .rev()
+            .find(|l| l.starts_with(&format!("{} at ", disk)) && l.contains('<'))
+            && let Some(inner) = line.split_once('<').and_then(|(_, r)| r.split_once('>'))
+        {
+            let parts: Vec<&str> = inner.0.split(',').map(str::trim).collect();
+            let part = |i: usize| {
+                parts
+                    .get(i)
+                    .map(|v| v.to_string())
+                    .filter(|v| !v.is_empty())
+            };
+            info.vendor = part(0);
+            info.model_name = part(1);
+            // Only the disk's DUID is exposed, not its serial number
+        }
+        info.size = dmesg
+            .lines()
+            .rev()
+            .find_map(|l| l.strip_prefix(&format!("{}: ", disk))?.split_once("MB,"))
+            .and_then(|(mb, _)| mb.trim().parse::<u64>().ok())
+            .map_or(0, |mb| mb * 1024 * 1024);
+    }
+    info
+}
+
+fn battery_info() -> Vec<BatteryInfo> {
+    // FreeBSD: hw.acpi.battery.state is a bit mask (1 discharging, 2 charging, 4 critical)
+    if let Some(life) = sysctl("hw.acpi.battery.life").and_then(|l| l.parse::<i64>().ok()) {
+        let units = sysctl("hw.acpi.battery.units")
+            .and_then(|u| u.parse::<u32>().ok())
+            .unwrap_or(1);
+        if units == 0 || life < 0 {
+            return Vec::new();
+        }
+        let state = sysctl("hw.acpi.battery.state")
+            .and_then(|s| s.parse::<u32>().ok())
+            .unwrap_or(0);
+        let status = if state & 2 != 0 {
+            "Charging"
+        } else if state & 1 != 0 {
+            "Discharging"
+        } else if life >= 100 {
+            "Full"
+        } else {
+            "Not charging"
+        };
+        return vec![BatteryInfo {
+            name: "battery".to_string(),
+            status: status.to_string(),
+            capacity: life.clamp(0, 100) as u8,
+            power_watts: sysctl("hw.acpi.battery.rate")
+                .and_then(|r| r.parse::<f32>().ok())
+                .filter(|r| *r > 0.0)
+                .map(|mw| mw / 1000.0),
+            cycle_count: None,
+            wear_percent: None,
+            // minutes, -1 while charging or unknown
+            time_remaining: sysctl("hw.acpi.battery.time")
+                .and_then(|m| m.parse::<i64>().ok())
+                .filter(|m| *m >= 0)
+                .map(|m| m as u64 * 60),
+        }];
+    }
+
+    // OpenBSD: apm -b is 0 high, 1 low, 2 critical, 3 charging, 4 absent, 255 unknown
+    let Some(state) = run("apm", &["-b"]).and_then(|s| s.parse::<u32>().ok()) else {
+        return Vec::new();
+    };
+    if state == 4 || state == 255 {
+        return Vec::new();
+    }
+    let capacity = run("apm", &["-l"])
+        .and_then(|l| l.parse::<i64>().ok())
+        .unwrap_or(0);
+    let on_ac = run("apm", &["-a"]).as_deref() == Some("1");
+    let status = match (state, on_ac) {
+        (3, _) => "Charging",
+        (_, true) if capacity >= 100 => "Full",
+        (_, true) => "Not charging",
+        _ => "Discharging",
+    };
+    vec![BatteryInfo {
+        name: "battery".to_string(),
+        status: status.to_string(),
+        capacity: capacity.clamp(0, 100) as u8,
+        power_watts: None,
+        cycle_count: None,
+        wear_percent: None,
+        time_remaining: None,
+    }]
+}
@@ -0,0 +1,15 @@
+//! Backend for targets without native support (e.g. Windows): only what
+//! sysinfo, libusb and pci-info report portably, through the [`Probe`]
+//! defaults. That is the OS, CPUs, RAM totals, filesystems, network
+//! interfaces, USB and PCI devices and temperatures.
+//!
+//! Native Windows probing (WMI for drives, batteries and SMART, the SMBIOS
+//! table from `GetSystemFirmwareTable` for RAM modules and the board) is out
+//! of scope for now: those sections come back empty rather than failing.
+
+use super::Probe;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GenericProbe;
+
+impl Probe for GenericProbe {}
@@ -0,0 +1,2556 @@
+//! Linux backend: sysfs, procfs and the SMBIOS tables, read from the live
+//! system or from a captured tree (`--from-sysfs`).
+
+use super::{CpuTicks, DiskStats, IrqStats, Probe, SystemIdentity};
+use crate::config::Sections;
+use crate::model::{
+    BatteryInfo, BoardLayout, CloudProvider, ContainerLimits, CoreGroup, CoreKind, CoreMetrics,
+    CpuPackage, FanInfo, Filesystem, Gateway, GpuInfo, GpuProcess, InterfaceKind, InterfaceMembers,
+    IommuDevice, IommuGroup, IommuInfo, KernelLimit, Limitation, MemberPort, MemorySettings,
+    MemorySlots, MotherboardInfo, NetworkConfig, NetworkInfo, OsDetails, PciDevice, PhysicalDisk,
+    PlatformState, Pressure, PressureStall, RamInfo, RamStick, SpdInfo, TemperatureSensor,
+    ThunderboltDevice, TimeHealth, UsbDevice, VirtualMemory, VirtualizationSupport,
+};
+use crate::sysfs::{self, SysfsRoot};
+use smbioslib::{SMBiosData, table_load_from_device};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub struct LinuxProbe {
+    pub root: SysfsRoot,
+}
+
+impl LinuxProbe {
+    pub fn with_root(root: SysfsRoot) -> Self {
+        Self { root }
+    }
+}
+
+impl LinuxProbe {
+    fn smbios(&self) -> Option<SMBiosData> {
+        if self.root.is_live() {
+            table_load_from_device().ok()
+        } else {
+            smbioslib::load_smbios_data_from_file(&self.root.path("/sys/firmware/dmi/tables/DMI"))
+                .ok()
+        }
+    }
+
+    /// The card in a slot at `address`. Firmware gives either the card's own
+    /// address or, for PCIe, the root port the slot hangs off; then the card
+    /// is the first function below that bridge.
+    fn slot_device(&self, address: &str) -> Option<String> {
+        let dir = format!("/sys/bus/pci/devices/{}", address);
+        let class = self.root.read(format!("{}/class", dir))?;
+        if !class.starts_with("0x0604") {
+            return Some(address.to_string());
+        }
+        let mut children: Vec<String> = self
+            .root
+            .read_dir(&dir)
+            .ok()?
+            .flatten()
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .filter(|name| is_pci_address(name))
+            .collect();
+        children.sort();
+        children.into_iter().next()
+    }
+}
+
+impl Default for LinuxProbe {
+    /// Reads from the root set by `--from-sysfs`, or the live system.
+    fn default() -> Self {
+        Self::with_root(sysfs::root().clone())
+    }
+}
+
+impl Probe for LinuxProbe {
+    fn system(&self) -> SystemIdentity {
+        if self.root.is_live() {
+            return super::sysinfo_system();
+        }
+        let os_release = self.root.read("/etc/os-release").unwrap_or_default();
+        let os_field = |key: &str| {
+            os_release
+                .lines()
+                .find_map(|l| l.strip_prefix(key)?.strip_prefix('='))
+                .map(|v| v.trim_matches('"').to_string())
+                .unwrap_or_default()
+        };
+        SystemIdentity {
+            os_name: os_field("NAME"),
+            os_version: os_field("VERSION_ID"),
+            kernel_version: self
+                .root
+                .read("/proc/sys/kernel/osrelease")
+                .unwrap_or_default(),
+            hostname: self
+                .root
+                .read("/proc/sys/kernel/hostname")
+                .unwrap_or_default(),
+            uptime: self
+                .root
+                .read("/proc/uptime")
+                .and_then(|u| u.split_whitespace().next()?.parse::<f64>().ok())
+                .map(|u| u as u64)
+                .unwrap_or(0),
+        }
+    }
+
+    fn os_details(&self) -> Option<OsDetails> {
+        let architecture = self
+            .root
+            .read("/proc/sys/kernel/arch")
+            .or_else(|| {
+                self.root
+                    .is_live()
+                    .then(|| std::env::consts::ARCH.to_string())
+            })
+            .unwrap_or_default();
+        let boot_time = self.root.read("/proc/stat").and_then(|stat| {
+            let btime = stat.lines().find_map(|l| l.strip_prefix("btime "))?;
+            super::boot_time(btime.trim().parse().ok()?)
+        });
+        let security_module = match self.root.read("/sys/fs/selinux/enforce").as_deref() {
+            Some("1") => Some("SELinux (enforcing)".to_string()),
+            Some(_) => Some("SELinux (permissive)".to_string()),
+            None => (self
+                .root
+                .read("/sys/module/apparmor/parameters/enabled")
+                .as_deref()
+                == Some("Y"))
+            .then(|| "AppArmor".to_string()),
+        };
+        // "systemd 255 (255.4-1ubuntu8)"
+        let systemd_version = self
+            .root
+            .is_live()
+            .then(|| {
+                std::process::Command::new("systemctl")
+                    .arg("--version")
+                    .output()
+            })
+            .and_then(Result::ok)
+            .filter(|output| output.status.success())
+            .and_then(|output| {
+                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+                Some(stdout.lines().next()?.strip_prefix("systemd ")?.to_string())
+            });
+        Some(OsDetails {
+            architecture,
+            boot_time,
+            kernel_cmdline: self.root.read("/proc/cmdline"),
+            kernel_modules: self
+                .root
+                .read("/proc/modules")
+                .map(|modules| modules.lines().count()),
+            security_module,
+            systemd_version,
+        })
+    }
+
+    fn cpus(&self) -> Vec<CpuPackage> {
+        let packages = if self.root.is_live() {
+            super::sysinfo_cpus()
+        } else {
+            self.captured_cpus()
+        };
+        let mut packages = self.split_sockets(packages);
+        self.label_core_types(&mut packages);
+        packages
+    }
+
+    fn memory(&self) -> RamInfo {
+        let meminfo = self.root.read("/proc/meminfo").unwrap_or_default();
+        if self.root.is_live() {
+            let mut ram = super::sysinfo_memory(self.ram_sticks());
+            ram.settings = Some(self.memory_settings(&meminfo));
+            ram.slots = self.memory_slots();
+            self.tag_virtual_memory(&mut ram);
+            return ram;
+        }
+        // meminfo counts in kB
+        let field = |key: &str| {
+            meminfo
+                .lines()
+                .find_map(|l| l.strip_prefix(key)?.strip_prefix(':'))
+                .and_then(|v| v.split_whitespace().next()?.parse::<u64>().ok())
+                .map(|kb| kb * 1024)
+                .unwrap_or(0)
+        };
+        let total = field("MemTotal");
+        let swap_total = field("SwapTotal");
+        let mut ram = RamInfo {
+            total,
+            used: total.saturating_sub(field("MemAvailable")),
+            free: field("MemFree"),
+            swap_total,
+            swap_used: swap_total.saturating_sub(field("SwapFree")),
+            sticks: self.ram_sticks(),
+            settings: Some(self.memory_settings(&meminfo)),
+            slots: self.memory_slots(),
+            spd: Vec::new(),
+            virtualized: None,
+        };
+        self.tag_virtual_memory(&mut ram);
+        ram
+    }
+
+    /// cgroup v2 `cpu.max`/`memory.max`, or the v1 `cpu`, `cpuset` and
+    /// `memory` controllers. Every level up to the mount root counts, since a
+    /// parent's limit caps its children.
+    fn container_limits(&self) -> Option<ContainerLimits> {
+        let cgroups = self.root.read("/proc/self/cgroup")?;
+        // "hierarchy-id:controllers:path"; the v2 hierarchy has no controllers
+        let mut v1: HashMap<&str, &str> = HashMap::new();
+        let mut unified = None;
+        for line in cgroups.lines() {
+            let mut fields = line.splitn(3, ':').skip(1);
+            let (Some(controllers), Some(path)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            if controllers.is_empty() {
+                unified = Some(path);
+            } else {
+                for controller in controllers.split(',') {
+                    v1.insert(controller, path);
+                }
+            }
+        }
+
+        let read_u64 = |file: PathBuf| self.root.read(file)?.parse::<u64>().ok();
+        let stat = |file: PathBuf, key: &str| {
+            self.root
+                .read(file)?
+                .lines()
+                .find_map(|l| l.strip_prefix(key)?.strip_prefix(' ')?.parse::<u64>().ok())
+        };
+        let cpuset = |file: PathBuf| self.root.read(file).map(|l| super::cpu_list(&l).len());
+
+        let hybrid_or_v1 = v1.contains_key("cpu") || v1.contains_key("memory");
+        let (version, path, cpu_quota, cpuset_cpus, memory_limit, memory_used) = if !hybrid_or_v1 {
+            let path = unified?;
+            let dirs = self.cgroup_dirs("/sys/fs/cgroup", path);
+            let leaf = dirs.first()?;
+            // "max 100000" when unlimited
+            let cpu_quota = dirs
+                .iter()
+                .filter_map(|dir| {
+                    let max = self.root.read(dir.join("cpu.max"))?;
+                    let (quota, period) = max.split_once(' ')?;
+                    Some(quota.parse::<f64>().ok()? / period.parse::<f64>().ok()?)
+                })
+                .reduce(f64::min);
+            let memory_limit = dirs
+                .iter()
+                .filter_map(|dir| read_u64(dir.join("memory.max")))
+                .min();
+            let memory_used = read_u64(leaf.join("memory.current")).map(|current| {
+                current.saturating_sub(stat(leaf.join("memory.stat"), "inactive_file").unwrap_or(0))
+            });
+            (
+                2,
+                path,
+                cpu_quota,
+                cpuset(leaf.join("cpuset.cpus.effective")),
+                memory_limit,
+                memory_used,
+            )
+        } else {
+            let dirs = |controller: &str| {
+                v1.get(controller)
+                    .map(|path| self.cgroup_dirs(&format!("/sys/fs/cgroup/{}", controller), path))
+                    .unwrap_or_default()
+            };
+            // cfs_quota_us is -1 when unlimited
+            let cpu_quota = dirs("cpu")
+                .iter()
+                .filter_map(|dir| {
+                    let quota = self
+                        .root
+                        .read(dir.join("cpu.cfs_quota_us"))?
+                        .parse::<i64>()
+                        .ok()?;
+                    let period = read_u64(dir.join("cpu.cfs_period_us"))?;
+                    (quota > 0 && period > 0).then(|| quota as f64 / period as f64)
+                })
+                .reduce(f64::min);
+            let memory_dirs = dirs("memory");
+            let memory_limit = memory_dirs
+                .iter()
+                .filter_map(|dir| read_u64(dir.join("memory.limit_in_bytes")))
+                .min();
+            let memory_used = memory_dirs.first().and_then(|leaf| {
+                let usage = read_u64(leaf.join("memory.usage_in_bytes"))?;
+                Some(usage.saturating_sub(
+                    stat(leaf.join("memory.stat"), "total_inactive_file").unwrap_or(0),
+                ))
+            });
+            let cpuset_cpus = dirs("cpuset").first().and_then(|leaf| {
+                cpuset(leaf.join("cpuset.effective_cpus"))
+                    .or_else(|| cpuset(leaf.join("cpuset.cpus")))
+            });
+            (
+                1,
+                v1.get("memory").or(v1.get("cpu")).copied().unwrap_or("/"),
+                cpu_quota,
+                cpuset_cpus,
+                memory_limit,
+                memory_used,
+            )
+        };
+
+        // Unlimited shows up as "max" in v2 and as a huge number in v1; only
+        // limits below what the host has are worth reporting
+        let online = self
+            .root
+            .read("/sys/devices/system/cpu/online")
+            .map(|l| super::cpu_list(&l).len());
+        let host_memory = self.root.read("/proc/meminfo").and_then(|meminfo| {
+            meminfo
+                .lines()
+                .find_map(|l| l.strip_prefix("MemTotal:"))?
+                .split_whitespace()
+                .next()?
+                .parse::<u64>()
+                .ok()
+                .map(|kb| kb * 1024)
+        });
+        let below_online = |cpus: f64| online.is_none_or(|online| cpus < online as f64);
+        let limits = ContainerLimits {
+            cgroup_version: version,
+            path: path.to_string(),
+            cpu_quota: cpu_quota.filter(|quota| below_online(*quota)),
+            cpuset_cpus: cpuset_cpus.filter(|cpus| below_online(*cpus as f64)),
+            memory_limit: memory_limit
+                .filter(|limit| host_memory.is_none_or(|total| *limit < total)),
+            memory_used,
+        };
+        (limits.cpu_quota.is_some()
+            || limits.cpuset_cpus.is_some()
+            || limits.memory_limit.is_some())
+        .then_some(limits)
+    }
+
+    fn time_health(&self) -> Option<TimeHealth> {
+        let clocksource = "/sys/devices/system/clocksource/clocksource0";
+        let mut time = TimeHealth {
+            clocksource: self
+                .root
+                .read(format!("{}/current_clocksource", clocksource)),
+            available_clocksources: self
+                .root
+                .read(format!("{}/available_clocksource", clocksource))
+                .map(|list| list.split_whitespace().map(str::to_string).collect())
+                .unwrap_or_default(),
+            rtc: self.root.read("/sys/class/rtc/rtc0/name"),
+            ..TimeHealth::default()
+        };
+        if !self.root.is_live() {
+            // Sync state and drift only mean something on the running machine
+            return time.clocksource.is_some().then_some(time);
+        }
+
+        if let Some(since_epoch) = self
+            .root
+            .read("/sys/class/rtc/rtc0/since_epoch")
+            .and_then(|s| s.parse::<i64>().ok())
+        {
+            time.rtc_offset = Some(since_epoch - chrono::Utc::now().timestamp());
+        }
+        let run = |program: &str, args: &[&str]| {
+            std::process::Command::new(program)
+                .args(args)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).to_string())
+        };
+        if let Some(tracking) = run("chronyc", &["-n", "tracking"]) {
+            let (synchronized, server, offset) = parse_chrony_tracking(&tracking);
+            time.sync_daemon = Some("chrony".to_string());
+            time.ntp_synchronized = Some(synchronized);
+            time.ntp_server = server;
+            time.ntp_offset = offset;
+        } else if let Some(synced) = run(
+            "timedatectl",
+            &["show", "--property=NTPSynchronized", "--value"],
+        ) {
+            time.sync_daemon = Some("timedatectl".to_string());
+            time.ntp_synchronized = Some(synced.trim() == "yes");
+        }
+        Some(time)
+    }
+
+    fn kernel_limits(&self) -> Vec<KernelLimit> {
+        let sysctl = |name: &str| {
+            self.root
+                .read(format!("/proc/sys/{}", name.replace('.', "/")))?
+                .split_whitespace()
+                .map(|v| v.parse::<u64>().ok())
+                .collect::<Option<Vec<u64>>>()
+        };
+        let first = |name: &str| sysctl(name)?.first().copied();
+        let mut limits = Vec::new();
+        let mut push = |name: &str, used: Option<u64>, limit: Option<u64>| {
+            if let Some(limit) = limit {
+                limits.push(KernelLimit {
+                    name: name.to_string(),
+                    used,
+                    limit,
+                });
+            }
+        };
+
+        // "allocated unused max"; unused has been 0 since 2.6
+        let file_nr = sysctl("fs.file-nr");
+        push(
+            "fs.file-max",
+            file_nr
+                .as_ref()
+                .and_then(|v| Some(v.first()?.saturating_sub(*v.get(1)?))),
+            first("fs.file-max"),
+        );
+        push("fs.nr_open", None, first("fs.nr_open"));
+        // "Max open files            1024                 524288               files"
+        let nofile = self.root.read("/proc/self/limits").and_then(|limits| {
+            limits
+                .lines()
+                .find_map(|l| l.strip_prefix("Max open files"))?
+                .split_whitespace()
+                .next()?
+                .parse::<u64>()
+                .ok()
+        });
+        push("RLIMIT_NOFILE", None, nofile);
+        // Fourth field of loadavg: "running/total" scheduling entities (threads)
+        let tasks = self.root.read("/proc/loadavg").and_then(|l| {
+            l.split_whitespace()
+                .nth(3)?
+                .split_once('/')?
+                .1
+                .parse::<u64>()
+                .ok()
+        });
+        push("kernel.pid_max", tasks, first("kernel.pid_max"));
+        push("kernel.threads-max", tasks, first("kernel.threads-max"));
+        push("fs.aio-max-nr", first("fs.aio-nr"), first("fs.aio-max-nr"));
+        push(
+            "fs.inotify.max_user_watches",
+            None,
+            first("fs.inotify.max_user_watches"),
+        );
+        push("vm.max_map_count", None, first("vm.max_map_count"));
+        limits
+    }
+
+    fn ram_sticks(&self) -> Vec<RamStick> {
+        use smbioslib::{SMBiosMemoryDevice, SMBiosStruct};
+
+        let mut sticks = Vec::new();
+        let Some(data) = self.smbios() else {
+            return sticks;
+        };
+        for sm_struct in data.iter() {
+            if sm_struct.header.struct_type() != 17 {
+                continue;
+            }
+            let dev = SMBiosMemoryDevice::new(sm_struct);
+
+            let manufacturer_raw = format!("{}", dev.manufacturer());
+            let part_number = format!("{}", dev.part_number());
+            let serial_number = format!("{}", dev.serial_number());
+
+            let speed = dev.configured_memory_speed().map(|s| {
+                let s_str = format!("{:?}", s);
+                s_str
+                    .chars()
+                    .filter(|c| c.is_ascii_digit())
+                    .collect::<String>()
+                    .parse::<u16>()
+                    .unwrap_or(0)
+            });
+
+            let clean = |s: String| {
+                let t = s.trim();
+                if t.is_empty()
+                    || t.to_lowercase() == "unknown"
+                    || t.to_lowercase() == "none"
+                    || t.to_lowercase() == "not specified"
+                    || t.to_lowercase().contains("empty")
+                    || t == "0"
+                {
+                    None
+                } else {
+                    Some(t.to_string())
+                }
+            };
+
+            if let Some(m) = clean(manufacturer_raw) {
+                // Firmware often leaves the JEDEC code as hex here; if it is not
+                // one we know, the SPD's module manufacturer ID (SMBIOS 3.2) is
+                // the same code in binary, bank byte first
+                let manufacturer = crate::jedec::decode(&m)
+                    .or_else(|| {
+                        if !m.chars().all(|c| c.is_ascii_hexdigit()) {
+                            return None;
+                        }
+                        let [bank, id] = sm_struct.get_field_word(0x2C)?.to_le_bytes();
+                        crate::jedec::manufacturer(bank, id)
+                    })
+                    .map(String::from)
+                    .unwrap_or(m);
+                sticks.push(RamStick {
+                    manufacturer: Some(manufacturer),
+                    part_number: clean(part_number),
+                    serial_number: clean(serial_number),
+                    speed: speed.and_then(|s| if s > 0 { Some(s) } else { None }),
+                });
+            }
+        }
+        sticks
+    }
+
+    fn memory_slots(&self) -> Option<MemorySlots> {
+        crate::smbios::memory_slots(self.smbios()?.iter())
+    }
+
+    /// `ee1004` (DDR4) and `spd5118` (DDR5) bind to the modules' EEPROMs on
+    /// the SMBus; neither loads by default on most distributions.
+    fn spd_modules(&self) -> Vec<SpdInfo> {
+        let mut modules = Vec::new();
+        for driver in ["ee1004", "spd5118"] {
+            let dir = format!("/sys/bus/i2c/drivers/{}/", driver);
+            let Ok(entries) = self.root.read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let device = entry.file_name().to_string_lossy().into_owned();
+                if let Ok(eeprom) = fs::read(entry.path().join("eeprom"))
+                    && let Some(spd) = crate::spd::decode(&device, &eeprom)
+                {
+                    modules.push(spd);
+                }
+            }
+        }
+        modules.sort_by(|a, b| a.device.cmp(&b.device));
+        modules
+    }
+
+    /// Block devices backed by hardware, i.e. with a `device` link: this
+    /// leaves out loop, zram, device-mapper and md devices.
+    fn disks(&self) -> Vec<PhysicalDisk> {
+        let Ok(entries) = self.root.read_dir("/sys/block/") else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = entries
+            .flatten()
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .filter(|name| {
+                self.root
+                    .path(format!("/sys/block/{}/device", name))
+                    .exists()
+            })
+            .collect();
+        names.sort();
+        names
+            .into_iter()
+            .map(|name| {
+                let (vendor, model_name, serial_number) = self.disk_metadata(&name);
+                let (interface, link_speed) = self.disk_transport(&name);
+                let sectors = self
+                    .root
+                    .read(format!("/sys/block/{}/size", name))
+                    .and_then(|s| s.parse::<u64>().ok())
+                    .unwrap_or(0);
+                let rotational = match self
+                    .root
+                    .read(format!("/sys/block/{}/queue/rotational", name))
+                    .as_deref()
+                {
+                    Some("0") => Some(false),
+                    Some("1") => Some(true),
+                    _ => None,
+                };
+                // Virtual disks are flagged rotational whatever backs them
+                let disk_type = match rotational {
+                    _ if interface.as_deref() == Some("virtio") => None,
+                    Some(true) => Some("HDD".to_string()),
+                    Some(false) => Some("SSD".to_string()),
+                    None => None,
+                };
+                let smart = if self.root.is_live() {
+                    super::smart_health(&format!("/dev/{}", name))
+                } else {
+                    None
+                };
+                PhysicalDisk {
+                    name,
+                    size: sectors * 512,
+                    vendor,
+                    model_name,
+                    serial_number,
+                    disk_type,
+                    rotational,
+                    interface,
+                    link_speed,
+                    smart,
+                }
+            })
+            .collect()
+    }
+
+    fn filesystems(&self) -> Vec<Filesystem> {
+        let mut filesystems = if self.root.is_live() {
+            super::sysinfo_filesystems()
+        } else {
+            self.captured_mounts()
+        };
+        for fs in &mut filesystems {
+            fs.disk = self.disk_of(&fs.name);
+        }
+        filesystems
+    }
+
+    fn network(&self) -> Vec<NetworkInfo> {
+        if self.root.is_live() {
+            let mut interfaces = super::sysinfo_network();
+            for net in &mut interfaces {
+                net.kind = self.interface_kind(&net.name);
+                net.members = self.interface_members(&net.name);
+            }
+            return interfaces;
+        }
+        let Ok(entries) = self.root.read_dir("/sys/class/net/") else {
+            return Vec::new();
+        };
+        let mut interfaces: Vec<NetworkInfo> = entries
+            .flatten()
+            .map(|entry| {
+                let read = |file: &str| read_trimmed(entry.path().join(file));
+                let counter =
+                    |file: &str| read(file).and_then(|s| s.parse::<u64>().ok()).unwrap_or(0);
+                let name = entry.file_name().to_string_lossy().to_string();
+                NetworkInfo {
+                    received: counter("statistics/rx_bytes"),
+                    transmitted: counter("statistics/tx_bytes"),
+                    mac_address: read("address").unwrap_or_default(),
+                    kind: self.interface_kind(&name),
+                    members: self.interface_members(&name),
+                    name,
+                }
+            })
+            .collect();
+        interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+        interfaces
+    }
+
+    /// Default routes from `/proc/net/route` and `/proc/net/ipv6_route`, the
+    /// lowest metric winning, and the resolv.conf name servers.
+    fn network_config(&self) -> Option<NetworkConfig> {
+        let mut gateway_v4: Option<(u32, Gateway)> = None;
+        let routes = self.root.read("/proc/net/route").unwrap_or_default();
+        // Iface Destination Gateway Flags RefCnt Use Metric Mask ...
+        for line in routes.lines().skip(1) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [iface, "00000000", gateway, _, _, _, metric, "00000000", ..] = fields[..] else {
+                continue;
+            };
+            // Hex of the address as the kernel's u32 in host byte order
+            let (Ok(gateway), Ok(metric)) = (u32::from_str_radix(gateway, 16), metric.parse())
+            else {
+                continue;
+            };
+            if gateway == 0 || gateway_v4.as_ref().is_some_and(|(best, _)| *best <= metric) {
+                continue;
+            }
+            let address = std::net::Ipv4Addr::from(gateway.to_ne_bytes());
+            gateway_v4 = Some((
+                metric,
+                Gateway {
+                    address: address.to_string(),
+                    interface: iface.to_string(),
+                },
+            ));
+        }
+
+        let mut gateway_v6: Option<(u32, Gateway)> = None;
+        let routes = self.root.read("/proc/net/ipv6_route").unwrap_or_default();
+        // Destination, prefix length, source, prefix length, next hop, metric,
+        // refcount, use, flags, interface
+        for line in routes.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [dest, "00", _, _, next_hop, metric, _, _, _, iface] = fields[..] else {
+                continue;
+            };
+            let (Ok(dest), Ok(next_hop), Ok(metric)) = (
+                u128::from_str_radix(dest, 16),
+                u128::from_str_radix(next_hop, 16),
+                u32::from_str_radix(metric, 16),
+            ) else {
+                continue;
+            };
+            if dest != 0
+                || next_hop == 0
+                || iface == "lo"
+                || gateway_v6.as_ref().is_some_and(|(best, _)| *best <= metric)
+            {
+                continue;
+            }
+            gateway_v6 = Some((
+                metric,
+                Gateway {
+                    address: std::net::Ipv6Addr::from(next_hop).to_string(),
+                    interface: iface.to_string(),
+                },
+            ));
+        }
+
+        let mut dns_servers = super::resolv_conf_servers(self.root.path("/etc/resolv.conf"));
+        // systemd-resolved's stub listener; the upstream servers are listed beside it
+        if dns_servers.iter().all(|s| s.starts_with("127.")) {
+            let upstream =
+                super::resolv_conf_servers(self.root.path("/run/systemd/resolve/resolv.conf"));
+            if !upstream.is_empty() {
+                dns_servers = upstream;
+            }
+        }
+        NetworkConfig::new(
+            gateway_v4.map(|(_, gateway)| gateway),
+            gateway_v6.map(|(_, gateway)| gateway),
+            dns_servers,
+        )
+    }
+
+    fn usb_ids(&self) -> Vec<(u8, u8, u16, u16)> {
+        if self.root.is_live() {
+            return super::libusb_ids();
+        }
+        self.usb_devices()
+            .iter()
+            .map(|d| (d.bus, d.address, d.vendor_id, d.product_id))
+            .collect()
+    }
+
+    fn usb_devices(&self) -> Vec<UsbDevice> {
+        if self.root.is_live() {
+            return super::libusb_devices();
+        }
+        let Ok(entries) = self.root.read_dir("/sys/bus/usb/devices/") else {
+            return Vec::new();
+        };
+        let mut devices: Vec<UsbDevice> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let read = |file: &str| read_trimmed(entry.path().join(file));
+                let hex = |file: &str| u16::from_str_radix(&read(file)?, 16).ok();
+                Some(UsbDevice {
+                    bus: read("busnum")?.parse().ok()?,
+                    address: read("devnum")?.parse().ok()?,
+                    vendor_id: hex("idVendor")?,
+                    product_id: hex("idProduct")?,
+                    manufacturer: read("manufacturer"),
+                    product: read("product"),
+                })
+            })
+            .collect();
+        devices.sort_by_key(|d| (d.bus, d.address));
+        devices
+    }
+
+    fn thunderbolt_devices(&self) -> Vec<ThunderboltDevice> {
+        let mut devices = Vec::new();
+        let Ok(entries) = self.root.read_dir("/sys/bus/thunderbolt/devices/") else {
+            return devices;
+        };
+        let mut entries: Vec<_> = entries.flatten().collect();
+        entries.sort_by_key(|e| e.file_name());
+        for entry in entries {
+            let read = |file: &str| {
+                fs::read_to_string(entry.path().join(file))
+                    .ok()
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+            };
+            // Routers are named domain-route (0-1, 0-301); 0-0 is the host itself,
+            // and entries with a colon are services or retimers.
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.ends_with("-0") || name.contains(':') {
+                continue;
+            }
+            let Some(device_name) = read("device_name") else {
+                continue;
+            };
+            devices.push(ThunderboltDevice {
+                name: device_name,
+                vendor: read("vendor_name"),
+                speed: read("rx_speed"),
+            });
+        }
+        devices
+    }
+
+    /// sysfs has the NUMA node and IOMMU group on top of what `pci_info`
+    /// reads, but may be partly hidden in a container; the listing that
+    /// knows more wins.
+    fn pci_devices(&self) -> Vec<PciDevice> {
+        let sysfs = self.sysfs_pci_devices();
+        if !self.root.is_live() {
+            return sysfs;
+        }
+        let enumerated = super::enumerate_pci();
+        let known =
+            |devices: &[PciDevice]| devices.iter().map(PciDevice::known_fields).sum::<usize>();
+        if known(&enumerated) > known(&sysfs) {
+            enumerated
+        } else {
+            sysfs
+        }
+    }
+
+    /// Remapping units register under /sys/class/iommu (dmar* for VT-d,
+    /// ivhd* for AMD-Vi); groups only exist while one is active.
+    fn iommu(&self) -> Option<IommuInfo> {
+        let mut units: Vec<String> = self
+            .root
+            .read_dir("/sys/class/iommu/")
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|e| e.file_name().to_string_lossy().into_owned())
+                    .collect()
+            })
+            .unwrap_or_default();
+        units.sort();
+        let kind = units.first().map(|unit| {
+            if unit.starts_with("dmar") {
+                "Intel VT-d".to_string()
+            } else if unit.starts_with("ivhd") {
+                "AMD-Vi".to_string()
+            } else if unit.contains("smmu") {
+                "ARM SMMU".to_string()
+            } else {
+                unit.trim_end_matches(|c: char| c.is_ascii_digit())
+                    .to_string()
+            }
+        });
+        let kernel_options = self
+            .root
+            .read("/proc/cmdline")
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter(|option| {
+                [
+                    "intel_iommu=",
+                    "amd_iommu=",
+                    "iommu=",
+                    "iommu.",
+                    "vfio-pci.ids=",
+                ]
+                .iter()
+                .any(|prefix| option.starts_with(prefix))
+            })
+            .map(str::to_string)
+            .collect();
+
+        let pci_db = super::load_pci_db();
+        let mut groups = Vec::new();
+        if let Ok(entries) = self.root.read_dir("/sys/kernel/iommu_groups/") {
+            for entry in entries.flatten() {
+                let Ok(id) = entry.file_name().to_string_lossy().parse::<u32>() else {
+                    continue;
+                };
+                let mut devices: Vec<IommuDevice> = fs::read_dir(entry.path().join("devices"))
+                    .map(|members| {
+                        members
+                            .flatten()
+                            .map(|member| {
+                                let path = member.path();
+                                let hex = |file: &str| {
+                                    let value = read_trimmed(path.join(file))?;
+                                    u32::from_str_radix(value.trim_start_matches("0x"), 16).ok()
+                                };
+                                let name = match (hex("vendor"), hex("device")) {
+                                    (Some(vendor), Some(device)) => {
+                                        let (vendor, device) =
+                                            super::pci_names(&pci_db, vendor as u16, device as u16);
+                                        match (vendor, device) {
+                                            (Some(vendor), Some(device)) => {
+                                                Some(format!("{} {}", vendor, device))
+                                            }
+                                            (vendor, device) => device.or(vendor),
+                                        }
+                                    }
+                                    _ => None,
+                                };
+                                IommuDevice {
+                                    slot: member.file_name().to_string_lossy().into_owned(),
+                                    name,
+                                    class: hex("class"),
+                                    driver: fs::read_link(path.join("driver")).ok().and_then(|d| {
+                                        Some(d.file_name()?.to_string_lossy().into_owned())
+                                    }),
+                                }
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                devices.sort_by(|a, b| a.slot.cmp(&b.slot));
+                groups.push(IommuGroup {
+                    id,
+                    domain: read_trimmed(entry.path().join("type")),
+                    devices,
+                });
+            }
+        }
+        groups.sort_by_key(|group| group.id);
+
+        Some(IommuInfo {
+            enabled: !units.is_empty() || !groups.is_empty(),
+            kind,
+            kernel_options,
+            groups,
+        })
+    }
+
+    fn board_layout(&self) -> Option<BoardLayout> {
+        let data = self.smbios()?;
+        let mut layout = crate::smbios::board_layout(data.iter());
+        let pci_db = super::load_pci_db();
+        for slot in &mut layout.slots {
+            let Some(device) = slot.address.as_deref().and_then(|a| self.slot_device(a)) else {
+                continue;
+            };
+            let read = |file: &str| {
+                self.root
+                    .read(format!("/sys/bus/pci/devices/{}/{}", device, file))
+            };
+            let hex =
+                |file: &str| u16::from_str_radix(read(file)?.trim_start_matches("0x"), 16).ok();
+            if let (Some(vendor_id), Some(device_id)) = (hex("vendor"), hex("device")) {
+                let (vendor, name) = super::pci_names(&pci_db, vendor_id, device_id);
+                slot.device_name = Some(match (vendor, name) {
+                    (Some(vendor), Some(name)) => format!("{} {}", vendor, name),
+                    (_, name) => {
+                        name.unwrap_or_else(|| format!("{:04x}:{:04x}", vendor_id, device_id))
+                    }
+                });
+            }
+            slot.link_generation = read("current_link_speed")
+                .as_deref()
+                .and_then(pcie_generation);
+            slot.link_width = read("current_link_width")
+                .and_then(|w| w.parse().ok())
+                .filter(|&w| w > 0);
+            slot.device = Some(device);
+        }
+        Some(layout)
+    }
+
+    fn motherboard(&self) -> Option<MotherboardInfo> {
+        let read_sys = |path: &str| {
+            self.root
+                .read(format!("/sys/class/dmi/id/{}", path))
+                .unwrap_or_else(|| "Unknown".to_string())
+        };
+
+        Some(MotherboardInfo {
+            vendor: read_sys("board_vendor"),
+            product: read_sys("board_name"),
+            bios_vendor: read_sys("bios_vendor"),
+            bios_version: read_sys("bios_version"),
+            bios_date: read_sys("bios_date"),
+        })
+    }
+
+    fn cloud_provider(&self) -> Option<CloudProvider> {
+        let fields: Vec<String> = [
+            "sys_vendor",
+            "product_name",
+            "bios_vendor",
+            "bios_version",
+            "chassis_asset_tag",
+        ]
+        .iter()
+        .filter_map(|name| self.root.read(format!("/sys/class/dmi/id/{}", name)))
+        .collect();
+        let fields: Vec<&str> = fields.iter().map(String::as_str).collect();
+        crate::cloud::provider_from_dmi(&fields)
+    }
+
+    fn batteries(&self) -> Vec<BatteryInfo> {
+        let mut batteries = Vec::new();
+        let Ok(entries) = self.root.read_dir("/sys/class/power_supply/") else {
+            return batteries;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !name.starts_with("BAT") {
+                continue;
+            }
+            let status = fs::read_to_string(entry.path().join("status"))
+                .map(|s| s.trim().to_string())
+                .unwrap_or_else(|_| "Unknown".to_string());
+            let capacity = fs::read_to_string(entry.path().join("capacity"))
+                .map(|s| s.trim().parse::<u8>().unwrap_or(0))
+                .unwrap_or(0);
+            let read_micro = |file: &str| {
+                fs::read_to_string(entry.path().join(file))
+                    .ok()
+                    .and_then(|s| s.trim().parse::<f64>().ok())
+            };
+            // power_now is in µW; some drivers only expose current_now (µA) and voltage_now (µV).
+            // A few report the discharge rate as a negative number.
+            let power_watts = read_micro("power_now")
+                .map(|p| p.abs() / 1_000_000.0)
+                .or_else(|| {
+                    let current = read_micro("current_now")?;
+                    let voltage = read_micro("voltage_now")?;
+                    Some(current.abs() / 1_000_000.0 * voltage / 1_000_000.0)
+                })
+                .map(|w| w as f32);
+
+            // energy_now/power_now (µWh, µW) or charge_now/current_now (µAh, µA)
+            let time_remaining = [("energy", "power"), ("charge", "current")]
+                .iter()
+                .find_map(|(level, rate)| {
+                    let now = read_micro(&format!("{}_now", level))?;
+                    let full = read_micro(&format!("{}_full", level))?;
+                    let rate = read_micro(&format!("{}_now", rate))?.abs();
+                    battery_time_remaining(&status, now, full, rate)
+                });
+
+            let cycle_count = fs::read_to_string(entry.path().join("cycle_count"))
+                .ok()
+                .and_then(|s| s.trim().parse::<u32>().ok())
+                .filter(|c| *c > 0);
+
+            // energy_* (µWh) or, on some drivers, charge_* (µAh)
+            let wear_percent = ["energy", "charge"].iter().find_map(|kind| {
+                let full = read_micro(&format!("{}_full", kind))?;
+                let design = read_micro(&format!("{}_full_design", kind))?;
+                (design > 0.0).then(|| ((1.0 - full / design) * 100.0).max(0.0) as f32)
+            });
+
+            batteries.push(BatteryInfo {
+                name,
+                status,
+                capacity,
+                power_watts,
+                cycle_count,
+                wear_percent,
+                time_remaining,
+            });
+        }
+        batteries
+    }
+
+    fn platform_state(&self) -> Option<PlatformState> {
+        // "state:      open"
+        let lid = self
+            .root
+            .read_dir("/proc/acpi/button/lid/")
+            .into_iter()
+            .flatten()
+            .flatten()
+            .find_map(|e| {
+                let state = read_trimmed(e.path().join("state"))?;
+                Some(state.strip_prefix("state:")?.trim().to_string())
+            });
+
+        // The ACPI dock driver registers dock.0, dock.1, ... with a `docked` flag
+        let docks: Vec<bool> = self
+            .root
+            .read_dir("/sys/devices/platform/")
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|e| e.file_name().to_string_lossy().starts_with("dock."))
+            .filter_map(|e| read_trimmed(e.path().join("docked")))
+            .map(|docked| docked == "1")
+            .collect();
+        let docked = (!docks.is_empty()).then(|| docks.contains(&true));
+
+        // ACPI passive cooling and intel_powerclamp throttle the CPU through a
+        // cooling device whose cur_state is above 0 while active
+        let processor_states: Vec<u64> = self
+            .root
+            .read_dir("/sys/class/thermal/")
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|e| {
+                e.file_name()
+                    .to_string_lossy()
+                    .starts_with("cooling_device")
+            })
+            .filter(|e| {
+                read_trimmed(e.path().join("type"))
+                    .is_some_and(|kind| kind == "Processor" || kind == "intel_powerclamp")
+            })
+            .filter_map(|e| read_trimmed(e.path().join("cur_state"))?.parse().ok())
+            .collect();
+        let throttling =
+            (!processor_states.is_empty()).then(|| processor_states.iter().any(|s| *s > 0));
+
+        let state = PlatformState {
+            lid,
+            docked,
+            throttling,
+            throttle_events: self.throttle_count(),
+        };
+        (state.lid.is_some()
+            || state.docked.is_some()
+            || state.throttling.is_some()
+            || state.throttle_events.is_some())
+        .then_some(state)
+    }
+
+    fn temperatures(&self) -> Vec<TemperatureSensor> {
+        let mut sensors = if self.root.is_live() {
+            super::sysinfo_temperatures()
+        } else {
+            self.captured_hwmon_temperatures()
+        };
+
+        // ACPI thermal zones are not always mirrored under hwmon
+        if let Ok(entries) = self.root.read_dir("/sys/class/thermal/") {
+            let mut zones: Vec<_> = entries
+                .flatten()
+                .filter(|e| e.file_name().to_string_lossy().starts_with("thermal_zone"))
+                .collect();
+            zones.sort_by_key(|e| e.file_name());
+
+            for zone in zones {
+                let read = |file: &str| {
+                    fs::read_to_string(zone.path().join(file))
+                        .ok()
+                        .map(|s| s.trim().to_string())
+                };
+                let Some(temp) = read("temp").and_then(|s| s.parse::<f32>().ok()) else {
+                    continue;
+                };
+                let kind = read("type").unwrap_or_else(|| "unknown".to_string());
+                sensors.push(TemperatureSensor {
+                    label: format!("{} ({})", zone.file_name().to_string_lossy(), kind),
+                    temperature: Some(temp / 1000.0),
+                    max: None,
+                    critical: None,
+                });
+            }
+        }
+
+        sensors
+    }
+
+    fn fans(&self) -> Vec<FanInfo> {
+        let mut fans = Vec::new();
+        let Ok(entries) = self.root.read_dir("/sys/class/hwmon/") else {
+            return fans;
+        };
+        let mut hwmons: Vec<_> = entries.flatten().collect();
+        hwmons.sort_by_key(|e| e.file_name());
+
+        for hwmon in hwmons {
+            let chip = fs::read_to_string(hwmon.path().join("name"))
+                .map(|s| s.trim().to_string())
+                .unwrap_or_else(|_| hwmon.file_name().to_string_lossy().to_string());
+            let Ok(files) = fs::read_dir(hwmon.path()) else {
+                continue;
+            };
+            let mut inputs: Vec<String> = files
+                .flatten()
+                .map(|f| f.file_name().to_string_lossy().to_string())
+                .filter(|f| f.starts_with("fan") && f.ends_with("_input"))
+                .collect();
+            inputs.sort();
+
+            for input in inputs {
+                let Some(rpm) = fs::read_to_string(hwmon.path().join(&input))
+                    .ok()
+                    .and_then(|s| s.trim().parse::<u32>().ok())
+                else {
+                    continue;
+                };
+                let prefix = input.trim_end_matches("_input");
+                let label = fs::read_to_string(hwmon.path().join(format!("{}_label", prefix)))
+                    .map(|s| s.trim().to_string())
+                    .unwrap_or_else(|_| prefix.to_string());
+                fans.push(FanInfo {
+                    label: format!("{} {}", chip, label),
+                    rpm,
+                });
+            }
+        }
+        fans
+    }
+
+    /// Display adapters from the DRM subsystem. VRAM comes from amdgpu's sysfs
+    /// counters or, for the proprietary NVIDIA driver, from `nvidia-smi`.
+    fn gpus(&self) -> Vec<GpuInfo> {
+        let mut gpus: Vec<GpuInfo> = Vec::new();
+        let Ok(entries) = self.root.read_dir("/sys/class/drm/") else {
+            return gpus;
+        };
+        let mut cards: Vec<_> = entries
+            .flatten()
+            .filter(|e| {
+                let name = e.file_name().to_string_lossy().to_string();
+                // cardN, but not connectors like card0-HDMI-A-1
+                name.starts_with("card") && !name.contains('-')
+            })
+            .collect();
+        cards.sort_by_key(|e| e.file_name());
+
+        for card in cards {
+            let device = card.path().join("device");
+            let Ok(slot) = fs::canonicalize(&device) else {
+                continue;
+            };
+            let slot = slot
+                .file_name()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if gpus.iter().any(|g| g.slot == slot) {
+                continue;
+            }
+            let read = |file: &str| {
+                fs::read_to_string(device.join(file))
+                    .ok()
+                    .map(|s| s.trim().to_string())
+            };
+            let vendor = match read("vendor").as_deref() {
+                Some("0x10de") => "NVIDIA",
+                Some("0x1002") => "AMD",
+                Some("0x8086") => "Intel",
+                Some(_) | None => "Unknown",
+            };
+            let name = format!(
+                "{} {}",
+                vendor,
+                read("device").unwrap_or_default().trim_start_matches("0x")
+            );
+            let driver = fs::read_link(device.join("driver"))
+                .ok()
+                .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()));
+            let vram_total = read("mem_info_vram_total").and_then(|s| s.parse::<u64>().ok());
+            gpus.push(GpuInfo {
+                slot,
+                name,
+                driver,
+                vram_total,
+            });
+        }
+
+        if self.root.is_live()
+            && gpus.iter().any(|g| g.driver.as_deref() == Some("nvidia"))
+            && let Ok(output) = std::process::Command::new("nvidia-smi")
+                .args([
+                    "--query-gpu=pci.bus_id,name,memory.total",
+                    "--format=csv,noheader,nounits",
+                ])
+                .output()
+        {
+            for line in String::from_utf8_lossy(&output.stdout).lines() {
+                let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+                let [bus_id, name, mib] = fields[..] else {
+                    continue;
+                };
+                // nvidia-smi pads the PCI domain to 8 digits: 00000000:01:00.0
+                let bus_id = bus_id.to_lowercase();
+                if let Some(gpu) = gpus.iter_mut().find(|g| {
+                    g.slot
+                        .split_once(':')
+                        .is_some_and(|(_, rest)| bus_id.ends_with(rest))
+                }) {
+                    gpu.name = name.to_string();
+                    gpu.vram_total = mib.parse::<u64>().ok().map(|m| m * 1024 * 1024);
+                }
+            }
+        }
+        gpus
+    }
+
+    /// Compute processes from `nvidia-smi` on the proprietary NVIDIA driver;
+    /// DRM drivers (amdgpu, i915, xe) describe each client in
+    /// `/proc/<pid>/fdinfo`. Other users' processes are only visible to root.
+    fn gpu_processes(&self) -> Vec<GpuProcess> {
+        if !self.root.is_live() {
+            return Vec::new();
+        }
+        let mut processes: Vec<GpuProcess> = Vec::new();
+        if let Ok(output) = std::process::Command::new("nvidia-smi")
+            .args([
+                "--query-compute-apps=gpu_bus_id,pid,process_name,used_memory",
+                "--format=csv,noheader,nounits",
+            ])
+            .output()
+        {
+            for line in String::from_utf8_lossy(&output.stdout).lines() {
+                let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+                let [bus_id, pid, name, mib] = fields[..] else {
+                    continue;
+                };
+                let Ok(pid) = pid.parse() else {
+                    continue;
+                };
+                // 00000000:01:00.0 -> 0000:01:00.0
+                let bus_id = bus_id.to_lowercase();
+                let slot = bus_id
+                    .get(bus_id.len().saturating_sub(12)..)
+                    .unwrap_or(&bus_id);
+                processes.push(GpuProcess {
+                    slot: slot.to_string(),
+                    pid,
+                    name: name.rsplit('/').next().unwrap_or(name).to_string(),
+                    vram_used: mib.parse::<u64>().ok().map(|m| m * 1024 * 1024),
+                });
+            }
+        }
+
+        let Ok(entries) = fs::read_dir("/proc") else {
+            return processes;
+        };
+        for entry in entries.flatten() {
+            let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+                continue;
+            };
+            let Ok(fds) = fs::read_dir(entry.path().join("fdinfo")) else {
+                continue;
+            };
+            // Several descriptors can share one DRM client; VRAM is per client
+            let mut clients: HashMap<(String, String), Option<u64>> = HashMap::new();
+            for fd in fds.flatten() {
+                let Ok(info) = fs::read_to_string(fd.path()) else {
+                    continue;
+                };
+                let field = |key: &str| {
+                    info.lines()
+                        .find_map(|l| l.strip_prefix(key))
+                        .map(|v| v.trim().to_string())
+                };
+                let (Some(pdev), Some(client)) = (field("drm-pdev:"), field("drm-client-id:"))
+                else {
+                    continue;
+                };
+                if field("drm-driver:").is_some_and(|d| d.starts_with("nvidia")) {
+                    continue;
+                }
+                // "drm-memory-vram: 1024 KiB" (amdgpu), "drm-total-vram0: 8 MiB" (xe)
+                let vram = field("drm-memory-vram:")
+                    .or_else(|| field("drm-total-vram0:"))
+                    .and_then(|v| {
+                        let mut parts = v.split_whitespace();
+                        let value: u64 = parts.next()?.parse().ok()?;
+                        Some(match parts.next() {
+                            Some("KiB") => value * 1024,
+                            Some("MiB") => value * 1024 * 1024,
+                            Some("GiB") => value * 1024 * 1024 * 1024,
+                            _ => value,
+                        })
+                    });
+                clients.insert((pdev, client), vram);
+            }
+            if clients.is_empty() {
+                continue;
+            }
+            let name = read_trimmed(entry.path().join("comm")).unwrap_or_default();
+            let mut slots: Vec<String> = clients.keys().map(|(pdev, _)| pdev.clone()).collect();
+            slots.sort();
+            slots.dedup();
+            for slot in slots {
+                let vram: Vec<Option<u64>> = clients
+                    .iter()
+                    .filter(|((pdev, _), _)| *pdev == slot)
+                    .map(|(_, vram)| *vram)
+                    .collect();
+                processes.push(GpuProcess {
+                    slot,
+                    pid,
+                    name: name.clone(),
+                    vram_used: vram
+                        .iter()
+                        .any(Option::is_some)
+                        .then(|| vram.iter().flatten().sum()),
+                });
+            }
+        }
+        processes
+    }
+
+    fn cpu_flags(&self) -> Vec<String> {
+        // x86 lists "flags", ARM lists "Features"
+        if let Some(cpuinfo) = self.root.read("/proc/cpuinfo")
+            && let Some(line) = cpuinfo
+                .lines()
+                .find(|l| l.starts_with("flags") || l.starts_with("Features"))
+        {
+            return line
+                .split(':')
+                .nth(1)
+                .unwrap_or_default()
+                .split_whitespace()
+                .map(str::to_string)
+                .collect();
+        }
+        if self.root.is_live() {
+            super::cpuid_flags()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// The kernel drops `vmx` from the flags when firmware locked VT-x off,
+    /// while CPUID still has it; AMD-V stays listed, but `kvm_amd` refuses
+    /// to load. EPT and NPT show among the (vmx) flags.
+    fn virtualization(&self) -> Option<VirtualizationSupport> {
+        let cpuinfo = self.root.read("/proc/cpuinfo")?;
+        let mut flags: Vec<&str> = Vec::new();
+        for prefix in ["flags", "vmx flags"] {
+            if let Some(line) = cpuinfo.lines().find(|l| l.starts_with(prefix)) {
+                flags.extend(
+                    line.split(':')
+                        .nth(1)
+                        .unwrap_or_default()
+                        .split_whitespace(),
+                );
+            }
+        }
+        let has = |flag: &str| flags.contains(&flag);
+        let listed = if has("vmx") {
+            Some("VT-x".to_string())
+        } else if has("svm") {
+            Some("AMD-V".to_string())
+        } else {
+            None
+        };
+        // CPUID describes this machine, not a replayed one
+        let cpuid = if self.root.is_live() {
+            super::cpuid_virtualization().and_then(|v| v.extension)
+        } else {
+            None
+        };
+        let module = |name: &str| self.root.path(format!("/sys/module/{}", name)).exists();
+        let vendor_module_missing = module("kvm") && !module("kvm_intel") && !module("kvm_amd");
+        let disabled_in_firmware =
+            (listed.is_none() && cpuid.is_some()) || (listed.is_some() && vendor_module_missing);
+        let extension = listed.or(cpuid);
+        let nested_virtualization = ["kvm_intel", "kvm_amd"].iter().find_map(|module| {
+            self.root
+                .read(format!("/sys/module/{}/parameters/nested", module))
+                .map(|value| value == "Y" || value == "1")
+        });
+        Some(VirtualizationSupport {
+            nested_paging: extension.is_some().then(|| has("ept") || has("npt")),
+            extension,
+            disabled_in_firmware,
+            // /dev/kvm is a misc device, registered once a vendor module loads
+            kvm: Some(self.root.path("/sys/class/misc/kvm").exists()),
+            nested_virtualization,
+        })
+    }
+
+    fn pressure(&self) -> Option<Pressure> {
+        let read = |resource: &str| {
+            parse_pressure(&self.root.read(format!("/proc/pressure/{}", resource))?)
+        };
+        let pressure = Pressure {
+            cpu: read("cpu"),
+            memory: read("memory"),
+            io: read("io"),
+        };
+        (pressure.resources().count() > 0).then_some(pressure)
+    }
+
+    fn load_average(&self) -> Option<[f64; 3]> {
+        // "0.52 0.61 0.70 2/1234 5678"
+        let loadavg = self.root.read("/proc/loadavg")?;
+        let mut fields = loadavg.split_whitespace().map(|f| f.parse::<f64>().ok());
+        Some([fields.next()??, fields.next()??, fields.next()??])
+    }
+
+    fn cpu_ticks(&self) -> Option<CpuTicks> {
+        let stat = self.root.read("/proc/stat")?;
+        parse_cpu_ticks(&stat)
+    }
+
+    /// Sums the Intel `thermal_throttle` counters of every CPU.
+    fn throttle_count(&self) -> Option<u64> {
+        let entries = self.root.read_dir("/sys/devices/system/cpu/").ok()?;
+        let mut total = None;
+        for entry in entries.flatten() {
+            let dir = entry.path().join("thermal_throttle");
+            for file in ["core_throttle_count", "package_throttle_count"] {
+                if let Some(count) = fs::read_to_string(dir.join(file))
+                    .ok()
+                    .and_then(|s| s.trim().parse::<u64>().ok())
+                {
+                    *total.get_or_insert(0) += count;
+                }
+            }
+        }
+        total
+    }
+
+    fn interrupts(&self) -> HashMap<String, IrqStats> {
+        let Some(content) = self.root.read("/proc/interrupts") else {
+            return HashMap::new();
+        };
+        let mut interrupts = parse_interrupts(&content);
+        for (irq, stats) in interrupts.iter_mut() {
+            if irq.chars().all(|c| c.is_ascii_digit()) {
+                stats.affinity = self
+                    .root
+                    .read(format!("/proc/irq/{}/smp_affinity_list", irq));
+            }
+        }
+        interrupts
+    }
+
+    fn diskstats(&self) -> HashMap<String, DiskStats> {
+        let mut stats = HashMap::new();
+        let Some(content) = self.root.read("/proc/diskstats") else {
+            return stats;
+        };
+
+        for line in content.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 {
+                continue;
+            }
+            let name = fields[2];
+            if name.starts_with("loop") || name.starts_with("ram") {
+                continue;
+            }
+            let field = |i: usize| fields[i].parse::<u64>().unwrap_or(0);
+            stats.insert(
+                name.to_string(),
+                DiskStats {
+                    reads_completed: field(3),
+                    sectors_read: field(5),
+                    writes_completed: field(7),
+                    sectors_written: field(9),
+                },
+            );
+        }
+        stats
+    }
+
+    fn limitations(&self, sections: &Sections) -> Vec<Limitation> {
+        // A capture does not say what its owner could read
+        if !self.root.is_live() || crate::privilege::is_elevated() {
+            return Vec::new();
+        }
+        let mut limitations = Vec::new();
+        // A guest's SMBIOS modules are made up by the hypervisor, if listed at all
+        if sections.ram
+            && self.virtual_memory().is_none()
+            && matches!(
+                fs::File::open("/sys/firmware/dmi/tables/DMI"),
+                Err(err) if err.kind() == ErrorKind::PermissionDenied
+            )
+        {
+            limitations.push(Limitation {
+                probe: "RAM module details (SMBIOS)".to_string(),
+                reason: "the SMBIOS tables are readable by root only".to_string(),
+            });
+        }
+        if sections.storage && super::smartctl_installed() {
+            limitations.push(Limitation {
+                probe: "Disk health (SMART)".to_string(),
+                reason: "smartctl can only query drives as root".to_string(),
+            });
+        }
+        if sections.usb {
+            limitations.extend(super::libusb_limitations());
+        }
+        limitations
+    }
+}
+
+fn read_trimmed(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+}
+
+/// The hypervisor a guest's DMI system strings name, if any.
+fn hypervisor_name(vendor: &str, product: &str) -> Option<&'static str> {
+    let name = match vendor {
+        "QEMU" => "KVM",
+        "innotek GmbH" | "Oracle Corporation" if product == "VirtualBox" => "VirtualBox",
+        "VMware, Inc." => "VMware",
+        "Microsoft Corporation" if product == "Virtual Machine" => "Hyper-V",
+        "Xen" => "Xen",
+        "Amazon EC2" if !product.ends_with(".metal") => "AWS Nitro",
+        "Google" if product == "Google Compute Engine" => "KVM",
+        "Parallels Software International Inc." => "Parallels",
+        "Apple Inc." if product == "Apple Virtualization Generic Platform" => {
+            "Apple Virtualization"
+        }
+        _ if product.starts_with("KVM") || product == "Firecracker" => "KVM",
+        _ if product == "Cloud Hypervisor" || vendor == "Cloud Hypervisor" => "Cloud Hypervisor",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Negotiated rate of the first phy of the SAS port an end device hangs
+/// off, e.g. "12.0 Gbit".
+fn sas_link_speed(end_device: &Path) -> Option<String> {
+    let port = end_device.parent()?;
+    let mut phys: Vec<String> = fs::read_dir(port)
+        .ok()?
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|name| name.starts_with("phy-"))
+        .collect();
+    phys.sort();
+    let phy = phys.first()?;
+    read_trimmed(
+        port.join(phy)
+            .join("sas_phy")
+            .join(phy)
+            .join("negotiated_linkrate"),
+    )
+    .filter(|rate| rate.ends_with("Gbit"))
+    .map(|rate| rate.replace("Gbit", "Gb/s"))
+}
+
+/// /proc/interrupts: a header of CPU columns, then one line per source with
+/// a count per CPU and a description. Numbered IRQs describe the controller
+/// and trigger before the device names; per-CPU sources (LOC, RES, ...) say
+/// what they are.
+///
+/// ```text
+///             CPU0       CPU1
+///  24:     812345          0  IR-PCI-MSI 524288-edge      nvme0q0
+/// LOC:    9876543    9123456   Local timer interrupts
+/// ERR:          0
+/// ```
+fn parse_interrupts(content: &str) -> HashMap<String, IrqStats> {
+    let mut lines = content.lines();
+    let cpus = lines
+        .next()
+        .map(|header| header.split_whitespace().count())
+        .unwrap_or(0);
+    let mut interrupts = HashMap::new();
+    for line in lines {
+        let Some((irq, rest)) = line.split_once(':') else {
+            continue;
+        };
+        let irq = irq.trim();
+        let mut fields = rest.split_whitespace().peekable();
+        let mut per_cpu = Vec::with_capacity(cpus);
+        while per_cpu.len() < cpus
+            && let Some(count) = fields.peek().and_then(|f| f.parse::<u64>().ok())
+        {
+            per_cpu.push(count);
+            fields.next();
+        }
+        let description: Vec<&str> = fields.collect();
+        let name = if irq.chars().all(|c| c.is_ascii_digit()) && description.len() > 2 {
+            description[2..].join(" ")
+        } else {
+            description.join(" ")
+        };
+        interrupts.insert(
+            irq.to_string(),
+            IrqStats {
+                name,
+                per_cpu,
+                affinity: None,
+            },
+        );
+    }
+    interrupts
+}
+
+/// One /proc/pressure file:
+///
+/// ```text
+/// some avg10=1.52 avg60=0.87 avg300=0.25 total=123456
+/// full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+/// ```
+fn parse_pressure(content: &str) -> Option<PressureStall> {
+    let averages = |kind: &str| -> Option<[f32; 3]> {
+        let line = content.lines().find_map(|l| l.strip_prefix(kind))?;
+        let value = |key: &str| {
+            line.split_whitespace()
+                .find_map(|field| field.strip_prefix(key)?.parse::<f32>().ok())
+        };
+        Some([value("avg10=")?, value("avg60=")?, value("avg300=")?])
+    };
+    Some(PressureStall {
+        some: averages("some ")?,
+        full: averages("full "),
+    })
+}
+
+/// The aggregate `cpu  user nice system idle iowait irq softirq steal ...`
+/// line of /proc/stat; kernels before 2.6.11 stop after softirq.
+fn parse_cpu_ticks(stat: &str) -> Option<CpuTicks> {
+    let line = stat.lines().find_map(|l| l.strip_prefix("cpu "))?;
+    let values: Vec<u64> = line
+        .split_whitespace()
+        .map(|v| v.parse().unwrap_or(0))
+        .collect();
+    let value = |i: usize| values.get(i).copied().unwrap_or(0);
+    (values.len() >= 4).then(|| CpuTicks {
+        user: value(0),
+        nice: value(1),
+        system: value(2),
+        idle: value(3),
+        iowait: value(4),
+        irq: value(5),
+        softirq: value(6),
+        steal: value(7),
+    })
+}
+
+/// Seconds until `now` reaches zero (discharging) or `full` (charging) at
+/// `rate`, all in the same µWh/µW or µAh/µA units.
+fn battery_time_remaining(status: &str, now: f64, full: f64, rate: f64) -> Option<u64> {
+    if rate <= 0.0 {
+        return None;
+    }
+    let hours = match status {
+        "Discharging" => now / rate,
+        "Charging" => (full - now).max(0.0) / rate,
+        _ => return None,
+    };
+    Some((hours * 3600.0).round() as u64)
+}
+
+/// PCIe generation from a negotiated link speed, e.g. "16.0 GT/s PCIe" -> 4.
+fn pcie_generation(speed: &str) -> Option<u8> {
+    let gts: f32 = speed.split_whitespace().next()?.parse().ok()?;
+    [2.5, 5.0, 8.0, 16.0, 32.0, 64.0]
+        .iter()
+        .position(|&rate| (gts - rate).abs() < 0.1)
+        .map(|i| i as u8 + 1)
+}
+
+/// A PCI address in sysfs form, `0000:01:00.0`.
+fn is_pci_address(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    bytes.len() == 12
+        && bytes[4] == b':'
+        && bytes[7] == b':'
+        && bytes[10] == b'.'
+        && name
+            .chars()
+            .enumerate()
+            .all(|(i, c)| matches!(i, 4 | 7 | 10) || c.is_ascii_hexdigit())
+}
+
+/// Sync state, reference server and system clock offset from `chronyc -n
+/// tracking`:
+///
+/// ```text
+/// Reference ID    : A29FC87B (162.159.200.123)
+/// System time     : 0.000012345 seconds slow of NTP time
+/// Leap status     : Normal
+/// ```
+fn parse_chrony_tracking(tracking: &str) -> (bool, Option<String>, Option<f64>) {
+    let field = |key: &str| {
+        tracking.lines().find_map(|l| {
+            let (name, value) = l.split_once(':')?;
+            (name.trim() == key).then(|| value.trim())
+        })
+    };
+    let synchronized = field("Leap status").is_some_and(|s| s != "Not synchronised");
+    let server = field("Reference ID")
+        .and_then(|id| Some(id.split_once('(')?.1.trim_end_matches(')').to_string()))
+        .filter(|server| !server.is_empty());
+    let offset = field("System time").and_then(|value| {
+        let mut words = value.split_whitespace();
+        let seconds: f64 = words.next()?.parse().ok()?;
+        // "fast of NTP time" means ahead
+        Some(match words.nth(1)? {
+            "slow" => -seconds,
+            _ => seconds,
+        })
+    });
+    (synchronized, server, offset)
+}
+
+impl LinuxProbe {
+    /// Every function under /sys/bus/pci/devices, with the config space
+    /// fields the kernel mirrors as attributes.
+    fn sysfs_pci_devices(&self) -> Vec<PciDevice> {
+        let Ok(entries) = self.root.read_dir("/sys/bus/pci/devices/") else {
+            return Vec::new();
+        };
+        let pci_db = super::load_pci_db();
+        let mut devices: Vec<PciDevice> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let hex = |file: &str| {
+                    let value = read_trimmed(entry.path().join(file))?;
+                    u16::from_str_radix(value.trim_start_matches("0x"), 16).ok()
+                };
+                let vendor_id = hex("vendor")?;
+                let device_id = hex("device")?;
+                let (vendor_name, device_name) = super::pci_names(&pci_db, vendor_id, device_id);
+                Some(PciDevice {
+                    slot: entry.file_name().to_string_lossy().to_string(),
+                    vendor_id,
+                    device_id,
+                    vendor_name,
+                    device_name,
+                    class_name: None,
+                    revision: hex("revision").and_then(|r| u8::try_from(r).ok()),
+                    subsystem_vendor_id: hex("subsystem_vendor"),
+                    subsystem_device_id: hex("subsystem_device"),
+                    // -1 on single-node machines
+                    numa_node: read_trimmed(entry.path().join("numa_node"))
+                        .and_then(|n| n.parse().ok()),
+                    iommu_group: fs::read_link(entry.path().join("iommu_group"))
+                        .ok()
+                        .and_then(|group| group.file_name()?.to_str()?.parse().ok()),
+                })
+            })
+            .collect();
+        devices.sort_by(|a, b| a.slot.cmp(&b.slot));
+        devices
+    }
+
+    /// On a virtual machine, the hypervisor and the devices that balloon or
+    /// hotplug guest memory; `None` on bare metal.
+    fn virtual_memory(&self) -> Option<VirtualMemory> {
+        let xen = self.root.read("/sys/hypervisor/type").as_deref() == Some("xen");
+        // DMI strings are world-readable, unlike the tables behind them
+        let dmi = |name: &str| {
+            self.root
+                .read(format!("/sys/class/dmi/id/{}", name))
+                .unwrap_or_default()
+        };
+        let hypervisor =
+            hypervisor_name(&dmi("sys_vendor"), &dmi("product_name")).or(xen.then_some("Xen"));
+        let flagged = self.cpu_flags().iter().any(|flag| flag == "hypervisor");
+
+        let mut devices = Vec::new();
+        if let Ok(entries) = self.root.read_dir("/sys/bus/virtio/devices/") {
+            let mut ids: Vec<String> = entries
+                .flatten()
+                .filter_map(|entry| read_trimmed(entry.path().join("device")))
+                .collect();
+            ids.sort();
+            for id in ids {
+                match id.as_str() {
+                    "0x0005" => devices.push("virtio-balloon"),
+                    "0x0018" => devices.push("virtio-mem"),
+                    _ => {}
+                }
+            }
+        }
+        for (path, device) in [
+            ("/sys/bus/vmbus/drivers/hv_balloon", "hv_balloon"),
+            ("/sys/module/vmw_balloon", "vmw_balloon"),
+            ("/sys/devices/system/xen_memory/xen_memory0", "xen-balloon"),
+        ] {
+            if self.root.path(path).exists() {
+                devices.push(device);
+            }
+        }
+
+        if hypervisor.is_none() && !flagged && !xen && devices.is_empty() {
+            return None;
+        }
+        devices.dedup();
+        Some(VirtualMemory {
+            hypervisor: hypervisor.map(str::to_string),
+            devices: devices.into_iter().map(str::to_string).collect(),
+        })
+    }
+
+    /// Marks guest memory as such and, when firmware lists no modules,
+    /// stands in for them with the balloon devices.
+    fn tag_virtual_memory(&self, ram: &mut RamInfo) {
+        let Some(virtualized) = self.virtual_memory() else {
+            return;
+        };
+        if ram.sticks.is_empty() {
+            ram.sticks = virtualized.sticks();
+        }
+        ram.virtualized = Some(virtualized);
+    }
+
+    /// Hugepage counts and commit figures from /proc/meminfo, plus the THP mode
+    /// and the overcommit sysctls.
+    fn memory_settings(&self, meminfo: &str) -> MemorySettings {
+        // HugePages_* are page counts, the rest kB
+        let field = |key: &str| {
+            meminfo
+                .lines()
+                .find_map(|l| l.strip_prefix(key)?.strip_prefix(':'))
+                .and_then(|v| v.split_whitespace().next()?.parse::<u64>().ok())
+        };
+        // "always [madvise] never"
+        let transparent_hugepages = self
+            .root
+            .read("/sys/kernel/mm/transparent_hugepage/enabled")
+            .and_then(|modes| {
+                let (_, rest) = modes.split_once('[')?;
+                Some(rest.split_once(']')?.0.to_string())
+            });
+        MemorySettings {
+            hugepages_total: field("HugePages_Total").unwrap_or(0),
+            hugepages_free: field("HugePages_Free").unwrap_or(0),
+            hugepage_size: field("Hugepagesize").unwrap_or(0) * 1024,
+            transparent_hugepages,
+            overcommit_memory: self
+                .root
+                .read("/proc/sys/vm/overcommit_memory")
+                .and_then(|v| v.parse().ok()),
+            overcommit_ratio: self
+                .root
+                .read("/proc/sys/vm/overcommit_ratio")
+                .and_then(|v| v.parse().ok()),
+            commit_limit: field("CommitLimit").map(|kb| kb * 1024),
+            committed: field("Committed_AS").map(|kb| kb * 1024),
+        }
+    }
+
+    /// A cgroup's directory and its ancestors under a cgroupfs mount, leaf
+    /// first. Inside a container the mount's root is the container's own
+    /// cgroup, while /proc/self/cgroup may still show the host's path to it.
+    fn cgroup_dirs(&self, mount: &str, path: &str) -> Vec<PathBuf> {
+        let mount = Path::new(mount);
+        let leaf = mount.join(path.trim_start_matches('/'));
+        if !self.root.path(&leaf).is_dir() {
+            return vec![mount.to_path_buf()];
+        }
+        leaf.ancestors()
+            .take_while(|dir| dir.starts_with(mount))
+            .map(Path::to_path_buf)
+            .collect()
+    }
+
+    /// What backs a network interface, from the attributes sysfs adds for
+    /// each link type under `/sys/class/net/<name>`.
+    fn interface_kind(&self, name: &str) -> Option<InterfaceKind> {
+        let dir = format!("/sys/class/net/{}", name);
+        if !self.root.path(&dir).exists() {
+            return None;
+        }
+        let has = |file: &str| self.root.path(format!("{}/{}", dir, file)).exists();
+        let read = |file: &str| self.root.read(format!("{}/{}", dir, file));
+        let devtype = read("uevent").and_then(|uevent| {
+            uevent
+                .lines()
+                .find_map(|l| l.strip_prefix("DEVTYPE=").map(str::to_string))
+        });
+        // ARPHRD_LOOPBACK
+        if read("type").as_deref() == Some("772") {
+            return Some(InterfaceKind::Loopback);
+        }
+        if has("bridge") {
+            return Some(InterfaceKind::Bridge);
+        }
+        if has("bonding") {
+            return Some(InterfaceKind::Bond);
+        }
+        match devtype.as_deref() {
+            Some("vlan") => return Some(InterfaceKind::Vlan),
+            Some("wireguard") => return Some(InterfaceKind::WireGuard),
+            _ => {}
+        }
+        if let Some(flags) = read("tun_flags") {
+            // IFF_TAP
+            let tap = u32::from_str_radix(flags.trim_start_matches("0x"), 16)
+                .is_ok_and(|flags| flags & 0x2 != 0);
+            return Some(if tap {
+                InterfaceKind::Tap
+            } else {
+                InterfaceKind::Tun
+            });
+        }
+        if has("device") {
+            return Some(InterfaceKind::Physical);
+        }
+        // A veth links to its peer; macvlan and friends also list their
+        // lower device
+        let lower = self
+            .root
+            .read_dir(&dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .any(|e| e.file_name().to_string_lossy().starts_with("lower_"))
+            })
+            .unwrap_or(false);
+        if !lower && read("iflink").is_some() && read("iflink") != read("ifindex") {
+            return Some(InterfaceKind::Veth);
+        }
+        Some(InterfaceKind::Virtual)
+    }
+
+    /// Mode, active slave and ports of a bond, or the ports of a bridge.
+    fn interface_members(&self, name: &str) -> Option<InterfaceMembers> {
+        let dir = format!("/sys/class/net/{}", name);
+        let mut members = InterfaceMembers {
+            mode: None,
+            active: None,
+            ports: Vec::new(),
+        };
+        let ports: Vec<String> =
+            if let Some(slaves) = self.root.read(format!("{}/bonding/slaves", dir)) {
+                // "active-backup 1"
+                members.mode = self
+                    .root
+                    .read(format!("{}/bonding/mode", dir))
+                    .and_then(|mode| mode.split_whitespace().next().map(str::to_string));
+                members.active = self
+                    .root
+                    .read(format!("{}/bonding/active_slave", dir))
+                    .filter(|slave| !slave.is_empty());
+                slaves.split_whitespace().map(str::to_string).collect()
+            } else {
+                let mut ports: Vec<String> = self
+                    .root
+                    .read_dir(format!("{}/brif", dir))
+                    .ok()?
+                    .flatten()
+                    .map(|e| e.file_name().to_string_lossy().to_string())
+                    .collect();
+                ports.sort();
+                ports
+            };
+        members.ports = ports
+            .into_iter()
+            .map(|port| MemberPort {
+                state: self
+                    .root
+                    .read(format!("/sys/class/net/{}/bonding_slave/mii_status", port))
+                    .or_else(|| self.root.read(format!("/sys/class/net/{}/operstate", port)))
+                    .unwrap_or_else(|| "unknown".to_string()),
+                name: port,
+            })
+            .collect();
+        Some(members)
+    }
+
+    /// (vendor, model, serial) of a whole disk such as `sda`.
+    fn disk_metadata(&self, parent_name: &str) -> (Option<String>, Option<String>, Option<String>) {
+        let model = self
+            .root
+            .read(format!("/sys/block/{}/device/model", parent_name));
+        let sn = self
+            .root
+            .read(format!("/sys/block/{}/device/serial", parent_name));
+        let vendor = self
+            .root
+            .read(format!("/sys/block/{}/device/vendor", parent_name));
+
+        (vendor, model, sn)
+    }
+
+    /// (transport, negotiated link speed) of a whole disk, from the first
+    /// bus found walking up its device path: e.g. a USB-SATA bridge is
+    /// `.../usb2/2-1/2-1:1.0/host6/target6:0:0/6:0:0:0` and reports as USB.
+    fn disk_transport(&self, name: &str) -> (Option<String>, Option<String>) {
+        let device = self.root.path(format!("/sys/block/{}/device", name));
+        let (Ok(device), Ok(base)) = (fs::canonicalize(device), fs::canonicalize(self.root.base()))
+        else {
+            return (None, None);
+        };
+        let mut scsi = false;
+        for dir in device.ancestors().take_while(|dir| *dir != base) {
+            let Some(component) = dir.file_name().map(|c| c.to_string_lossy().to_string()) else {
+                break;
+            };
+            let parent = dir
+                .parent()
+                .and_then(Path::file_name)
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let numbered = |prefix: &str| {
+                component
+                    .strip_prefix(prefix)
+                    .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+            };
+
+            // USB devices (not interfaces) carry their negotiated speed in Mb/s
+            if dir.join("idVendor").exists() {
+                let speed = read_trimmed(dir.join("speed")).map(|mbps| match mbps.as_str() {
+                    "1.5" | "12" | "480" => format!("{} Mb/s", mbps),
+                    _ => match mbps.parse::<f32>() {
+                        Ok(m) => format!("{} Gb/s", m / 1000.0),
+                        Err(_) => mbps,
+                    },
+                });
+                return (Some("USB".to_string()), speed);
+            }
+            if numbered("ata") {
+                let link = format!("link{}", &component[3..]);
+                let speed = read_trimmed(
+                    dir.join(&link)
+                        .join("ata_link")
+                        .join(&link)
+                        .join("sata_spd"),
+                )
+                .filter(|s| s.ends_with("Gbps"))
+                .map(|s| s.replace("Gbps", "Gb/s"));
+                return (Some("SATA".to_string()), speed);
+            }
+            if component.starts_with("end_device-") {
+                return (Some("SAS".to_string()), sas_link_speed(dir));
+            }
+            if parent == "nvme" || parent == "nvme-subsystem" {
+                let speed = dir.ancestors().find_map(|d| {
+                    let speed = read_trimmed(d.join("current_link_speed"))?;
+                    let width = read_trimmed(d.join("current_link_width"))?;
+                    // "16.0 GT/s PCIe" on recent kernels
+                    let speed = speed.trim_end_matches(" PCIe");
+                    Some(format!("PCIe {} x{}", speed, width))
+                });
+                return (Some("NVMe".to_string()), speed);
+            }
+            if parent == "mmc_host" {
+                let kind = match read_trimmed(device.join("type")).as_deref() {
+                    Some("MMC") => "eMMC",
+                    _ => "SD",
+                };
+                return (Some(kind.to_string()), None);
+            }
+            if numbered("virtio") {
+                return (Some("virtio".to_string()), None);
+            }
+            scsi |= numbered("host");
+        }
+        // e.g. behind a hardware RAID controller
+        (scsi.then(|| "SCSI".to_string()), None)
+    }
+
+    /// The disk under a mounted device such as `/dev/sda1` or
+    /// `/dev/mapper/vg-root`, following partitions to their disk and
+    /// device-mapper and md devices to the first device they are built on.
+    fn disk_of(&self, device: &str) -> Option<String> {
+        let path = Path::new(device);
+        if !path.starts_with("/dev/") {
+            return None;
+        }
+        // /dev/mapper/* and /dev/disk/by-*/* are links to the kernel name
+        let path = if self.root.is_live() {
+            fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+        } else {
+            path.to_path_buf()
+        };
+        let mut block = path.file_name()?.to_string_lossy().to_string();
+        // Bounded, in case of a loop in a corrupt capture
+        for _ in 0..8 {
+            if self
+                .root
+                .path(format!("/sys/block/{}/device", block))
+                .exists()
+            {
+                return Some(block);
+            }
+            let slave = self
+                .root
+                .read_dir(format!("/sys/block/{}/slaves", block))
+                .ok()
+                .and_then(|entries| entries.flatten().map(|e| e.file_name()).min());
+            if let Some(slave) = slave {
+                block = slave.to_string_lossy().to_string();
+                continue;
+            }
+            // A partition, listed under its disk as /sys/block/<disk>/<partition>
+            let parent = self
+                .root
+                .read_dir("/sys/block/")
+                .ok()?
+                .flatten()
+                .map(|e| e.file_name().to_string_lossy().to_string())
+                .find(|disk| {
+                    self.root
+                        .path(format!("/sys/block/{}/{}/partition", disk, block))
+                        .exists()
+                })?;
+            block = parent;
+        }
+        None
+    }
+
+    // The readers below stand in for sysinfo, libusb and pci-info, which only
+    // see the live system, when replaying a captured tree.
+
+    /// Regroups the logical cores of a single package by socket, using the
+    /// topology sysfs publishes for each core.
+    fn split_sockets(&self, mut packages: Vec<CpuPackage>) -> Vec<CpuPackage> {
+        if packages.len() != 1 {
+            return packages;
+        }
+        let package = packages.remove(0);
+        let topology = |id: usize, file: &str| {
+            self.root.read(format!(
+                "/sys/devices/system/cpu/cpu{}/topology/{}",
+                id, file
+            ))
+        };
+        let mut sockets: BTreeMap<u32, (Vec<CoreMetrics>, BTreeSet<String>)> = BTreeMap::new();
+        for core in &package.cores {
+            let Some(socket) =
+                topology(core.id, "physical_package_id").and_then(|s| s.parse().ok())
+            else {
+                return vec![package];
+            };
+            let (cores, core_ids) = sockets.entry(socket).or_default();
+            cores.push(core.clone());
+            core_ids.extend(topology(core.id, "core_id"));
+        }
+        if sockets.len() < 2 {
+            return vec![package];
+        }
+        sockets
+            .into_values()
+            .map(|(cores, core_ids)| CpuPackage {
+                physical_cores: core_ids.len(),
+                logical_cores: cores.len(),
+                cores,
+                ..package.clone()
+            })
+            .collect()
+    }
+
+    /// Labels the cores of hybrid CPUs. Intel lists its P and E cores under
+    /// the `cpu_core` and `cpu_atom` PMUs; on ARM big.LITTLE the little cores
+    /// have a lower `cpu_capacity`.
+    fn label_core_types(&self, packages: &mut [CpuPackage]) {
+        let cpu = |id: usize, file: &str| {
+            self.root
+                .read(format!("/sys/devices/system/cpu/cpu{}/{}", id, file))
+        };
+        let mut kinds: HashMap<usize, CoreKind> = HashMap::new();
+        for (pmu, kind) in [
+            ("cpu_core", CoreKind::Performance),
+            ("cpu_atom", CoreKind::Efficiency),
+        ] {
+            if let Some(list) = self.root.read(format!("/sys/devices/{}/cpus", pmu)) {
+                kinds.extend(super::cpu_list(&list).into_iter().map(|id| (id, kind)));
+            }
+        }
+        if kinds.is_empty() {
+            let capacities: Vec<(usize, u32)> = packages
+                .iter()
+                .flat_map(|p| &p.cores)
+                .filter_map(|c| Some((c.id, cpu(c.id, "cpu_capacity")?.parse().ok()?)))
+                .collect();
+            // The middle tier of three-tier designs counts as performance cores
+            let lowest = capacities.iter().map(|&(_, capacity)| capacity).min();
+            if capacities
+                .iter()
+                .any(|&(_, capacity)| Some(capacity) != lowest)
+            {
+                kinds.extend(capacities.iter().map(|&(id, capacity)| {
+                    let kind = if Some(capacity) == lowest {
+                        CoreKind::Efficiency
+                    } else {
+                        CoreKind::Performance
+                    };
+                    (id, kind)
+                }));
+            }
+        }
+        if kinds.is_empty() {
+            return;
+        }
+
+        let mhz = |id: usize, file: &str| {
+            cpu(id, file)
+                .and_then(|khz| khz.parse::<u64>().ok())
+                .map(|khz| khz / 1000)
+        };
+        for package in packages {
+            let mut groups: Vec<CoreGroup> = Vec::new();
+            for core in &mut package.cores {
+                core.kind = kinds.get(&core.id).copied();
+                core.cluster = cpu(core.id, "topology/cluster_id").and_then(|s| s.parse().ok());
+                let Some(kind) = core.kind else {
+                    continue;
+                };
+                let base = mhz(core.id, "cpufreq/base_frequency");
+                let boost = mhz(core.id, "cpufreq/cpuinfo_max_freq");
+                match groups.iter_mut().find(|g| g.kind == kind) {
+                    Some(group) => {
+                        group.logical_cores += 1;
+                        group.base_mhz = group.base_mhz.max(base);
+                        group.boost_mhz = group.boost_mhz.max(boost);
+                    }
+                    None => groups.push(CoreGroup {
+                        kind,
+                        logical_cores: 1,
+                        base_mhz: base,
+                        boost_mhz: boost,
+                    }),
+                }
+            }
+            groups.sort_by_key(|g| g.kind);
+            package.core_groups = groups;
+        }
+    }
+
+    fn captured_cpus(&self) -> Vec<CpuPackage> {
+        let cpuinfo = self.root.read("/proc/cpuinfo").unwrap_or_default();
+        let caches = super::sysfs_caches(&self.root);
+
+        let blocks: Vec<HashMap<&str, &str>> = cpuinfo
+            .split("\n\n")
+            .map(|block| {
+                block
+                    .lines()
+                    .filter_map(|l| l.split_once(':'))
+                    .map(|(k, v)| (k.trim(), v.trim()))
+                    .collect()
+            })
+            .filter(|fields: &HashMap<&str, &str>| fields.contains_key("processor"))
+            .collect();
+        let Some(first) = blocks.first() else {
+            return Vec::new();
+        };
+        let mut physical: Vec<(&str, &str)> = blocks
+            .iter()
+            .filter_map(|f| Some((*f.get("physical id")?, *f.get("core id")?)))
+            .collect();
+        physical.sort_unstable();
+        physical.dedup();
+        let physical_cores = if physical.is_empty() {
+            blocks.len()
+        } else {
+            physical.len()
+        };
+
+        let field = |fields: &HashMap<&str, &str>, key: &str| {
+            fields.get(key).copied().unwrap_or_default().to_string()
+        };
+        vec![CpuPackage {
+            model: field(first, "model name"),
+            vendor: field(first, "vendor_id"),
+            caches,
+            physical_cores,
+            logical_cores: blocks.len(),
+            core_groups: Vec::new(),
+            cores: blocks
+                .iter()
+                .enumerate()
+                .map(|(i, fields)| CoreMetrics {
+                    id: field(fields, "processor").parse().unwrap_or(i),
+                    kind: None,
+                    cluster: None,
+                    frequency: field(fields, "cpu MHz").parse::<f64>().unwrap_or(0.0) as u64,
+                    usage: 0.0,
+                })
+                .collect(),
+        }]
+    }
+
+    /// Block-device mounts from the captured mount table. Usage figures are
+    /// not part of a capture, so only the size is known.
+    fn captured_mounts(&self) -> Vec<Filesystem> {
+        let mounts = self.root.read("/proc/mounts").unwrap_or_default();
+        let mut filesystems: Vec<Filesystem> = Vec::new();
+        for line in mounts.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [device, mount_point, filesystem, ..] = fields[..] else {
+                continue;
+            };
+            let Some(block) = device.strip_prefix("/dev/") else {
+                continue;
+            };
+            if filesystems.iter().any(|s| s.name == device) {
+                continue;
+            }
+            let block = block.rsplit('/').next().unwrap_or(block);
+            let sectors = self
+                .root
+                .read(format!("/sys/class/block/{}/size", block))
+                .and_then(|s| s.parse::<u64>().ok())
+                .unwrap_or(0);
+            filesystems.push(Filesystem {
+                name: device.to_string(),
+                // spaces in mount points are escaped as \040
+                mount_point: mount_point.replace("\\040", " "),
+                total: sectors * 512,
+                used: 0,
+                free: 0,
+                filesystem: filesystem.to_string(),
+                disk: None,
+                container: None,
+                inodes: None,
+            });
+        }
+        filesystems
+    }
+
+    fn captured_hwmon_temperatures(&self) -> Vec<TemperatureSensor> {
+        let mut sensors = Vec::new();
+        let Ok(entries) = self.root.read_dir("/sys/class/hwmon/") else {
+            return sensors;
+        };
+        let mut hwmons: Vec<_> = entries.flatten().collect();
+        hwmons.sort_by_key(|e| e.file_name());
+        for hwmon in hwmons {
+            let read = |file: &str| read_trimmed(hwmon.path().join(file));
+            let chip = read("name").unwrap_or_default();
+            let Ok(files) = fs::read_dir(hwmon.path()) else {
+                continue;
+            };
+            let mut inputs: Vec<String> = files
+                .flatten()
+                .map(|f| f.file_name().to_string_lossy().to_string())
+                .filter(|f| f.starts_with("temp") && f.ends_with("_input"))
+                .collect();
+            inputs.sort();
+            for input in inputs {
+                let prefix = input.trim_end_matches("_input");
+                // hwmon reports millidegrees
+                let celsius = |file: String| {
+                    read(&file)
+                        .and_then(|s| s.parse::<f32>().ok())
+                        .map(|m| m / 1000.0)
+                };
+                let label = read(&format!("{}_label", prefix))
+                    .map(|l| format!("{} {}", chip, l))
+                    .unwrap_or_else(|| chip.clone());
+                sensors.push(TemperatureSensor {
+                    label,
+                    temperature: celsius(input.clone()),
+                    max: celsius(format!("{}_max", prefix)),
+                    critical: celsius(format!("{}_crit", prefix)),
+                });
+            }
+        }
+        sensors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::cpu_time_shares;
+
+    #[test]
+    fn slot_links() {
+        assert_eq!(pcie_generation("16.0 GT/s PCIe"), Some(4));
+        assert_eq!(pcie_generation("2.5 GT/s"), Some(1));
+        assert_eq!(pcie_generation("Unknown"), None);
+        assert!(is_pci_address("0000:01:00.0"));
+        assert!(!is_pci_address("pci_bus"));
+        assert!(!is_pci_address("0000:01:00:0"));
+    }
+
+    #[test]
+    fn hypervisors_from_dmi() {
+        assert_eq!(
+            hypervisor_name("QEMU", "Standard PC (Q35 + ICH9, 2009)"),
+            Some("KVM")
+        );
+        assert_eq!(
+            hypervisor_name("Microsoft Corporation", "Virtual Machine"),
+            Some("Hyper-V")
+        );
+        assert_eq!(
+            hypervisor_name("Microsoft Corporation", "Surface Pro 9"),
+            None
+        );
+        assert_eq!(hypervisor_name("Amazon EC2", "m5.large"), Some("AWS Nitro"));
+        assert_eq!(hypervisor_name("Amazon EC2", "m5.metal"), None);
+        assert_eq!(hypervisor_name("Dell Inc.", "PowerEdge R650"), None);
+
+        let virtualized = VirtualMemory {
+            hypervisor: Some("KVM".to_string()),
+            devices: vec!["virtio-balloon".to_string()],
+        };
+        let sticks = virtualized.sticks();
+        assert_eq!(sticks.len(), 1);
+        assert_eq!(sticks[0].manufacturer.as_deref(), Some("KVM"));
+        assert_eq!(sticks[0].part_number.as_deref(), Some("virtio-balloon"));
+    }
+
+    #[test]
+    fn chrony_tracking() {
+        let tracking = "Reference ID    : A29FC87B (162.159.200.123)
+Stratum         : 4
+Ref time (UTC)  : Fri Oct 16 12:00:00 2026
+System time     : 0.000012345 seconds slow of NTP time
+Last offset     : -0.000001201 seconds
+Leap status     : Normal
+";
+        let (synchronized, server, offset) = parse_chrony_tracking(tracking);
+        assert!(synchronized);
+        assert_eq!(server.as_deref(), Some("162.159.200.123"));
+        assert_eq!(offset, Some(-0.000012345));
+
+        let unsynced = "Reference ID    : 00000000 ()
+System time     : 1.250000000 seconds fast of NTP time
+Leap status     : Not synchronised
+";
+        let (synchronized, server, offset) = parse_chrony_tracking(unsynced);
+        assert!(!synchronized);
+        assert_eq!(server, None);
+        assert_eq!(offset, Some(1.25));
+    }
+
+    #[test]
+    fn interrupt_lines() {
+        let interrupts = parse_interrupts(
+            "            CPU0       CPU1
+   0:         35          0   IO-APIC   2-edge      timer
+  24:     812345         12  IR-PCI-MSI 524288-edge      nvme0q0
+  31:          0          0  IR-PCI-MSI 1048576-edge      ehci_hcd:usb1, ahci[0000:00:1f.2]
+ LOC:    9876543    9123456   Local timer interrupts
+ ERR:          0
+",
+        );
+        assert_eq!(interrupts["24"].name, "nvme0q0");
+        assert_eq!(interrupts["24"].per_cpu, [812345, 12]);
+        assert_eq!(interrupts["31"].name, "ehci_hcd:usb1, ahci[0000:00:1f.2]");
+        assert_eq!(interrupts["LOC"].name, "Local timer interrupts");
+        assert_eq!(interrupts["ERR"].per_cpu, [0]);
+    }
+
+    #[test]
+    fn pressure_files() {
+        let io = parse_pressure(
+            "some avg10=1.52 avg60=0.87 avg300=0.25 total=123456\nfull avg10=0.50 avg60=0.10 avg300=0.00 total=42\n",
+        )
+        .unwrap();
+        assert_eq!(io.some, [1.52, 0.87, 0.25]);
+        assert_eq!(io.full, Some([0.5, 0.1, 0.0]));
+        let cpu = parse_pressure("some avg10=0.00 avg60=0.00 avg300=0.00 total=0\n").unwrap();
+        assert_eq!(cpu.full, None);
+        assert!(parse_pressure("").is_none());
+    }
+
+    #[test]
+    fn cpu_time_split() {
+        let before = parse_cpu_ticks("cpu  100 0 50 800 40 5 5 0 0 0\ncpu0 1 2 3 4\n").unwrap();
+        let after = parse_cpu_ticks("cpu  160 20 70 900 80 10 10 50 0 0\n").unwrap();
+        // 80 user, 30 system, 40 iowait, 50 steal, 100 idle out of 300
+        let times = cpu_time_shares(&before, &after).unwrap();
+        assert!((times.user - 26.67).abs() < 0.01);
+        assert!((times.system - 10.0).abs() < 0.01);
+        assert!((times.iowait - 13.33).abs() < 0.01);
+        assert!((times.steal - 16.67).abs() < 0.01);
+        assert!(cpu_time_shares(&after, &after).is_none());
+    }
+
+    #[test]
+    fn battery_estimates() {
+        // 30 Wh left at 15 W, 20 Wh to go at 40 W
+        assert_eq!(
+            battery_time_remaining("Discharging", 30e6, 50e6, 15e6),
+            Some(7200)
+        );
+        assert_eq!(
+            battery_time_remaining("Charging", 30e6, 50e6, 40e6),
+            Some(1800)
+        );
+        assert_eq!(battery_time_remaining("Full", 50e6, 50e6, 0.0), None);
+        assert_eq!(battery_time_remaining("Discharging", 30e6, 50e6, 0.0), None);
+    }
+}
@@ -0,0 +1,380 @@
+//! macOS discovery through `system_profiler -json`, which wraps the same IOKit
+//! registries as System Information.app without linking any frameworks.
+
+use super::Probe;
+use crate::model::{
+    BatteryInfo, CoreGroup, CoreKind, CpuPackage, Filesystem, MotherboardInfo, NetworkConfig,
+    PhysicalDisk, ThunderboltDevice, UsbDevice,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MacProbe;
+
+impl Probe for MacProbe {
+    fn cpus(&self) -> Vec<CpuPackage> {
+        let mut packages = super::sysinfo_cpus();
+        label_core_types(&mut packages);
+        packages
+    }
+
+    fn disks(&self) -> Vec<PhysicalDisk> {
+        physical_disks()
+    }
+
+    fn filesystems(&self) -> Vec<Filesystem> {
+        let mut filesystems = super::sysinfo_filesystems();
+        annotate_filesystems(&mut filesystems, &physical_disks());
+        filesystems
+    }
+
+    fn usb_devices(&self) -> Vec<UsbDevice> {
+        let mut devices = super::libusb_devices();
+        annotate_usb(&mut devices);
+        devices
+    }
+
+    fn thunderbolt_devices(&self) -> Vec<ThunderboltDevice> {
+        thunderbolt_devices()
+    }
+
+    fn network_config(&self) -> Option<NetworkConfig> {
+        super::route_network_config()
+    }
+
+    fn motherboard(&self) -> Option<MotherboardInfo> {
+        motherboard_info()
+    }
+
+    fn batteries(&self) -> Vec<BatteryInfo> {
+        battery_info()
+    }
+}
+
+// system_profiler takes up to a few seconds per data type, and the TUI asks
+// for storage and batteries on every refresh.
+const CACHE_TTL: Duration = Duration::from_secs(30);
+
+static CACHE: Mutex<Option<HashMap<&'static str, (Instant, Value)>>> = Mutex::new(None);
+
+/// The item list of one system_profiler data type, e.g. `SPHardwareDataType`.
+fn profile(data_type: &'static str) -> Vec<Value> {
+    if let Ok(cache) = CACHE.lock()
+        && let Some((at, value)) = cache.as_ref().and_then(|c| c.get(data_type))
+        && at.elapsed() < CACHE_TTL
+    {
+        return items(value, data_type);
+    }
+    let value = Command::new("system_profiler")
+        .args(["-json", "-detailLevel", "full", data_type])
+        .output()
+        .ok()
+        .and_then(|output| serde_json::from_slice::<Value>(&output.stdout).ok())
+        .unwrap_or(Value::Null);
+    let list = items(&value, data_type);
+    if let Ok(mut cache) = CACHE.lock() {
+        cache
+            .get_or_insert_with(HashMap::new)
+            .insert(data_type, (Instant::now(), value));
+    }
+    list
+}
+
+fn items(value: &Value, data_type: &str) -> Vec<Value> {
+    value
+        .get(data_type)
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn text(value: &Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+// "0x05ac  (Apple Inc.)" or "0x05ac" -> 0x05ac
+fn hex_id(value: &Value, key: &str) -> Option<u16> {
+    let raw = text(value, key)?;
+    let hex = raw.split_whitespace().next()?.trim_start_matches("0x");
+    u16::from_str_radix(hex, 16).ok()
+}
+
+/// Walks nested `_items` lists (hubs, Thunderbolt daisy chains) depth-first.
+fn flatten(list: &[Value], out: &mut Vec<Value>) {
+    for item in list {
+        out.push(item.clone());
+        if let Some(children) = item.get("_items").and_then(Value::as_array) {
+            flatten(children, out);
+        }
+    }
+}
+
+/// Apple Silicon describes its core types as performance levels, fastest
+/// first (`hw.perflevel0` are the P cores), while the kernel numbers the
+/// efficiency cores first. Clock ratings are not published.
+fn label_core_types(packages: &mut [CpuPackage]) {
+    let levels = sysctl("hw.nperflevels")
+        .and_then(|n| n.parse::<usize>().ok())
+        .unwrap_or(0);
+    let Some(package) = packages.first_mut() else {
+        return;
+    };
+    if levels < 2 {
+        return;
+    }
+    let (mut first_id, mut first_cluster) = (0, 0);
+    for level in (0..levels).rev() {
+        let number = |key: &str| {
+            sysctl(&format!("hw.perflevel{}.{}", level, key)).and_then(|v| v.parse::<usize>().ok())
+        };
+        let Some(count) = number("logicalcpu") else {
+            return;
+        };
+        let per_cluster = number("cpusperl2").filter(|&n| n > 0).unwrap_or(count);
+        let kind = if level == 0 {
+            CoreKind::Performance
+        } else {
+            CoreKind::Efficiency
+        };
+        for core in &mut package.cores {
+            if (first_id..first_id + count).contains(&core.id) {
+                core.kind = Some(kind);
+                core.cluster = Some((first_cluster + (core.id - first_id) / per_cluster) as u32);
+            }
+        }
+        first_id += count;
+        first_cluster += count.div_ceil(per_cluster);
+        package.core_groups.push(CoreGroup {
+            kind,
+            logical_cores: count,
+            base_mhz: None,
+            boost_mhz: None,
+        });
+    }
+    package.core_groups.sort_by_key(|g| g.kind);
+}
+
+fn sysctl(name: &str) -> Option<String> {
+    let output = Command::new("sysctl").args(["-n", name]).output().ok()?;
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !value.is_empty()).then_some(value)
+}
+
+/// Model identifier (e.g. `MacBookPro18,3`) and firmware, in place of DMI board data.
+fn motherboard_info() -> Option<MotherboardInfo> {
+    let hardware = profile("SPHardwareDataType").into_iter().next()?;
+    let model = text(&hardware, "machine_model").unwrap_or_else(|| "Unknown".to_string());
+    let product = match text(&hardware, "machine_name") {
+        Some(name) => format!("{} ({})", name, model),
+        None => model,
+    };
+    Some(MotherboardInfo {
+        vendor: "Apple Inc.".to_string(),
+        product,
+        bios_vendor: "Apple Inc.".to_string(),
+        // Apple silicon reports "system_firmware_version", Intel Macs "boot_rom_version"
+        bios_version: text(&hardware, "boot_rom_version")
+            .or_else(|| text(&hardware, "system_firmware_version"))
+            .unwrap_or_else(|| "Unknown".to_string()),
+        bios_date: "Unknown".to_string(),
+    })
+}
+
+fn battery_info() -> Vec<BatteryInfo> {
+    let power = profile("SPPowerDataType");
+    let Some(battery) = power
+        .iter()
+        .find(|item| item.get("sppower_battery_charge_info").is_some())
+    else {
+        return Vec::new();
+    };
+    let charge = &battery["sppower_battery_charge_info"];
+    let health = &battery["sppower_battery_health_info"];
+    let flag = |key: &str| text(charge, key).is_some_and(|v| v.eq_ignore_ascii_case("TRUE"));
+    let status = if flag("sppower_battery_fully_charged") {
+        "Full"
+    } else if flag("sppower_battery_is_charging") {
+        "Charging"
+    } else {
+        "Discharging"
+    };
+    vec![BatteryInfo {
+        name: text(
+            &battery["sppower_battery_model_info"],
+            "sppower_battery_device_name",
+        )
+        .unwrap_or_else(|| "InternalBattery".to_string()),
+        status: status.to_string(),
+        capacity: charge
+            .get("sppower_battery_state_of_charge")
+            .and_then(Value::as_u64)
+            .map(|c| c.min(100) as u8)
+            .unwrap_or(0),
+        power_watts: None,
+        cycle_count: health
+            .get("sppower_battery_cycle_count")
+            .and_then(Value::as_u64)
+            .map(|c| c as u32),
+        // e.g. "87%" of the design capacity
+        wear_percent: text(health, "sppower_battery_health_maximum_capacity")
+            .and_then(|c| c.trim_end_matches('%').trim().parse::<f32>().ok())
+            .map(|c| (100.0 - c).max(0.0)),
+        time_remaining: None,
+    }]
+}
+
+/// Whole drives (`disk0`) from the NVMe and SATA data types, plus drives on
+/// other buses (USB, Thunderbolt) that only show up behind a mounted volume.
+fn physical_disks() -> Vec<PhysicalDisk> {
+    let mut disks = Vec::new();
+    for (data_type, interface) in [("SPNVMeDataType", "NVMe"), ("SPSerialATADataType", "SATA")] {
+        let mut drives = Vec::new();
+        flatten(&profile(data_type), &mut drives);
+        for drive in drives {
+            let Some(name) = text(&drive, "bsd_name") else {
+                continue;
+            };
+            if name.trim_start_matches("disk").contains('s') {
+                continue;
+            }
+            let disk_type = if interface == "NVMe" {
+                Some("SSD".to_string())
+            } else {
+                text(&drive, "spsata_medium_type").map(|medium| medium.to_uppercase())
+            };
+            disks.push(PhysicalDisk {
+                size: drive["size_in_bytes"].as_u64().unwrap_or(0),
+                vendor: None,
+                model_name: text(&drive, "device_model").or_else(|| text(&drive, "_name")),
+                serial_number: text(&drive, "device_serial"),
+                rotational: disk_type.as_deref().map(|t| t != "SSD"),
+                disk_type,
+                interface: Some(interface.to_string()),
+                link_speed: None,
+                smart: super::smart_health(&format!("/dev/{}", name)),
+                name,
+            });
+        }
+    }
+    for volume in profile("SPStorageDataType") {
+        let drive = &volume["physical_drive"];
+        let model = text(drive, "device_name");
+        let Some(bsd) = text(&volume, "bsd_name") else {
+            continue;
+        };
+        if model.is_none() || disks.iter().any(|d| d.model_name == model) {
+            continue;
+        }
+        disks.push(PhysicalDisk {
+            name: whole_disk(&bsd),
+            size: 0,
+            vendor: None,
+            model_name: model,
+            serial_number: None,
+            disk_type: text(drive, "medium_type").map(|medium| medium.to_uppercase()),
+            rotational: None,
+            interface: text(drive, "protocol"),
+            link_speed: None,
+            smart: None,
+        });
+    }
+    disks
+}
+
+/// "disk3s1" -> "disk3"
+fn whole_disk(bsd: &str) -> String {
+    bsd.chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .chain(
+            bsd.trim_start_matches(|c: char| c.is_ascii_alphabetic())
+                .chars()
+                .take_while(char::is_ascii_digit),
+        )
+        .collect()
+}
+
+/// Links mounted volumes to their drive and APFS container.
+fn annotate_filesystems(filesystems: &mut [Filesystem], disks: &[PhysicalDisk]) {
+    let volumes = profile("SPStorageDataType");
+    for fs in filesystems.iter_mut() {
+        let Some(volume) = volumes
+            .iter()
+            .find(|v| text(v, "mount_point").as_deref() == Some(fs.mount_point.as_str()))
+        else {
+            continue;
+        };
+        let model = text(&volume["physical_drive"], "device_name");
+        fs.disk = disks
+            .iter()
+            .find(|d| model.is_some() && d.model_name == model)
+            .map(|d| d.name.clone());
+        // APFS volumes diskNsM share the space of container diskN
+        if text(volume, "file_system").as_deref() == Some("APFS")
+            && let Some(bsd) = text(volume, "bsd_name")
+        {
+            fs.container = Some(whole_disk(&bsd));
+        }
+    }
+}
+
+/// Adds names from system_profiler, since libusb usually cannot open devices
+/// on macOS to read their string descriptors.
+fn annotate_usb(devices: &mut [UsbDevice]) {
+    let mut listed = Vec::new();
+    // SPUSBDataType was replaced by SPUSBHostDataType in macOS 14
+    flatten(&profile("SPUSBDataType"), &mut listed);
+    if listed.is_empty() {
+        flatten(&profile("SPUSBHostDataType"), &mut listed);
+    }
+    for device in devices.iter_mut() {
+        let Some(item) = listed.iter().find(|item| {
+            hex_id(item, "vendor_id").or_else(|| hex_id(item, "USBDeviceKeyVendorID"))
+                == Some(device.vendor_id)
+                && hex_id(item, "product_id").or_else(|| hex_id(item, "USBDeviceKeyProductID"))
+                    == Some(device.product_id)
+        }) else {
+            continue;
+        };
+        if device.manufacturer.is_none() {
+            device.manufacturer =
+                text(item, "manufacturer").or_else(|| text(item, "USBDeviceKeyVendorName"));
+        }
+        if device.product.is_none() {
+            device.product = text(item, "_name");
+        }
+    }
+}
+
+fn thunderbolt_devices() -> Vec<ThunderboltDevice> {
+    let mut listed = Vec::new();
+    for bus in profile("SPThunderboltDataType") {
+        // The top level entries are the Mac's own controllers; attached devices are nested.
+        if let Some(children) = bus.get("_items").and_then(Value::as_array) {
+            flatten(children, &mut listed);
+        }
+    }
+    listed
+        .iter()
+        .map(|item| ThunderboltDevice {
+            name: text(item, "device_name_key")
+                .or_else(|| text(item, "_name"))
+                .unwrap_or_else(|| "Unknown".to_string()),
+            vendor: text(item, "vendor_name_key"),
+            speed: text(item, "mode_key").or_else(|| {
+                text(
+                    &item["receptacle_upstream_ambiguous_tag"],
+                    "current_speed_key",
+                )
+            }),
+        })
+        .collect()
+}
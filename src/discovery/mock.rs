@@ -0,0 +1,334 @@
+//! Replays a saved report, so formatters, exporters and alert rules can be
+//! exercised without the hardware it describes.
+
+use super::{DiskStats, IrqStats, Probe, SystemIdentity};
+use crate::config::Sections;
+use crate::model::{
+    BatteryInfo, BoardLayout, CloudProvider, ContainerLimits, CpuLoad, CpuPackage, FanInfo,
+    Filesystem, GpuInfo, GpuProcess, HardwareReport, IommuInfo, KernelLimit, Limitation,
+    MemorySlots, MotherboardInfo, NetworkConfig, NetworkInfo, OsDetails, PciDevice, PhysicalDisk,
+    PlatformState, Pressure, RamInfo, RamStick, SpdInfo, TemperatureSensor, ThunderboltDevice,
+    TimeHealth, UsbDevice, VirtualizationSupport,
+};
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Debug, Clone)]
+pub struct MockProbe {
+    pub report: HardwareReport,
+    /// Sections that are not part of a report
+    pub cpu_flags: Vec<String>,
+    pub throttle_count: Option<u64>,
+    pub diskstats: HashMap<String, DiskStats>,
+    pub interrupts: HashMap<String, IrqStats>,
+}
+
+impl MockProbe {
+    pub fn new(report: HardwareReport) -> Self {
+        Self {
+            report,
+            cpu_flags: Vec::new(),
+            throttle_count: None,
+            diskstats: HashMap::new(),
+            interrupts: HashMap::new(),
+        }
+    }
+
+    /// Loads a report written with `--format json`, `yaml`, `msgpack` or
+    /// `cbor`; the binary formats are told apart by file extension.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let bytes =
+            std::fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        let report: HardwareReport = match extension {
+            "msgpack" | "mpk" => rmp_serde::from_slice(&bytes)
+                .with_context(|| format!("{} is not a MessagePack report", path.display()))?,
+            "cbor" => ciborium::from_reader(bytes.as_slice())
+                .with_context(|| format!("{} is not a CBOR report", path.display()))?,
+            // YAML is a superset of JSON, so one parser reads both formats.
+            _ => serde_yaml::from_slice(&bytes)
+                .with_context(|| format!("{} is not a JSON or YAML report", path.display()))?,
+        };
+        Ok(Self::new(report))
+    }
+}
+
+impl Probe for MockProbe {
+    fn system(&self) -> SystemIdentity {
+        SystemIdentity {
+            os_name: self.report.os_name.clone(),
+            os_version: self.report.os_version.clone(),
+            kernel_version: self.report.kernel_version.clone(),
+            hostname: self.report.hostname.clone(),
+            uptime: self.report.uptime,
+        }
+    }
+
+    fn cpus(&self) -> Vec<CpuPackage> {
+        self.report.cpu.clone()
+    }
+
+    fn os_details(&self) -> Option<OsDetails> {
+        self.report.os_details.clone()
+    }
+
+    fn memory(&self) -> RamInfo {
+        self.report.ram.clone()
+    }
+
+    fn container_limits(&self) -> Option<ContainerLimits> {
+        self.report.container.clone()
+    }
+
+    fn kernel_limits(&self) -> Vec<KernelLimit> {
+        self.report.limits.clone()
+    }
+
+    fn time_health(&self) -> Option<TimeHealth> {
+        self.report.time.clone()
+    }
+
+    fn ram_sticks(&self) -> Vec<RamStick> {
+        self.report.ram.sticks.clone()
+    }
+
+    fn memory_slots(&self) -> Option<MemorySlots> {
+        self.report.ram.slots.clone()
+    }
+
+    fn spd_modules(&self) -> Vec<SpdInfo> {
+        self.report.ram.spd.clone()
+    }
+
+    fn disks(&self) -> Vec<PhysicalDisk> {
+        self.report.disks.clone()
+    }
+
+    fn filesystems(&self) -> Vec<Filesystem> {
+        self.report.filesystems.clone()
+    }
+
+    fn network(&self) -> Vec<NetworkInfo> {
+        self.report.network.clone()
+    }
+
+    fn network_config(&self) -> Option<NetworkConfig> {
+        self.report.network_config.clone()
+    }
+
+    fn usb_ids(&self) -> Vec<(u8, u8, u16, u16)> {
+        self.report
+            .usb
+            .iter()
+            .map(|d| (d.bus, d.address, d.vendor_id, d.product_id))
+            .collect()
+    }
+
+    fn usb_devices(&self) -> Vec<UsbDevice> {
+        self.report.usb.clone()
+    }
+
+    fn thunderbolt_devices(&self) -> Vec<ThunderboltDevice> {
+        self.report.thunderbolt.clone()
+    }
+
+    fn pci_devices(&self) -> Vec<PciDevice> {
+        self.report.pci.clone()
+    }
+
+    fn iommu(&self) -> Option<IommuInfo> {
+        self.report.iommu.clone()
+    }
+
+    fn motherboard(&self) -> Option<MotherboardInfo> {
+        self.report.motherboard.clone()
+    }
+
+    fn cloud_provider(&self) -> Option<CloudProvider> {
+        self.report.cloud.as_ref().map(|cloud| cloud.provider)
+    }
+
+    fn batteries(&self) -> Vec<BatteryInfo> {
+        self.report.battery.clone()
+    }
+
+    fn platform_state(&self) -> Option<PlatformState> {
+        self.report.platform.clone()
+    }
+
+    fn board_layout(&self) -> Option<BoardLayout> {
+        self.report.board_layout.clone()
+    }
+
+    fn temperatures(&self) -> Vec<TemperatureSensor> {
+        self.report.temperatures.clone()
+    }
+
+    fn fans(&self) -> Vec<FanInfo> {
+        self.report.fans.clone()
+    }
+
+    fn gpus(&self) -> Vec<GpuInfo> {
+        self.report.gpus.clone()
+    }
+
+    fn gpu_processes(&self) -> Vec<GpuProcess> {
+        self.report.gpu_processes.clone()
+    }
+
+    fn cpu_flags(&self) -> Vec<String> {
+        self.cpu_flags.clone()
+    }
+
+    fn virtualization(&self) -> Option<VirtualizationSupport> {
+        self.report.virtualization.clone()
+    }
+
+    fn pressure(&self) -> Option<Pressure> {
+        self.report.pressure.clone()
+    }
+
+    fn cpu_load(&self) -> Option<CpuLoad> {
+        self.report.cpu_load.clone()
+    }
+
+    fn throttle_count(&self) -> Option<u64> {
+        self.throttle_count
+    }
+
+    fn diskstats(&self) -> HashMap<String, DiskStats> {
+        self.diskstats.clone()
+    }
+
+    fn interrupts(&self) -> HashMap<String, IrqStats> {
+        self.interrupts.clone()
+    }
+
+    fn limitations(&self, _sections: &Sections) -> Vec<Limitation> {
+        self.report.limitations.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alerts::{AlertLevel, report_problems};
+    use crate::cli::OutputFormat;
+    use crate::config::{Config, Sections};
+    use crate::discovery::{collect_report, collect_sections};
+    use crate::exporter::render_report;
+
+    // A laptop with a full root filesystem, a hot CPU and a worn, low battery
+    fn probe() -> MockProbe {
+        MockProbe::new(
+            serde_json::from_str(
+                r#"{"os_name": "Debian GNU/Linux", "os_version": "12", "kernel_version": "6.1.0",
+                    "hostname": "lab-7", "uptime": 90061,
+                    "cpu_packages": [{"model": "Intel(R) Core(TM) i7-8650U", "vendor": "GenuineIntel",
+                        "physical_cores": 2, "logical_cores": 2,
+                        "cores": [{"id": 0, "frequency": 1900, "usage": 40.0},
+                                  {"id": 1, "frequency": 2100, "usage": 20.0}]}],
+                    "ram": {"total": 17179869184, "used": 4294967296, "free": 12884901888,
+                        "swap_total": 0, "swap_used": 0, "sticks": []},
+                    "filesystems": [
+                        {"name": "/dev/nvme0n1p2", "mount_point": "/", "total": 100, "used": 95,
+                         "free": 5, "filesystem": "ext4"},
+                        {"name": "/dev/nvme0n1p1", "mount_point": "/boot/efi", "total": 100,
+                         "used": 10, "free": 90, "filesystem": "vfat"}],
+                    "network": [], "usb": [], "pci": [],
+                    "motherboard": {"vendor": "LENOVO", "product": "20L8S2N800",
+                        "bios_vendor": "LENOVO", "bios_version": "N22ET80W", "bios_date": "2023-01-01"},
+                    "battery": [{"name": "BAT0", "status": "Discharging", "capacity": 35,
+                        "wear_percent": 28.5}],
+                    "temperatures": [{"label": "Package id 0", "temperature": 82.0, "max": 100.0,
+                        "critical": 100.0}]}"#,
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn sections_left_out_stay_empty() {
+        let sections = Sections {
+            cpu: true,
+            ram: false,
+            storage: true,
+            network: false,
+            usb: false,
+            thunderbolt: false,
+            pci: false,
+            gpu: false,
+            limits: false,
+            time: false,
+            motherboard: false,
+            battery: false,
+            sensors: false,
+        };
+        let report = collect_sections(&probe(), &sections);
+        assert_eq!(report.hostname, "lab-7");
+        assert_eq!(report.cpu[0].cores.len(), 2);
+        assert_eq!(report.filesystems.len(), 2);
+        assert_eq!(report.ram.total, 0);
+        assert!(report.motherboard.is_none());
+        assert!(report.battery.is_empty());
+        assert!(report.temperatures.is_empty());
+
+        let report = collect_report(&probe());
+        assert_eq!(report.ram.total, 17179869184);
+        assert_eq!(report.motherboard.unwrap().vendor, "LENOVO");
+        assert_eq!(report.battery[0].capacity, 35);
+    }
+
+    #[test]
+    fn problems_from_a_replayed_report() {
+        let problems: Vec<_> = report_problems(&collect_report(&probe()), &Config::default())
+            .into_iter()
+            .map(|problem| (problem.metric, problem.level, problem.message))
+            .collect();
+        assert_eq!(
+            problems,
+            vec![
+                (
+                    "disk:/".to_string(),
+                    AlertLevel::Critical,
+                    "disk:/: 95.0% > critical 90%".to_string()
+                ),
+                (
+                    "temp:Package id 0".to_string(),
+                    AlertLevel::Warning,
+                    "temp:Package id 0: 82.0°C > warning 70°C".to_string()
+                ),
+                (
+                    "battery:BAT0".to_string(),
+                    AlertLevel::Warning,
+                    "battery:BAT0: 35% < warning 50%".to_string()
+                ),
+                (
+                    "battery:BAT0:wear".to_string(),
+                    AlertLevel::Warning,
+                    "battery:BAT0 wear: 28.5% > max 20%".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn formats_a_replayed_report() {
+        let report = collect_report(&probe());
+        let summary = render_report(&report, OutputFormat::Summary).unwrap();
+        assert!(summary.contains("Intel(R) Core(TM) i7-8650U (2C/2T) @ 2.10 GHz"));
+        assert!(summary.contains("LENOVO 20L8S2N800"));
+
+        let json = render_report(&report, OutputFormat::Json).unwrap();
+        let again: HardwareReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(again.filesystems[0].used, 95);
+
+        let kv = render_report(&report, OutputFormat::Kv).unwrap();
+        assert!(kv.contains("hw.cpu.usage[1]"));
+        assert!(kv.contains("hw.hostname"));
+    }
+}
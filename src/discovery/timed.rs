@@ -0,0 +1,211 @@
+use super::{CpuTicks, DiskStats, IrqStats, NativeProbe, Probe, SystemIdentity, native_probe};
+use crate::config::{ProbeTimeouts, Sections};
+use crate::model::{
+    BatteryInfo, BoardLayout, CloudProvider, ContainerLimits, CpuLoad, CpuPackage, FanInfo,
+    Filesystem, GpuInfo, GpuProcess, IommuInfo, KernelLimit, Limitation, MemorySlots,
+    MotherboardInfo, NetworkConfig, NetworkInfo, OsDetails, PciDevice, PhysicalDisk, PlatformState,
+    Pressure, RamInfo, RamStick, SpdInfo, TemperatureSensor, ThunderboltDevice, TimeHealth,
+    UsbDevice, VirtualizationSupport,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::mpsc::{self, RecvTimeoutError};
+
+/// The native backend with a time limit on every method. Each call runs on a
+/// worker thread with its own [`NativeProbe`]; past its timeout the worker is
+/// abandoned (a blocking read on a hung device cannot be interrupted), the
+/// section comes back empty and the step is remembered in `timed_out`.
+pub struct TimedProbe<'a> {
+    timeouts: &'a ProbeTimeouts,
+    pub timed_out: RefCell<Vec<String>>,
+}
+
+impl<'a> TimedProbe<'a> {
+    pub fn new(timeouts: &'a ProbeTimeouts) -> Self {
+        Self {
+            timeouts,
+            timed_out: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn run<T: Default + Send + 'static>(
+        &self,
+        step: &str,
+        probe: impl FnOnce(&NativeProbe) -> T + Send + 'static,
+    ) -> T {
+        let Some(limit) = self.timeouts.for_step(step) else {
+            return probe(&native_probe());
+        };
+        let (sender, receiver) = mpsc::channel();
+        let worker = std::thread::spawn(move || {
+            let _ = sender.send(probe(&native_probe()));
+        });
+        match receiver.recv_timeout(limit) {
+            Ok(value) => value,
+            Err(RecvTimeoutError::Timeout) => {
+                self.timed_out.borrow_mut().push(step.to_string());
+                T::default()
+            }
+            // The worker died without sending: pass its panic on
+            Err(RecvTimeoutError::Disconnected) => match worker.join() {
+                Err(panic) => std::panic::resume_unwind(panic),
+                Ok(()) => T::default(),
+            },
+        }
+    }
+}
+
+impl Probe for TimedProbe<'_> {
+    fn system(&self) -> SystemIdentity {
+        self.run("system", |p| p.system())
+    }
+
+    fn os_details(&self) -> Option<OsDetails> {
+        self.run("os_details", |p| p.os_details())
+    }
+
+    fn cpus(&self) -> Vec<CpuPackage> {
+        self.run("cpu", |p| p.cpus())
+    }
+
+    fn memory(&self) -> RamInfo {
+        self.run("ram", |p| p.memory())
+    }
+
+    fn container_limits(&self) -> Option<ContainerLimits> {
+        self.run("container", |p| p.container_limits())
+    }
+
+    fn kernel_limits(&self) -> Vec<KernelLimit> {
+        self.run("limits", |p| p.kernel_limits())
+    }
+
+    fn time_health(&self) -> Option<TimeHealth> {
+        self.run("time", |p| p.time_health())
+    }
+
+    fn ram_sticks(&self) -> Vec<RamStick> {
+        self.run("ram", |p| p.ram_sticks())
+    }
+
+    fn memory_slots(&self) -> Option<MemorySlots> {
+        self.run("ram", |p| p.memory_slots())
+    }
+
+    fn spd_modules(&self) -> Vec<SpdInfo> {
+        self.run("spd", |p| p.spd_modules())
+    }
+
+    fn disks(&self) -> Vec<PhysicalDisk> {
+        self.run("disks", |p| p.disks())
+    }
+
+    fn filesystems(&self) -> Vec<Filesystem> {
+        self.run("filesystems", |p| p.filesystems())
+    }
+
+    fn network(&self) -> Vec<NetworkInfo> {
+        self.run("network", |p| p.network())
+    }
+
+    fn network_config(&self) -> Option<NetworkConfig> {
+        self.run("network_config", |p| p.network_config())
+    }
+
+    fn usb_ids(&self) -> Vec<(u8, u8, u16, u16)> {
+        self.run("usb", |p| p.usb_ids())
+    }
+
+    fn usb_devices(&self) -> Vec<UsbDevice> {
+        self.run("usb", |p| p.usb_devices())
+    }
+
+    fn thunderbolt_devices(&self) -> Vec<ThunderboltDevice> {
+        self.run("thunderbolt", |p| p.thunderbolt_devices())
+    }
+
+    fn pci_devices(&self) -> Vec<PciDevice> {
+        self.run("pci", |p| p.pci_devices())
+    }
+
+    fn iommu(&self) -> Option<IommuInfo> {
+        self.run("iommu", |p| p.iommu())
+    }
+
+    fn motherboard(&self) -> Option<MotherboardInfo> {
+        self.run("motherboard", |p| p.motherboard())
+    }
+
+    fn cloud_provider(&self) -> Option<CloudProvider> {
+        self.run("cloud", |p| p.cloud_provider())
+    }
+
+    fn batteries(&self) -> Vec<BatteryInfo> {
+        self.run("battery", |p| p.batteries())
+    }
+
+    fn platform_state(&self) -> Option<PlatformState> {
+        self.run("platform", |p| p.platform_state())
+    }
+
+    fn board_layout(&self) -> Option<BoardLayout> {
+        self.run("board_layout", |p| p.board_layout())
+    }
+
+    fn temperatures(&self) -> Vec<TemperatureSensor> {
+        self.run("temperatures", |p| p.temperatures())
+    }
+
+    fn fans(&self) -> Vec<FanInfo> {
+        self.run("fans", |p| p.fans())
+    }
+
+    fn gpus(&self) -> Vec<GpuInfo> {
+        self.run("gpus", |p| p.gpus())
+    }
+
+    fn gpu_processes(&self) -> Vec<GpuProcess> {
+        self.run("gpu_processes", |p| p.gpu_processes())
+    }
+
+    fn cpu_flags(&self) -> Vec<String> {
+        self.run("cpu_flags", |p| p.cpu_flags())
+    }
+
+    fn virtualization(&self) -> Option<VirtualizationSupport> {
+        self.run("virtualization", |p| p.virtualization())
+    }
+
+    fn pressure(&self) -> Option<Pressure> {
+        self.run("pressure", |p| p.pressure())
+    }
+
+    fn load_average(&self) -> Option<[f64; 3]> {
+        self.run("cpu_load", |p| p.load_average())
+    }
+
+    fn cpu_ticks(&self) -> Option<CpuTicks> {
+        self.run("cpu_load", |p| p.cpu_ticks())
+    }
+
+    fn cpu_load(&self) -> Option<CpuLoad> {
+        self.run("cpu_load", |p| p.cpu_load())
+    }
+
+    fn throttle_count(&self) -> Option<u64> {
+        self.run("throttle_count", |p| p.throttle_count())
+    }
+
+    fn interrupts(&self) -> HashMap<String, IrqStats> {
+        self.run("irqs", |p| p.interrupts())
+    }
+
+    fn diskstats(&self) -> HashMap<String, DiskStats> {
+        self.run("disk_io", |p| p.diskstats())
+    }
+
+    fn limitations(&self, sections: &Sections) -> Vec<Limitation> {
+        let sections = sections.clone();
+        self.run("limitations", move |p| p.limitations(&sections))
+    }
+}
@@ -0,0 +1,204 @@
+use crate::alerts::{AlertLevel, AlertSink, EmailConfig, SmtpSecurity, ThresholdEvent};
+use crate::i18n::t;
+use crate::model::MetricsSample;
+use anyhow::{Context, Result};
+use comfy_table::Table;
+use comfy_table::presets::ASCII_FULL;
+use lettre::message::Mailbox;
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{Message, SmtpTransport, Transport};
+use std::fmt::Write as _;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Emails a summary of every warning/critical breach, with the matching
+/// sections of the sample, to the configured recipients.
+pub struct EmailSink {
+    mailer: SmtpTransport,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+}
+
+impl EmailSink {
+    pub fn new(config: &EmailConfig) -> Result<Self> {
+        let mut builder = match config.security {
+            SmtpSecurity::Starttls => SmtpTransport::starttls_relay(&config.server)?,
+            SmtpSecurity::Tls => SmtpTransport::relay(&config.server)?,
+            SmtpSecurity::None => SmtpTransport::builder_dangerous(&config.server),
+        };
+        if let Some(port) = config.port {
+            builder = builder.port(port);
+        }
+        if let (Ok(username), Ok(password)) = (
+            std::env::var(&config.username_env),
+            std::env::var(&config.password_env),
+        ) {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        let from = config
+            .from
+            .parse()
+            .with_context(|| format!("invalid `alerts.email.from` address {}", config.from))?;
+        let to = config
+            .to
+            .iter()
+            .map(|addr| {
+                addr.parse()
+                    .with_context(|| format!("invalid `alerts.email.to` address {}", addr))
+            })
+            .collect::<Result<Vec<Mailbox>>>()?;
+        if to.is_empty() {
+            anyhow::bail!("`alerts.email.to` needs at least one recipient");
+        }
+        Ok(Self {
+            mailer: builder.timeout(Some(TIMEOUT)).build(),
+            from,
+            to,
+        })
+    }
+}
+
+impl AlertSink for EmailSink {
+    fn send(&mut self, events: &[ThresholdEvent], sample: &MetricsSample) -> Result<()> {
+        let breaches: Vec<&ThresholdEvent> = events
+            .iter()
+            .filter(|e| e.level != AlertLevel::Ok)
+            .collect();
+        let Some(first) = breaches.first() else {
+            return Ok(());
+        };
+
+        let subject = if breaches.len() == 1 {
+            format!("[hw-checker] {}: {}", first.hostname, first.message())
+        } else {
+            format!(
+                "[hw-checker] {}: {} {}",
+                first.hostname,
+                breaches.len(),
+                t("threshold breaches")
+            )
+        };
+        let mut message = Message::builder().from(self.from.clone());
+        for to in &self.to {
+            message = message.to(to.clone());
+        }
+        let message = message
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(render_body(&breaches, sample))?;
+        self.mailer.send(&message)?;
+        Ok(())
+    }
+}
+
+fn render_body(breaches: &[&ThresholdEvent], sample: &MetricsSample) -> String {
+    let mut body = String::new();
+    let _ = writeln!(
+        body,
+        "{} {} ({}):\n",
+        t("Threshold breaches on"),
+        breaches[0].hostname,
+        sample.timestamp
+    );
+    for event in breaches {
+        let level = match event.level {
+            AlertLevel::Critical => t("CRITICAL"),
+            _ => t("WARNING"),
+        };
+        let _ = writeln!(body, "  {:<8}  {}", level, event.message());
+    }
+
+    // One report section per kind of metric that was breached, in the order they appear.
+    let mut kinds: Vec<&str> = Vec::new();
+    for event in breaches {
+        let kind = match event.metric.split(':').next().unwrap_or_default() {
+            "swap" => "ram",
+            kind => kind,
+        };
+        if !kinds.contains(&kind) {
+            kinds.push(kind);
+        }
+    }
+    for kind in kinds {
+        let (title, table) = match kind {
+            "cpu" => (t("CPU Information"), cpu_table(sample)),
+            "ram" => (t("RAM Information & Usage"), ram_table(sample)),
+            "disk" => (t("Storage Information"), storage_table(sample)),
+            "temp" => (t("Temperatures"), temperature_table(sample)),
+            _ => continue,
+        };
+        let _ = writeln!(body, "\n{}\n{}", title, table);
+    }
+    body
+}
+
+fn new_table(header: Vec<&str>) -> Table {
+    let mut table = Table::new();
+    table.load_preset(ASCII_FULL).set_header(header);
+    table
+}
+
+fn percent(used: u64, total: u64) -> String {
+    if total == 0 {
+        "N/A".to_string()
+    } else {
+        format!("{:.1}", used as f64 / total as f64 * 100.0)
+    }
+}
+
+fn cpu_table(sample: &MetricsSample) -> Table {
+    let mut table = new_table(vec![t("Usage (%)")]);
+    table.add_row(vec![format!("{:.1}", sample.cpu_usage)]);
+    table
+}
+
+fn ram_table(sample: &MetricsSample) -> Table {
+    let mut table = new_table(vec![
+        t("Component"),
+        t("Total (MiB)"),
+        t("Used (MiB)"),
+        t("Usage (%)"),
+    ]);
+    table.add_row(vec![
+        t("Main Memory").to_string(),
+        (sample.ram_total / 1024 / 1024).to_string(),
+        (sample.ram_used / 1024 / 1024).to_string(),
+        percent(sample.ram_used, sample.ram_total),
+    ]);
+    table.add_row(vec![
+        t("Swap").to_string(),
+        (sample.swap_total / 1024 / 1024).to_string(),
+        (sample.swap_used / 1024 / 1024).to_string(),
+        percent(sample.swap_used, sample.swap_total),
+    ]);
+    table
+}
+
+fn storage_table(sample: &MetricsSample) -> Table {
+    let mut table = new_table(vec![
+        t("Mount"),
+        t("Total (GiB)"),
+        t("Used (GiB)"),
+        t("Usage (%)"),
+    ]);
+    for disk in &sample.disks {
+        table.add_row(vec![
+            disk.mount_point.clone(),
+            format!("{:.1}", disk.total as f64 / 1024.0 / 1024.0 / 1024.0),
+            format!("{:.1}", disk.used as f64 / 1024.0 / 1024.0 / 1024.0),
+            percent(disk.used, disk.total),
+        ]);
+    }
+    table
+}
+
+fn temperature_table(sample: &MetricsSample) -> Table {
+    let mut table = new_table(vec![t("Sensor"), t("Temperature")]);
+    for temp in &sample.temperatures {
+        table.add_row(vec![temp.label.clone(), format!("{:.1} °C", temp.celsius)]);
+    }
+    table
+}
@@ -0,0 +1,186 @@
+//! `--format env`: `HW_*="value"` lines for provisioning scripts to
+//! `eval "$(hw-checker --format env --cpu --ram)"`. Sizes are in bytes,
+//! lists are numbered from 0 with a `_COUNT` variable, and only collected
+//! sections produce variables, so the section flags pick what gets set.
+
+use crate::formatter::custom_rows;
+use crate::model::HardwareReport;
+use std::fmt::Write as _;
+
+pub fn render_env(report: &HardwareReport) -> String {
+    let mut vars: Vec<(String, String)> = Vec::new();
+    let mut set = |name: &str, value: String| vars.push((format!("HW_{}", name), value));
+
+    set("HOSTNAME", report.hostname.clone());
+    set("OS_NAME", report.os_name.clone());
+    set("OS_VERSION", report.os_version.clone());
+    set("KERNEL", report.kernel_version.clone());
+    set("UPTIME", report.uptime.to_string());
+    if let Some(details) = &report.os_details {
+        set("ARCH", details.architecture.clone());
+    }
+
+    if let Some(package) = report.cpu.first() {
+        set("CPU_MODEL", package.model.trim().to_string());
+        set("CPU_VENDOR", package.vendor.clone());
+        set("CPU_SOCKETS", report.cpu.len().to_string());
+        let cores: usize = report.cpu.iter().map(|p| p.physical_cores).sum();
+        let threads: usize = report.cpu.iter().map(|p| p.logical_cores).sum();
+        set("CPU_CORES", cores.to_string());
+        set("CPU_THREADS", threads.to_string());
+    }
+
+    let ram = &report.ram;
+    if ram.total > 0 {
+        set("RAM_TOTAL", ram.total.to_string());
+        set("RAM_USED", ram.used.to_string());
+        set("RAM_FREE", ram.free.to_string());
+        set("SWAP_TOTAL", ram.swap_total.to_string());
+        set("RAM_MODULES", ram.sticks.len().to_string());
+        if let Some(slots) = &ram.slots {
+            set("RAM_SLOTS", slots.total.to_string());
+            set("RAM_SLOTS_USED", slots.populated.to_string());
+            if let Some(max) = slots.max_capacity {
+                set("RAM_MAX", max.to_string());
+            }
+        }
+    }
+
+    if !report.disks.is_empty() {
+        set("DISK_COUNT", report.disks.len().to_string());
+        let total: u64 = report.disks.iter().map(|d| d.size).sum();
+        set("DISK_TOTAL", total.to_string());
+        for (i, disk) in report.disks.iter().enumerate() {
+            set(&format!("DISK_{}_NAME", i), disk.name.clone());
+            set(&format!("DISK_{}_SIZE", i), disk.size.to_string());
+            set(
+                &format!("DISK_{}_MODEL", i),
+                disk.model_name.clone().unwrap_or_default(),
+            );
+            set(
+                &format!("DISK_{}_TYPE", i),
+                disk.disk_type.clone().unwrap_or_default(),
+            );
+        }
+    }
+
+    let interfaces: Vec<_> = report.network.iter().filter(|n| n.name != "lo").collect();
+    if !interfaces.is_empty() {
+        set("NET_COUNT", interfaces.len().to_string());
+        for (i, interface) in interfaces.iter().enumerate() {
+            set(&format!("NET_{}_NAME", i), interface.name.clone());
+            set(&format!("NET_{}_MAC", i), interface.mac_address.clone());
+        }
+    }
+
+    if !report.gpus.is_empty() {
+        set("GPU_COUNT", report.gpus.len().to_string());
+        for (i, gpu) in report.gpus.iter().enumerate() {
+            set(&format!("GPU_{}_NAME", i), gpu.name.clone());
+            set(
+                &format!("GPU_{}_DRIVER", i),
+                gpu.driver.clone().unwrap_or_default(),
+            );
+        }
+    }
+
+    if !report.usb.is_empty() {
+        set("USB_COUNT", report.usb.len().to_string());
+    }
+    if !report.pci.is_empty() {
+        set("PCI_COUNT", report.pci.len().to_string());
+    }
+
+    if let Some(board) = &report.motherboard {
+        set("BOARD_VENDOR", board.vendor.clone());
+        set("BOARD_PRODUCT", board.product.clone());
+        set("BIOS_VENDOR", board.bios_vendor.clone());
+        set("BIOS_VERSION", board.bios_version.clone());
+        set("BIOS_DATE", board.bios_date.clone());
+    }
+
+    if !report.battery.is_empty() {
+        set("BATTERY_COUNT", report.battery.len().to_string());
+        for (i, battery) in report.battery.iter().enumerate() {
+            set(
+                &format!("BATTERY_{}_CAPACITY", i),
+                battery.capacity.to_string(),
+            );
+            set(&format!("BATTERY_{}_STATUS", i), battery.status.clone());
+        }
+    }
+
+    if let Some(time) = &report.time {
+        set("CLOCKSOURCE", time.clocksource.clone().unwrap_or_default());
+        if let Some(synced) = time.ntp_synchronized {
+            set("NTP_SYNCHRONIZED", u8::from(synced).to_string());
+        }
+    }
+
+    if let Some(tag) = &report.asset.asset_tag {
+        set("ASSET_TAG", tag.clone());
+    }
+    if let Some(location) = &report.asset.location {
+        set("ASSET_LOCATION", location.clone());
+    }
+    if let Some(owner) = &report.asset.owner {
+        set("ASSET_OWNER", owner.clone());
+    }
+
+    // HW_CUSTOM_<NAME>_<PATH>, e.g. HW_CUSTOM_RAID_ARRAYS_0_STATE
+    for row in custom_rows(&report.custom) {
+        let name: String = format!("CUSTOM_{}_{}", row[0], row[1])
+            .trim_end_matches('_')
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        set(&name, row[2].clone());
+    }
+
+    let mut out = String::new();
+    for (name, value) in vars {
+        let _ = writeln!(out, "{}={}", name, quote(&value));
+    }
+    out.truncate(out.trim_end().len());
+    out
+}
+
+/// Double-quotes a value for POSIX shells: `\`, `"`, `$` and backticks are
+/// escaped so `eval` never expands anything, and line breaks become spaces.
+fn quote(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' | '"' | '$' | '`' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' | '\r' => out.push(' '),
+            c if c.is_control() => {}
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_cannot_expand() {
+        assert_eq!(quote("Intel(R) Xeon(R)"), "\"Intel(R) Xeon(R)\"");
+        assert_eq!(
+            quote("$(rm -rf ~) `id` \"x\\\"\n"),
+            "\"\\$(rm -rf ~) \\`id\\` \\\"x\\\\\\\" \""
+        );
+    }
+}
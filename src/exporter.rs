@@ -1,18 +1,765 @@
 use crate::cli::OutputFormat;
+use crate::env::render_env;
+use crate::formatter::{
+    cache_cell, cloud_row, custom_rows, memory_settings_rows, os_details_rows, time_rows,
+};
+use crate::i18n::t;
+use crate::influx::render_influx;
+use crate::inxi::render_inxi;
+use crate::lshw::render_lshw;
 use crate::model::HardwareReport;
-use anyhow::Result;
+use crate::pdf::render_pdf;
+use crate::summary::render_summary;
+use crate::xml::render_xml;
+use crate::zabbix::{render_kv, render_lld};
+use anyhow::{Result, bail};
+use std::fmt::Write as _;
+use std::io::{IsTerminal, Write as _};
+use std::path::Path;
 
 pub fn export_report(report: &HardwareReport, format: OutputFormat) -> Result<()> {
-    match format {
-        OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(report)?;
-            println!("{json}");
+    if is_binary(format) {
+        let mut stdout = std::io::stdout();
+        if stdout.is_terminal() {
+            bail!(
+                "{} output is binary; redirect it to a file",
+                format!("{:?}", format).to_uppercase()
+            );
         }
-        OutputFormat::Yaml => {
-            let yaml = serde_yaml::to_string(report)?;
-            println!("{yaml}");
+        stdout.write_all(&render_bytes(report, format)?)?;
+        return Ok(());
+    }
+    let rendered = render_report(report, format)?;
+    println!("{rendered}");
+    Ok(())
+}
+
+pub fn is_binary(format: OutputFormat) -> bool {
+    matches!(
+        format,
+        OutputFormat::Msgpack | OutputFormat::Cbor | OutputFormat::Pdf
+    )
+}
+
+/// Like `render_report`, but also covers the binary formats.
+pub fn render_bytes(report: &HardwareReport, format: OutputFormat) -> Result<Vec<u8>> {
+    match format {
+        // Field names rather than positions, so skipped optional fields and
+        // older readers still line up
+        OutputFormat::Msgpack => Ok(rmp_serde::to_vec_named(report)?),
+        OutputFormat::Cbor => {
+            let mut out = Vec::new();
+            ciborium::into_writer(report, &mut out)?;
+            Ok(out)
         }
+        OutputFormat::Pdf => render_pdf(report),
+        _ => Ok(render_report(report, format)?.into_bytes()),
+    }
+}
+
+pub fn render_report(report: &HardwareReport, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Json => Ok(serde_json::to_string_pretty(report)?),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(report)?),
+        OutputFormat::Html => Ok(render_html(report)),
+        OutputFormat::Influx => Ok(render_influx(report)),
+        OutputFormat::Kv => Ok(render_kv(report)),
+        OutputFormat::Env => Ok(render_env(report)),
+        OutputFormat::ZabbixLld => Ok(render_lld(report)),
+        OutputFormat::Xml => render_xml(report),
+        OutputFormat::LshwJson => render_lshw(report),
+        OutputFormat::Inxi => Ok(render_inxi(report)),
+        OutputFormat::Summary => Ok(render_summary(report, false)),
         OutputFormat::Table => unreachable!("Table format should be handled by formatter"),
+        OutputFormat::Msgpack | OutputFormat::Cbor | OutputFormat::Pdf => {
+            unreachable!("binary formats go through render_bytes")
+        }
+    }
+}
+
+pub fn format_for_path(path: &Path) -> Option<OutputFormat> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    match ext.as_str() {
+        "json" => Some(OutputFormat::Json),
+        "yaml" | "yml" => Some(OutputFormat::Yaml),
+        "html" | "htm" => Some(OutputFormat::Html),
+        "xml" => Some(OutputFormat::Xml),
+        "msgpack" | "mpk" => Some(OutputFormat::Msgpack),
+        "cbor" => Some(OutputFormat::Cbor),
+        "pdf" => Some(OutputFormat::Pdf),
+        _ => None,
+    }
+}
+
+/// Writes the report to `path`, picking the format from the file extension.
+pub fn save_report(report: &HardwareReport, path: &Path) -> Result<OutputFormat> {
+    let Some(format) = format_for_path(path) else {
+        bail!(
+            "unsupported file extension for {} (use .json, .yaml, .html, .xml, .msgpack, .cbor or .pdf)",
+            path.display()
+        );
+    };
+    std::fs::write(path, render_bytes(report, format)?)?;
+    Ok(format)
+}
+
+pub fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Opens an HTML document with the shared report stylesheet, up to `<body>`.
+pub fn html_header(out: &mut String, title: &str) {
+    let _ = writeln!(
+        out,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>",
+        escape_html(title)
+    );
+    out.push_str(
+        "<style>\n\
+         body { font-family: sans-serif; margin: 2em; color: #222; }\n\
+         h1 { color: #0a6b8a; }\n\
+         h2 { color: #0a6b8a; border-bottom: 1px solid #ccc; padding-bottom: 4px; }\n\
+         table { border-collapse: collapse; margin-bottom: 1.5em; }\n\
+         th, td { border: 1px solid #ccc; padding: 4px 10px; text-align: left; }\n\
+         th { background: #eef5f8; }\n\
+         </style>\n</head>\n<body>\n",
+    );
+}
+
+pub fn html_table(out: &mut String, title: &str, headers: &[&str], rows: &[Vec<String>]) {
+    if rows.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "<h2>{}</h2>\n<table>\n<tr>", escape_html(title));
+    for h in headers {
+        let _ = write!(out, "<th>{}</th>", escape_html(h));
+    }
+    out.push_str("</tr>\n");
+    for row in rows {
+        out.push_str("<tr>");
+        for cell in row {
+            let _ = write!(out, "<td>{}</td>", escape_html(cell));
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</table>\n");
+}
+
+/// A titled table of the report, shared by the HTML and PDF exports.
+pub struct ReportTable {
+    pub title: &'static str,
+    pub headers: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+}
+
+fn push_table(
+    tables: &mut Vec<ReportTable>,
+    title: &'static str,
+    headers: &[&'static str],
+    rows: &[Vec<String>],
+) {
+    if !rows.is_empty() {
+        tables.push(ReportTable {
+            title,
+            headers: headers.to_vec(),
+            rows: rows.to_vec(),
+        });
+    }
+}
+
+fn render_html(report: &HardwareReport) -> String {
+    let mut out = String::new();
+    html_header(&mut out, &format!("hw-checker report: {}", report.hostname));
+    let _ = writeln!(out, "<h1>{}</h1>", escape_html(&report.hostname));
+    for table in report_tables(report) {
+        html_table(&mut out, table.title, &table.headers, &table.rows);
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// Every section of the report as a table, in display order.
+pub fn report_tables(report: &HardwareReport) -> Vec<ReportTable> {
+    let opt = |v: &Option<String>| v.clone().unwrap_or_else(|| "N/A".to_string());
+    let mut tables = Vec::new();
+
+    push_table(
+        &mut tables,
+        t("System Summary"),
+        &[t("Hostname"), t("OS"), t("Kernel"), t("Uptime")],
+        &[vec![
+            report.hostname.clone(),
+            format!("{} {}", report.os_name, report.os_version),
+            report.kernel_version.clone(),
+            format!("{}s", report.uptime),
+        ]],
+    );
+    if let Some(details) = &report.os_details {
+        let rows: Vec<Vec<String>> = os_details_rows(details)
+            .into_iter()
+            .map(|(label, value)| vec![label, value])
+            .collect();
+        push_table(
+            &mut tables,
+            t("OS Details"),
+            &[t("OS Details"), t("Value")],
+            &rows,
+        );
+    }
+    let asset_rows: Vec<Vec<String>> = report
+        .asset
+        .fields()
+        .into_iter()
+        .map(|(label, value)| vec![label, value])
+        .collect();
+    push_table(
+        &mut tables,
+        t("Asset"),
+        &[t("Asset"), t("Value")],
+        &asset_rows,
+    );
+    if let Some(cloud) = &report.cloud {
+        push_table(
+            &mut tables,
+            t("Cloud"),
+            &[
+                t("Cloud"),
+                t("Instance Type"),
+                t("Region"),
+                t("Zone"),
+                t("Instance ID"),
+            ],
+            &[cloud_row(cloud)],
+        );
+    }
+
+    let package_rows: Vec<Vec<String>> = report
+        .cpu
+        .iter()
+        .enumerate()
+        .map(|(i, package)| {
+            vec![
+                i.to_string(),
+                package.model.clone(),
+                package.vendor.clone(),
+                package.physical_cores.to_string(),
+                package.logical_cores.to_string(),
+                cache_cell(package, 1),
+                cache_cell(package, 2),
+                cache_cell(package, 3),
+            ]
+        })
+        .collect();
+    push_table(
+        &mut tables,
+        t("CPU Information"),
+        &[
+            t("Socket"),
+            t("Model"),
+            t("Vendor"),
+            t("Cores"),
+            t("Threads"),
+            t("L1 Cache"),
+            t("L2 Cache"),
+            t("L3 Cache"),
+        ],
+        &package_rows,
+    );
+    let core_rows: Vec<Vec<String>> = report
+        .cores()
+        .map(|core| {
+            vec![
+                core.id.to_string(),
+                core.frequency.to_string(),
+                format!("{:.1}", core.usage),
+            ]
+        })
+        .collect();
+    push_table(
+        &mut tables,
+        t("Cores"),
+        &[t("Core"), t("Frequency (MHz)"), t("Usage (%)")],
+        &core_rows,
+    );
+
+    let ram = &report.ram;
+    push_table(
+        &mut tables,
+        t("RAM Information & Usage"),
+        &[
+            t("Component"),
+            t("Total (MiB)"),
+            t("Used (MiB)"),
+            t("Free (MiB)"),
+        ],
+        &[
+            vec![
+                t("Main Memory").to_string(),
+                (ram.total / 1024 / 1024).to_string(),
+                (ram.used / 1024 / 1024).to_string(),
+                (ram.free / 1024 / 1024).to_string(),
+            ],
+            vec![
+                t("Swap").to_string(),
+                (ram.swap_total / 1024 / 1024).to_string(),
+                (ram.swap_used / 1024 / 1024).to_string(),
+                (ram.swap_total.saturating_sub(ram.swap_used) / 1024 / 1024).to_string(),
+            ],
+        ],
+    );
+    if let Some(settings) = &ram.settings {
+        let rows: Vec<Vec<String>> = memory_settings_rows(settings)
+            .into_iter()
+            .map(|(label, value)| vec![label, value])
+            .collect();
+        push_table(
+            &mut tables,
+            t("Memory Settings"),
+            &[t("Setting"), t("Value")],
+            &rows,
+        );
+    }
+    if let Some(limits) = &report.container {
+        let mib = |bytes: u64| (bytes / 1024 / 1024).to_string();
+        push_table(
+            &mut tables,
+            t("Container Limits"),
+            &[t("Component"), t("Host"), t("Limit"), t("Used")],
+            &[
+                vec![
+                    t("CPUs").to_string(),
+                    report.cores().count().to_string(),
+                    limits
+                        .effective_cpus()
+                        .map_or(t("Unlimited").to_string(), |cpus| format!("{:.2}", cpus)),
+                    "N/A".to_string(),
+                ],
+                vec![
+                    t("Memory (MiB)").to_string(),
+                    mib(ram.total),
+                    limits.memory_limit.map_or(t("Unlimited").to_string(), mib),
+                    limits.memory_used.map_or("N/A".to_string(), mib),
+                ],
+            ],
+        );
+    }
+    let stick_rows: Vec<Vec<String>> = ram
+        .sticks
+        .iter()
+        .enumerate()
+        .map(|(i, stick)| {
+            vec![
+                format!("DIMM {}", i),
+                opt(&stick.manufacturer),
+                opt(&stick.part_number),
+                opt(&stick.serial_number),
+                stick
+                    .speed
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| "N/A".to_string()),
+            ]
+        })
+        .collect();
+    push_table(
+        &mut tables,
+        t("Physical RAM Sticks"),
+        &[
+            t("Slot"),
+            t("Manufacturer"),
+            t("Part Number"),
+            t("Serial Number"),
+            t("Speed (MT/s)"),
+        ],
+        &stick_rows,
+    );
+
+    let disk_rows: Vec<Vec<String>> = report
+        .disks
+        .iter()
+        .map(|disk| {
+            vec![
+                disk.name.clone(),
+                opt(&disk.model_name),
+                opt(&disk.serial_number),
+                opt(&disk.disk_type),
+                opt(&disk.interface_label()),
+                format!("{:.1}", disk.size as f64 / 1024.0 / 1024.0 / 1024.0),
+                match &disk.smart {
+                    Some(smart) if smart.passed => t("Passed").to_string(),
+                    Some(_) => t("Failed").to_string(),
+                    None => "N/A".to_string(),
+                },
+            ]
+        })
+        .collect();
+    push_table(
+        &mut tables,
+        t("Storage Information"),
+        &[
+            t("Disk"),
+            t("Model"),
+            t("Serial Number"),
+            t("Type"),
+            t("Interface"),
+            t("Size (GiB)"),
+            "SMART",
+        ],
+        &disk_rows,
+    );
+    let filesystem_rows: Vec<Vec<String>> = report
+        .filesystems
+        .iter()
+        .map(|fs| {
+            vec![
+                fs.name.clone(),
+                fs.mount_point.clone(),
+                fs.filesystem.clone(),
+                opt(&fs.disk),
+                format!("{:.1}", fs.total as f64 / 1024.0 / 1024.0 / 1024.0),
+                format!("{:.1}", fs.used as f64 / 1024.0 / 1024.0 / 1024.0),
+                fs.inodes
+                    .and_then(|inodes| inodes.usage_percent())
+                    .map_or("N/A".to_string(), |usage| format!("{:.1}", usage)),
+            ]
+        })
+        .collect();
+    push_table(
+        &mut tables,
+        t("Filesystems"),
+        &[
+            t("Name"),
+            t("Mount"),
+            "FS",
+            t("Disk"),
+            t("Total (GiB)"),
+            t("Used (GiB)"),
+            t("Inodes (%)"),
+        ],
+        &filesystem_rows,
+    );
+
+    let network_rows: Vec<Vec<String>> = report
+        .network
+        .iter()
+        .map(|net| {
+            vec![
+                net.name.clone(),
+                net.mac_address.clone(),
+                format!("{:.2}", net.received as f64 / 1024.0 / 1024.0),
+                format!("{:.2}", net.transmitted as f64 / 1024.0 / 1024.0),
+            ]
+        })
+        .collect();
+    push_table(
+        &mut tables,
+        t("Network Interfaces"),
+        &[
+            t("Interface"),
+            "MAC",
+            t("Received (MiB)"),
+            t("Transmitted (MiB)"),
+        ],
+        &network_rows,
+    );
+
+    if let Some(config) = &report.network_config {
+        let gateway = |gateway: &Option<crate::model::Gateway>| match gateway {
+            Some(gateway) => format!("{} ({})", gateway.address, gateway.interface),
+            None => "N/A".to_string(),
+        };
+        push_table(
+            &mut tables,
+            t("Network Configuration"),
+            &[t("Component"), t("Information")],
+            &[
+                vec![
+                    t("Default gateway (IPv4)").to_string(),
+                    gateway(&config.gateway_v4),
+                ],
+                vec![
+                    t("Default gateway (IPv6)").to_string(),
+                    gateway(&config.gateway_v6),
+                ],
+                vec![t("DNS servers").to_string(), config.dns_servers.join(", ")],
+                vec![
+                    t("Primary interface").to_string(),
+                    opt(&config.primary_interface),
+                ],
+            ],
+        );
+    }
+
+    if let Some(connectivity) = &report.connectivity {
+        let latency = |ms: Option<f64>| ms.map_or("N/A".to_string(), |ms| format!("{:.1}", ms));
+        let mut rows: Vec<Vec<String>> = connectivity
+            .gateway
+            .iter()
+            .map(|ping| (t("Gateway"), ping))
+            .chain([(t("Target"), &connectivity.target)])
+            .map(|(check, ping)| {
+                vec![
+                    check.to_string(),
+                    ping.host.clone(),
+                    match &ping.error {
+                        Some(_) => t("ping not available").to_string(),
+                        None => format!("{}/{}", ping.received, ping.sent),
+                    },
+                    latency(ping.avg_latency_ms),
+                    match &ping.error {
+                        Some(_) => "-".to_string(),
+                        None => format!("{:.0}", ping.loss_percent),
+                    },
+                ]
+            })
+            .collect();
+        rows.push(vec![
+            "DNS".to_string(),
+            connectivity.dns.name.clone(),
+            match connectivity.dns.resolved {
+                true => t("Resolved").to_string(),
+                false => t("Failed").to_string(),
+            },
+            latency(connectivity.dns.latency_ms),
+            "-".to_string(),
+        ]);
+        push_table(
+            &mut tables,
+            t("Connectivity"),
+            &[
+                t("Check"),
+                t("Host"),
+                t("Result"),
+                t("Latency (ms)"),
+                t("Loss (%)"),
+            ],
+            &rows,
+        );
+    }
+
+    let usb_rows: Vec<Vec<String>> = report
+        .usb
+        .iter()
+        .map(|dev| {
+            vec![
+                format!("{:03}/{:03}", dev.bus, dev.address),
+                format!("{:04x}:{:04x}", dev.vendor_id, dev.product_id),
+                opt(&dev.manufacturer),
+                opt(&dev.product),
+            ]
+        })
+        .collect();
+    push_table(
+        &mut tables,
+        t("USB Devices"),
+        &[t("Bus/Addr"), "ID", t("Manufacturer"), t("Product")],
+        &usb_rows,
+    );
+
+    let thunderbolt_rows: Vec<Vec<String>> = report
+        .thunderbolt
+        .iter()
+        .map(|dev| vec![dev.name.clone(), opt(&dev.vendor), opt(&dev.speed)])
+        .collect();
+    push_table(
+        &mut tables,
+        t("Thunderbolt Devices"),
+        &[t("Device"), t("Vendor"), t("Speed")],
+        &thunderbolt_rows,
+    );
+
+    let limit_rows: Vec<Vec<String>> = report
+        .limits
+        .iter()
+        .map(|limit| {
+            vec![
+                limit.name.clone(),
+                limit
+                    .used
+                    .map_or("N/A".to_string(), |used| used.to_string()),
+                limit.limit.to_string(),
+                limit
+                    .usage_percent()
+                    .map_or("N/A".to_string(), |usage| format!("{:.1}", usage)),
+            ]
+        })
+        .collect();
+    push_table(
+        &mut tables,
+        t("Kernel Limits"),
+        &[t("Limit"), t("Used"), t("Max"), t("Usage (%)")],
+        &limit_rows,
+    );
+
+    if let Some(time) = &report.time {
+        let rows: Vec<Vec<String>> = time_rows(time)
+            .into_iter()
+            .map(|(label, value)| vec![label, value])
+            .collect();
+        push_table(&mut tables, t("Time"), &[t("Setting"), t("Value")], &rows);
+    }
+
+    let pci_rows: Vec<Vec<String>> = report
+        .pci
+        .iter()
+        .map(|dev| {
+            vec![
+                dev.slot.clone(),
+                format!("{:04x}:{:04x}", dev.vendor_id, dev.device_id),
+                opt(&dev.vendor_name),
+                opt(&dev.device_name),
+            ]
+        })
+        .collect();
+    push_table(
+        &mut tables,
+        t("PCI Devices"),
+        &[t("Slot"), "ID", t("Vendor"), t("Device")],
+        &pci_rows,
+    );
+
+    if !report.gpus.is_empty() {
+        let mib =
+            |bytes: Option<u64>| bytes.map_or("N/A".to_string(), |b| (b / 1024 / 1024).to_string());
+        let gpu_rows: Vec<Vec<String>> = report
+            .gpus
+            .iter()
+            .map(|gpu| {
+                vec![
+                    gpu.slot.clone(),
+                    gpu.name.clone(),
+                    opt(&gpu.driver),
+                    mib(gpu.vram_total),
+                ]
+            })
+            .collect();
+        push_table(
+            &mut tables,
+            t("GPUs"),
+            &[t("Slot"), t("Name"), t("Driver"), t("VRAM (MiB)")],
+            &gpu_rows,
+        );
+        if !report.gpu_processes.is_empty() {
+            let process_rows: Vec<Vec<String>> = report
+                .gpu_processes
+                .iter()
+                .map(|p| {
+                    vec![
+                        p.slot.clone(),
+                        p.pid.to_string(),
+                        p.name.clone(),
+                        mib(p.vram_used),
+                    ]
+                })
+                .collect();
+            push_table(
+                &mut tables,
+                t("GPU Processes"),
+                &[t("Slot"), "PID", t("Name"), t("VRAM (MiB)")],
+                &process_rows,
+            );
+        }
+    }
+
+    if let Some(mb) = &report.motherboard {
+        push_table(
+            &mut tables,
+            t("Motherboard & BIOS"),
+            &[t("Component"), t("Information")],
+            &[
+                vec![t("Manufacturer").to_string(), mb.vendor.clone()],
+                vec![t("Product").to_string(), mb.product.clone()],
+                vec![t("BIOS Vendor").to_string(), mb.bios_vendor.clone()],
+                vec![t("BIOS Version").to_string(), mb.bios_version.clone()],
+                vec![t("BIOS Date").to_string(), mb.bios_date.clone()],
+            ],
+        );
+    }
+
+    let battery_rows: Vec<Vec<String>> = report
+        .battery
+        .iter()
+        .map(|bat| {
+            vec![
+                bat.name.clone(),
+                bat.status.clone(),
+                bat.capacity.to_string(),
+                bat.cycle_count
+                    .map(|c| c.to_string())
+                    .unwrap_or_else(|| "N/A".to_string()),
+            ]
+        })
+        .collect();
+    push_table(
+        &mut tables,
+        t("Battery Status"),
+        &[t("Battery"), t("Status"), t("Capacity (%)"), t("Cycles")],
+        &battery_rows,
+    );
+
+    let temp_rows: Vec<Vec<String>> = report
+        .temperatures
+        .iter()
+        .map(|s| {
+            vec![
+                s.label.clone(),
+                s.temperature
+                    .map(|c| format!("{:.1}", c))
+                    .unwrap_or_else(|| "N/A".to_string()),
+            ]
+        })
+        .collect();
+    push_table(
+        &mut tables,
+        t("Temperatures"),
+        &[t("Sensor"), t("Temperature")],
+        &temp_rows,
+    );
+    push_table(
+        &mut tables,
+        t("Custom"),
+        &[t("Name"), t("Field"), t("Value")],
+        &custom_rows(&report.custom),
+    );
+
+    tables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> HardwareReport {
+        serde_json::from_str(
+            r#"{"os_name": "Debian GNU/Linux", "os_version": "12", "kernel_version": "6.1.0",
+                "hostname": "db-1", "uptime": 3600,
+                "cpu_packages": [{"model": "EPYC 7313", "vendor": "AuthenticAMD",
+                    "physical_cores": 16, "logical_cores": 32, "cores": []}],
+                "ram": {"total": 68719476736, "used": 8589934592, "free": 60129542144,
+                    "swap_total": 0, "swap_used": 0, "sticks": []},
+                "filesystems": [{"name": "/dev/nvme0n1p2", "mount_point": "/",
+                    "total": 512000000000, "used": 256000000000, "free": 256000000000,
+                    "filesystem": "ext4", "inodes": {"total": 100, "used": 5, "free": 95}}],
+                "network": [], "usb": [], "pci": [], "motherboard": null, "battery": []}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn binary_formats_round_trip() {
+        let report = report();
+        let json = serde_json::to_value(&report).unwrap();
+
+        let msgpack = render_bytes(&report, OutputFormat::Msgpack).unwrap();
+        let decoded: HardwareReport = rmp_serde::from_slice(&msgpack).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), json);
+
+        let cbor = render_bytes(&report, OutputFormat::Cbor).unwrap();
+        let decoded: HardwareReport = ciborium::from_reader(cbor.as_slice()).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), json);
+
+        assert!(msgpack.len() < serde_json::to_vec(&report).unwrap().len());
     }
-    Ok(())
 }
@@ -0,0 +1,437 @@
+use crate::cli::FleetArgs;
+use crate::config::Config;
+use crate::exporter::{escape_html, html_header, html_table};
+use crate::i18n::t;
+use crate::model::HardwareReport;
+use crate::redact::redact_report;
+use crate::theme::theme;
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::{Cell, Color, Table};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+struct FleetHost {
+    host: String,
+    report: HardwareReport,
+    outliers: Vec<String>,
+}
+
+/// A titled table, rendered either to the terminal or to HTML.
+struct Section {
+    title: &'static str,
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+fn load_reports(dir: &Path, config: &Config) -> Result<Vec<FleetHost>> {
+    let mut paths: Vec<_> = std::fs::read_dir(dir)
+        .with_context(|| format!("failed to read {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    let mut hosts = Vec::new();
+    for path in paths {
+        let mut report: HardwareReport = match std::fs::read_to_string(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|text| Ok(serde_json::from_str(&text)?))
+        {
+            Ok(report) => report,
+            Err(err) => {
+                eprintln!("{} {}: {:#}", t("Skipping"), path.display(), err);
+                continue;
+            }
+        };
+        redact_report(&mut report, &config.redact);
+        let host = if report.hostname.is_empty() {
+            path.file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        } else {
+            report.hostname.clone()
+        };
+        hosts.push(FleetHost {
+            host,
+            report,
+            outliers: Vec::new(),
+        });
+    }
+    Ok(hosts)
+}
+
+fn cpu_model(report: &HardwareReport) -> String {
+    report
+        .cpu
+        .first()
+        .map(|cpu| cpu.model.trim().to_string())
+        .filter(|model| !model.is_empty())
+        .unwrap_or_else(|| t("Unknown").to_string())
+}
+
+fn ram_gib(report: &HardwareReport) -> u64 {
+    (report.ram.total as f64 / GIB).round() as u64
+}
+
+fn board(report: &HardwareReport) -> Option<String> {
+    report
+        .motherboard
+        .as_ref()
+        .map(|mb| format!("{} {}", mb.vendor, mb.product).trim().to_string())
+}
+
+fn bios(report: &HardwareReport) -> Option<String> {
+    report.motherboard.as_ref().map(|mb| {
+        format!("{} {}", mb.bios_vendor, mb.bios_version)
+            .trim()
+            .to_string()
+    })
+}
+
+fn percent(used: u64, total: u64) -> f32 {
+    if total == 0 {
+        0.0
+    } else {
+        used as f32 / total as f32 * 100.0
+    }
+}
+
+fn max_disk_usage(report: &HardwareReport) -> f32 {
+    report
+        .filesystems
+        .iter()
+        .map(|disk| percent(disk.used, disk.total))
+        .fold(0.0, f32::max)
+}
+
+/// Counts each value; the result is ordered by count (descending), then value.
+fn counts<T: Ord + Clone>(values: impl Iterator<Item = T>) -> Vec<(T, usize)> {
+    let mut map: BTreeMap<T, usize> = BTreeMap::new();
+    for value in values {
+        *map.entry(value).or_default() += 1;
+    }
+    let mut counts: Vec<(T, usize)> = map.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+// The most common value, if it is shared by more than one host.
+fn majority<T: Ord + Clone>(values: impl Iterator<Item = T>) -> Option<T> {
+    counts(values)
+        .into_iter()
+        .next()
+        .filter(|(_, count)| *count > 1)
+        .map(|(value, _)| value)
+}
+
+/// Flags hosts whose hardware or firmware differs from the rest of the fleet.
+fn find_outliers(hosts: &mut [FleetHost], config: &Config) {
+    let common_ram = majority(hosts.iter().map(|h| ram_gib(&h.report)));
+    let common_cores = majority(hosts.iter().map(|h| h.report.cores().count()));
+    let mut common_bios: BTreeMap<String, Option<String>> = BTreeMap::new();
+    for board in hosts.iter().filter_map(|h| board(&h.report)) {
+        common_bios.entry(board.clone()).or_insert_with(|| {
+            majority(
+                hosts
+                    .iter()
+                    .filter(|h| self::board(&h.report).as_ref() == Some(&board))
+                    .filter_map(|h| bios(&h.report)),
+            )
+        });
+    }
+
+    for host in hosts.iter_mut() {
+        let report = &host.report;
+        let ram = ram_gib(report);
+        if let Some(common) = common_ram
+            && ram != common
+        {
+            host.outliers.push(format!(
+                "{} {} GiB ({} {} GiB)",
+                t("RAM"),
+                ram,
+                t("fleet:"),
+                common
+            ));
+        }
+        if let Some(common) = common_cores
+            && report.cores().count() != common
+        {
+            host.outliers.push(format!(
+                "{} {} ({} {})",
+                t("Cores"),
+                report.cores().count(),
+                t("fleet:"),
+                common
+            ));
+        }
+        if let (Some(board), Some(bios)) = (board(report), bios(report))
+            && let Some(Some(common)) = common_bios.get(&board)
+            && &bios != common
+        {
+            host.outliers.push(format!(
+                "BIOS {} ({} {}: {})",
+                bios,
+                t("most"),
+                board,
+                common
+            ));
+        }
+        let ram_usage = percent(report.ram.used, report.ram.total);
+        if ram_usage > config.ram_thresholds.critical {
+            host.outliers
+                .push(format!("{} {:.1}%", t("RAM usage"), ram_usage));
+        }
+    }
+}
+
+fn sections(hosts: &[FleetHost], config: &Config) -> Vec<Section> {
+    let total_ram: u64 = hosts.iter().map(|h| h.report.ram.total).sum();
+    let total_cores: usize = hosts.iter().map(|h| h.report.cores().count()).sum();
+    let (storage_used, storage_total) = hosts
+        .iter()
+        .flat_map(|h| &h.report.filesystems)
+        .fold((0u64, 0u64), |(used, total), disk| {
+            (used + disk.used, total + disk.total)
+        });
+
+    let summary = Section {
+        title: t("Fleet Summary"),
+        headers: vec![
+            t("Hosts"),
+            t("Cores"),
+            t("Total RAM (GiB)"),
+            t("Total Storage (GiB)"),
+            t("Used (%)"),
+        ],
+        rows: vec![vec![
+            hosts.len().to_string(),
+            total_cores.to_string(),
+            format!("{:.1}", total_ram as f64 / GIB),
+            format!("{:.1}", storage_total as f64 / GIB),
+            format!("{:.1}", percent(storage_used, storage_total)),
+        ]],
+    };
+
+    let host_rows = hosts
+        .iter()
+        .map(|h| {
+            let report = &h.report;
+            vec![
+                h.host.clone(),
+                format!("{} {}", report.os_name, report.os_version),
+                cpu_model(report),
+                report.cores().count().to_string(),
+                format!("{:.1}", report.ram.total as f64 / GIB),
+                format!("{:.1}", max_disk_usage(report)),
+                bios(report).unwrap_or_else(|| "N/A".to_string()),
+            ]
+        })
+        .collect();
+    let per_host = Section {
+        title: t("Hosts"),
+        headers: vec![
+            t("Host"),
+            t("OS"),
+            t("Model"),
+            t("Cores"),
+            t("RAM (GiB)"),
+            t("Fullest Disk (%)"),
+            "BIOS",
+        ],
+        rows: host_rows,
+    };
+
+    let cpu_models = Section {
+        title: t("CPU Models"),
+        headers: vec![t("Model"), t("Hosts")],
+        rows: counts(hosts.iter().map(|h| cpu_model(&h.report)))
+            .into_iter()
+            .map(|(model, count)| vec![model, count.to_string()])
+            .collect(),
+    };
+
+    let bios_rows = counts(
+        hosts
+            .iter()
+            .filter_map(|h| Some((board(&h.report)?, bios(&h.report)?))),
+    )
+    .into_iter()
+    .map(|((board, bios), count)| {
+        let names: Vec<&str> = hosts
+            .iter()
+            .filter(|h| {
+                self::board(&h.report).as_ref() == Some(&board)
+                    && self::bios(&h.report).as_ref() == Some(&bios)
+            })
+            .map(|h| h.host.as_str())
+            .collect();
+        vec![board, bios, count.to_string(), names.join(", ")]
+    })
+    .collect();
+    let bios_versions = Section {
+        title: t("BIOS Versions"),
+        headers: vec![t("Motherboard"), "BIOS", t("Hosts"), t("Host")],
+        rows: bios_rows,
+    };
+
+    let mut disk_rows: Vec<(f32, Vec<String>)> = Vec::new();
+    for h in hosts {
+        for disk in &h.report.filesystems {
+            let usage = percent(disk.used, disk.total);
+            if usage > config.storage_thresholds.warning {
+                disk_rows.push((
+                    usage,
+                    vec![
+                        h.host.clone(),
+                        disk.mount_point.clone(),
+                        format!("{:.1}", disk.used as f64 / GIB),
+                        format!("{:.1}", disk.total as f64 / GIB),
+                        format!("{:.1}", usage),
+                    ],
+                ));
+            }
+        }
+    }
+    disk_rows.sort_by(|a, b| b.0.total_cmp(&a.0));
+    let full_disks = Section {
+        title: t("Disks Nearing Capacity"),
+        headers: vec![
+            t("Host"),
+            t("Mount"),
+            t("Used (GiB)"),
+            t("Total (GiB)"),
+            t("Usage (%)"),
+        ],
+        rows: disk_rows.into_iter().map(|(_, row)| row).collect(),
+    };
+
+    let outliers = Section {
+        title: t("Outliers"),
+        headers: vec![t("Host"), t("Deviation")],
+        rows: hosts
+            .iter()
+            .flat_map(|h| h.outliers.iter().map(|o| vec![h.host.clone(), o.clone()]))
+            .collect(),
+    };
+
+    vec![
+        summary,
+        per_host,
+        cpu_models,
+        bios_versions,
+        full_disks,
+        outliers,
+    ]
+}
+
+fn print_sections(sections: &[Section], config: &Config) {
+    for section in sections {
+        if section.rows.is_empty() {
+            continue;
+        }
+        println!("\n{}", section.title.bold().color(theme().title));
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(section.headers.clone());
+        let usage_column = section.headers.iter().position(|h| *h == t("Usage (%)"));
+        for row in &section.rows {
+            table.add_row(row.iter().enumerate().map(|(i, cell)| {
+                let cell_value = Cell::new(cell);
+                match (usage_column, cell.parse::<f32>()) {
+                    (Some(column), Ok(usage)) if column == i => cell_value.fg(Color::from(
+                        theme().level(usage, &config.storage_thresholds),
+                    )),
+                    _ => cell_value,
+                }
+            }));
+        }
+        println!("{table}");
+    }
+}
+
+// RFC 4180: a bare carriage return would split the row in most readers too
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// One row per host, for spreadsheets.
+fn render_csv(hosts: &[FleetHost]) -> String {
+    let mut out = String::from(
+        "host,os,kernel,cpu_model,cores,ram_gib,ram_used_percent,storage_gib,fullest_disk_percent,motherboard,bios,outliers\n",
+    );
+    for h in hosts {
+        let report = &h.report;
+        let storage: u64 = report.filesystems.iter().map(|d| d.total).sum();
+        let fields = [
+            h.host.clone(),
+            format!("{} {}", report.os_name, report.os_version),
+            report.kernel_version.clone(),
+            cpu_model(report),
+            report.cores().count().to_string(),
+            format!("{:.1}", report.ram.total as f64 / GIB),
+            format!("{:.1}", percent(report.ram.used, report.ram.total)),
+            format!("{:.1}", storage as f64 / GIB),
+            format!("{:.1}", max_disk_usage(report)),
+            board(report).unwrap_or_default(),
+            bios(report).unwrap_or_default(),
+            h.outliers.join("; "),
+        ];
+        let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        let _ = writeln!(out, "{}", line.join(","));
+    }
+    out
+}
+
+fn render_html(sections: &[Section]) -> String {
+    let mut out = String::new();
+    html_header(&mut out, "hw-checker fleet report");
+    let _ = writeln!(out, "<h1>{}</h1>", escape_html(t("Fleet Summary")));
+    for section in sections {
+        html_table(&mut out, section.title, &section.headers, &section.rows);
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+pub fn run_fleet(args: &FleetArgs, config: &Config) -> Result<()> {
+    let mut hosts = load_reports(&args.dir, config)?;
+    if hosts.is_empty() {
+        bail!("no JSON reports found in {}", args.dir.display());
+    }
+    find_outliers(&mut hosts, config);
+    let sections = sections(&hosts, config);
+
+    let Some(path) = &args.output else {
+        print_sections(&sections, config);
+        return Ok(());
+    };
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+    let rendered = match ext.as_str() {
+        "csv" => render_csv(&hosts),
+        "html" | "htm" => render_html(&sections),
+        _ => bail!(
+            "unsupported file extension for {} (use .csv or .html)",
+            path.display()
+        ),
+    };
+    std::fs::write(path, rendered)?;
+    println!("{} {}", t("Report saved to"), path.display());
+    Ok(())
+}
@@ -1,28 +1,131 @@
-use crate::config::{Config, Thresholds};
-use crate::model::{BatteryInfo, HardwareReport, MotherboardInfo, PciDevice, UsbDevice};
+use crate::alerts::{AlertLevel, Problem, report_problems};
+use crate::config::{BatteryConfig, Config, PressureConfig, Thresholds};
+use crate::i18n::t;
+use crate::model::{
+    BatteryInfo, BoardLayout, CloudInstance, CloudProvider, Connectivity, ConnectivityVerdict,
+    ContainerLimits, CoreKind, CpuLoad, CpuPackage, DiskIoInfo, ExpansionSlot, Filesystem, GpuInfo,
+    GpuProcess, HardwareReport, InterfaceKind, IommuDevice, IommuGroup, IommuInfo, IrqInfo,
+    KernelLimit, MemorySettings, MemorySlot, MemorySlots, MotherboardInfo, NetworkConfig,
+    OsDetails, PciDevice, PhysicalDisk, PlatformState, Pressure, SpdInfo, StepTiming,
+    ThunderboltDevice, TimeHealth, UsbDevice, VirtualizationSupport,
+};
+use crate::theme::theme;
 use colored::Colorize;
 use comfy_table::modifiers::UTF8_ROUND_CORNERS;
 use comfy_table::presets::UTF8_FULL;
-use comfy_table::{Cell, Color, Table};
+use comfy_table::{Cell, Color, ContentArrangement, Table};
+use serde_json::Value;
+use std::collections::BTreeMap;
 
 pub fn print_report(report: &HardwareReport, config: &Config) {
+    let sections = &config.sections;
+    print_issues(&report_problems(report, config));
     print_summary(report);
-    print_cpu(&report.cpu, &config.cpu_thresholds);
-    print_ram(&report.ram, &config.ram_thresholds);
-    print_storage(&report.storage, &config.storage_thresholds);
-    print_network(&report.network);
-    print_usb(&report.usb);
-    print_pci(&report.pci);
-    print_health(report.motherboard.as_ref(), &report.battery);
+    if sections.cpu {
+        print_cpu(
+            &report.cpu,
+            report.cpu_load.as_ref(),
+            &config.cpu_thresholds,
+        );
+    }
+    if let Some(support) = &report.virtualization {
+        print_virtualization(support);
+    }
+    if sections.ram {
+        print_ram(&report.ram, &config.ram_thresholds);
+    }
+    if let Some(limits) = &report.container {
+        print_container(limits, report, &config.ram_thresholds);
+    }
+    if let Some(pressure) = &report.pressure {
+        print_pressure(pressure, &config.pressure);
+    }
+    if sections.storage {
+        print_storage(
+            &report.disks,
+            &report.filesystems,
+            &config.storage_thresholds,
+            &config.inode_thresholds,
+        );
+    }
+    if !report.disk_io.is_empty() {
+        print_io(&report.disk_io);
+    }
+    if !report.irqs.is_empty() {
+        print_irqs(&report.irqs);
+    }
+    if sections.network {
+        print_network(
+            &report.network,
+            report.network_config.as_ref(),
+            config.all_interfaces,
+        );
+    }
+    if let Some(connectivity) = &report.connectivity {
+        print_connectivity(connectivity);
+    }
+    if sections.usb {
+        print_usb(&report.usb);
+    }
+    if sections.thunderbolt {
+        print_thunderbolt(&report.thunderbolt);
+    }
+    if sections.pci {
+        print_pci(&report.pci);
+    }
+    if report.iommu.is_some() {
+        print_iommu(report.iommu.as_ref());
+    }
+    if sections.gpu {
+        print_gpus(&report.gpus, &report.gpu_processes);
+    }
+    if sections.limits {
+        print_limits(&report.limits);
+    }
+    if sections.time {
+        print_time(report.time.as_ref());
+    }
+    // Empty when the motherboard/battery sections are disabled, and then skipped
+    print_health(
+        report.motherboard.as_ref(),
+        &report.battery,
+        report.platform.as_ref(),
+        &config.battery,
+    );
+    if let Some(layout) = &report.board_layout {
+        print_board_layout(layout);
+    }
+    print_custom(&report.custom);
+}
+
+/// Every threshold breach of the report in one list, so the red cells of the
+/// tables below need no scanning. Prints nothing when all is well.
+pub fn print_issues(problems: &[Problem]) {
+    let Some(worst) = problems.first() else {
+        return;
+    };
+    println!(
+        "\n{}",
+        format!("{} ({})", t("Issues"), problems.len())
+            .bold()
+            .color(theme().alert(worst.level))
+    );
+    for problem in problems {
+        let label = match problem.level {
+            AlertLevel::Critical => t("CRITICAL").color(theme().critical),
+            _ => t("WARNING").color(theme().warning),
+        };
+        println!("  {:<8}  {}", label.bold(), problem.message);
+    }
 }
 
 pub fn print_summary(report: &HardwareReport) {
-    println!("\n{}", "System Summary".bold().cyan());
+    println!("\n{}", t("System Summary").bold().color(theme().title));
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
-        .set_header(vec!["Hostname", "OS", "Kernel", "Uptime"]);
+        .set_header(vec![t("Hostname"), t("OS"), t("Kernel"), t("Uptime")]);
 
     table.add_row(vec![
         Cell::new(&report.hostname).fg(Color::Magenta),
@@ -31,9 +134,158 @@ pub fn print_summary(report: &HardwareReport) {
         Cell::new(format_uptime(report.uptime)),
     ]);
     println!("{table}");
+
+    if let Some(details) = &report.os_details {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            // The kernel command line alone can be wider than the terminal
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_header(vec![t("OS Details"), t("Value")]);
+        for (label, value) in os_details_rows(details) {
+            table.add_row(vec![label, value]);
+        }
+        println!("{table}");
+    }
+
+    if !report.asset.is_empty() {
+        let mut asset = Table::new();
+        asset
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![t("Asset"), t("Value")]);
+        for (label, value) in report.asset.fields() {
+            asset.add_row(vec![label, value]);
+        }
+        println!("{asset}");
+    }
+
+    if let Some(cloud) = &report.cloud {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![
+                t("Cloud"),
+                t("Instance Type"),
+                t("Region"),
+                t("Zone"),
+                t("Instance ID"),
+            ]);
+        table.add_row(cloud_row(cloud));
+        println!("{table}");
+    }
+}
+
+/// `--timings`: each discovery step, slowest first, and the total.
+pub fn print_timings(timings: &[StepTiming]) {
+    println!("\n{}", t("Discovery Timings").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![t("Step"), t("Time (ms)")]);
+    let mut sorted: Vec<&StepTiming> = timings.iter().collect();
+    sorted.sort_by(|a, b| b.ms.total_cmp(&a.ms));
+    for timing in sorted {
+        table.add_row(vec![
+            Cell::new(&timing.step),
+            Cell::new(format!("{:.1}", timing.ms)),
+        ]);
+    }
+    let total: f64 = timings.iter().map(|timing| timing.ms).sum();
+    table.add_row(vec![
+        Cell::new(t("Total")),
+        Cell::new(format!("{:.1}", total)),
+    ]);
+    println!("{table}");
+}
+
+pub fn os_details_rows(details: &OsDetails) -> Vec<(String, String)> {
+    let or_na = |v: &Option<String>| v.clone().unwrap_or_else(|| "N/A".to_string());
+    vec![
+        (t("Architecture").to_string(), details.architecture.clone()),
+        (t("Booted").to_string(), or_na(&details.boot_time)),
+        (
+            t("Kernel command line").to_string(),
+            or_na(&details.kernel_cmdline),
+        ),
+        (
+            t("Loaded modules").to_string(),
+            details
+                .kernel_modules
+                .map_or_else(|| "N/A".to_string(), |n| n.to_string()),
+        ),
+        (
+            t("Security module").to_string(),
+            or_na(&details.security_module),
+        ),
+        ("systemd".to_string(), or_na(&details.systemd_version)),
+    ]
 }
 
-fn format_uptime(seconds: u64) -> String {
+pub fn virtualization_rows(support: &VirtualizationSupport) -> Vec<(String, String)> {
+    let yes_no = |value: Option<bool>| match value {
+        Some(true) => t("Yes").to_string(),
+        Some(false) => t("No").to_string(),
+        None => "N/A".to_string(),
+    };
+    let extension = match (&support.extension, support.disabled_in_firmware) {
+        (Some(extension), true) => format!("{} ({})", extension, t("disabled in firmware")),
+        (Some(extension), false) => extension.clone(),
+        (None, _) => t("Not supported").to_string(),
+    };
+    vec![
+        (t("Hardware virtualization").to_string(), extension),
+        (
+            t("Nested paging").to_string(),
+            yes_no(support.nested_paging),
+        ),
+        ("KVM (/dev/kvm)".to_string(), yes_no(support.kvm)),
+        (
+            t("Nested virtualization").to_string(),
+            yes_no(support.nested_virtualization),
+        ),
+    ]
+}
+
+pub fn print_virtualization(support: &VirtualizationSupport) {
+    println!("\n{}", t("Virtualization").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![t("Setting"), t("Value")]);
+    for (i, (label, value)) in virtualization_rows(support).into_iter().enumerate() {
+        let mut cell = Cell::new(value);
+        // The extension comes first
+        if i == 0 && support.disabled_in_firmware {
+            cell = cell.fg(theme().warning.into());
+        }
+        table.add_row(vec![Cell::new(label), cell]);
+    }
+    println!("{table}");
+}
+
+/// Provider, instance type, region, zone and instance ID.
+pub fn cloud_row(cloud: &CloudInstance) -> Vec<String> {
+    let or_na = |v: &Option<String>| v.clone().unwrap_or_else(|| "N/A".to_string());
+    let provider = match cloud.provider {
+        CloudProvider::Aws => "AWS",
+        CloudProvider::Gcp => "GCP",
+        CloudProvider::Azure => "Azure",
+    };
+    vec![
+        provider.to_string(),
+        or_na(&cloud.instance_type),
+        or_na(&cloud.region),
+        or_na(&cloud.zone),
+        or_na(&cloud.instance_id),
+    ]
+}
+
+pub fn format_uptime(seconds: u64) -> String {
     let days = seconds / (24 * 3600);
     let hours = (seconds % (24 * 3600)) / 3600;
     let minutes = (seconds % 3600) / 60;
@@ -47,71 +299,174 @@ fn format_uptime(seconds: u64) -> String {
     }
 }
 
-pub fn print_cpu(cpus: &[crate::model::CpuInfo], thresholds: &Thresholds) {
-    println!("\n{}", "CPU Information".bold().cyan());
+/// e.g. "48 KB D + 32 KB I (per core)", or "N/A".
+pub fn cache_cell(package: &CpuPackage, level: u8) -> String {
+    let Some(label) = package.cache_label(level) else {
+        return "N/A".to_string();
+    };
+    match package.cache_per_core(level) {
+        Some(true) => format!("{} ({})", label, t("per core")),
+        Some(false) => format!("{} ({})", label, t("shared")),
+        None => label,
+    }
+}
+
+pub fn print_cpu(packages: &[CpuPackage], load: Option<&CpuLoad>, thresholds: &Thresholds) {
+    println!("\n{}", t("CPU Information").bold().color(theme().title));
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
         .set_header(vec![
-            "Core",
-            "Model",
-            "Vendor",
-            "Frequency (MHz)",
-            "L1 Cache",
-            "L2 Cache",
-            "L3 Cache",
-            "Usage (%)",
-        ]);
-
-    for (i, cpu) in cpus.iter().enumerate() {
-        let usage_color = if cpu.usage > thresholds.critical {
-            Color::Red
-        } else if cpu.usage > thresholds.warning {
-            Color::Yellow
-        } else {
-            Color::Green
-        };
-
+            t("Socket"),
+            t("Model"),
+            t("Vendor"),
+            t("Cores"),
+            t("Threads"),
+            t("L1 Cache"),
+            t("L2 Cache"),
+            t("L3 Cache"),
+        ]);
+    for (i, package) in packages.iter().enumerate() {
         table.add_row(vec![
             Cell::new(i.to_string()),
-            Cell::new(&cpu.model),
-            Cell::new(&cpu.vendor_id),
-            Cell::new(cpu.frequency.to_string()),
-            Cell::new(cpu.l1_cache.as_deref().unwrap_or("N/A")),
-            Cell::new(cpu.l2_cache.as_deref().unwrap_or("N/A")),
-            Cell::new(cpu.l3_cache.as_deref().unwrap_or("N/A")),
-            Cell::new(format!("{:.1}", cpu.usage)).fg(usage_color),
+            Cell::new(&package.model),
+            Cell::new(&package.vendor),
+            Cell::new(package.physical_cores.to_string()),
+            Cell::new(package.logical_cores.to_string()),
+            Cell::new(cache_cell(package, 1)),
+            Cell::new(cache_cell(package, 2)),
+            Cell::new(cache_cell(package, 3)),
         ]);
     }
     println!("{table}");
+
+    if let Some(load) = load {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![
+                t("Load (1m / 5m / 15m)"),
+                t("User (%)"),
+                t("System (%)"),
+                t("I/O Wait (%)"),
+                t("Steal (%)"),
+                t("Idle (%)"),
+            ]);
+        let mut row = vec![Cell::new(
+            load.load_average
+                .map(|[one, five, fifteen]| format!("{:.2} / {:.2} / {:.2}", one, five, fifteen))
+                .unwrap_or_else(|| "N/A".to_string()),
+        )];
+        match load.times {
+            Some(times) => row.extend(
+                [
+                    times.user,
+                    times.system,
+                    times.iowait,
+                    times.steal,
+                    times.idle,
+                ]
+                .map(|share| Cell::new(format!("{:.1}", share))),
+            ),
+            None => row.extend((0..5).map(|_| Cell::new("N/A"))),
+        }
+        table.add_row(row);
+        println!("{table}");
+    }
+
+    let hybrid = packages.iter().any(|p| !p.core_groups.is_empty());
+    if hybrid {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![
+                t("Socket"),
+                t("Type"),
+                t("Threads"),
+                t("Base (MHz)"),
+                t("Boost (MHz)"),
+            ]);
+        for (i, package) in packages.iter().enumerate() {
+            for group in &package.core_groups {
+                let mhz = |value: Option<u64>| value.map_or("N/A".to_string(), |v| v.to_string());
+                table.add_row(vec![
+                    Cell::new(i.to_string()),
+                    Cell::new(core_kind_label(group.kind)),
+                    Cell::new(group.logical_cores.to_string()),
+                    Cell::new(mhz(group.base_mhz)),
+                    Cell::new(mhz(group.boost_mhz)),
+                ]);
+            }
+        }
+        println!("{table}");
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS);
+    if hybrid {
+        table.set_header(vec![
+            t("Core"),
+            t("Type"),
+            t("Cluster"),
+            t("Frequency (MHz)"),
+            t("Usage (%)"),
+        ]);
+    } else {
+        table.set_header(vec![t("Core"), t("Frequency (MHz)"), t("Usage (%)")]);
+    }
+    // Performance cores first, then efficiency cores
+    let mut cores: Vec<_> = packages.iter().flat_map(|p| &p.cores).collect();
+    cores.sort_by_key(|c| (c.kind, c.id));
+    for core in cores {
+        let usage_color = Color::from(theme().level(core.usage, thresholds));
+        let mut row = vec![Cell::new(core.id.to_string())];
+        if hybrid {
+            row.push(Cell::new(core.kind.map_or("N/A", core_kind_label)));
+            row.push(Cell::new(
+                core.cluster.map_or("N/A".to_string(), |c| c.to_string()),
+            ));
+        }
+        row.push(Cell::new(core.frequency.to_string()));
+        row.push(Cell::new(format!("{:.1}", core.usage)).fg(usage_color));
+        table.add_row(row);
+    }
+    println!("{table}");
+}
+
+pub fn core_kind_label(kind: CoreKind) -> &'static str {
+    match kind {
+        CoreKind::Performance => t("Performance"),
+        CoreKind::Efficiency => t("Efficiency"),
+    }
 }
 
 pub fn print_ram(ram: &crate::model::RamInfo, thresholds: &Thresholds) {
-    println!("\n{}", "RAM Information & Usage".bold().cyan());
+    println!(
+        "\n{}",
+        t("RAM Information & Usage").bold().color(theme().title)
+    );
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
         .set_header(vec![
-            "Component",
-            "Total (MiB)",
-            "Used (MiB)",
-            "Free (MiB)",
-            "Usage (%)",
+            t("Component"),
+            t("Total (MiB)"),
+            t("Used (MiB)"),
+            t("Free (MiB)"),
+            t("Usage (%)"),
         ]);
 
     let ram_usage = (ram.used as f32 / ram.total as f32) * 100.0;
-    let ram_color = if ram_usage > thresholds.critical {
-        Color::Red
-    } else if ram_usage > thresholds.warning {
-        Color::Yellow
-    } else {
-        Color::Green
-    };
+    let ram_color = Color::from(theme().level(ram_usage, thresholds));
 
     table.add_row(vec![
-        Cell::new("Main Memory"),
+        Cell::new(t("Main Memory")),
         Cell::new((ram.total / 1024 / 1024).to_string()),
         Cell::new((ram.used / 1024 / 1024).to_string()),
         Cell::new((ram.free / 1024 / 1024).to_string()),
@@ -124,7 +479,7 @@ pub fn print_ram(ram: &crate::model::RamInfo, thresholds: &Thresholds) {
         0.0
     };
     table.add_row(vec![
-        Cell::new("Swap"),
+        Cell::new(t("Swap")),
         Cell::new((ram.swap_total / 1024 / 1024).to_string()),
         Cell::new((ram.swap_used / 1024 / 1024).to_string()),
         Cell::new(((ram.swap_total - ram.swap_used) / 1024 / 1024).to_string()),
@@ -133,26 +488,60 @@ pub fn print_ram(ram: &crate::model::RamInfo, thresholds: &Thresholds) {
 
     println!("{table}");
 
+    if let Some(settings) = &ram.settings {
+        println!("\n{}", t("Memory Settings").bold().color(theme().title));
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![t("Setting"), t("Value")]);
+        for (label, value) in memory_settings_rows(settings) {
+            table.add_row(vec![label, value]);
+        }
+        println!("{table}");
+    }
+
+    if let Some(virtualized) = &ram.virtualized {
+        let mut source = virtualized.hypervisor.clone().unwrap_or_default();
+        if !virtualized.devices.is_empty() {
+            if !source.is_empty() {
+                source.push_str(", ");
+            }
+            source.push_str(&virtualized.devices.join(", "));
+        }
+        let note = t("Virtual machine memory: there are no physical modules to report");
+        if source.is_empty() {
+            println!("\n{}", note.italic());
+        } else {
+            println!("\n{} ({})", note.italic(), source);
+        }
+    }
+
     if !ram.sticks.is_empty() {
-        println!("\n{}", "Physical RAM Sticks".bold().cyan());
+        let title = if ram.virtualized.is_some() {
+            t("Virtual Memory Devices")
+        } else {
+            t("Physical RAM Sticks")
+        };
+        println!("\n{}", title.bold().color(theme().title));
         let mut stick_table = Table::new();
         stick_table
             .load_preset(UTF8_FULL)
             .apply_modifier(UTF8_ROUND_CORNERS)
             .set_header(vec![
-                "Slot",
-                "Manufacturer",
-                "Part Number",
-                "Serial Number",
-                "Speed (MT/s)",
+                t("Slot"),
+                t("Manufacturer"),
+                t("Part Number"),
+                t("Serial Number"),
+                t("Speed (MT/s)"),
             ]);
 
         for (i, stick) in ram.sticks.iter().enumerate() {
             stick_table.add_row(vec![
                 Cell::new(format!("DIMM {}", i)),
-                Cell::new(stick.manufacturer.as_deref().unwrap_or("Unknown")),
-                Cell::new(stick.part_number.as_deref().unwrap_or("Unknown")),
-                Cell::new(stick.serial_number.as_deref().unwrap_or("Unknown")),
+                Cell::new(stick.manufacturer.as_deref().unwrap_or(t("Unknown"))),
+                Cell::new(stick.part_number.as_deref().unwrap_or(t("Unknown"))),
+                Cell::new(stick.serial_number.as_deref().unwrap_or(t("Unknown"))),
                 Cell::new(
                     stick
                         .speed
@@ -162,174 +551,1363 @@ pub fn print_ram(ram: &crate::model::RamInfo, thresholds: &Thresholds) {
             ]);
         }
         println!("{stick_table}");
-    } else {
-        println!(
-            "\n{}",
-            "Note: Run with sudo to see physical RAM stick details."
-                .yellow()
-                .italic()
-        );
+    }
+
+    if let Some(slots) = &ram.slots {
+        print_memory_slots(slots);
+    }
+    if !ram.spd.is_empty() {
+        print_spd(&ram.spd);
     }
 }
 
-pub fn print_storage(storage: &[crate::model::StorageInfo], thresholds: &Thresholds) {
-    println!("\n{}", "Storage Information".bold().cyan());
+/// One row per module EEPROM, its XMP/EXPO profiles stacked in the last cell.
+fn print_spd(modules: &[SpdInfo]) {
+    println!("\n{}", t("SPD").bold().color(theme().title));
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
         .set_header(vec![
-            "Name",
-            "Mount",
-            "FS",
-            "Type",
-            "Interface",
-            "Total (GiB)",
-            "Used (GiB)",
-            "Usage (%)",
-        ]);
-
-    for disk in storage {
-        let usage_pct = (disk.used as f32 / disk.total as f32) * 100.0;
-        let color = if usage_pct > thresholds.critical {
-            Color::Red
-        } else if usage_pct > thresholds.warning {
-            Color::Yellow
-        } else {
-            Color::Green
+            t("Device"),
+            t("Type"),
+            t("Size"),
+            t("Manufacturer"),
+            t("DRAM"),
+            t("Part Number"),
+            t("Speed (MT/s)"),
+            t("Timings"),
+            t("Profiles"),
+        ]);
+    let or_na = |value: Option<String>| value.unwrap_or_else(|| "N/A".to_string());
+    for module in modules {
+        let kind = match &module.module_type {
+            Some(form) => format!("{} {}", module.memory_type, form),
+            None => module.memory_type.clone(),
         };
+        let profiles: Vec<String> = module
+            .profiles
+            .iter()
+            .map(|profile| {
+                let mut line = profile.name.clone();
+                if let Some(speed) = profile.speed {
+                    line.push_str(&format!(": {}", speed));
+                }
+                if let Some(timings) = profile.timings {
+                    line.push_str(&format!(" {}", timings));
+                }
+                if let Some(voltage) = profile.voltage {
+                    line.push_str(&format!(" {:.2} V", voltage));
+                }
+                line
+            })
+            .collect();
+        table.add_row(vec![
+            Cell::new(&module.device),
+            Cell::new(kind),
+            Cell::new(or_na(module.size.map(|size| format!("{} GB", size >> 30)))),
+            Cell::new(or_na(module.manufacturer.clone())),
+            Cell::new(or_na(module.dram_manufacturer.clone())),
+            Cell::new(or_na(module.part_number.clone())),
+            Cell::new(or_na(module.speed.map(|speed| speed.to_string()))),
+            Cell::new(or_na(module.timings.map(|timings| timings.to_string()))),
+            Cell::new(profiles.join("\n")),
+        ]);
+    }
+    println!("{table}");
+}
+
+/// Slots grouped by channel, then how much more memory the board takes.
+fn print_memory_slots(slots: &MemorySlots) {
+    let gb = |bytes: u64| {
+        let gb = bytes as f64 / (1u64 << 30) as f64;
+        if gb.fract() == 0.0 {
+            format!("{} GB", gb)
+        } else {
+            format!("{:.1} GB", gb)
+        }
+    };
+    if !slots.slots.is_empty() {
+        println!("\n{}", t("Memory Slots").bold().color(theme().title));
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![
+                t("Channel"),
+                t("Slot"),
+                t("Size"),
+                t("Type"),
+                t("Speed (MT/s)"),
+            ]);
+        let mut rows: Vec<&MemorySlot> = slots.slots.iter().collect();
+        rows.sort_by(|a, b| a.channel.cmp(&b.channel));
+        for slot in rows {
+            let speed = match (slot.configured_speed, slot.speed) {
+                (Some(configured), Some(rated)) if configured < rated => {
+                    format!("{} / {}", configured, rated)
+                }
+                (Some(mts), _) | (None, Some(mts)) => mts.to_string(),
+                (None, None) => String::new(),
+            };
+            table.add_row(vec![
+                Cell::new(slot.channel.as_deref().unwrap_or("?")),
+                Cell::new(&slot.locator),
+                match slot.size {
+                    Some(bytes) => Cell::new(gb(bytes)),
+                    None => Cell::new(t("Empty")).fg(theme().muted.into()),
+                },
+                Cell::new(slot.memory_type.as_deref().unwrap_or_default()),
+                Cell::new(speed),
+            ]);
+        }
+        println!("{table}");
+    }
+
+    let mut summary = format!("{}: {}/{}", t("Populated"), slots.populated, slots.total);
+    if let Some(max) = slots.max_capacity {
+        summary = format!("{}, {} {}", summary, t("max"), gb(max));
+    }
+    if let Some(mts) = slots.max_speed {
+        summary = format!("{}, {} {} MT/s", summary, t("modules rated"), mts);
+    }
+    println!("{}", summary);
+    let free = slots.total - slots.populated;
+    match slots.headroom() {
+        Some(0) => println!("{}", t("Memory is at the board's maximum")),
+        Some(headroom) if free > 0 => println!(
+            "{}",
+            format!(
+                "{} {} ({} {})",
+                t("You can add up to"),
+                gb(headroom),
+                free,
+                t("free slots")
+            )
+            .color(theme().ok)
+        ),
+        Some(headroom) => println!(
+            "{} {} {}",
+            t("All slots are populated; replace modules to add up to"),
+            gb(headroom),
+            t("more")
+        ),
+        None => {}
+    }
+}
+
+/// PSI averages per resource; the 10-second `some` share is the one held
+/// against the `pressure:` thresholds.
+pub fn print_pressure(pressure: &Pressure, config: &PressureConfig) {
+    println!(
+        "\n{}",
+        t("Pressure Stall (PSI)").bold().color(theme().title)
+    );
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            t("Resource"),
+            t("Some 10s (%)"),
+            t("Some 60s (%)"),
+            t("Some 300s (%)"),
+            t("Full 10s (%)"),
+            t("Full 60s (%)"),
+            t("Full 300s (%)"),
+        ]);
+    for (name, stall) in pressure.resources() {
+        let thresholds = config.thresholds(name);
+        let [some10, some60, some300] = stall.some;
+        let mut row = vec![
+            Cell::new(name),
+            Cell::new(format!("{:.2}", some10)).fg(theme().level(some10, thresholds).into()),
+            Cell::new(format!("{:.2}", some60)),
+            Cell::new(format!("{:.2}", some300)),
+        ];
+        match stall.full {
+            Some(full) => row.extend(full.map(|share| Cell::new(format!("{:.2}", share)))),
+            None => row.extend((0..3).map(|_| Cell::new("N/A"))),
+        }
+        table.add_row(row);
+    }
+    println!("{table}");
+}
+
+/// Host totals next to the cgroup's limits, since inside a container the
+/// host figures overstate what is available.
+pub fn print_container(limits: &ContainerLimits, report: &HardwareReport, thresholds: &Thresholds) {
+    println!(
+        "\n{} (cgroup v{}: {})",
+        t("Container Limits").bold().color(theme().title),
+        limits.cgroup_version,
+        limits.path
+    );
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            t("Component"),
+            t("Host"),
+            t("Limit"),
+            t("Used"),
+            t("Usage (%)"),
+        ]);
+
+    let mib = |bytes: u64| (bytes / 1024 / 1024).to_string();
+    // Zero when only the RAM section was collected
+    let host_cpus = report.cores().count();
+    table.add_row(vec![
+        Cell::new(t("CPUs")),
+        Cell::new(if host_cpus > 0 {
+            host_cpus.to_string()
+        } else {
+            "N/A".to_string()
+        }),
+        Cell::new(
+            limits
+                .effective_cpus()
+                .map_or(t("Unlimited").to_string(), |cpus| format!("{:.2}", cpus)),
+        ),
+        Cell::new("N/A"),
+        Cell::new("N/A"),
+    ]);
+    let mut memory_row = vec![
+        Cell::new(t("Memory (MiB)")),
+        Cell::new(mib(report.ram.total)),
+        Cell::new(limits.memory_limit.map_or(t("Unlimited").to_string(), mib)),
+        Cell::new(limits.memory_used.map_or("N/A".to_string(), mib)),
+    ];
+    memory_row.push(match (limits.memory_used, limits.memory_limit) {
+        (Some(used), Some(limit)) if limit > 0 => {
+            let usage = used as f32 * 100.0 / limit as f32;
+            Cell::new(format!("{:.1}", usage)).fg(theme().level(usage, thresholds).into())
+        }
+        _ => Cell::new("N/A"),
+    });
+    table.add_row(memory_row);
+    println!("{table}");
+}
+
+/// Label/value rows for hugepages, THP and overcommit.
+pub fn memory_settings_rows(settings: &MemorySettings) -> Vec<(String, String)> {
+    let mib = |bytes: u64| format!("{} MiB", bytes / 1024 / 1024);
+    let na = || "N/A".to_string();
+    vec![
+        (
+            t("Hugepages (total/free)").to_string(),
+            format!(
+                "{} / {} × {}",
+                settings.hugepages_total,
+                settings.hugepages_free,
+                mib(settings.hugepage_size)
+            ),
+        ),
+        (
+            t("Transparent hugepages").to_string(),
+            settings.transparent_hugepages.clone().unwrap_or_else(na),
+        ),
+        (
+            t("Overcommit").to_string(),
+            match (settings.overcommit_label(), settings.overcommit_ratio) {
+                (Some(mode @ "strict"), Some(ratio)) => format!("{} ({}%)", mode, ratio),
+                (Some(mode), _) => mode.to_string(),
+                (None, _) => na(),
+            },
+        ),
+        (
+            t("Committed / limit").to_string(),
+            format!(
+                "{} / {}",
+                settings.committed.map_or_else(na, mib),
+                settings.commit_limit.map_or_else(na, mib)
+            ),
+        ),
+    ]
+}
+
+pub fn print_storage(
+    disks: &[PhysicalDisk],
+    filesystems: &[Filesystem],
+    thresholds: &Thresholds,
+    inode_thresholds: &Thresholds,
+) {
+    println!("\n{}", t("Storage Information").bold().color(theme().title));
+    if !disks.is_empty() {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![
+                t("Disk"),
+                t("Model"),
+                t("Serial Number"),
+                t("Type"),
+                t("Interface"),
+                t("Size (GiB)"),
+                "SMART",
+            ]);
+        for disk in disks {
+            let smart = match &disk.smart {
+                Some(smart) if smart.passed => Cell::new(t("Passed")).fg(theme().ok.into()),
+                Some(_) => Cell::new(t("Failed")).fg(theme().critical.into()),
+                None => Cell::new("N/A"),
+            };
+            table.add_row(vec![
+                Cell::new(&disk.name),
+                Cell::new(disk.model_name.as_deref().unwrap_or(t("Unknown"))),
+                Cell::new(disk.serial_number.as_deref().unwrap_or("N/A")),
+                Cell::new(disk.disk_type.as_deref().unwrap_or(t("Unknown"))),
+                Cell::new(disk.interface_label().unwrap_or_else(|| "N/A".to_string())),
+                Cell::new(format!(
+                    "{:.1}",
+                    disk.size as f64 / 1024.0 / 1024.0 / 1024.0
+                )),
+                smart,
+            ]);
+        }
+        println!("{table}");
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            t("Name"),
+            t("Mount"),
+            "FS",
+            t("Disk"),
+            t("Total (GiB)"),
+            t("Used (GiB)"),
+            t("Usage (%)"),
+            t("Inodes (%)"),
+        ]);
+
+    for fs in filesystems {
+        let usage_pct = (fs.used as f32 / fs.total as f32) * 100.0;
+        let color = Color::from(theme().level(usage_pct, thresholds));
 
         table.add_row(vec![
-            Cell::new(&disk.name),
-            Cell::new(&disk.mount_point),
-            Cell::new(&disk.filesystem),
-            Cell::new(disk.disk_type.as_deref().unwrap_or("Unknown")),
-            Cell::new(disk.interface.as_deref().unwrap_or("N/A")),
-            Cell::new(format!(
-                "{:.1}",
-                disk.total as f64 / 1024.0 / 1024.0 / 1024.0
-            )),
-            Cell::new(format!(
-                "{:.1}",
-                disk.used as f64 / 1024.0 / 1024.0 / 1024.0
-            )),
+            Cell::new(&fs.name),
+            Cell::new(&fs.mount_point),
+            Cell::new(&fs.filesystem),
+            Cell::new(fs.disk.as_deref().unwrap_or("N/A")),
+            Cell::new(format!("{:.1}", fs.total as f64 / 1024.0 / 1024.0 / 1024.0)),
+            Cell::new(format!("{:.1}", fs.used as f64 / 1024.0 / 1024.0 / 1024.0)),
             Cell::new(format!("{:.1}", usage_pct)).fg(color),
+            match fs.inodes.and_then(|inodes| inodes.usage_percent()) {
+                Some(usage) => Cell::new(format!("{:.1}", usage))
+                    .fg(theme().level(usage, inode_thresholds).into()),
+                None => Cell::new("N/A"),
+            },
         ]);
     }
     println!("{table}");
 }
 
-pub fn print_network(network: &[crate::model::NetworkInfo]) {
-    println!("\n{}", "Network Interfaces".bold().cyan());
+pub fn print_io(disk_io: &[DiskIoInfo]) {
+    println!("\n{}", t("Disk I/O").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            t("Device"),
+            t("Read (MiB/s)"),
+            t("Write (MiB/s)"),
+            t("Read IOPS"),
+            t("Write IOPS"),
+        ]);
+
+    for io in disk_io {
+        table.add_row(vec![
+            Cell::new(&io.name),
+            Cell::new(format!("{:.2}", io.read_bytes_per_sec / 1024.0 / 1024.0)),
+            Cell::new(format!("{:.2}", io.write_bytes_per_sec / 1024.0 / 1024.0)),
+            Cell::new(format!("{:.0}", io.read_iops)),
+            Cell::new(format!("{:.0}", io.write_iops)),
+        ]);
+    }
+    println!("{table}");
+}
+
+/// The busiest interrupt sources. "CPUs" is where they were handled during
+/// the sample, which on a multi-queue NIC or NVMe drive should be spread out;
+/// "Affinity" is where they are allowed to go.
+pub fn print_irqs(irqs: &[IrqInfo]) {
+    const SHOWN: usize = 15;
+    println!("\n{}", t("Interrupts").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            t("IRQ"),
+            t("Device"),
+            t("Rate (/s)"),
+            t("CPUs"),
+            t("Affinity"),
+        ]);
+
+    for irq in irqs.iter().take(SHOWN) {
+        table.add_row(vec![
+            Cell::new(&irq.irq),
+            Cell::new(&irq.name),
+            Cell::new(format!("{:.0}", irq.rate)),
+            Cell::new(irq_cpu_shares(irq)),
+            Cell::new(irq.affinity.as_deref().unwrap_or("N/A")),
+        ]);
+    }
+    println!("{table}");
+    if irqs.len() > SHOWN {
+        println!("+{} {}", irqs.len() - SHOWN, t("more"));
+    }
+}
+
+/// The CPUs that handled the most of an IRQ, e.g. "0: 92%, 3: 8%".
+fn irq_cpu_shares(irq: &IrqInfo) -> String {
+    let mut shares: Vec<(usize, f64)> = irq
+        .per_cpu
+        .iter()
+        .enumerate()
+        .filter(|(_, rate)| **rate > 0.0)
+        .map(|(cpu, rate)| (cpu, rate * 100.0 / irq.rate))
+        .collect();
+    shares.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let mut cells: Vec<String> = shares
+        .iter()
+        .take(3)
+        .map(|(cpu, share)| format!("{}: {:.0}%", cpu, share))
+        .collect();
+    if shares.len() > 3 {
+        cells.push(format!("+{}", shares.len() - 3));
+    }
+    cells.join(", ")
+}
+
+pub fn interface_kind_label(kind: InterfaceKind) -> &'static str {
+    match kind {
+        InterfaceKind::Physical => t("Physical"),
+        InterfaceKind::Loopback => "Loopback",
+        InterfaceKind::Bridge => "Bridge",
+        InterfaceKind::Bond => "Bond",
+        InterfaceKind::Vlan => "VLAN",
+        InterfaceKind::Veth => "veth",
+        InterfaceKind::Tun => "TUN",
+        InterfaceKind::Tap => "TAP",
+        InterfaceKind::WireGuard => "WireGuard",
+        InterfaceKind::Virtual => t("Virtual"),
+    }
+}
+
+/// Virtual interfaces are left out unless `all` is set.
+pub fn print_network(
+    network: &[crate::model::NetworkInfo],
+    network_config: Option<&NetworkConfig>,
+    all: bool,
+) {
+    println!("\n{}", t("Network Interfaces").bold().color(theme().title));
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
         .set_header(vec![
-            "Interface",
+            t("Interface"),
+            t("Type"),
             "MAC",
-            "Received (MiB)",
-            "Transmitted (MiB)",
+            t("Received (MiB)"),
+            t("Transmitted (MiB)"),
         ]);
 
-    for net in network {
+    for net in network.iter().filter(|net| all || !net.is_virtual()) {
         table.add_row(vec![
             Cell::new(&net.name),
+            Cell::new(net.kind.map_or("N/A", interface_kind_label)),
             Cell::new(&net.mac_address),
             Cell::new(format!("{:.2}", net.received as f64 / 1024.0 / 1024.0)),
             Cell::new(format!("{:.2}", net.transmitted as f64 / 1024.0 / 1024.0)),
         ]);
     }
     println!("{table}");
+
+    if let Some(config) = network_config {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![t("Network Configuration"), t("Information")]);
+        let gateway = |gateway: &Option<crate::model::Gateway>| match gateway {
+            Some(gateway) => format!("{} ({})", gateway.address, gateway.interface),
+            None => "N/A".to_string(),
+        };
+        table.add_row(vec![
+            Cell::new(t("Default gateway (IPv4)")),
+            Cell::new(gateway(&config.gateway_v4)),
+        ]);
+        table.add_row(vec![
+            Cell::new(t("Default gateway (IPv6)")),
+            Cell::new(gateway(&config.gateway_v6)),
+        ]);
+        table.add_row(vec![
+            Cell::new(t("DNS servers")),
+            Cell::new(match config.dns_servers.is_empty() {
+                true => "N/A".to_string(),
+                false => config.dns_servers.join(", "),
+            }),
+        ]);
+        table.add_row(vec![
+            Cell::new(t("Primary interface")),
+            Cell::new(config.primary_interface.as_deref().unwrap_or("N/A")),
+        ]);
+        println!("{table}");
+    }
+
+    // Shown whatever `all` says: a degraded bond matters even when bonds are hidden above
+    let groups: Vec<_> = network
+        .iter()
+        .filter_map(|net| Some((net, net.members.as_ref()?)))
+        .collect();
+    if groups.is_empty() {
+        return;
+    }
+    println!("\n{}", t("Bonds & Bridges").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            t("Interface"),
+            t("Type"),
+            t("Mode"),
+            t("Active"),
+            t("Members"),
+        ]);
+    for (net, members) in groups {
+        let ports = members
+            .ports
+            .iter()
+            .map(|port| format!("{} ({})", port.name, port.state))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let down = members.down().count();
+        let ports = match ports.as_str() {
+            "" => Cell::new("-"),
+            _ if down == members.ports.len() => Cell::new(ports).fg(theme().critical.into()),
+            _ if down > 0 => Cell::new(ports).fg(theme().warning.into()),
+            _ => Cell::new(ports),
+        };
+        table.add_row(vec![
+            Cell::new(&net.name),
+            Cell::new(net.kind.map_or("N/A", interface_kind_label)),
+            Cell::new(members.mode.as_deref().unwrap_or("-")),
+            Cell::new(members.active.as_deref().unwrap_or("-")),
+            ports,
+        ]);
+    }
+    println!("{table}");
+}
+
+pub fn print_connectivity(connectivity: &Connectivity) {
+    println!("\n{}", t("Connectivity").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            t("Check"),
+            t("Host"),
+            t("Result"),
+            t("Latency (ms)"),
+            t("Loss (%)"),
+        ]);
+    let latency = |ms: Option<f64>| ms.map_or("N/A".to_string(), |ms| format!("{:.1}", ms));
+    let pings = connectivity
+        .gateway
+        .iter()
+        .map(|ping| (t("Gateway"), ping))
+        .chain([(t("Target"), &connectivity.target)]);
+    for (check, ping) in pings {
+        let result = match ping.received {
+            _ if ping.error.is_some() => Cell::new(t("ping not available")),
+            0 => Cell::new(t("Unreachable")).fg(theme().critical.into()),
+            n if n < ping.sent => Cell::new(t("Packet loss")).fg(theme().warning.into()),
+            _ => Cell::new(t("Reachable")).fg(theme().ok.into()),
+        };
+        table.add_row(vec![
+            Cell::new(check),
+            Cell::new(&ping.host),
+            result,
+            Cell::new(latency(ping.avg_latency_ms)),
+            Cell::new(match ping.error {
+                Some(_) => "-".to_string(),
+                None => format!("{:.0}", ping.loss_percent),
+            }),
+        ]);
+    }
+    let dns = &connectivity.dns;
+    table.add_row(vec![
+        Cell::new("DNS"),
+        Cell::new(&dns.name),
+        match dns.resolved {
+            true => Cell::new(t("Resolved")).fg(theme().ok.into()),
+            false => Cell::new(t("Failed")).fg(theme().critical.into()),
+        },
+        Cell::new(latency(dns.latency_ms)),
+        Cell::new("-"),
+    ]);
+    println!("{table}");
+
+    let (verdict, color) = match connectivity.verdict {
+        ConnectivityVerdict::Online => (t("Online"), theme().ok),
+        ConnectivityVerdict::Degraded => (t("Degraded"), theme().warning),
+        ConnectivityVerdict::Offline => (t("Offline"), theme().critical),
+    };
+    println!("{}: {}", t("Verdict"), verdict.bold().color(color));
 }
 
 pub fn print_usb(usb: &[UsbDevice]) {
     if usb.is_empty() {
         return;
     }
-    println!("\n{}", "USB Devices".bold().cyan());
+    println!("\n{}", t("USB Devices").bold().color(theme().title));
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
-        .set_header(vec!["Bus/Addr", "ID", "Manufacturer", "Product"]);
+        .set_header(vec![t("Bus/Addr"), "ID", t("Manufacturer"), t("Product")]);
 
     for dev in usb {
         table.add_row(vec![
             Cell::new(format!("{:03}/{:03}", dev.bus, dev.address)),
             Cell::new(format!("{:04x}:{:04x}", dev.vendor_id, dev.product_id)),
-            Cell::new(dev.manufacturer.as_deref().unwrap_or("Unknown")),
-            Cell::new(dev.product.as_deref().unwrap_or("Unknown")),
+            Cell::new(dev.manufacturer.as_deref().unwrap_or(t("Unknown"))),
+            Cell::new(dev.product.as_deref().unwrap_or(t("Unknown"))),
+        ]);
+    }
+    println!("{table}");
+}
+
+pub fn print_thunderbolt(devices: &[ThunderboltDevice]) {
+    if devices.is_empty() {
+        return;
+    }
+    println!("\n{}", t("Thunderbolt Devices").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![t("Device"), t("Vendor"), t("Speed")]);
+    for dev in devices {
+        table.add_row(vec![
+            Cell::new(&dev.name),
+            Cell::new(dev.vendor.as_deref().unwrap_or(t("Unknown"))),
+            Cell::new(dev.speed.as_deref().unwrap_or("N/A")),
         ]);
     }
     println!("{table}");
 }
 
+pub fn print_gpus(gpus: &[GpuInfo], processes: &[GpuProcess]) {
+    if gpus.is_empty() {
+        return;
+    }
+    let mib =
+        |bytes: Option<u64>| bytes.map_or("N/A".to_string(), |b| (b / 1024 / 1024).to_string());
+    println!("\n{}", t("GPUs").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![t("Slot"), t("Name"), t("Driver"), t("VRAM (MiB)")]);
+    for gpu in gpus {
+        table.add_row(vec![
+            Cell::new(&gpu.slot),
+            Cell::new(&gpu.name),
+            Cell::new(gpu.driver.as_deref().unwrap_or("N/A")),
+            Cell::new(mib(gpu.vram_total)),
+        ]);
+    }
+    println!("{table}");
+
+    if processes.is_empty() {
+        return;
+    }
+    let mut processes: Vec<&GpuProcess> = processes.iter().collect();
+    processes.sort_by(|a, b| b.vram_used.cmp(&a.vram_used).then(a.pid.cmp(&b.pid)));
+    println!("\n{}", t("GPU Processes").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![t("Slot"), "PID", t("Name"), t("VRAM (MiB)")]);
+    for process in processes {
+        table.add_row(vec![
+            Cell::new(&process.slot),
+            Cell::new(process.pid),
+            Cell::new(&process.name),
+            Cell::new(mib(process.vram_used)),
+        ]);
+    }
+    println!("{table}");
+}
+
+pub fn print_limits(limits: &[KernelLimit]) {
+    if limits.is_empty() {
+        return;
+    }
+    println!("\n{}", t("Kernel Limits").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![t("Limit"), t("Used"), t("Max"), t("Usage (%)")]);
+    for limit in limits {
+        let usage = match limit.usage_percent() {
+            Some(usage) => Cell::new(format!("{:.1}", usage))
+                .fg(theme().level(usage, &Thresholds::default()).into()),
+            None => Cell::new("N/A"),
+        };
+        table.add_row(vec![
+            Cell::new(&limit.name),
+            Cell::new(
+                limit
+                    .used
+                    .map_or("N/A".to_string(), |used| used.to_string()),
+            ),
+            Cell::new(limit.limit),
+            usage,
+        ]);
+    }
+    println!("{table}");
+}
+
+pub fn print_time(time: Option<&TimeHealth>) {
+    let Some(time) = time else {
+        return;
+    };
+    println!("\n{}", t("Time").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![t("Setting"), t("Value")]);
+    let synchronized = t("NTP synchronized");
+    for (label, value) in time_rows(time) {
+        let cell = match time.ntp_synchronized {
+            Some(true) if label == synchronized => Cell::new(value).fg(theme().ok.into()),
+            Some(false) if label == synchronized => Cell::new(value).fg(theme().critical.into()),
+            _ => Cell::new(value),
+        };
+        table.add_row(vec![Cell::new(label), cell]);
+    }
+    println!("{table}");
+}
+
+pub fn time_rows(time: &TimeHealth) -> Vec<(String, String)> {
+    let na = || "N/A".to_string();
+    let clocksource = match &time.clocksource {
+        Some(current) if time.available_clocksources.len() > 1 => format!(
+            "{} ({}: {})",
+            current,
+            t("available"),
+            time.available_clocksources.join(", ")
+        ),
+        Some(current) => current.clone(),
+        None => na(),
+    };
+    let synchronized = match time.ntp_synchronized {
+        Some(synced) => format!(
+            "{}{}",
+            if synced { t("Yes") } else { t("No") },
+            time.sync_daemon
+                .as_ref()
+                .map_or(String::new(), |daemon| format!(" ({})", daemon))
+        ),
+        None => na(),
+    };
+    vec![
+        (t("Clock source").to_string(), clocksource),
+        (t("NTP synchronized").to_string(), synchronized),
+        (
+            t("NTP server").to_string(),
+            time.ntp_server.clone().unwrap_or_else(na),
+        ),
+        (
+            t("NTP offset").to_string(),
+            time.ntp_offset
+                .map_or_else(na, |offset| format!("{:+.6} s", offset)),
+        ),
+        (
+            "RTC".to_string(),
+            time.rtc.clone().unwrap_or_else(|| t("None").to_string()),
+        ),
+        (
+            t("RTC offset").to_string(),
+            time.rtc_offset
+                .map_or_else(na, |offset| format!("{:+} s", offset)),
+        ),
+    ]
+}
+
 pub fn print_pci(pci: &[PciDevice]) {
     if pci.is_empty() {
         return;
     }
-    println!("\n{}", "PCI Devices".bold().cyan());
+    println!("\n{}", t("PCI Devices").bold().color(theme().title));
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL)
         .apply_modifier(UTF8_ROUND_CORNERS)
-        .set_header(vec!["Slot", "ID", "Name/Class"]);
+        .set_content_arrangement(ContentArrangement::Dynamic);
+    // Columns only for what the platform's listing has
+    let revision = pci.iter().any(|dev| dev.revision.is_some());
+    let subsystem = pci.iter().any(|dev| dev.subsystem_vendor_id.is_some());
+    let numa = pci.iter().any(|dev| dev.numa_node.is_some());
+    let iommu = pci.iter().any(|dev| dev.iommu_group.is_some());
+    let mut header = vec![t("Slot"), "ID"];
+    for (shown, label) in [
+        (revision, t("Rev")),
+        (subsystem, t("Subsystem")),
+        (numa, "NUMA"),
+        (iommu, "IOMMU"),
+    ] {
+        if shown {
+            header.push(label);
+        }
+    }
+    header.push(t("Name/Class"));
+    table.set_header(header);
 
+    let na = || "N/A".to_string();
     for dev in pci {
-        table.add_row(vec![
+        let mut row = vec![
             Cell::new(&dev.slot),
             Cell::new(format!("{:04x}:{:04x}", dev.vendor_id, dev.device_id)),
-            Cell::new(format!(
-                "{} {}",
-                dev.vendor_name.as_deref().unwrap_or("Unknown"),
-                dev.device_name.as_deref().unwrap_or("Device")
-            )),
-        ]);
+        ];
+        if revision {
+            row.push(Cell::new(
+                dev.revision.map_or_else(na, |r| format!("{:02x}", r)),
+            ));
+        }
+        if subsystem {
+            row.push(Cell::new(
+                match (dev.subsystem_vendor_id, dev.subsystem_device_id) {
+                    (Some(vendor), Some(device)) => format!("{:04x}:{:04x}", vendor, device),
+                    _ => na(),
+                },
+            ));
+        }
+        if numa {
+            row.push(Cell::new(dev.numa_node.map_or_else(na, |n| n.to_string())));
+        }
+        if iommu {
+            row.push(Cell::new(
+                dev.iommu_group.map_or_else(na, |g| g.to_string()),
+            ));
+        }
+        row.push(Cell::new(format!(
+            "{} {}",
+            dev.vendor_name.as_deref().unwrap_or(t("Unknown")),
+            dev.device_name.as_deref().unwrap_or(t("Device"))
+        )));
+        table.add_row(row);
     }
     println!("{table}");
 }
 
-pub fn print_health(motherboard: Option<&MotherboardInfo>, battery: &[BatteryInfo]) {
+/// Remapping state, every group with its members, then whether each GPU
+/// could be handed to a VM on its own.
+pub fn print_iommu(iommu: Option<&IommuInfo>) {
+    println!("\n{}", t("IOMMU").bold().color(theme().title));
+    let Some(iommu) = iommu else {
+        println!("{}", t("IOMMU state is only read on Linux"));
+        return;
+    };
+    if iommu.enabled {
+        let kind = iommu.kind.as_deref().unwrap_or("IOMMU");
+        println!(
+            "{} {} ({} {})",
+            kind,
+            t("enabled").color(theme().ok),
+            iommu.groups.len(),
+            t("groups")
+        );
+    } else {
+        println!("{}", t("IOMMU disabled").color(theme().warning));
+        println!(
+            "  {}",
+            t(
+                "Enable VT-d (Intel) or AMD-Vi/IOMMU (AMD) in the firmware setup and boot with intel_iommu=on or amd_iommu=on"
+            )
+        );
+    }
+    if !iommu.kernel_options.is_empty() {
+        println!(
+            "{}: {}",
+            t("Kernel options"),
+            iommu.kernel_options.join(" ")
+        );
+    }
+    if iommu.groups.is_empty() {
+        return;
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![t("Group"), t("Slot"), t("Device"), t("Driver")]);
+    for group in &iommu.groups {
+        for (i, device) in group.devices.iter().enumerate() {
+            let id = if i == 0 {
+                match group.domain.as_deref() {
+                    Some("identity") => format!("{} (passthrough)", group.id),
+                    _ => group.id.to_string(),
+                }
+            } else {
+                String::new()
+            };
+            table.add_row(vec![
+                Cell::new(id),
+                Cell::new(&device.slot),
+                Cell::new(device.name.as_deref().unwrap_or(t("Unknown"))),
+                Cell::new(device.driver.as_deref().unwrap_or("-")),
+            ]);
+        }
+    }
+    println!("{table}");
+
+    let gpus: Vec<(&IommuGroup, &IommuDevice)> = iommu
+        .groups
+        .iter()
+        .flat_map(|group| {
+            group
+                .devices
+                .iter()
+                .filter(|d| d.is_gpu())
+                .map(move |d| (group, d))
+        })
+        .collect();
+    if gpus.is_empty() {
+        return;
+    }
+    println!("\n{}", t("GPU passthrough").bold());
+    for (group, gpu) in gpus {
+        let name = gpu.name.as_deref().unwrap_or(&gpu.slot);
+        let companions = group.companions(gpu);
+        let verdict = if companions.is_empty() {
+            format!(
+                "{} {}: {}",
+                t("group"),
+                group.id,
+                t("isolated, can be passed through")
+            )
+            .color(theme().ok)
+        } else {
+            let slots: Vec<&str> = companions.iter().map(|d| d.slot.as_str()).collect();
+            format!(
+                "{} {} {}: {}",
+                t("group"),
+                group.id,
+                t("shared with"),
+                slots.join(", ")
+            )
+            .color(theme().warning)
+        };
+        let vfio = if gpu.driver.as_deref() == Some("vfio-pci") {
+            format!(" [{}]", t("bound to vfio-pci"))
+        } else {
+            String::new()
+        };
+        println!("  {} ({}) — {}{}", name, gpu.slot, verdict, vfio);
+    }
+}
+
+pub fn print_health(
+    motherboard: Option<&MotherboardInfo>,
+    battery: &[BatteryInfo],
+    platform: Option<&PlatformState>,
+    battery_config: &BatteryConfig,
+) {
     if let Some(mb) = motherboard {
-        println!("\n{}", "Motherboard & BIOS".bold().cyan());
+        println!("\n{}", t("Motherboard & BIOS").bold().color(theme().title));
         let mut table = Table::new();
         table
             .load_preset(UTF8_FULL)
             .apply_modifier(UTF8_ROUND_CORNERS)
-            .set_header(vec!["Component", "Information"]);
+            .set_header(vec![t("Component"), t("Information")]);
 
-        table.add_row(vec![Cell::new("Manufacturer"), Cell::new(&mb.vendor)]);
-        table.add_row(vec![Cell::new("Product"), Cell::new(&mb.product)]);
-        table.add_row(vec![Cell::new("BIOS Vendor"), Cell::new(&mb.bios_vendor)]);
-        table.add_row(vec![Cell::new("BIOS Version"), Cell::new(&mb.bios_version)]);
-        table.add_row(vec![Cell::new("BIOS Date"), Cell::new(&mb.bios_date)]);
+        table.add_row(vec![Cell::new(t("Manufacturer")), Cell::new(&mb.vendor)]);
+        table.add_row(vec![Cell::new(t("Product")), Cell::new(&mb.product)]);
+        table.add_row(vec![
+            Cell::new(t("BIOS Vendor")),
+            Cell::new(&mb.bios_vendor),
+        ]);
+        table.add_row(vec![
+            Cell::new(t("BIOS Version")),
+            Cell::new(&mb.bios_version),
+        ]);
+        table.add_row(vec![Cell::new(t("BIOS Date")), Cell::new(&mb.bios_date)]);
         println!("{table}");
     }
 
     if !battery.is_empty() {
-        println!("\n{}", "Battery Status".bold().cyan());
+        println!("\n{}", t("Battery Status").bold().color(theme().title));
         let mut table = Table::new();
         table
             .load_preset(UTF8_FULL)
             .apply_modifier(UTF8_ROUND_CORNERS)
-            .set_header(vec!["Battery", "Status", "Capacity (%)"]);
+            .set_header(vec![
+                t("Battery"),
+                t("Status"),
+                t("Capacity (%)"),
+                t("Wear (%)"),
+                t("Cycles"),
+                t("Rate (W)"),
+                t("Time Left"),
+            ]);
 
         for bat in battery {
-            let color = if bat.capacity < 20 {
-                Color::Red
-            } else if bat.capacity < 50 {
-                Color::Yellow
-            } else {
-                Color::Green
+            let color = Color::from(
+                theme().alert(AlertLevel::classify_charge(bat.capacity, battery_config)),
+            );
+            let wear = match bat.wear_percent {
+                Some(wear) => Cell::new(format!("{:.1}", wear)).fg(Color::from(
+                    theme().alert(AlertLevel::classify_wear(wear, battery_config)),
+                )),
+                None => Cell::new("N/A"),
             };
 
             table.add_row(vec![
                 Cell::new(&bat.name),
                 Cell::new(&bat.status),
                 Cell::new(bat.capacity.to_string()).fg(color),
+                wear,
+                Cell::new(
+                    bat.cycle_count
+                        .map(|c| c.to_string())
+                        .unwrap_or_else(|| "N/A".to_string()),
+                ),
+                Cell::new(
+                    bat.power_watts
+                        .map(|w| format!("{:.2}", w))
+                        .unwrap_or_else(|| "N/A".to_string()),
+                ),
+                Cell::new(battery_time_left(bat).unwrap_or_else(|| "N/A".to_string())),
             ]);
         }
         println!("{table}");
     }
+
+    if let Some(platform) = platform {
+        println!("\n{}", t("Platform State").bold().color(theme().title));
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![t("Component"), t("Information")]);
+
+        if let Some(lid) = &platform.lid {
+            let lid = match lid.as_str() {
+                "open" => t("Open"),
+                "closed" => t("Closed"),
+                other => other,
+            };
+            table.add_row(vec![Cell::new(t("Lid")), Cell::new(lid)]);
+        }
+        if let Some(docked) = platform.docked {
+            let docked = if docked { t("Docked") } else { t("Undocked") };
+            table.add_row(vec![Cell::new(t("Dock")), Cell::new(docked)]);
+        }
+        if let Some(throttling) = platform.throttling {
+            let cell = if throttling {
+                Cell::new(t("Active")).fg(theme().warning.into())
+            } else {
+                Cell::new(t("Inactive"))
+            };
+            table.add_row(vec![Cell::new(t("Thermal Throttling")), cell]);
+        }
+        if let Some(events) = platform.throttle_events {
+            table.add_row(vec![
+                Cell::new(t("Throttle Events")),
+                Cell::new(events.to_string()),
+            ]);
+        }
+        println!("{table}");
+    }
+}
+
+/// Plugin and collector output, one row per scalar.
+pub fn print_custom(custom: &BTreeMap<String, Value>) {
+    if custom.is_empty() {
+        return;
+    }
+    println!("\n{}", t("Custom").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![t("Name"), t("Field"), t("Value")]);
+    for row in custom_rows(custom) {
+        table.add_row(row);
+    }
+    println!("{table}");
+}
+
+/// `(name, dotted.path, scalar)` for every scalar in the plugin and
+/// collector output; a bare scalar gets an empty path.
+pub fn custom_fields(custom: &BTreeMap<String, Value>) -> Vec<(&str, String, &Value)> {
+    fn flatten<'a>(path: String, value: &'a Value, fields: &mut Vec<(String, &'a Value)>) {
+        let join = |key: &str| {
+            if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{}.{}", path, key)
+            }
+        };
+        match value {
+            Value::Object(object) => {
+                for (key, value) in object {
+                    flatten(join(key), value, fields);
+                }
+            }
+            Value::Array(items) => {
+                for (i, value) in items.iter().enumerate() {
+                    flatten(join(&i.to_string()), value, fields);
+                }
+            }
+            scalar => fields.push((path, scalar)),
+        }
+    }
+    let mut out = Vec::new();
+    for (name, value) in custom {
+        let mut fields = Vec::new();
+        flatten(String::new(), value, &mut fields);
+        out.extend(
+            fields
+                .into_iter()
+                .map(|(path, value)| (name.as_str(), path, value)),
+        );
+    }
+    out
+}
+
+/// [`custom_fields`] as `[name, path, value]` table rows.
+pub fn custom_rows(custom: &BTreeMap<String, Value>) -> Vec<Vec<String>> {
+    custom_fields(custom)
+        .into_iter()
+        .map(|(name, path, value)| {
+            let value = match value {
+                Value::Null => "N/A".to_string(),
+                Value::String(s) => s.clone(),
+                scalar => scalar.to_string(),
+            };
+            vec![name.to_string(), path, value]
+        })
+        .collect()
+}
+
+/// The SMBIOS view of the board: what sits in which socket, slot and port.
+pub fn print_board_layout(layout: &BoardLayout) {
+    let na = || "N/A".to_string();
+    let table = |header: Vec<&str>| {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(header);
+        table
+    };
+
+    if !layout.sockets.is_empty() {
+        println!("\n{}", t("Processor Sockets").bold().color(theme().title));
+        let mut sockets = table(vec![
+            t("Socket"),
+            t("Type"),
+            t("Processor"),
+            t("Cores/Threads"),
+            t("Speed (MHz)"),
+        ]);
+        for socket in &layout.sockets {
+            let processor = if socket.populated {
+                socket.version.clone().unwrap_or_else(na)
+            } else {
+                t("Empty").to_string()
+            };
+            let count =
+                |n: Option<u16>| n.map(|n| n.to_string()).unwrap_or_else(|| "?".to_string());
+            let speed = match (socket.current_speed, socket.max_speed) {
+                (Some(current), Some(max)) => format!("{} / {}", current, max),
+                (Some(mhz), None) | (None, Some(mhz)) => mhz.to_string(),
+                (None, None) => na(),
+            };
+            sockets.add_row(vec![
+                Cell::new(&socket.designation),
+                Cell::new(socket.socket.clone().unwrap_or_else(na)),
+                Cell::new(processor),
+                Cell::new(if socket.populated {
+                    format!("{}/{}", count(socket.cores), count(socket.threads))
+                } else {
+                    na()
+                }),
+                Cell::new(speed),
+            ]);
+        }
+        println!("{sockets}");
+    }
+
+    if !layout.caches.is_empty() {
+        println!("\n{}", t("Caches (Firmware)").bold().color(theme().title));
+        let mut caches = table(vec![
+            t("Cache"),
+            t("Level"),
+            t("Type"),
+            t("Size"),
+            t("Associativity"),
+            t("Socket"),
+        ]);
+        for cache in &layout.caches {
+            let size = match cache.size {
+                Some(bytes) if cache.enabled => format!("{} KB", bytes / 1024),
+                Some(_) => t("Disabled").to_string(),
+                None => na(),
+            };
+            caches.add_row(vec![
+                Cell::new(&cache.designation),
+                Cell::new(format!("L{}", cache.level)),
+                Cell::new(cache.kind.clone().unwrap_or_else(na)),
+                Cell::new(size),
+                Cell::new(cache.associativity.clone().unwrap_or_else(na)),
+                Cell::new(cache.socket.clone().unwrap_or_else(na)),
+            ]);
+        }
+        println!("{caches}");
+    }
+
+    if !layout.ports.is_empty() {
+        println!("\n{}", t("Port Connectors").bold().color(theme().title));
+        let mut ports = table(vec![t("Port"), t("Internal"), t("Connector"), t("Type")]);
+        for port in &layout.ports {
+            ports.add_row(vec![
+                Cell::new(port.external.clone().unwrap_or_else(na)),
+                Cell::new(port.internal.clone().unwrap_or_else(na)),
+                Cell::new(port.connector.clone().unwrap_or_else(na)),
+                Cell::new(port.port_type.clone().unwrap_or_else(na)),
+            ]);
+        }
+        println!("{ports}");
+    }
+
+    print_slot_map(&layout.slots);
+}
+
+/// One line per expansion slot with its lanes drawn to scale (solid: wired,
+/// shaded: physical only) and what is installed, then the free slots by
+/// width: "is there a free x16 slot?" at a glance.
+pub fn print_slot_map(slots: &[ExpansionSlot]) {
+    if slots.is_empty() {
+        return;
+    }
+    println!("\n{}", t("Slot Map").bold().color(theme().title));
+    let kind = |slot: &ExpansionSlot| match slot.width {
+        Some(width) => format!("{} x{}", slot.slot_type, width),
+        None => slot.slot_type.clone(),
+    };
+    let bar = |slot: &ExpansionSlot| {
+        let Some(width) = slot.width.or(slot.lanes) else {
+            return String::new();
+        };
+        let width = usize::from(width).min(16);
+        let wired = slot
+            .lanes
+            .map_or(width, |lanes| usize::from(lanes).min(width));
+        format!("{}{}", "█".repeat(wired), "░".repeat(width - wired))
+    };
+    let name_width = slots
+        .iter()
+        .map(|s| s.designation.chars().count())
+        .max()
+        .unwrap_or(0);
+    let kind_width = slots
+        .iter()
+        .map(|s| kind(s).chars().count())
+        .max()
+        .unwrap_or(0);
+    let bar_width = slots
+        .iter()
+        .map(|s| bar(s).chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut free: Vec<(String, Vec<String>)> = Vec::new();
+    for slot in slots {
+        let installed = if slot.is_free() {
+            let label = slot
+                .width
+                .map(|width| format!("x{}", width))
+                .unwrap_or_else(|| slot.slot_type.clone());
+            let mut name = slot.designation.clone();
+            if let (Some(width), Some(lanes)) = (slot.width, slot.lanes)
+                && lanes < width
+            {
+                name = format!("{} ({} x{})", name, t("wired"), lanes);
+            }
+            match free.iter_mut().find(|(l, _)| *l == label) {
+                Some((_, names)) => names.push(name),
+                None => free.push((label, vec![name])),
+            }
+            t("free").color(theme().ok).to_string()
+        } else {
+            let mut device = slot
+                .device_name
+                .clone()
+                .or_else(|| slot.device.clone())
+                .unwrap_or_else(|| t("In Use").to_string());
+            if let (Some(generation), Some(width)) = (slot.link_generation, slot.link_width) {
+                device = format!("{} [{} Gen{} x{}]", device, t("link"), generation, width);
+            }
+            device
+        };
+        println!(
+            "  {}  {:<kind_width$}  {}  {}",
+            format!("{:<name_width$}", slot.designation).bold(),
+            kind(slot),
+            format!("{:<bar_width$}", bar(slot)).color(theme().accent),
+            installed,
+        );
+    }
+
+    free.sort_by_key(|(label, _)| {
+        std::cmp::Reverse(
+            label
+                .strip_prefix('x')
+                .and_then(|w| w.parse::<u8>().ok())
+                .unwrap_or(0),
+        )
+    });
+    let summary = if free.is_empty() {
+        t("none").to_string()
+    } else {
+        free.iter()
+            .map(|(label, names)| format!("{} ×{}: {}", label, names.len(), names.join(", ")))
+            .collect::<Vec<_>>()
+            .join("; ")
+    };
+    println!("  {}: {}", t("Free slots").bold(), summary);
+}
+
+/// e.g. "2h 5m to empty" while discharging, "40m to full" while charging.
+pub fn battery_time_left(battery: &BatteryInfo) -> Option<String> {
+    let seconds = battery.time_remaining?;
+    let until = if battery.status == "Charging" {
+        t("to full")
+    } else {
+        t("to empty")
+    };
+    Some(format!("{} {}", format_uptime(seconds), until))
 }
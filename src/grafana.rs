@@ -0,0 +1,350 @@
+//! `hw-checker grafana`: the recorded history over HTTP in the shape of
+//! Grafana's JSON datasource plugin, so dashboards can chart what `record`
+//! writes without an exporter in between. `POST /search` lists metric names,
+//! `POST /query` returns their time series and `POST /annotations` marks
+//! where a metric crossed its warning or critical threshold.
+
+use crate::alerts::AlertLevel;
+use crate::cli::GrafanaArgs;
+use crate::config::Config;
+use crate::history::{HistoryDb, Series, database_path, format_value, thresholds_for};
+use crate::i18n::t;
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::time::Duration;
+
+const MAX_BODY: usize = 1024 * 1024;
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct Range {
+    from: String,
+    to: String,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct SearchRequest {
+    target: String,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct QueryRequest {
+    range: Range,
+    targets: Vec<Target>,
+    max_data_points: Option<usize>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct Target {
+    target: String,
+    hide: bool,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct AnnotationRequest {
+    range: Range,
+    annotation: Value,
+}
+
+pub fn run_grafana(args: &GrafanaArgs, config: &Config) -> Result<()> {
+    let path = database_path(args.database.as_deref(), config);
+    // Fail at startup rather than on Grafana's first "Save & test"
+    HistoryDb::open_existing(&path)?;
+    let listener =
+        TcpListener::bind(&args.bind).with_context(|| format!("failed to bind {}", args.bind))?;
+    eprintln!(
+        "{} http://{} ({})",
+        t("Serving"),
+        listener.local_addr()?,
+        path.display()
+    );
+
+    accept(listener, &path, config)
+}
+
+/// One request at a time: Grafana retries, and SQLite reads are quick.
+#[cfg(not(feature = "async"))]
+fn accept(listener: TcpListener, path: &Path, config: &Config) -> Result<()> {
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("{}: {}", t("Connection failed"), err);
+                continue;
+            }
+        };
+        if let Err(err) = serve(stream, path, config) {
+            eprintln!("{}: {:#}", t("Request failed"), err);
+        }
+    }
+    Ok(())
+}
+
+/// Accepts on the shared runtime and answers on its blocking pool, since
+/// SQLite has no async API: a few requests at a time, the rest queued.
+#[cfg(feature = "async")]
+fn accept(listener: TcpListener, path: &Path, config: &Config) -> Result<()> {
+    use std::sync::Arc;
+
+    listener.set_nonblocking(true)?;
+    let config = Arc::new(config.clone());
+    crate::runtime::runtime().block_on(async {
+        let listener = tokio::net::TcpListener::from_std(listener)?;
+        loop {
+            let stream = match listener.accept().await.and_then(|(stream, _)| {
+                let stream = stream.into_std()?;
+                stream.set_nonblocking(false)?;
+                Ok(stream)
+            }) {
+                Ok(stream) => stream,
+                Err(err) => {
+                    eprintln!("{}: {}", t("Connection failed"), err);
+                    continue;
+                }
+            };
+            let (path, config) = (path.to_path_buf(), config.clone());
+            tokio::task::spawn_blocking(move || {
+                if let Err(err) = serve(stream, &path, &config) {
+                    eprintln!("{}: {:#}", t("Request failed"), err);
+                }
+            });
+        }
+    })
+}
+
+fn serve(stream: TcpStream, path: &Path, config: &Config) -> Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        bail!("malformed request line");
+    };
+    let route = target.split('?').next().unwrap_or_default();
+
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            length = value.trim().parse().context("bad Content-Length")?;
+        }
+    }
+    if length > MAX_BODY {
+        return respond(&stream, 413, &json!({"error": "request body too large"}));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    let (status, reply) = match handle(method, route, &body, path, config) {
+        Ok(Some(reply)) => (200, reply),
+        Ok(None) => (
+            404,
+            json!({"error": format!("no route for {} {}", method, route)}),
+        ),
+        Err(err) => (400, json!({"error": format!("{:#}", err)})),
+    };
+    respond(&stream, status, &reply)
+}
+
+fn respond(mut stream: &TcpStream, status: u16, body: &Value) -> Result<()> {
+    let reason = match status {
+        200 => "OK",
+        404 => "Not Found",
+        413 => "Payload Too Large",
+        _ => "Bad Request",
+    };
+    let body = serde_json::to_vec(body)?;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason,
+        body.len()
+    )?;
+    stream.write_all(&body)?;
+    Ok(())
+}
+
+/// The JSON reply for one request, or `None` for an unknown route.
+fn handle(
+    method: &str,
+    route: &str,
+    body: &[u8],
+    path: &Path,
+    config: &Config,
+) -> Result<Option<Value>> {
+    let parse = |body: &[u8]| -> Result<Value> {
+        if body.iter().all(u8::is_ascii_whitespace) {
+            Ok(json!({}))
+        } else {
+            serde_json::from_slice(body).context("request body is not JSON")
+        }
+    };
+    let reply = match (method, route) {
+        // The datasource's "Save & test"
+        ("GET", "/") => json!({"status": "ok"}),
+        ("POST", "/search") => {
+            let request: SearchRequest = serde_json::from_value(parse(body)?)?;
+            json!(HistoryDb::open_existing(path)?.metrics(&request.target)?)
+        }
+        ("POST", "/query") => {
+            let request: QueryRequest = serde_json::from_value(parse(body)?)?;
+            let (since, until) = range(&request.range)?;
+            let db = HistoryDb::open_existing(path)?;
+            let mut replies = Vec::new();
+            for target in request.targets.iter().filter(|target| !target.hide) {
+                for series in db.series_between(since, until, &target.target)? {
+                    let points = downsample(&series.points, request.max_data_points);
+                    replies.push(json!({
+                        "target": series.metric,
+                        "datapoints": points
+                            .iter()
+                            .map(|&(ts, value)| json!([value, ts * 1000]))
+                            .collect::<Vec<_>>(),
+                    }));
+                }
+            }
+            json!(replies)
+        }
+        ("POST", "/annotations") => {
+            let request: AnnotationRequest = serde_json::from_value(parse(body)?)?;
+            let (since, until) = range(&request.range)?;
+            let prefix = request
+                .annotation
+                .get("query")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            let db = HistoryDb::open_existing(path)?;
+            let mut replies = Vec::new();
+            for series in db.series_between(since, until, prefix)? {
+                for (ts, level, value) in crossings(&series, config) {
+                    let limit =
+                        thresholds_for(&series.metric, config).map(|thresholds| match level {
+                            AlertLevel::Critical => thresholds.critical,
+                            _ => thresholds.warning,
+                        });
+                    replies.push(json!({
+                        "annotation": request.annotation,
+                        "time": ts * 1000,
+                        "title": format!("{} {}", series.metric, level.as_str()),
+                        "text": format!(
+                            "{} reached {} ({} {})",
+                            series.metric,
+                            format_value(value, series.unit()),
+                            level.as_str(),
+                            format_value(limit.unwrap_or_default() as f64, series.unit())
+                        ),
+                        "tags": ["hw-checker", level.as_str()],
+                    }));
+                }
+            }
+            json!(replies)
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(reply))
+}
+
+/// A Grafana time range (RFC 3339) as inclusive unix seconds.
+fn range(range: &Range) -> Result<(i64, i64)> {
+    let parse = |value: &str| {
+        chrono::DateTime::parse_from_rfc3339(value)
+            .map(|time| time.timestamp())
+            .with_context(|| format!("bad range timestamp {:?}", value))
+    };
+    Ok((parse(&range.from)?, parse(&range.to)?))
+}
+
+/// Averages consecutive points so at most `max` are left.
+fn downsample(points: &[(i64, f64)], max: Option<usize>) -> Vec<(i64, f64)> {
+    let Some(max) = max.filter(|&max| max > 0 && points.len() > max) else {
+        return points.to_vec();
+    };
+    points
+        .chunks(points.len().div_ceil(max))
+        .map(|chunk| {
+            let sum: f64 = chunk.iter().map(|&(_, value)| value).sum();
+            (chunk[chunk.len() - 1].0, sum / chunk.len() as f64)
+        })
+        .collect()
+}
+
+/// Every point where the series rose to a worse alert level than the point
+/// before it. Metrics without thresholds (network rates) have none.
+fn crossings(series: &Series, config: &Config) -> Vec<(i64, AlertLevel, f64)> {
+    let Some(thresholds) = thresholds_for(&series.metric, config) else {
+        return Vec::new();
+    };
+    let mut previous = AlertLevel::Ok;
+    let mut crossings = Vec::new();
+    for &(ts, value) in &series.points {
+        let level = AlertLevel::classify(value as f32, thresholds);
+        if level > previous {
+            crossings.push((ts, level, value));
+        }
+        previous = level;
+    }
+    crossings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn downsample_averages_buckets() {
+        let points: Vec<(i64, f64)> = (0..10).map(|i| (i, i as f64)).collect();
+        assert_eq!(downsample(&points, None).len(), 10);
+        assert_eq!(downsample(&points, Some(20)).len(), 10);
+        assert_eq!(
+            downsample(&points, Some(5)),
+            vec![(1, 0.5), (3, 2.5), (5, 4.5), (7, 6.5), (9, 8.5)]
+        );
+    }
+
+    #[test]
+    fn annotations_mark_rising_levels() {
+        let series = Series {
+            metric: "cpu".to_string(),
+            points: vec![
+                (1, 10.0),
+                (2, 75.0),
+                (3, 80.0),
+                (4, 95.0),
+                (5, 20.0),
+                (6, 92.0),
+            ],
+        };
+        let config = Config::default();
+        assert_eq!(
+            crossings(&series, &config),
+            vec![
+                (2, AlertLevel::Warning, 75.0),
+                (4, AlertLevel::Critical, 95.0),
+                (6, AlertLevel::Critical, 92.0),
+            ]
+        );
+        let net = Series {
+            metric: "net:eth0:rx".to_string(),
+            points: vec![(1, 1e9)],
+        };
+        assert!(crossings(&net, &config).is_empty());
+    }
+}
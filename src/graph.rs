@@ -0,0 +1,165 @@
+use crate::cli::GraphArgs;
+use crate::config::Config;
+use crate::history::{
+    HistoryDb, Series, database_path, format_value, since_timestamp, thresholds_for,
+};
+use crate::i18n::t;
+use crate::theme::theme;
+use anyhow::Result;
+use colored::Colorize;
+
+// Dot bit for (row, column) inside a 2x4 braille cell.
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+pub fn run_graph(args: &GraphArgs, config: &Config) -> Result<()> {
+    let db = HistoryDb::open_existing(&database_path(args.database.as_deref(), config))?;
+    let start = since_timestamp(args.since);
+    let end = chrono::Local::now().timestamp();
+    let series = db.series(start, &args.metric)?;
+    if series.is_empty() {
+        println!(
+            "{}",
+            t("No samples recorded in this period").color(theme().muted)
+        );
+        return Ok(());
+    }
+
+    let width = args
+        .width
+        .or_else(|| crossterm::terminal::size().ok().map(|(w, _)| w))
+        .unwrap_or(80) as usize;
+    for s in &series {
+        print_chart(s, start, end, width, args.height as usize, config);
+    }
+    Ok(())
+}
+
+fn print_chart(
+    series: &Series,
+    start: i64,
+    end: i64,
+    width: usize,
+    height: usize,
+    config: &Config,
+) {
+    let unit = series.unit();
+    // Percentages keep a fixed 0-100 scale so charts of different metrics compare at a glance.
+    let (lo, hi) = if unit == "%" {
+        (0.0, 100.0)
+    } else {
+        let (min, max) = (series.min(), series.max());
+        let pad = ((max - min) * 0.1).max(1.0);
+        ((min - pad).max(0.0), max + pad)
+    };
+    let top = format_value(hi, unit);
+    let bottom = format_value(lo, unit);
+    let label_width = top.chars().count().max(bottom.chars().count());
+    let cols = width.saturating_sub(label_width + 2).max(10);
+
+    let color = thresholds_for(&series.metric, config)
+        .map(|thresholds| theme().level(series.max() as f32, thresholds))
+        .unwrap_or(theme().accent);
+
+    println!(
+        "\n{}  {} {}  {} {}  {} {}  {} {}",
+        series.metric.bold().color(theme().title),
+        t("Min").color(theme().muted),
+        format_value(series.min(), unit),
+        t("Avg").color(theme().muted),
+        format_value(series.avg(), unit),
+        t("Max").color(theme().muted),
+        format_value(series.max(), unit),
+        t("Last").color(theme().muted),
+        format_value(series.last(), unit),
+    );
+    let rows = plot(&series.points, start, end, lo, hi, cols, height);
+    for (i, row) in rows.iter().enumerate() {
+        let (label, tick) = match i {
+            0 => (top.as_str(), '┤'),
+            _ if i == height - 1 => (bottom.as_str(), '┤'),
+            _ => ("", '│'),
+        };
+        println!(
+            "{:>w$} {}{}",
+            label,
+            tick.to_string().color(theme().muted),
+            row.color(color),
+            w = label_width
+        );
+    }
+    println!(
+        "{:>w$} {}",
+        "",
+        format!("└{}", "─".repeat(cols)).color(theme().muted),
+        w = label_width
+    );
+    let (from, to) = (time_label(start, end - start), time_label(end, end - start));
+    println!(
+        "{:>w$}  {}{:>rest$}",
+        "",
+        from,
+        to,
+        w = label_width,
+        rest = cols.saturating_sub(from.chars().count())
+    );
+}
+
+fn time_label(ts: i64, span: i64) -> String {
+    let format = if span > 24 * 3600 {
+        "%m-%d %H:%M"
+    } else {
+        "%H:%M"
+    };
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map(|dt| dt.with_timezone(&chrono::Local).format(format).to_string())
+        .unwrap_or_default()
+}
+
+/// Renders the points as `height` rows of `cols` braille cells. Each dot column
+/// averages the samples in its time slice and is joined to the previous one.
+fn plot(
+    points: &[(i64, f64)],
+    start: i64,
+    end: i64,
+    lo: f64,
+    hi: f64,
+    cols: usize,
+    height: usize,
+) -> Vec<String> {
+    let (dots_x, dots_y) = (cols * 2, height * 4);
+    let span = (end - start).max(1) as f64;
+    let mut sums = vec![(0.0, 0usize); dots_x];
+    for &(ts, value) in points {
+        let x = ((ts - start) as f64 / span * dots_x as f64) as usize;
+        let slot = &mut sums[x.min(dots_x - 1)];
+        slot.0 += value;
+        slot.1 += 1;
+    }
+
+    let mut cells = vec![vec![0u8; cols]; height];
+    let mut prev: Option<usize> = None;
+    for (x, &(sum, n)) in sums.iter().enumerate() {
+        if n == 0 {
+            continue;
+        }
+        let value = (sum / n as f64).clamp(lo, hi);
+        let y = ((hi - value) / (hi - lo) * (dots_y - 1) as f64).round() as usize;
+        let (from, to) = match prev {
+            Some(p) => (p.min(y), p.max(y)),
+            None => (y, y),
+        };
+        for dot in from..=to {
+            cells[dot / 4][x / 2] |= BRAILLE_DOTS[dot % 4][x % 2];
+        }
+        prev = Some(y);
+    }
+
+    cells
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&bits| char::from_u32(0x2800 + bits as u32).unwrap_or(' '))
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,432 @@
+use crate::alerts::Alerter;
+use crate::cli::{HistoryArgs, HistoryFormat, RecordArgs};
+use crate::config::{Config, Thresholds};
+use crate::discovery::MetricsSampler;
+use crate::i18n::t;
+use crate::model::MetricsSample;
+use crate::theme::theme;
+use crate::watch::SamplePrinter;
+use anyhow::{Context, Result};
+use colored::Colorize;
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::{Cell, CellAlignment, Color, Table};
+use rusqlite::{Connection, params};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const TREND_WIDTH: usize = 24;
+
+/// `$XDG_DATA_HOME/hw-checker` (or the platform equivalent).
+pub fn data_dir() -> PathBuf {
+    std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("hw-checker")
+}
+
+/// `$XDG_DATA_HOME/hw-checker/history.db` (or the platform equivalent).
+pub fn default_database_path() -> PathBuf {
+    data_dir().join("history.db")
+}
+
+pub fn database_path(arg: Option<&Path>, config: &Config) -> PathBuf {
+    arg.map(Path::to_path_buf)
+        .or_else(|| config.record.database.clone())
+        .unwrap_or_else(default_database_path)
+}
+
+/// A single metric over time, as `(unix seconds, value)` pairs in ascending order.
+#[derive(Debug, Clone, Serialize)]
+pub struct Series {
+    pub metric: String,
+    pub points: Vec<(i64, f64)>,
+}
+
+impl Series {
+    pub fn unit(&self) -> &'static str {
+        metric_unit(&self.metric)
+    }
+
+    pub fn min(&self) -> f64 {
+        self.points
+            .iter()
+            .map(|&(_, v)| v)
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    pub fn max(&self) -> f64 {
+        self.points
+            .iter()
+            .map(|&(_, v)| v)
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    pub fn avg(&self) -> f64 {
+        self.points.iter().map(|&(_, v)| v).sum::<f64>() / self.points.len().max(1) as f64
+    }
+
+    pub fn last(&self) -> f64 {
+        self.points.last().map(|&(_, v)| v).unwrap_or_default()
+    }
+}
+
+pub fn metric_unit(metric: &str) -> &'static str {
+    if metric.starts_with("temp:") {
+        "°C"
+    } else if metric.starts_with("net:") {
+        "B/s"
+    } else {
+        "%"
+    }
+}
+
+// Network metrics are stored as cumulative byte counters and reported as rates.
+fn is_counter(metric: &str) -> bool {
+    metric.starts_with("net:")
+}
+
+fn counter_rates(points: &[(i64, f64)]) -> Vec<(i64, f64)> {
+    points
+        .windows(2)
+        .filter(|w| w[1].0 > w[0].0 && w[1].1 >= w[0].1)
+        .map(|w| (w[1].0, (w[1].1 - w[0].1) / (w[1].0 - w[0].0) as f64))
+        .collect()
+}
+
+fn percent(used: u64, total: u64) -> Option<f64> {
+    (total > 0).then(|| used as f64 / total as f64 * 100.0)
+}
+
+/// Turns a sample into `(metric, value)` rows, e.g. `cpu`, `ram`, `disk:/home`,
+/// `temp:<sensor>` and `net:<iface>:rx`.
+pub fn flatten(sample: &MetricsSample) -> Vec<(String, f64)> {
+    let mut rows = vec![("cpu".to_string(), sample.cpu_usage as f64)];
+    if let Some(ram) = percent(sample.ram_used, sample.ram_total) {
+        rows.push(("ram".to_string(), ram));
+    }
+    if let Some(swap) = percent(sample.swap_used, sample.swap_total) {
+        rows.push(("swap".to_string(), swap));
+    }
+    for disk in &sample.disks {
+        if let Some(used) = percent(disk.used, disk.total) {
+            rows.push((format!("disk:{}", disk.mount_point), used));
+        }
+    }
+    for temp in &sample.temperatures {
+        rows.push((format!("temp:{}", temp.label), temp.celsius as f64));
+    }
+    for net in &sample.network {
+        rows.push((format!("net:{}:rx", net.name), net.received as f64));
+        rows.push((format!("net:{}:tx", net.name), net.transmitted as f64));
+    }
+    rows
+}
+
+pub struct HistoryDb {
+    conn: Connection,
+}
+
+impl HistoryDb {
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let conn = Connection::open(path)
+            .with_context(|| format!("failed to open history database {}", path.display()))?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS samples (
+                 ts INTEGER NOT NULL,
+                 metric TEXT NOT NULL,
+                 value REAL NOT NULL
+             );
+             CREATE INDEX IF NOT EXISTS samples_metric_ts ON samples (metric, ts);
+             CREATE INDEX IF NOT EXISTS samples_ts ON samples (ts);",
+        )?;
+        Ok(Self { conn })
+    }
+
+    /// Like `open`, but fails instead of creating an empty database.
+    pub fn open_existing(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            anyhow::bail!(
+                "no history database at {} (start one with `hw-checker record`)",
+                path.display()
+            );
+        }
+        Self::open(path)
+    }
+
+    pub fn insert(&mut self, sample: &MetricsSample) -> Result<usize> {
+        let ts = chrono::DateTime::parse_from_rfc3339(&sample.timestamp)?.timestamp();
+        let rows = flatten(sample);
+        let tx = self.conn.transaction()?;
+        {
+            let mut stmt =
+                tx.prepare_cached("INSERT INTO samples (ts, metric, value) VALUES (?1, ?2, ?3)")?;
+            for (metric, value) in &rows {
+                stmt.execute(params![ts, metric, value])?;
+            }
+        }
+        tx.commit()?;
+        Ok(rows.len())
+    }
+
+    /// Deletes every sample taken before `before` (unix seconds).
+    pub fn prune(&self, before: i64) -> Result<usize> {
+        Ok(self
+            .conn
+            .execute("DELETE FROM samples WHERE ts < ?1", [before])?)
+    }
+
+    /// Every metric starting with `prefix` recorded at or after `since` (unix seconds),
+    /// sorted by name. Network counters come back as per-second rates.
+    pub fn series(&self, since: i64, prefix: &str) -> Result<Vec<Series>> {
+        self.series_between(since, i64::MAX, prefix)
+    }
+
+    /// Like `series`, but only up to `until` (unix seconds, inclusive).
+    pub fn series_between(&self, since: i64, until: i64, prefix: &str) -> Result<Vec<Series>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT metric, ts, value FROM samples
+             WHERE ts >= ?1 AND ts <= ?2 AND substr(metric, 1, length(?3)) = ?3
+             ORDER BY metric, ts",
+        )?;
+        let rows = stmt.query_map(params![since, until, prefix], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, f64>(2)?,
+            ))
+        })?;
+
+        let mut series: Vec<Series> = Vec::new();
+        for row in rows {
+            let (metric, ts, value) = row?;
+            match series.last_mut() {
+                Some(last) if last.metric == metric => last.points.push((ts, value)),
+                _ => series.push(Series {
+                    metric,
+                    points: vec![(ts, value)],
+                }),
+            }
+        }
+        for s in &mut series {
+            if is_counter(&s.metric) {
+                s.points = counter_rates(&s.points);
+            }
+        }
+        series.retain(|s| !s.points.is_empty());
+        Ok(series)
+    }
+
+    /// The names of every recorded metric starting with `prefix`, sorted.
+    pub fn metrics(&self, prefix: &str) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT DISTINCT metric FROM samples
+             WHERE substr(metric, 1, length(?1)) = ?1
+             ORDER BY metric",
+        )?;
+        let rows = stmt.query_map([prefix], |row| row.get(0))?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+}
+
+/// Unix timestamp `ago` before now.
+pub fn since_timestamp(ago: Duration) -> i64 {
+    chrono::Local::now().timestamp() - ago.as_secs() as i64
+}
+
+pub fn run_record(args: &RecordArgs, config: &Config) -> Result<()> {
+    let path = database_path(args.database.as_deref(), config);
+    let interval = args
+        .interval
+        .unwrap_or(Duration::from_secs(config.record.interval_secs))
+        .max(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    let retention = Duration::from_secs(config.record.retention_days * 24 * 3600);
+
+    let mut db = HistoryDb::open(&path)?;
+    let mut sampler = MetricsSampler::new();
+    let mut printer = args.format.map(|format| SamplePrinter::new(format, config));
+    let mut alerter = Alerter::new(&args.alert_target, config)?;
+    eprintln!(
+        "{} {} ({} {})",
+        t("Recording metrics to"),
+        path.display(),
+        t("every"),
+        humantime::format_duration(interval)
+    );
+
+    loop {
+        // Sleeping first also gives the CPU usage its first measurement window.
+        std::thread::sleep(interval);
+        let sample = sampler.sample();
+        db.insert(&sample)?;
+        alerter.check(&sample);
+        if let Some(printer) = &mut printer {
+            printer.print(&sample)?;
+        }
+        if !retention.is_zero() {
+            db.prune(since_timestamp(retention))?;
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct MetricSummary<'a> {
+    metric: &'a str,
+    unit: &'a str,
+    samples: usize,
+    min: f64,
+    avg: f64,
+    max: f64,
+    last: f64,
+}
+
+pub fn run_history(args: &HistoryArgs, config: &Config) -> Result<()> {
+    let db = HistoryDb::open_existing(&database_path(args.database.as_deref(), config))?;
+    let series = db.series(
+        since_timestamp(args.since),
+        args.metric.as_deref().unwrap_or_default(),
+    )?;
+
+    if args.format == HistoryFormat::Json {
+        let summaries: Vec<MetricSummary> = series
+            .iter()
+            .map(|s| MetricSummary {
+                metric: &s.metric,
+                unit: s.unit(),
+                samples: s.points.len(),
+                min: s.min(),
+                avg: s.avg(),
+                max: s.max(),
+                last: s.last(),
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&summaries)?);
+        return Ok(());
+    }
+
+    println!(
+        "\n{} ({})",
+        t("Metric History").bold().color(theme().title),
+        humantime::format_duration(args.since)
+    );
+    if series.is_empty() {
+        println!(
+            "{}",
+            t("No samples recorded in this period").color(theme().muted)
+        );
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![
+            t("Metric"),
+            t("Samples"),
+            t("Min"),
+            t("Avg"),
+            t("Max"),
+            t("Last"),
+            t("Trend"),
+        ]);
+    for s in &series {
+        let value =
+            |v: f64| Cell::new(format_value(v, s.unit())).set_alignment(CellAlignment::Right);
+        let trend = Cell::new(sparkline(&s.points, TREND_WIDTH)).fg(trend_color(s, config));
+        table.add_row(vec![
+            Cell::new(&s.metric),
+            Cell::new(s.points.len()).set_alignment(CellAlignment::Right),
+            value(s.min()),
+            value(s.avg()),
+            value(s.max()),
+            value(s.last()),
+            trend,
+        ]);
+    }
+    println!("{table}");
+    Ok(())
+}
+
+/// The configured thresholds for a percentage or temperature metric.
+pub fn thresholds_for<'a>(metric: &str, config: &'a Config) -> Option<&'a Thresholds> {
+    match metric.split(':').next()? {
+        "cpu" => Some(&config.cpu_thresholds),
+        "ram" | "swap" => Some(&config.ram_thresholds),
+        "disk" => Some(&config.storage_thresholds),
+        "temp" => Some(&config.temperature_thresholds),
+        _ => None,
+    }
+}
+
+// Colors a trend by its peak against the thresholds, or with the accent color when there are none.
+fn trend_color(series: &Series, config: &Config) -> Color {
+    match thresholds_for(&series.metric, config) {
+        Some(thresholds) => Color::from(theme().level(series.max() as f32, thresholds)),
+        None => Color::from(theme().accent),
+    }
+}
+
+pub fn format_value(value: f64, unit: &str) -> String {
+    if unit == "B/s" {
+        if value >= 1024.0 * 1024.0 {
+            format!("{:.1} MiB/s", value / 1024.0 / 1024.0)
+        } else if value >= 1024.0 {
+            format!("{:.1} KiB/s", value / 1024.0)
+        } else {
+            format!("{:.0} B/s", value)
+        }
+    } else {
+        format!("{:.1}{}", value, unit)
+    }
+}
+
+/// Averages the points into `width` time buckets and draws them with block characters.
+pub fn sparkline(points: &[(i64, f64)], width: usize) -> String {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return String::new();
+    };
+    let width = width.min(points.len());
+    let span = (last.0 - first.0).max(1) as f64;
+    let mut buckets = vec![(0.0, 0usize); width];
+    for &(ts, value) in points {
+        let i = (((ts - first.0) as f64 / span) * (width - 1) as f64).round() as usize;
+        buckets[i.min(width - 1)].0 += value;
+        buckets[i.min(width - 1)].1 += 1;
+    }
+    let values: Vec<Option<f64>> = buckets
+        .iter()
+        .map(|&(sum, n)| (n > 0).then(|| sum / n as f64))
+        .collect();
+    let lo = values
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f64::INFINITY, f64::min);
+    let hi = values
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f64::NEG_INFINITY, f64::max);
+    values
+        .iter()
+        .map(|v| match v {
+            None => ' ',
+            Some(_) if hi <= lo => SPARK_LEVELS[0],
+            Some(v) => {
+                let level = ((v - lo) / (hi - lo) * (SPARK_LEVELS.len() - 1) as f64).round();
+                SPARK_LEVELS[level as usize]
+            }
+        })
+        .collect()
+}
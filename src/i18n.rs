@@ -0,0 +1,668 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Vi,
+}
+
+impl Locale {
+    /// Picks a locale from the usual POSIX environment variables (`LC_ALL`, `LC_MESSAGES`, `LANG`).
+    pub fn from_env() -> Option<Locale> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| {
+                let lang = value.split(['_', '.', '@']).next().unwrap_or_default();
+                match lang.to_lowercase().as_str() {
+                    "vi" => Some(Locale::Vi),
+                    "en" | "c" | "posix" => Some(Locale::En),
+                    _ => None,
+                }
+            })
+    }
+}
+
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+pub fn set_locale(locale: Locale) {
+    let _ = LOCALE.set(locale);
+}
+
+pub fn locale() -> Locale {
+    LOCALE.get().copied().unwrap_or_default()
+}
+
+/// Translates a label gettext-style: the English text is the message id and
+/// is returned unchanged when the active catalog has no entry for it.
+pub fn t(msgid: &'static str) -> &'static str {
+    let catalog = match locale() {
+        Locale::En => return msgid,
+        Locale::Vi => VI,
+    };
+    catalog
+        .iter()
+        .find(|(id, _)| *id == msgid)
+        .map(|(_, msgstr)| *msgstr)
+        .unwrap_or(msgid)
+}
+
+const VI: &[(&str, &str)] = &[
+    // Section titles
+    ("System Summary", "Tóm tắt hệ thống"),
+    ("CPU Information", "Thông tin CPU"),
+    ("RAM Information & Usage", "Thông tin & mức sử dụng RAM"),
+    ("Physical RAM Sticks", "Thanh RAM vật lý"),
+    ("Storage Information", "Thông tin lưu trữ"),
+    ("Disk I/O", "Nhập/xuất đĩa"),
+    ("Network Interfaces", "Giao diện mạng"),
+    ("USB Devices", "Thiết bị USB"),
+    ("PCI Devices", "Thiết bị PCI"),
+    ("Motherboard & BIOS", "Bo mạch chủ & BIOS"),
+    ("Battery Status", "Trạng thái pin"),
+    // Table headers and field labels
+    ("Hostname", "Tên máy"),
+    ("Host", "Máy"),
+    ("OS", "Hệ điều hành"),
+    ("Kernel", "Nhân"),
+    ("Uptime", "Thời gian hoạt động"),
+    ("Core", "Lõi"),
+    ("Cores", "Số lõi"),
+    ("Physical Cores", "Số lõi vật lý"),
+    ("Model", "Mẫu"),
+    ("Brand", "Thương hiệu"),
+    ("Vendor", "Nhà cung cấp"),
+    ("Frequency (MHz)", "Tần số (MHz)"),
+    ("L1 Cache", "Bộ nhớ đệm L1"),
+    ("L2 Cache", "Bộ nhớ đệm L2"),
+    ("L3 Cache", "Bộ nhớ đệm L3"),
+    ("Caches", "Bộ nhớ đệm"),
+    ("Usage (%)", "Sử dụng (%)"),
+    ("Component", "Thành phần"),
+    ("Information", "Thông tin"),
+    ("Total", "Tổng"),
+    ("Used", "Đã dùng"),
+    ("Free", "Còn trống"),
+    ("Total (MiB)", "Tổng (MiB)"),
+    ("Used (MiB)", "Đã dùng (MiB)"),
+    ("Free (MiB)", "Còn trống (MiB)"),
+    ("Total (GiB)", "Tổng (GiB)"),
+    ("Used (GiB)", "Đã dùng (GiB)"),
+    ("Total (GB)", "Tổng (GB)"),
+    ("Used (%)", "Đã dùng (%)"),
+    ("Main Memory", "Bộ nhớ chính"),
+    ("Swap", "Bộ nhớ hoán đổi"),
+    ("Swap Total", "Tổng bộ nhớ hoán đổi"),
+    ("Swap Used", "Bộ nhớ hoán đổi đã dùng"),
+    ("Slot", "Khe"),
+    ("Manufacturer", "Nhà sản xuất"),
+    ("Part Number", "Mã linh kiện"),
+    ("Serial Number", "Số sê-ri"),
+    ("Speed", "Tốc độ"),
+    ("Speed (MT/s)", "Tốc độ (MT/s)"),
+    ("Disk", "Ổ đĩa"),
+    ("Name", "Tên"),
+    ("Mount", "Điểm gắn kết"),
+    ("Type", "Loại"),
+    ("Interface", "Giao diện"),
+    ("MAC Address", "Địa chỉ MAC"),
+    ("Read (MiB/s)", "Đọc (MiB/s)"),
+    ("Write (MiB/s)", "Ghi (MiB/s)"),
+    ("Read IOPS", "IOPS đọc"),
+    ("Write IOPS", "IOPS ghi"),
+    ("Read/s", "Đọc/giây"),
+    ("Write/s", "Ghi/giây"),
+    ("Received (MiB)", "Đã nhận (MiB)"),
+    ("Transmitted (MiB)", "Đã gửi (MiB)"),
+    ("Bus/Addr", "Bus/Địa chỉ"),
+    ("Bus:Addr", "Bus:Địa chỉ"),
+    ("Product", "Sản phẩm"),
+    ("Device", "Thiết bị"),
+    ("Name/Class", "Tên/Lớp"),
+    ("Motherboard", "Bo mạch chủ"),
+    ("BIOS Vendor", "Nhà cung cấp BIOS"),
+    ("BIOS Version", "Phiên bản BIOS"),
+    ("BIOS Date", "Ngày BIOS"),
+    ("Battery", "Pin"),
+    ("Status", "Trạng thái"),
+    ("Capacity (%)", "Dung lượng (%)"),
+    ("Unknown", "Không rõ"),
+    (
+        "Run with sudo (or --elevate) to also get:",
+        "Chạy với sudo (hoặc --elevate) để xem thêm:",
+    ),
+    // TUI
+    ("TUI Mode", "Chế độ TUI"),
+    ("Overview", "Tổng quan"),
+    ("CPU & RAM", "CPU & RAM"),
+    ("Storage & Network", "Lưu trữ & Mạng"),
+    ("PCI & USB", "PCI & USB"),
+    ("System Health", "Tình trạng hệ thống"),
+    (
+        "q: quit | ←→: tabs | Tab: focus | /: search | ?: help",
+        "q: thoát | ←→: tab | Tab: chọn bảng | /: tìm kiếm | ?: trợ giúp",
+    ),
+    ("Search", "Tìm kiếm"),
+    ("Filter", "Bộ lọc"),
+    ("Esc to clear", "Esc để xóa"),
+    ("Save report as", "Lưu báo cáo thành"),
+    (
+        ".json/.yaml/.html, Enter to save, Esc to cancel",
+        ".json/.yaml/.html, Enter để lưu, Esc để hủy",
+    ),
+    ("Report saved to", "Đã lưu báo cáo vào"),
+    ("Save failed", "Lưu thất bại"),
+    ("Refresh", "Làm mới"),
+    ("Paused", "Đã tạm dừng"),
+    ("Esc: close", "Esc: đóng"),
+    ("Filesystem", "Hệ thống tệp"),
+    ("Filesystems", "Các hệ thống tệp"),
+    ("Vendor ID", "Mã nhà cung cấp"),
+    ("Device ID", "Mã thiết bị"),
+    ("Product ID", "Mã sản phẩm"),
+    ("Class", "Lớp"),
+    ("Bus", "Bus"),
+    ("Processes", "Tiến trình"),
+    ("User", "Người dùng"),
+    ("CPU (%)", "CPU (%)"),
+    ("RSS (MiB)", "RSS (MiB)"),
+    (
+        "o: sort column | r: reverse | k: SIGTERM | K: SIGKILL",
+        "o: cột sắp xếp | r: đảo thứ tự | k: SIGTERM | K: SIGKILL",
+    ),
+    ("Confirm", "Xác nhận"),
+    ("Send", "Gửi"),
+    ("Sent", "Đã gửi"),
+    (
+        "y: confirm | any other key: cancel",
+        "y: xác nhận | phím khác: hủy",
+    ),
+    ("Failed to signal", "Không gửi được tín hiệu tới"),
+    (
+        "permission denied or process exited",
+        "không đủ quyền hoặc tiến trình đã kết thúc",
+    ),
+    (
+        "Signal not supported on this platform",
+        "Tín hiệu không được hỗ trợ trên nền tảng này",
+    ),
+    ("Process no longer exists", "Tiến trình không còn tồn tại"),
+    ("Alerts", "Cảnh báo"),
+    ("warning", "ngưỡng cảnh báo"),
+    ("critical", "ngưỡng nguy hiểm"),
+    ("back to normal", "đã trở lại bình thường"),
+    ("Help", "Trợ giúp"),
+    ("Keybindings", "Phím tắt"),
+    ("Configuration", "Cấu hình"),
+    ("Quit", "Thoát"),
+    (
+        "Clear search, close popup, or quit",
+        "Xóa tìm kiếm, đóng cửa sổ hoặc thoát",
+    ),
+    ("Switch tab", "Chuyển tab"),
+    ("Move focus between tables", "Chuyển giữa các bảng"),
+    ("Move selection", "Di chuyển dòng chọn"),
+    ("Move selection by a page", "Di chuyển dòng chọn theo trang"),
+    ("Jump to first / last row", "Đến dòng đầu / cuối"),
+    (
+        "Show details for the selected device",
+        "Xem chi tiết thiết bị đang chọn",
+    ),
+    ("Search all tables", "Tìm kiếm trong mọi bảng"),
+    (
+        "Save the current report (JSON/YAML/HTML)",
+        "Lưu báo cáo hiện tại (JSON/YAML/HTML)",
+    ),
+    (
+        "Pause / resume live updates",
+        "Tạm dừng / tiếp tục cập nhật",
+    ),
+    (
+        "Processes: cycle sort column",
+        "Tiến trình: đổi cột sắp xếp",
+    ),
+    (
+        "Processes: reverse sort order",
+        "Tiến trình: đảo thứ tự sắp xếp",
+    ),
+    ("Processes: send SIGTERM", "Tiến trình: gửi SIGTERM"),
+    ("Processes: send SIGKILL", "Tiến trình: gửi SIGKILL"),
+    ("Toggle this help", "Bật/tắt trợ giúp này"),
+    (
+        "Select a tab or row (click again for details)",
+        "Chọn tab hoặc dòng (nhấn lần nữa để xem chi tiết)",
+    ),
+    (
+        "Scroll the table under the cursor",
+        "Cuộn bảng dưới con trỏ",
+    ),
+    ("Confirm a pending signal", "Xác nhận gửi tín hiệu"),
+    ("Refresh interval", "Chu kỳ làm mới"),
+    ("CPU thresholds (%)", "Ngưỡng CPU (%)"),
+    ("RAM thresholds (%)", "Ngưỡng RAM (%)"),
+    ("Storage thresholds (%)", "Ngưỡng lưu trữ (%)"),
+    ("Temperature thresholds (°C)", "Ngưỡng nhiệt độ (°C)"),
+    ("Theme", "Giao diện"),
+    ("Language", "Ngôn ngữ"),
+    ("USB connected", "Đã cắm USB"),
+    ("USB disconnected", "Đã rút USB"),
+    ("Storage added", "Đã thêm ổ lưu trữ"),
+    ("Storage removed", "Đã gỡ ổ lưu trữ"),
+    ("Interface added", "Đã thêm giao diện mạng"),
+    ("Interface removed", "Đã gỡ giao diện mạng"),
+    ("Custom", "Tùy chỉnh"),
+    ("Field", "Trường"),
+    ("Plugin failed:", "Plugin lỗi:"),
+    ("added", "đã thêm"),
+    ("Change", "Thay đổi"),
+    (
+        "No hardware changes logged",
+        "Chưa ghi nhận thay đổi phần cứng nào",
+    ),
+    ("Hardware changes since", "Thay đổi phần cứng kể từ"),
+    (
+        "No hardware changes since",
+        "Không có thay đổi phần cứng kể từ",
+    ),
+    (
+        "Audit baseline recorded in",
+        "Đã ghi trạng thái phần cứng ban đầu vào",
+    ),
+    ("Enable with:", "Bật bằng:"),
+    ("Enabled", "Đã bật"),
+    ("Serving", "Đang phục vụ"),
+    ("Connection failed", "Kết nối thất bại"),
+    ("Request failed", "Yêu cầu thất bại"),
+    ("Listening on", "Đang lắng nghe trên"),
+    ("Refresh failed", "Làm mới thất bại"),
+    ("refused, already serving", "bị từ chối, đang phục vụ"),
+    ("removed", "đã gỡ"),
+    // hw-checker monitor
+    ("TIME", "THỜI GIAN"),
+    ("ACTION", "SỰ KIỆN"),
+    ("SUBSYSTEM", "HỆ THỐNG CON"),
+    ("DEVICE", "THIẾT BỊ"),
+    ("DETAILS", "CHI TIẾT"),
+    ("SWAP", "HOÁN ĐỔI"),
+    ("TEMP", "NHIỆT ĐỘ"),
+    ("Failed to deliver alert", "Gửi cảnh báo thất bại"),
+    (
+        "All metrics within thresholds",
+        "Mọi số liệu đều trong ngưỡng",
+    ),
+    ("CRITICAL", "NGUY HIỂM"),
+    ("WARNING", "CẢNH BÁO"),
+    ("threshold breaches", "lần vượt ngưỡng"),
+    ("Threshold breaches on", "Vượt ngưỡng trên"),
+    ("Publishing metrics to", "Đang xuất bản số liệu tới"),
+    ("Serving SNMP subtree", "Đang phục vụ cây con SNMP"),
+    ("Failed to collect from", "Không thu thập được từ"),
+    ("Skipping", "Bỏ qua"),
+    ("Fleet Summary", "Tổng quan đội máy"),
+    ("Hosts", "Số máy"),
+    ("Total RAM (GiB)", "Tổng RAM (GiB)"),
+    ("Total Storage (GiB)", "Tổng dung lượng (GiB)"),
+    ("RAM (GiB)", "RAM (GiB)"),
+    ("Fullest Disk (%)", "Ổ đầy nhất (%)"),
+    ("CPU Models", "Mẫu CPU"),
+    ("BIOS Versions", "Phiên bản BIOS"),
+    ("Disks Nearing Capacity", "Ổ đĩa sắp đầy"),
+    ("Outliers", "Máy khác biệt"),
+    ("Deviation", "Khác biệt"),
+    ("RAM", "RAM"),
+    ("RAM usage", "Mức dùng RAM"),
+    ("fleet:", "đội máy:"),
+    ("most", "đa số"),
+    ("CPU cores", "Số lõi CPU"),
+    ("CPU threads", "Số luồng CPU"),
+    ("CPU flag", "Cờ CPU"),
+    ("present", "có"),
+    ("missing", "thiếu"),
+    ("Free space on", "Dung lượng trống trên"),
+    ("not mounted", "chưa được gắn"),
+    ("GPU VRAM", "VRAM GPU"),
+    ("no GPU with known VRAM", "không có GPU rõ VRAM"),
+    ("Rule", "Quy tắc"),
+    ("Required", "Yêu cầu"),
+    ("Found", "Thực tế"),
+    ("Result", "Kết quả"),
+    ("PASS", "ĐẠT"),
+    ("FAIL", "KHÔNG ĐẠT"),
+    ("requirements not met", "yêu cầu không đạt"),
+    ("All requirements met", "Đáp ứng mọi yêu cầu"),
+    ("Stress test for", "Kiểm tra tải trong"),
+    ("memory", "bộ nhớ"),
+    ("press Ctrl-C to abort", "nhấn Ctrl-C để dừng"),
+    ("throttling events", "lần giảm xung"),
+    ("Stress Test Results", "Kết quả kiểm tra tải"),
+    ("Value", "Giá trị"),
+    ("Duration", "Thời lượng"),
+    ("Max temperature", "Nhiệt độ cao nhất"),
+    ("Peak frequency", "Xung nhịp cao nhất"),
+    ("Lowest frequency", "Xung nhịp thấp nhất"),
+    ("Frequency droop", "Mức tụt xung nhịp"),
+    ("Throttling events", "Số lần giảm xung"),
+    ("Memory bandwidth", "Băng thông bộ nhớ"),
+    ("No thermal limits hit", "Không chạm ngưỡng nhiệt"),
+    ("Thermal limits hit", "Đã chạm ngưỡng nhiệt"),
+    ("received", "đã nhận"),
+    ("sent", "đã gửi"),
+    ("in", "trong"),
+    (
+        "Bench server listening on",
+        "Máy chủ đo đang lắng nghe trên",
+    ),
+    ("Testing throughput to", "Đang đo thông lượng tới"),
+    ("download", "tải xuống"),
+    ("upload", "tải lên"),
+    ("streams", "luồng"),
+    ("Network Throughput", "Thông lượng mạng"),
+    ("Transferred", "Đã truyền"),
+    ("Bitrate", "Tốc độ"),
+    ("Link speed", "Tốc độ liên kết"),
+    ("Link utilization", "Mức sử dụng liên kết"),
+    ("OK", "OK"),
+    ("Limited by port", "Bị giới hạn bởi cổng"),
+    ("MISMATCH", "KHÔNG KHỚP"),
+    (
+        "No USB mass-storage devices found",
+        "Không tìm thấy thiết bị lưu trữ USB",
+    ),
+    ("Port", "Cổng"),
+    ("Device max", "Tối đa của thiết bị"),
+    ("Port max", "Tối đa của cổng"),
+    ("Negotiated", "Đã thương lượng"),
+    (
+        "device(s) slower than both the port and device support; check the cable and connector",
+        "thiết bị chạy chậm hơn mức cổng và thiết bị hỗ trợ; hãy kiểm tra cáp và đầu nối",
+    ),
+    ("Signature valid", "Chữ ký hợp lệ"),
+    ("Signature invalid", "Chữ ký không hợp lệ"),
+    ("key", "khóa"),
+    (
+        "The key was taken from the report itself; pass --key to require a known signer",
+        "Khóa được lấy từ chính báo cáo; dùng --key để yêu cầu người ký đã biết",
+    ),
+    ("Asset", "Tài sản"),
+    ("Asset tag", "Mã tài sản"),
+    ("Location", "Vị trí"),
+    ("Owner", "Chủ sở hữu"),
+    ("Thunderbolt Devices", "Thiết bị Thunderbolt"),
+    ("Cycles", "Số chu kỳ"),
+    ("Wrote", "Đã ghi"),
+    ("files", "tệp"),
+    ("Replay with", "Phát lại bằng"),
+    ("No config file found", "Không tìm thấy tệp cấu hình"),
+    ("profile", "hồ sơ"),
+    ("Battery levels (%)", "Mức pin (%)"),
+    ("Wear (%)", "Hao mòn (%)"),
+    ("Wear", "Hao mòn"),
+    ("wear", "hao mòn"),
+    ("max", "tối đa"),
+    ("Socket", "Đế cắm"),
+    ("Threads", "Số luồng"),
+    ("Sockets", "Số đế cắm"),
+    ("per core", "mỗi nhân"),
+    ("shared", "dùng chung"),
+    ("Cluster", "Cụm"),
+    ("Performance", "Hiệu năng"),
+    ("Efficiency", "Tiết kiệm"),
+    ("Base (MHz)", "Cơ bản (MHz)"),
+    ("Boost (MHz)", "Tăng tốc (MHz)"),
+    ("Passed", "Đạt"),
+    ("Failed", "Lỗi"),
+    ("Size (GiB)", "Dung lượng (GiB)"),
+    ("Size", "Dung lượng"),
+    ("Power-on hours", "Số giờ hoạt động"),
+    ("Physical", "Vật lý"),
+    ("Virtual", "Ảo"),
+    ("Bonds & Bridges", "Bond & Bridge"),
+    ("Mode", "Chế độ"),
+    ("Active", "Đang hoạt động"),
+    ("Members", "Thành viên"),
+    ("members down", "thành viên mất kết nối"),
+    ("Network Configuration", "Cấu hình mạng"),
+    ("Default gateway (IPv4)", "Cổng mặc định (IPv4)"),
+    ("Default gateway (IPv6)", "Cổng mặc định (IPv6)"),
+    ("DNS servers", "Máy chủ DNS"),
+    ("Primary interface", "Giao diện chính"),
+    ("Connectivity", "Kết nối"),
+    ("Check", "Kiểm tra"),
+    ("Latency (ms)", "Độ trễ (ms)"),
+    ("Loss (%)", "Mất gói (%)"),
+    ("Gateway", "Cổng"),
+    ("Target", "Đích"),
+    ("Unreachable", "Không kết nối được"),
+    ("Packet loss", "Mất gói"),
+    ("ping not available", "không có lệnh ping"),
+    ("GPUs", "GPU"),
+    ("No GPU detected", "Không phát hiện GPU"),
+    ("GPU Processes", "Tiến trình dùng GPU"),
+    ("Driver", "Trình điều khiển"),
+    ("VRAM (MiB)", "VRAM (MiB)"),
+    ("Container Limits", "Giới hạn container"),
+    ("Limit", "Giới hạn"),
+    ("CPUs", "CPU"),
+    ("Unlimited", "Không giới hạn"),
+    ("Memory (MiB)", "Bộ nhớ (MiB)"),
+    ("Memory Settings", "Cấu hình bộ nhớ"),
+    ("Setting", "Thiết lập"),
+    ("Hugepages (total/free)", "Hugepages (tổng/trống)"),
+    ("Transparent hugepages", "Transparent hugepages"),
+    ("Overcommit", "Overcommit"),
+    ("Committed / limit", "Đã cấp phát / giới hạn"),
+    ("Inodes", "Inode"),
+    ("Inodes (%)", "Inode (%)"),
+    ("Inode thresholds (%)", "Ngưỡng inode (%)"),
+    ("Kernel Limits", "Giới hạn nhân"),
+    ("Cloud", "Đám mây"),
+    ("Instance Type", "Loại máy ảo"),
+    ("Region", "Vùng"),
+    ("Zone", "Vùng sẵn sàng"),
+    ("Instance ID", "ID máy ảo"),
+    (
+        "--cloud: cloud VMs are recognized from SMBIOS strings, which are only read on Linux and FreeBSD/OpenBSD",
+        "--cloud: máy ảo đám mây được nhận diện qua chuỗi SMBIOS, vốn chỉ đọc được trên Linux và FreeBSD/OpenBSD",
+    ),
+    ("OS Details", "Chi tiết hệ điều hành"),
+    ("Architecture", "Kiến trúc"),
+    ("Booted", "Khởi động lúc"),
+    ("Kernel command line", "Tham số dòng lệnh kernel"),
+    ("Loaded modules", "Module đã nạp"),
+    ("Security module", "Module bảo mật"),
+    ("Time", "Thời gian"),
+    ("Clock source", "Nguồn xung nhịp"),
+    ("available", "khả dụng"),
+    ("NTP synchronized", "Đồng bộ NTP"),
+    ("NTP server", "Máy chủ NTP"),
+    ("NTP offset", "Độ lệch NTP"),
+    ("RTC offset", "Độ lệch RTC"),
+    ("Yes", "Có"),
+    ("No", "Không"),
+    ("None", "Không có"),
+    ("Hardware Report", "Báo cáo phần cứng"),
+    ("Generated", "Tạo lúc"),
+    ("Acceptance", "Nghiệm thu"),
+    ("Delivered by", "Bên giao"),
+    ("Accepted by", "Bên nhận"),
+    ("Date", "Ngày"),
+    ("Page", "Trang"),
+    ("Memory", "Bộ nhớ"),
+    ("Issues", "Vấn đề"),
+    ("Probing", "Đang dò"),
+    ("Timed out:", "Quá thời gian:"),
+    ("Discovery Timings", "Thời gian dò tìm"),
+    ("Step", "Bước"),
+    ("Time (ms)", "Thời gian (ms)"),
+    ("Rate (W)", "Công suất (W)"),
+    ("Time Left", "Thời gian còn lại"),
+    ("to empty", "đến khi cạn"),
+    ("to full", "đến khi đầy"),
+    ("Platform State", "Trạng thái nền tảng"),
+    ("Lid", "Nắp máy"),
+    ("Open", "Mở"),
+    ("Closed", "Đóng"),
+    ("Dock", "Đế cắm"),
+    ("Docked", "Đã gắn đế"),
+    ("Undocked", "Chưa gắn đế"),
+    ("Inactive", "Không hoạt động"),
+    ("Thermal Throttling", "Giảm xung do nhiệt"),
+    ("Throttle Events", "Số lần giảm xung"),
+    ("Load (1m / 5m / 15m)", "Tải (1p / 5p / 15p)"),
+    ("User (%)", "Người dùng (%)"),
+    ("System (%)", "Hệ thống (%)"),
+    ("I/O Wait (%)", "Chờ I/O (%)"),
+    ("Steal (%)", "Bị chiếm (%)"),
+    ("Idle (%)", "Rảnh (%)"),
+    ("Load", "Tải"),
+    ("Pressure Stall (PSI)", "Áp lực tài nguyên (PSI)"),
+    ("Resource", "Tài nguyên"),
+    ("Some 10s (%)", "Một phần 10s (%)"),
+    ("Some 60s (%)", "Một phần 60s (%)"),
+    ("Some 300s (%)", "Một phần 300s (%)"),
+    ("Full 10s (%)", "Toàn bộ 10s (%)"),
+    ("Full 60s (%)", "Toàn bộ 60s (%)"),
+    ("Full 300s (%)", "Toàn bộ 300s (%)"),
+    ("Pressure (10s)", "Áp lực (10s)"),
+    ("Interrupts", "Ngắt"),
+    ("Rate (/s)", "Tần suất (/s)"),
+    ("Affinity", "Ràng buộc CPU"),
+    ("more", "nữa"),
+    ("Processor Sockets", "Đế cắm bộ xử lý"),
+    ("Processor", "Bộ xử lý"),
+    ("Cores/Threads", "Lõi/Luồng"),
+    ("Speed (MHz)", "Tốc độ (MHz)"),
+    ("Empty", "Trống"),
+    ("Caches (Firmware)", "Bộ nhớ đệm (Firmware)"),
+    ("Cache", "Bộ nhớ đệm"),
+    ("Level", "Cấp"),
+    ("Associativity", "Độ kết hợp"),
+    ("Disabled", "Đã tắt"),
+    ("Port Connectors", "Cổng kết nối"),
+    ("Internal", "Bên trong"),
+    ("Connector", "Đầu nối"),
+    ("Expansion Slots", "Khe mở rộng"),
+    ("Width", "Độ rộng"),
+    ("wired", "nối dây"),
+    ("In Use", "Đang dùng"),
+    ("Available", "Còn trống"),
+    ("Slot Map", "Sơ đồ khe cắm"),
+    ("free", "trống"),
+    ("link", "liên kết"),
+    ("none", "không có"),
+    ("Free slots", "Khe còn trống"),
+    ("Memory Slots", "Khe cắm bộ nhớ"),
+    ("Channel", "Kênh"),
+    ("Populated", "Đã cắm"),
+    ("modules rated", "thanh RAM định mức"),
+    (
+        "Memory is at the board's maximum",
+        "Bộ nhớ đã đạt mức tối đa của bo mạch",
+    ),
+    ("You can add up to", "Có thể thêm tối đa"),
+    ("free slots", "khe trống"),
+    ("SPD", "SPD"),
+    ("DRAM", "Chip DRAM"),
+    ("Timings", "Thời gian trễ"),
+    ("Profiles", "Cấu hình"),
+    (
+        "Virtual machine memory: there are no physical modules to report",
+        "Bộ nhớ máy ảo: không có thanh RAM vật lý nào để báo cáo",
+    ),
+    ("Virtual Memory Devices", "Thiết bị bộ nhớ ảo"),
+    ("Rev", "Phiên bản"),
+    ("Revision", "Phiên bản"),
+    ("Subsystem", "Hệ con"),
+    ("NUMA node", "Nút NUMA"),
+    ("IOMMU group", "Nhóm IOMMU"),
+    (
+        "IOMMU state is only read on Linux",
+        "Trạng thái IOMMU chỉ được đọc trên Linux",
+    ),
+    ("enabled", "đã bật"),
+    ("groups", "nhóm"),
+    ("IOMMU disabled", "IOMMU đang tắt"),
+    (
+        "Enable VT-d (Intel) or AMD-Vi/IOMMU (AMD) in the firmware setup and boot with intel_iommu=on or amd_iommu=on",
+        "Bật VT-d (Intel) hoặc AMD-Vi/IOMMU (AMD) trong firmware và khởi động với intel_iommu=on hoặc amd_iommu=on",
+    ),
+    ("Kernel options", "Tham số nhân"),
+    ("Group", "Nhóm"),
+    ("GPU passthrough", "Chuyển GPU cho máy ảo"),
+    ("group", "nhóm"),
+    (
+        "isolated, can be passed through",
+        "tách biệt, có thể chuyển cho máy ảo",
+    ),
+    ("shared with", "dùng chung với"),
+    ("bound to vfio-pci", "đã gắn vfio-pci"),
+    ("Virtualization", "Ảo hóa"),
+    ("Hardware virtualization", "Ảo hóa phần cứng"),
+    ("disabled in firmware", "bị tắt trong firmware"),
+    ("Not supported", "Không hỗ trợ"),
+    ("Nested paging", "Phân trang lồng"),
+    ("Nested virtualization", "Ảo hóa lồng"),
+    (
+        "--spd: no SPD EEPROMs found; load the ee1004 (DDR4) or spd5118 (DDR5) kernel module",
+        "--spd: không tìm thấy EEPROM SPD; hãy nạp mô-đun nhân ee1004 (DDR4) hoặc spd5118 (DDR5)",
+    ),
+    (
+        "All slots are populated; replace modules to add up to",
+        "Mọi khe đã được cắm; thay thanh RAM để thêm tối đa",
+    ),
+    (
+        "SMART self-assessment failed",
+        "SMART tự đánh giá không đạt",
+    ),
+    ("Reachable", "Kết nối được"),
+    ("Resolved", "Phân giải được"),
+    ("Verdict", "Kết luận"),
+    ("Online", "Trực tuyến"),
+    ("Degraded", "Suy giảm"),
+    ("Offline", "Ngoại tuyến"),
+    ("Recording metrics to", "Đang ghi số liệu vào"),
+    ("every", "mỗi"),
+    ("Metric History", "Lịch sử số liệu"),
+    (
+        "No samples recorded in this period",
+        "Không có mẫu nào được ghi trong khoảng thời gian này",
+    ),
+    ("Metric", "Số liệu"),
+    ("Samples", "Số mẫu"),
+    ("Min", "Thấp nhất"),
+    ("Avg", "Trung bình"),
+    ("Max", "Cao nhất"),
+    ("Last", "Gần nhất"),
+    ("Trend", "Xu hướng"),
+    ("Address", "Địa chỉ"),
+    ("CPU Info", "Thông tin CPU"),
+    ("CPU Details", "Chi tiết CPU"),
+    ("Total Usage", "Tổng mức sử dụng"),
+    ("Per-Core Usage (60s)", "Mức sử dụng từng lõi (60 giây)"),
+    ("RAM Usage", "Mức sử dụng RAM"),
+    ("RAM & Swap Details", "Chi tiết RAM & bộ nhớ hoán đổi"),
+    ("DIMM Details", "Chi tiết DIMM"),
+    ("Storage (Top 5)", "Lưu trữ (5 mục đầu)"),
+    ("Storage Details", "Chi tiết lưu trữ"),
+    ("Throughput (60s)", "Lưu lượng (60 giây)"),
+    ("Thermal & Power", "Nhiệt độ & Nguồn"),
+    ("Temperatures", "Nhiệt độ"),
+    ("Sensor", "Cảm biến"),
+    ("Temperature", "Nhiệt độ"),
+    ("Critical", "Tới hạn"),
+    ("Fans", "Quạt"),
+    ("Fan", "Quạt"),
+    ("Power", "Công suất"),
+    ("Discharge rate", "Tốc độ xả"),
+    ("Charge rate", "Tốc độ sạc"),
+    ("No battery detected", "Không phát hiện pin"),
+    (
+        "(See CPU & RAM tab for DIMM details)",
+        "(Xem tab CPU & RAM để biết chi tiết DIMM)",
+    ),
+];
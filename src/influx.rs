@@ -0,0 +1,252 @@
+use crate::formatter::custom_fields;
+use crate::model::HardwareReport;
+use serde_json::Value;
+use std::fmt::Write as _;
+
+// Line protocol escaping: https://docs.influxdata.com/influxdb/v2/reference/syntax/line-protocol/
+fn escape_key(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace('=', "\\=")
+        .replace(' ', "\\ ")
+}
+
+fn escape_string(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+enum Field {
+    Float(f64),
+    Int(u64),
+    Bool(bool),
+    Str(String),
+}
+
+/// One line: `measurement,tag=v,... field=v,...`. Timestamps are left to the
+/// collector (e.g. telegraf `exec`), which stamps each line when it reads it.
+struct Line<'a> {
+    measurement: &'a str,
+    tags: Vec<(&'a str, String)>,
+    fields: Vec<(&'a str, Field)>,
+}
+
+impl<'a> Line<'a> {
+    fn new(measurement: &'a str, host: &str) -> Self {
+        Self {
+            measurement,
+            tags: Vec::new(),
+            fields: Vec::new(),
+        }
+        .tag("host", host)
+    }
+
+    fn tag(mut self, key: &'a str, value: &str) -> Self {
+        // Empty tag values are not allowed in line protocol.
+        if !value.is_empty() {
+            self.tags.push((key, value.to_string()));
+        }
+        self
+    }
+
+    fn field(mut self, key: &'a str, value: Field) -> Self {
+        self.fields.push((key, value));
+        self
+    }
+
+    fn float(self, key: &'a str, value: Option<f64>) -> Self {
+        match value {
+            Some(v) if v.is_finite() => self.field(key, Field::Float(v)),
+            _ => self,
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        if self.fields.is_empty() {
+            return;
+        }
+        out.push_str(self.measurement);
+        for (key, value) in &self.tags {
+            let _ = write!(out, ",{}={}", key, escape_key(value));
+        }
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            out.push(if i == 0 { ' ' } else { ',' });
+            let _ = match value {
+                Field::Float(v) => write!(out, "{}={}", escape_key(key), v),
+                Field::Int(v) => write!(out, "{}={}i", escape_key(key), v),
+                Field::Bool(v) => write!(out, "{}={}", escape_key(key), v),
+                Field::Str(v) => write!(out, "{}=\"{}\"", escape_key(key), escape_string(v)),
+            };
+        }
+        out.push('\n');
+    }
+}
+
+fn percent(used: u64, total: u64) -> Option<f64> {
+    (total > 0).then(|| used as f64 / total as f64 * 100.0)
+}
+
+/// Renders the report as InfluxDB line protocol, one `hw_*` measurement per kind of component.
+pub fn render_influx(report: &HardwareReport) -> String {
+    let host = report.hostname.as_str();
+    let mut lines = vec![
+        Line::new("hw_system", host)
+            .tag("os", &report.os_name)
+            .tag("os_version", &report.os_version)
+            .tag("kernel", &report.kernel_version)
+            .field("uptime", Field::Int(report.uptime)),
+    ];
+
+    for package in &report.cpu {
+        for core in &package.cores {
+            lines.push(
+                Line::new("hw_cpu", host)
+                    .tag("cpu", &core.id.to_string())
+                    .tag("model", package.model.trim())
+                    .float("usage", Some(core.usage as f64))
+                    .field("frequency_mhz", Field::Int(core.frequency))
+                    .field("cores", Field::Int(package.physical_cores as u64)),
+            );
+        }
+    }
+
+    let ram = &report.ram;
+    lines.push(
+        Line::new("hw_memory", host)
+            .field("total", Field::Int(ram.total))
+            .field("used", Field::Int(ram.used))
+            .field("free", Field::Int(ram.free))
+            .float("used_percent", percent(ram.used, ram.total))
+            .field("swap_total", Field::Int(ram.swap_total))
+            .field("swap_used", Field::Int(ram.swap_used))
+            .float("swap_used_percent", percent(ram.swap_used, ram.swap_total)),
+    );
+
+    for fs in &report.filesystems {
+        let mut line = Line::new("hw_disk", host)
+            .tag("device", &fs.name)
+            .tag("mount", &fs.mount_point)
+            .tag("fstype", &fs.filesystem)
+            .field("total", Field::Int(fs.total))
+            .field("used", Field::Int(fs.used))
+            .field("free", Field::Int(fs.free))
+            .float("used_percent", percent(fs.used, fs.total));
+        if let Some(model) = report.disk_for(fs).and_then(|d| d.model_name.as_ref()) {
+            line = line.field("model", Field::Str(model.clone()));
+        }
+        lines.push(line);
+    }
+
+    for disk in &report.disks {
+        let mut line = Line::new("hw_drive", host)
+            .tag("name", &disk.name)
+            .field("size", Field::Int(disk.size));
+        if let Some(model) = &disk.model_name {
+            line = line.tag("model", model);
+        }
+        if let Some(smart) = &disk.smart {
+            line = line
+                .field("smart_passed", Field::Bool(smart.passed))
+                .float("temperature", smart.temperature.map(f64::from));
+            if let Some(hours) = smart.power_on_hours {
+                line = line.field("power_on_hours", Field::Int(hours));
+            }
+        }
+        lines.push(line);
+    }
+
+    for io in &report.disk_io {
+        lines.push(
+            Line::new("hw_diskio", host)
+                .tag("name", &io.name)
+                .float("read_bytes_per_sec", Some(io.read_bytes_per_sec))
+                .float("write_bytes_per_sec", Some(io.write_bytes_per_sec))
+                .float("read_iops", Some(io.read_iops))
+                .float("write_iops", Some(io.write_iops)),
+        );
+    }
+
+    for net in &report.network {
+        lines.push(
+            Line::new("hw_net", host)
+                .tag("interface", &net.name)
+                .field("bytes_recv", Field::Int(net.received))
+                .field("bytes_sent", Field::Int(net.transmitted)),
+        );
+    }
+
+    for sensor in &report.temperatures {
+        lines.push(
+            Line::new("hw_temp", host)
+                .tag("sensor", &sensor.label)
+                .float("celsius", sensor.temperature.map(f64::from))
+                .float("max", sensor.max.map(f64::from))
+                .float("critical", sensor.critical.map(f64::from)),
+        );
+    }
+
+    for fan in &report.fans {
+        lines.push(
+            Line::new("hw_fan", host)
+                .tag("fan", &fan.label)
+                .field("rpm", Field::Int(fan.rpm as u64)),
+        );
+    }
+
+    for battery in &report.battery {
+        lines.push(
+            Line::new("hw_battery", host)
+                .tag("name", &battery.name)
+                .tag("status", &battery.status)
+                .field("capacity", Field::Int(battery.capacity as u64))
+                .float("power_watts", battery.power_watts.map(f64::from)),
+        );
+    }
+
+    // One hw_custom line per plugin or collector, a field per scalar
+    let custom = custom_fields(&report.custom);
+    for name in report.custom.keys() {
+        let mut line = Line::new("hw_custom", host).tag("name", name);
+        for (_, path, value) in custom.iter().filter(|(n, _, _)| n == name) {
+            let key = if path.is_empty() {
+                "value"
+            } else {
+                path.as_str()
+            };
+            line = match value {
+                Value::Bool(b) => line.field(key, Field::Bool(*b)),
+                Value::Number(n) => line.float(key, n.as_f64()),
+                Value::String(s) => line.field(key, Field::Str(s.clone())),
+                _ => line,
+            };
+        }
+        lines.push(line);
+    }
+
+    let mut out = String::new();
+    for line in &lines {
+        line.write(&mut out);
+    }
+    out.truncate(out.trim_end().len());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_host_leaves_out_the_tag() {
+        let mut out = String::new();
+        Line::new("hw_cpu", "")
+            .tag("core", "0")
+            .float("usage", Some(12.5))
+            .write(&mut out);
+        assert_eq!(out, "hw_cpu,core=0 usage=12.5\n");
+
+        out.clear();
+        Line::new("hw_cpu", "db 1")
+            .float("usage", Some(12.5))
+            .write(&mut out);
+        assert_eq!(out, "hw_cpu,host=db\\ 1 usage=12.5\n");
+    }
+}
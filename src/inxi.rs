@@ -0,0 +1,164 @@
+//! `--format inxi`: a condensed plain-text overview in the layout of
+//! `inxi -b`, one `Key: value` line per device under each section heading.
+
+use crate::formatter::format_uptime;
+use crate::model::HardwareReport;
+use std::fmt::Write as _;
+
+pub fn render_inxi(report: &HardwareReport) -> String {
+    let mut out = String::new();
+    let mut section = |title: &str, lines: Vec<String>| {
+        if lines.is_empty() {
+            return;
+        }
+        let _ = writeln!(out, "{}:", title);
+        for line in lines {
+            let _ = writeln!(out, "  {}", line);
+        }
+    };
+
+    let arch = report
+        .os_details
+        .as_ref()
+        .map(|d| format!(" arch: {}", d.architecture))
+        .unwrap_or_default();
+    section(
+        "System",
+        vec![format!(
+            "Host: {} Kernel: {}{} Distro: {} {}",
+            report.hostname, report.kernel_version, arch, report.os_name, report.os_version
+        )],
+    );
+
+    if let Some(board) = &report.motherboard {
+        section(
+            "Machine",
+            vec![format!(
+                "Mobo: {} model: {} BIOS: {} v: {} date: {}",
+                or_na(&board.vendor),
+                or_na(&board.product),
+                or_na(&board.bios_vendor),
+                or_na(&board.bios_version),
+                or_na(&board.bios_date)
+            )],
+        );
+    }
+
+    section(
+        "CPU",
+        report
+            .cpu
+            .iter()
+            .map(|package| {
+                let speed = package
+                    .cores
+                    .iter()
+                    .map(|c| c.frequency)
+                    .max()
+                    .map(|mhz| format!(" speed: {} MHz", mhz))
+                    .unwrap_or_default();
+                format!(
+                    "Info: {}-core model: {} threads: {}{}",
+                    package.physical_cores, package.model, package.logical_cores, speed
+                )
+            })
+            .collect(),
+    );
+
+    section(
+        "Graphics",
+        report
+            .gpus
+            .iter()
+            .enumerate()
+            .map(|(i, gpu)| {
+                format!(
+                    "Device-{}: {} driver: {}",
+                    i + 1,
+                    gpu.name,
+                    gpu.driver.as_deref().unwrap_or("N/A")
+                )
+            })
+            .collect(),
+    );
+
+    section(
+        "Network",
+        report
+            .network
+            .iter()
+            .filter(|n| n.name != "lo")
+            .map(|n| format!("IF: {} mac: {}", n.name, or_na(&n.mac_address)))
+            .collect(),
+    );
+
+    let total: u64 = report.disks.iter().map(|d| d.size).sum();
+    let used: u64 = report.filesystems.iter().map(|fs| fs.used).sum();
+    let mut drives = Vec::new();
+    if total > 0 {
+        drives.push(format!(
+            "Local Storage: total: {} used: {} ({:.1}%)",
+            gib(total),
+            gib(used),
+            used as f64 * 100.0 / total as f64
+        ));
+    }
+    for (i, disk) in report.disks.iter().enumerate() {
+        drives.push(format!(
+            "ID-{}: /dev/{} vendor: {} model: {} size: {}",
+            i + 1,
+            disk.name,
+            disk.vendor.as_deref().unwrap_or("N/A"),
+            disk.model_name.as_deref().unwrap_or("N/A"),
+            gib(disk.size)
+        ));
+    }
+    section("Drives", drives);
+
+    section(
+        "Battery",
+        report
+            .battery
+            .iter()
+            .enumerate()
+            .map(|(i, b)| {
+                format!(
+                    "ID-{}: {} charge: {}% status: {}",
+                    i + 1,
+                    b.name,
+                    b.capacity,
+                    b.status
+                )
+            })
+            .collect(),
+    );
+
+    let ram = &report.ram;
+    let memory = if ram.total > 0 {
+        format!(
+            "Memory: total: {} used: {} ({:.1}%) ",
+            gib(ram.total),
+            gib(ram.used),
+            ram.used as f64 * 100.0 / ram.total as f64
+        )
+    } else {
+        String::new()
+    };
+    section(
+        "Info",
+        vec![format!(
+            "{}Uptime: {}",
+            memory,
+            format_uptime(report.uptime)
+        )],
+    );
+    out
+}
+
+fn or_na(value: &str) -> &str {
+    if value.is_empty() { "N/A" } else { value }
+}
+
+fn gib(bytes: u64) -> String {
+    format!("{:.2} GiB", bytes as f64 / 1024.0 / 1024.0 / 1024.0)
+}
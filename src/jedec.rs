@@ -0,0 +1,2250 @@
+//! JEDEC JEP-106 manufacturer codes, the IDs memory modules, DRAM dies and
+//! SPD EEPROMs carry instead of a name. A code is a bank (1 to 17, written as
+//! that many minus one 0x7F continuation bytes) and a 7-bit ID with an odd
+//! parity bit on top.
+
+/// Registered names by bank, `BANKS[bank - 1][id - 1]`; "" where the ID is
+/// unassigned. From JEP106BE.
+static BANKS: [[&str; 126]; 17] = [
+    [
+        "AMD",
+        "AMI",
+        "Fairchild",
+        "RAMXEED Limited",
+        "GTE",
+        "Harris",
+        "Hitachi",
+        "Inmos",
+        "Intel",
+        "I.T.T.",
+        "Intersil",
+        "Monolithic Memories",
+        "Mostek",
+        "Freescale (Motorola)",
+        "National",
+        "NEC",
+        "RCA",
+        "Raytheon",
+        "Synaptics",
+        "Seeq",
+        "NXP (Philips)",
+        "Synertek",
+        "Texas Instruments",
+        "Kioxia Corporation",
+        "Xicor",
+        "Zilog",
+        "Eurotechnique",
+        "Mitsubishi",
+        "Lucent (AT&T)",
+        "Exel",
+        "Atmel",
+        "STMicroelectronics",
+        "Lattice Semi.",
+        "NCR",
+        "Wafer Scale Integration",
+        "IBM",
+        "Tristar",
+        "Visic",
+        "Intl. CMOS Technology",
+        "SSSI",
+        "Microchip Technology",
+        "Ricoh Ltd",
+        "VLSI",
+        "Micron Technology",
+        "SK Hynix",
+        "OKI Semiconductor",
+        "ACTEL",
+        "Sharp",
+        "Catalyst",
+        "Panasonic",
+        "IDT",
+        "Cypress",
+        "DEC",
+        "LSI Logic",
+        "",
+        "UTMC",
+        "Thinking Machine",
+        "Thomson CSF",
+        "Integrated CMOS (Vertex)",
+        "Honeywell",
+        "Tektronix",
+        "Oracle Corporation",
+        "Silicon Storage Technology",
+        "ProMos/Mosel Vitelic",
+        "Infineon (Siemens)",
+        "Macronix",
+        "Xerox",
+        "Plus Logic",
+        "Western Digital Technologies Inc",
+        "Elan Circuit Tech.",
+        "European Silicon Str.",
+        "Apple Computer",
+        "Xilinx",
+        "Compaq",
+        "Protocol Engines",
+        "SCI",
+        "ABLIC",
+        "Samsung",
+        "I3 Design System",
+        "Klic",
+        "Crosspoint Solutions",
+        "Alliance Memory Inc",
+        "Tandem",
+        "Hewlett-Packard",
+        "Integrated Silicon Solutions",
+        "Brooktree",
+        "New Media",
+        "MHS Electronic",
+        "Performance Semi.",
+        "Winbond Electronic",
+        "Kawasaki Steel",
+        "Bright Micro",
+        "TECMAR",
+        "Exar",
+        "PCMCIA",
+        "LG Semi (Goldstar)",
+        "Northern Telecom",
+        "Sanyo",
+        "Array Microsystems",
+        "Crystal Semiconductor",
+        "Analog Devices",
+        "PMC-Sierra",
+        "Asparix",
+        "Convex Computer",
+        "Quality Semiconductor",
+        "Nimbus Technology",
+        "Transwitch",
+        "Micronas (ITT Intermetall)",
+        "Cannon",
+        "Altera",
+        "NEXCOM",
+        "",
+        "Sony",
+        "Cray Research",
+        "AMS(Austria Micro)",
+        "Vitesse",
+        "Aster Electronics",
+        "Bay Networks (Synoptic)",
+        "Zentrum/ZMD",
+        "TRW",
+        "Thesys",
+        "Solbourne Computer",
+        "Allied-Signal",
+        "Dialog Semiconductor",
+        "Media Vision",
+        "Numonyx Corporation",
+    ],
+    [
+        "Cirrus Logic",
+        "National Instruments",
+        "ILC Data Device",
+        "Alcatel Mietec",
+        "Micro Linear",
+        "Univ. of NC",
+        "JTAG Technologies",
+        "BAE Systems (Loral)",
+        "Nchip",
+        "Galileo Tech",
+        "Bestlink Systems",
+        "Graychip",
+        "GENNUM",
+        "Imagination Technologies Limited",
+        "Robert Bosch",
+        "Chip Express",
+        "DATARAM",
+        "United Microelectronics Corp",
+        "TCSI",
+        "Smart Modular",
+        "Hughes Aircraft",
+        "Lanstar Semiconductor",
+        "Qlogic",
+        "Kingston",
+        "Music Semi",
+        "Ericsson Components",
+        "SpaSE",
+        "Eon Silicon Devices",
+        "Integrated Silicon Solution (ISSI)",
+        "DoD",
+        "Integ. Memories Tech.",
+        "Corollary Inc",
+        "Dallas Semiconductor",
+        "Omnivision",
+        "EIV(Switzerland)",
+        "Novatel Wireless",
+        "Zarlink (Mitel)",
+        "Clearpoint",
+        "Cabletron",
+        "STEC (Silicon Tech)",
+        "Vanguard",
+        "Hagiwara Solutions Co Ltd",
+        "Vantis",
+        "Celestica",
+        "Century",
+        "Hal Computers",
+        "Rohm Company Ltd",
+        "Juniper Networks",
+        "Libit Signal Processing",
+        "Mushkin Enhanced Memory",
+        "Tundra Semiconductor",
+        "Adaptec Inc",
+        "LightSpeed Semi.",
+        "ZSP Corp",
+        "",
+        "Adobe Systems",
+        "Dynachip",
+        "PNY Technologies Inc",
+        "Newport Digital",
+        "MMC Networks",
+        "T Square",
+        "Seiko Epson",
+        "Broadcom",
+        "Viking Components",
+        "V3 Semiconductor",
+        "Flextronics (Orbit Semiconductor)",
+        "Suwa Electronics",
+        "Transmeta",
+        "Micron CMS",
+        "",
+        "Enhance 3000 Inc",
+        "Tower Semiconductor",
+        "CPU Design",
+        "Price Point",
+        "Maxim Integrated Product",
+        "Tellabs",
+        "Centaur Technology",
+        "Unigen Corporation",
+        "Transcend Information",
+        "Memory Card Technology",
+        "CKD Corporation Ltd",
+        "Capital Instruments Inc",
+        "Aica Kogyo Ltd",
+        "Linvex Technology",
+        "MSC Vertriebs GmbH",
+        "AKM Company Ltd",
+        "Dynamem Inc",
+        "NERA ASA",
+        "GSI Technology",
+        "Dane-Elec (C Memory)",
+        "Acorn Computers",
+        "Lara Technology",
+        "Oak Technology Inc",
+        "Itec Memory",
+        "Tanisys Technology",
+        "Truevision",
+        "Wintec Industries",
+        "Super PC Memory",
+        "MGV Memory",
+        "Galvantech",
+        "Gadzoox Networks",
+        "Multi Dimensional Cons.",
+        "GateField",
+        "Integrated Memory System",
+        "Triscend",
+        "XaQti",
+        "Goldenram",
+        "Clear Logic",
+        "Cimaron Communications",
+        "Nippon Steel Semi. Corp",
+        "",
+        "AMCC",
+        "LeCroy",
+        "Yamaha Corporation",
+        "Digital Microwave",
+        "NetLogic Microsystems",
+        "MIMOS Semiconductor",
+        "Advanced Fibre",
+        "BF Goodrich Data.",
+        "Epigram",
+        "Acbel Polytech Inc",
+        "Apacer Technology",
+        "Admor Memory",
+        "FOXCONN",
+        "Quadratics Superconductor",
+        "3COM",
+    ],
+    [
+        "Camintonn Corporation",
+        "ISOA Incorporated",
+        "Agate Semiconductor",
+        "ADMtek Incorporated",
+        "HYPERTEC",
+        "Adhoc Technologies",
+        "MOSAID Technologies",
+        "Ardent Technologies",
+        "Switchcore",
+        "Cisco Systems Inc",
+        "Allayer Technologies",
+        "WorkX AG (Wichman)",
+        "Oasis Semiconductor",
+        "Novanet Semiconductor",
+        "E-M Solutions",
+        "Power General",
+        "Advanced Hardware Arch.",
+        "Inova Semiconductors GmbH",
+        "Telocity",
+        "Delkin Devices",
+        "Symagery Microsystems",
+        "C-Port Corporation",
+        "SiberCore Technologies",
+        "Southland Microsystems",
+        "Malleable Technologies",
+        "Kendin Communications",
+        "Great Technology Microcomputer",
+        "Sanmina Corporation",
+        "HADCO Corporation",
+        "Corsair",
+        "Actrans System Inc",
+        "ALPHA Technologies",
+        "Silicon Laboratories Inc (Cygnal)",
+        "Artesyn Technologies",
+        "Align Manufacturing",
+        "Peregrine Semiconductor",
+        "Chameleon Systems",
+        "Aplus Flash Technology",
+        "MIPS Technologies",
+        "Chrysalis ITS",
+        "ADTEC Corporation",
+        "Kentron Technologies",
+        "Win Technologies",
+        "Tezzaron Semiconductor",
+        "Extreme Packet Devices",
+        "RF Micro Devices",
+        "Siemens AG",
+        "Sarnoff Corporation",
+        "Itautec SA",
+        "Radiata Inc",
+        "Benchmark Elect. (AVEX)",
+        "Legend",
+        "SpecTek Incorporated",
+        "",
+        "Enikia Incorporated",
+        "SwitchOn Networks",
+        "AANetcom Incorporated",
+        "Micro Memory Bank",
+        "ESS Technology",
+        "Virata Corporation",
+        "Excess Bandwidth",
+        "West Bay Semiconductor",
+        "DSP Group",
+        "Newport Communications",
+        "Chip2Chip Incorporated",
+        "Phobos Corporation",
+        "Intellitech Corporation",
+        "Nordic VLSI ASA",
+        "Ishoni Networks",
+        "Silicon Spice",
+        "Alchemy Semiconductor",
+        "Agilent Technologies",
+        "Centillium Communications",
+        "W.L. Gore",
+        "HanBit Electronics",
+        "GlobeSpan",
+        "Element 14",
+        "Pycon",
+        "Saifun Semiconductors",
+        "Sibyte Incorporated",
+        "MetaLink Technologies",
+        "Feiya Technology",
+        "I & C Technology",
+        "Shikatronics",
+        "Elektrobit",
+        "Megic",
+        "Com-Tier",
+        "Malaysia Micro Solutions",
+        "Hyperchip",
+        "Gemstone Communications",
+        "Anadigm (Anadyne)",
+        "3ParData",
+        "Mellanox Technologies",
+        "Tenx Technologies",
+        "Helix AG",
+        "Domosys",
+        "Skyup Technology",
+        "HiNT Corporation",
+        "Chiaro",
+        "MDT Technologies GmbH",
+        "Exbit Technology A/S",
+        "Integrated Technology Express",
+        "AVED Memory",
+        "Legerity",
+        "Jasmine Networks",
+        "Caspian Networks",
+        "nCUBE",
+        "Silicon Access Networks",
+        "FDK Corporation",
+        "High Bandwidth Access",
+        "",
+        "BRECIS",
+        "World Wide Packets",
+        "APW",
+        "Chicory Systems",
+        "Xstream Logic",
+        "Fast-Chip",
+        "Zucotto Wireless",
+        "Realchip",
+        "Galaxy Power",
+        "eSilicon",
+        "Morphics Technology",
+        "Accelerant Networks",
+        "Silicon Wave",
+        "SandCraft",
+        "Elpida",
+    ],
+    [
+        "Solectron",
+        "Optosys Technologies",
+        "Buffalo (Formerly Melco)",
+        "TriMedia Technologies",
+        "Cyan Technologies",
+        "Global Locate",
+        "Optillion",
+        "Terago Communications",
+        "Ikanos Communications",
+        "Princeton Technology",
+        "Nanya Technology",
+        "Elite Flash Storage",
+        "Mysticom",
+        "LightSand Communications",
+        "ATI Technologies",
+        "Agere Systems",
+        "NeoMagic",
+        "AuroraNetics",
+        "Golden Empire",
+        "Mushkin",
+        "Tioga Technologies",
+        "Netlist",
+        "TeraLogic",
+        "Cicada Semiconductor",
+        "Centon Electronics",
+        "Tyco Electronics",
+        "Magis Works",
+        "Zettacom",
+        "Cogency Semiconductor",
+        "Chipcon AS",
+        "Aspex Technology",
+        "F5 Networks",
+        "Programmable Silicon Solutions",
+        "ChipWrights",
+        "Acorn Networks",
+        "Quicklogic",
+        "Kingmax Semiconductor",
+        "BOPS",
+        "Flasys",
+        "BitBlitz Communications",
+        "eMemory Technology",
+        "Procket Networks",
+        "Purple Ray",
+        "Trebia Networks",
+        "Delta Electronics",
+        "Onex Communications",
+        "Ample Communications",
+        "Memory Experts Intl",
+        "Astute Networks",
+        "Azanda Network Devices",
+        "Dibcom",
+        "Tekmos",
+        "",
+        "Bay Microsystems",
+        "Firecron Ltd",
+        "Resonext Communications",
+        "Tachys Technologies",
+        "Equator Technology",
+        "Concept Computer",
+        "SILCOM",
+        "3Dlabs",
+        "c’t Magazine",
+        "Sanera Systems",
+        "Silicon Packets",
+        "Viasystems Group",
+        "Simtek",
+        "Semicon Devices Singapore",
+        "Satron Handelsges",
+        "Improv Systems",
+        "INDUSYS GmbH",
+        "Corrent",
+        "Infrant Technologies",
+        "Ritek Corp",
+        "empowerTel Networks",
+        "Hypertec",
+        "Cavium Networks",
+        "PLX Technology",
+        "Massana Design",
+        "Intrinsity",
+        "Valence Semiconductor",
+        "Terawave Communications",
+        "IceFyre Semiconductor",
+        "Primarion",
+        "Picochip Designs Ltd",
+        "Silverback Systems",
+        "Jade Star Technologies",
+        "Pijnenburg Securealink",
+        "takeMS - Ultron AG",
+        "Cambridge Silicon Radio",
+        "Swissbit",
+        "Nazomi Communications",
+        "eWave System",
+        "Rockwell Collins",
+        "Picocel Co Ltd (Paion)",
+        "Alphamosaic Ltd",
+        "Sandburst",
+        "SiCon Video",
+        "NanoAmp Solutions",
+        "Ericsson Technology",
+        "PrairieComm",
+        "Mitac International",
+        "Layer N Networks",
+        "MtekVision (Atsana)",
+        "Allegro Networks",
+        "Marvell Semiconductors",
+        "Netergy Microelectronic",
+        "NVIDIA",
+        "Internet Machines",
+        "Memorysolution GmbH",
+        "",
+        "Accton Technology",
+        "Teradiant Networks",
+        "Scaleo Chip",
+        "Cortina Systems",
+        "RAM Components",
+        "Raqia Networks",
+        "ClearSpeed",
+        "Matsushita Battery",
+        "Xelerated",
+        "SimpleTech",
+        "Utron Technology",
+        "Astec International",
+        "AVM gmbH",
+        "Redux Communications",
+        "Dot Hill Systems",
+        "TeraChip",
+    ],
+    [
+        "T-RAM Incorporated",
+        "Innovics Wireless",
+        "Teknovus",
+        "KeyEye Communications",
+        "Runcom Technologies",
+        "RedSwitch",
+        "Dotcast",
+        "Silicon Mountain Memory",
+        "Signia Technologies",
+        "Pixim",
+        "Galazar Networks",
+        "White Electronic Designs",
+        "Patriot Scientific",
+        "Neoaxiom Corporation",
+        "3Y Power Technology",
+        "Scaleo Chip",
+        "Potentia Power Systems",
+        "C-guys Incorporated",
+        "Digital Communications Technology Inc",
+        "Silicon-Based Technology",
+        "Fulcrum Microsystems",
+        "Positivo Informatica Ltd",
+        "XIOtech Corporation",
+        "PortalPlayer",
+        "Zhiying Software",
+        "ParkerVision Inc",
+        "Phonex Broadband",
+        "Skyworks Solutions",
+        "Entropic Communications",
+        "I’M Intelligent Memory Ltd",
+        "Zensys A/S",
+        "Legend Silicon Corp",
+        "Sci-worx GmbH",
+        "SMSC (Standard Microsystems)",
+        "Renesas Electronics",
+        "Raza Microelectronics",
+        "Phyworks",
+        "MediaTek",
+        "Non-cents Productions",
+        "US Modular",
+        "Wintegra Ltd",
+        "Mathstar",
+        "StarCore",
+        "Oplus Technologies",
+        "Mindspeed",
+        "Just Young Computer",
+        "Radia Communications",
+        "OCZ",
+        "Emuzed",
+        "LOGIC Devices",
+        "Inphi Corporation",
+        "",
+        "Vixel",
+        "SolusTek",
+        "Kongsberg Maritime",
+        "Faraday Technology",
+        "Altium Ltd",
+        "Insyte",
+        "ARM Ltd",
+        "DigiVision",
+        "Vativ Technologies",
+        "Endicott Interconnect Technologies",
+        "Pericom",
+        "Bandspeed",
+        "LeWiz Communications",
+        "CPU Technology",
+        "Ramaxel Technology",
+        "DSP Group",
+        "Axis Communications",
+        "Legacy Electronics",
+        "Chrontel",
+        "Powerchip Semiconductor",
+        "MobilEye Technologies",
+        "Excel Semiconductor",
+        "A-DATA Technology",
+        "VirtualDigm",
+        "G Skill Intl",
+        "Quanta Computer",
+        "Yield Microelectronics",
+        "Afa Technologies",
+        "KINGBOX Technology Co Ltd",
+        "Ceva",
+        "iStor Networks",
+        "Advance Modules",
+        "Microsoft",
+        "Open-Silicon",
+        "Goal Semiconductor",
+        "ARC International",
+        "Simmtec",
+        "Metanoia",
+        "Key Stream",
+        "Lowrance Electronics",
+        "Adimos",
+        "SiGe Semiconductor",
+        "Fodus Communications",
+        "Credence Systems Corp",
+        "Genesis Microchip Inc",
+        "Vihana Inc",
+        "WIS Technologies",
+        "GateChange Technologies",
+        "High Density Devices AS",
+        "Synopsys",
+        "Gigaram",
+        "Enigma Semiconductor Inc",
+        "Century Micro Inc",
+        "Icera Semiconductor",
+        "Mediaworks Integrated Systems",
+        "O’Neil Product Development",
+        "",
+        "MicroDisplay Corporation",
+        "Team Group Inc",
+        "Sinett Corporation",
+        "Toshiba Corporation",
+        "Tensilica",
+        "SiRF Technology",
+        "Bacoc Inc",
+        "SMaL Camera Technologies",
+        "Thomson SC",
+        "Airgo Networks",
+        "Wisair Ltd",
+        "SigmaTel",
+        "Arkados",
+        "Compete IT gmbH Co KG",
+        "Eudar Technology Inc",
+        "Focus Enhancements",
+        "Xyratex",
+    ],
+    [
+        "Specular Networks",
+        "Patriot Memory (PDP Systems)",
+        "U-Chip Technology Corp",
+        "Silicon Optix",
+        "Greenfield Networks",
+        "CompuRAM GmbH",
+        "Stargen Inc",
+        "NetCell Corporation",
+        "Excalibrus Technologies Ltd",
+        "SCM Microsystems",
+        "Xsigo Systems Inc",
+        "CHIPS & Systems Inc",
+        "Tier 1 Multichip Solutions",
+        "CWRL Labs",
+        "Teradici",
+        "Gigaram Inc",
+        "g2 Microsystems",
+        "PowerFlash Semiconductor",
+        "P.A. Semi Inc",
+        "NovaTech Solutions S.A.",
+        "c2 Microsystems Inc",
+        "Level5 Networks",
+        "COS Memory AG",
+        "Innovasic Semiconductor",
+        "02IC Co Ltd",
+        "Tabula Inc",
+        "Crucial Technology",
+        "Chelsio Communications",
+        "Solarflare Communications",
+        "Xambala Inc",
+        "EADS Astrium",
+        "Terra Semiconductor Inc",
+        "Imaging Works Inc",
+        "Astute Networks Inc",
+        "Tzero",
+        "Emulex",
+        "Power-One",
+        "Pulse~LINK Inc",
+        "Hon Hai Precision Industry",
+        "White Rock Networks Inc",
+        "Telegent Systems USA Inc",
+        "Atrua Technologies Inc",
+        "Acbel Polytech Inc",
+        "eRide Inc",
+        "ULi Electronics Inc",
+        "Magnum Semiconductor Inc",
+        "neoOne Technology Inc",
+        "Connex Technology Inc",
+        "Stream Processors Inc",
+        "Focus Enhancements",
+        "",
+        "uNav Microelectronics",
+        "Tarari Inc",
+        "Ambric Inc",
+        "Newport Media Inc",
+        "VMTS",
+        "Enuclia Semiconductor Inc",
+        "Virtium Technology Inc",
+        "Solid State System Co Ltd",
+        "Kian Tech LLC",
+        "Artimi",
+        "Power Quotient International",
+        "Avago Technologies",
+        "ADTechnology",
+        "Sigma Designs",
+        "SiCortex Inc",
+        "Ventura Technology Group",
+        "eASIC",
+        "M.H.S. SAS",
+        "Micro Star International",
+        "Rapport Inc",
+        "Makway International",
+        "Broad Reach Engineering Co",
+        "Semiconductor Mfg Intl Corp",
+        "SiConnect",
+        "FCI USA Inc",
+        "Validity Sensors",
+        "Coney Technology Co Ltd",
+        "Spans Logic",
+        "Neterion Inc",
+        "Qimonda",
+        "New Japan Radio Co Ltd",
+        "Velogix",
+        "Montalvo Systems",
+        "iVivity Inc",
+        "Walton Chaintech",
+        "AENEON",
+        "Lorom Industrial Co Ltd",
+        "Radiospire Networks",
+        "Sensio Technologies Inc",
+        "Nethra Imaging",
+        "Hexon Technology Pte Ltd",
+        "CompuStocx (CSX)",
+        "Methode Electronics Inc",
+        "Connect One Ltd",
+        "Opulan Technologies",
+        "Septentrio NV",
+        "Goldenmars Technology Inc",
+        "Kreton Corporation",
+        "Cochlear Ltd",
+        "Altair Semiconductor",
+        "NetEffect Inc",
+        "Spansion Inc",
+        "Taiwan Semiconductor Mfg",
+        "Emphany Systems Inc",
+        "ApaceWave Technologies",
+        "Mobilygen Corporation",
+        "",
+        "Cswitch Corporation",
+        "Haier (Beijing) IC Design Co",
+        "MetaRAM",
+        "Axel Electronics Co Ltd",
+        "Tilera Corporation",
+        "Aquantia",
+        "Vivace Semiconductor",
+        "Redpine Signals",
+        "Octalica",
+        "InterDigital Communications",
+        "Avant Technology",
+        "Asrock Inc",
+        "Availink",
+        "Quartics Inc",
+        "Element CXI",
+        "Innovaciones Microelectronicas",
+        "VeriSilicon Microelectronics",
+        "W5 Networks",
+    ],
+    [
+        "MOVEKING",
+        "Mavrix Technology Inc",
+        "CellGuide Ltd",
+        "Faraday Technology",
+        "Diablo Technologies Inc",
+        "Jennic",
+        "Octasic",
+        "Molex Incorporated",
+        "3Leaf Networks",
+        "Bright Micron Technology",
+        "Netxen",
+        "NextWave Broadband Inc",
+        "DisplayLink",
+        "ZMOS Technology",
+        "Tec-Hill",
+        "Multigig Inc",
+        "Amimon",
+        "Euphonic Technologies Inc",
+        "BRN Phoenix",
+        "InSilica",
+        "Ember Corporation",
+        "Avexir Technologies Corporation",
+        "Echelon Corporation",
+        "Edgewater Computer Systems",
+        "XMOS Semiconductor Ltd",
+        "GENUSION Inc",
+        "Memory Corp NV",
+        "SiliconBlue Technologies",
+        "Rambus Inc",
+        "Andes Technology Corporation",
+        "Coronis Systems",
+        "Achronix Semiconductor",
+        "Siano Mobile Silicon Ltd",
+        "Semtech Corporation",
+        "Pixelworks Inc",
+        "Gaisler Research AB",
+        "Teranetics",
+        "Toppan Printing Co Ltd",
+        "Kingxcon",
+        "Silicon Integrated Systems",
+        "I-O Data Device Inc",
+        "NDS Americas Inc",
+        "Solomon Systech Limited",
+        "On Demand Microelectronics",
+        "Amicus Wireless Inc",
+        "SMARDTV SNC",
+        "Comsys Communication Ltd",
+        "Movidia Ltd",
+        "Javad GNSS Inc",
+        "",
+        "Trident Microsystems",
+        "Super Talent",
+        "Optichron Inc",
+        "Future Waves UK Ltd",
+        "SiBEAM Inc",
+        "InicoreInc",
+        "Virident Systems",
+        "M2000 Inc",
+        "ZeroG Wireless Inc",
+        "Gingle Technology Co Ltd",
+        "Space Micro Inc",
+        "Wilocity",
+        "Novafora Inc",
+        "iKoa Corporation",
+        "ASint Technology",
+        "Ramtron",
+        "Plato Networks Inc",
+        "IPtronics AS",
+        "Infinite-Memories",
+        "Parade Technologies Inc",
+        "Dune Networks",
+        "GigaDevice Semiconductor",
+        "Modu Ltd",
+        "CEITEC",
+        "Northrop Grumman",
+        "XRONET Corporation",
+        "Sicon Semiconductor AB",
+        "Atla Electronics Co Ltd",
+        "TOPRAM Technology",
+        "Silego Technology Inc",
+        "Kinglife",
+        "Ability Industries Ltd",
+        "",
+        "Augusta Technology Inc",
+        "Nantronics Semiconductors",
+        "Hilscher Gesellschaft",
+        "Quixant Ltd",
+        "Percello Ltd",
+        "NextIO Inc",
+        "Scanimetrics Inc",
+        "FS-Semi Company Ltd",
+        "Infinera Corporation",
+        "SandForce Inc",
+        "Lexar Media",
+        "Teradyne Inc",
+        "Memory Exchange Corp",
+        "Suzhou Smartek Electronics",
+        "Avantium Corporation",
+        "ATP Electronics Inc",
+        "Valens Semiconductor Ltd",
+        "Agate Logic Inc",
+        "Netronome",
+        "Zenverge Inc",
+        "N-trig Ltd",
+        "SanMax Technologies Inc",
+        "",
+        "TwinMOS",
+        "Silicon Systems Inc",
+        "V-Color Technology Inc",
+        "Certicom Corporation",
+        "JSC ICC Milandr",
+        "PhotoFast Global Inc",
+        "InnoDisk Corporation",
+        "Muscle Power",
+        "Energy Micro",
+        "Innofidei",
+        "CopperGate Communications",
+        "Holtek Semiconductor Inc",
+        "Myson Century Inc",
+        "FIDELIX",
+        "Red Digital Cinema",
+        "Densbits Technology",
+        "Zempro",
+        "MoSys",
+        "Provigent",
+        "Triad Semiconductor Inc",
+    ],
+    [
+        "Siklu Communication Ltd",
+        "A Force Manufacturing Ltd",
+        "Strontium",
+        "ALi Corp (Abilis Systems)",
+        "Siglead Inc",
+        "Ubicom Inc",
+        "Unifosa Corporation",
+        "Stretch Inc",
+        "Lantiq Deutschland GmbH",
+        "Visipro.",
+        "EKMemory",
+        "Microelectronics Institute ZTE",
+        "u-blox AG",
+        "Carry Technology Co Ltd",
+        "Nokia",
+        "King Tiger Technology",
+        "Sierra Wireless",
+        "HT Micron",
+        "Albatron Technology Co Ltd",
+        "Leica Geosystems AG",
+        "BroadLight",
+        "AEXEA",
+        "ClariPhy Communications Inc",
+        "Green Plug",
+        "Design Art Networks",
+        "Mach Xtreme Technology Ltd",
+        "ATO Solutions Co Ltd",
+        "Ramsta",
+        "Greenliant Systems Ltd",
+        "Teikon",
+        "Antec Hadron",
+        "NavCom Technology Inc",
+        "Shanghai Fudan Microelectronics",
+        "Calxeda Inc",
+        "JSC EDC Electronics",
+        "Kandit Technology Co Ltd",
+        "Ramos Technology",
+        "Goldenmars Technology",
+        "XeL Technology Inc",
+        "Newzone Corporation",
+        "ShenZhen MercyPower Tech",
+        "Nanjing Yihuo Technology",
+        "Nethra Imaging Inc",
+        "SiTel Semiconductor BV",
+        "SolidGear Corporation",
+        "Topower Computer Ind Co Ltd",
+        "Wilocity",
+        "Profichip GmbH",
+        "Gerad Technologies",
+        "Ritek Corporation",
+        "Gomos Technology Limited",
+        "Memoright Corporation",
+        "D-Broad Inc",
+        "HiSilicon Technologies",
+        "Syndiant Inc.",
+        "Enverv Inc",
+        "Cognex",
+        "Xinnova Technology Inc",
+        "Ultron AG",
+        "Concord Idea Corporation",
+        "AIM Corporation",
+        "Lifetime Memory Products",
+        "Ramsway",
+        "Recore Systems B.V.",
+        "Haotian Jinshibo Science Tech",
+        "Being Advanced Memory",
+        "Adesto Technologies",
+        "Giantec Semiconductor Inc",
+        "HMD Electronics AG",
+        "Gloway International (HK)",
+        "Kingcore",
+        "Anucell Technology Holding",
+        "Accord Software & Systems Pvt. Ltd",
+        "Active-Semi Inc",
+        "Denso Corporation",
+        "TLSI Inc",
+        "Qidan",
+        "Mustang",
+        "Orca Systems",
+        "Passif Semiconductor",
+        "GigaDevice Semiconductor (Beijing)",
+        "Memphis Electronic",
+        "Beckhoff Automation GmbH",
+        "Harmony Semiconductor Corp",
+        "Air Computers SRL",
+        "TMT Memory",
+        "Eorex Corporation",
+        "Xingtera",
+        "Netsol",
+        "Bestdon Technology Co Ltd",
+        "Baysand Inc",
+        "Uroad Technology Co Ltd",
+        "Wilk Elektronik S.A.",
+        "AAI",
+        "Harman",
+        "Berg Microelectronics Inc",
+        "ASSIA Inc",
+        "Visiontek Products LLC",
+        "OCMEMORY",
+        "Welink Solution Inc",
+        "Shark Gaming",
+        "Avalanche Technology",
+        "R&D Center ELVEES OJSC",
+        "KingboMars Technology Co Ltd",
+        "",
+        "Transcend Technology Co Ltd",
+        "Everspin Technologies",
+        "Hon-Hai Precision",
+        "Smart Storage Systems",
+        "Toumaz Group",
+        "Zentel Electronics Corporation",
+        "Panram International Corporation",
+        "Silicon Space Technology",
+        "LITE-ON IT Corporation",
+        "Inuitive",
+        "HMicro",
+        "BittWare Inc",
+        "GLOBALFOUNDRIES",
+        "ACPI Digital Co Ltd",
+        "Annapurna Labs",
+        "AcSiP Technology Corporation",
+        "Idea! Electronic Systems",
+        "Gowe Technology Co Ltd",
+        "Hermes Testing Solutions Inc",
+        "Positivo BGH",
+        "Intelligence  Silicon Technology",
+    ],
+    [
+        "3D PLUS",
+        "Diehl Aerospace",
+        "Fairchild",
+        "Mercury Systems",
+        "Sonics Inc",
+        "Emerson Automation Solutions",
+        "Shenzhen Jinge Information Co Ltd",
+        "SCWW",
+        "Silicon Motion Inc",
+        "Anurag",
+        "King Kong",
+        "FROM30 Co Ltd",
+        "Gowin Semiconductor Corp",
+        "Fremont Micro Devices Ltd",
+        "Ericsson Modems",
+        "Exelis",
+        "Satixfy Ltd",
+        "Galaxy Microsystems Ltd",
+        "Gloway International Co Ltd",
+        "Lab",
+        "Smart Energy Instruments",
+        "Approved Memory Corporation",
+        "Axell Corporation",
+        "Essencore Limited",
+        "Phytium",
+        "UniIC Semiconductors Co Ltd",
+        "Ambiq Micro",
+        "eveRAM Technology Inc",
+        "Infomax",
+        "Butterfly Network Inc",
+        "Shenzhen City Gcai Electronics",
+        "Stack Devices Corporation",
+        "ADK Media Group",
+        "TSP Global Co Ltd",
+        "HighX",
+        "Shenzhen Elicks Technology",
+        "XinKai/Silicon Kaiser",
+        "Google Inc",
+        "Dasima International Development",
+        "Leahkinn Technology Limited",
+        "HIMA Paul Hildebrandt GmbH Co KG",
+        "Keysight Technologies",
+        "Techcomp International (Fastable)",
+        "Ancore Technology Corporation",
+        "Nuvoton",
+        "Korea Uhbele International Group Ltd",
+        "Ikegami Tsushinki Co Ltd",
+        "RelChip Inc",
+        "Baikal Electronics",
+        "Nemostech Inc",
+        "Memorysolution GmbH",
+        "Silicon Integrated Systems Corporation",
+        "Xiede",
+        "BRC",
+        "Flash Chi",
+        "Jone",
+        "GCT Semiconductor Inc",
+        "Hong Kong Zetta Device Technology",
+        "Unimemory Technology(s) Pte Ltd",
+        "Cuso",
+        "Kuso",
+        "Uniquify Inc",
+        "Skymedi Corporation",
+        "Core Chance Co Ltd",
+        "Tekism Co Ltd",
+        "Seagate Technology PLC",
+        "Hong Kong Gaia Group Co Limited",
+        "Gigacom Semiconductor LLC",
+        "V2 Technologies",
+        "TLi",
+        "Neotion",
+        "Lenovo",
+        "Shenzhen Zhongteng Electronic Corp Ltd",
+        "Compound Photonics",
+        "in2H2 inc",
+        "Shenzhen Pango Microsystems Co Ltd",
+        "Vasekey",
+        "",
+        "Eyenix Co Ltd",
+        "Heoriady",
+        "Accelerated Memory Production Inc",
+        "INVECAS Inc",
+        "AP Memory",
+        "Douqi Technology",
+        "Etron Technology Inc",
+        "Indie Semiconductor",
+        "Socionext Inc",
+        "HGST",
+        "EVGA",
+        "Audience Inc",
+        "EpicGear",
+        "Vitesse Enterprise Co",
+        "Foxtronn International Corporation",
+        "Bretelon Inc",
+        "Graphcore",
+        "Eoplex Inc",
+        "MaxLinear Inc",
+        "ETA Devices",
+        "LOKI",
+        "IMS Electronics Co Ltd",
+        "Dosilicon Co Ltd",
+        "Dolphin Integration",
+        "Shenzhen Mic Electronics Technolog",
+        "Boya Microelectronics Inc",
+        "Geniachip (Roche)",
+        "Axign",
+        "Kingred Electronic Technology Ltd",
+        "Chao Yue Zhuo Computer Business Dept.",
+        "",
+        "Crocus Technology Inc",
+        "Creative Chips GmbH",
+        "GE Aviation Systems LLC.",
+        "Asgard",
+        "Good Wealth Technology Ltd",
+        "TriCor Technologies",
+        "Nova-Systems GmbH",
+        "JUHOR",
+        "Zhuhai Douke Commerce Co Ltd",
+        "DSL Memory",
+        "Anvo-Systems Dresden GmbH",
+        "Realtek",
+        "AltoBeam",
+        "Wave Computing",
+        "Beijing TrustNet Technology Co Ltd",
+        "Innovium Inc",
+        "Starsway Technology Limited",
+    ],
+    [
+        "Weltronics Co LTD",
+        "VMware Inc",
+        "Hewlett Packard Enterprise",
+        "INTENSO",
+        "Puya Semiconductor",
+        "MEMORFI",
+        "MSC Technologies GmbH",
+        "Txrui",
+        "SiFive Inc",
+        "Spreadtrum Communications",
+        "XTX Technology Limited",
+        "UMAX Technology",
+        "Shenzhen Yong Sheng Technology",
+        "SNOAMOO (Shenzhen Kai Zhuo Yue)",
+        "Daten Tecnologia LTDA",
+        "Shenzhen XinRuiYan Electronics",
+        "Eta Compute",
+        "Energous",
+        "Raspberry Pi Trading Ltd",
+        "Shenzhen Chixingzhe Tech Co Ltd",
+        "Silicon Mobility",
+        "IQ-Analog Corporation",
+        "Uhnder Inc",
+        "Impinj",
+        "DEPO Computers",
+        "Nespeed Sysems",
+        "Yangtze Memory Technologies Co Ltd",
+        "MemxPro Inc",
+        "Tammuz Co Ltd",
+        "Allwinner Technology",
+        "",
+        "XMC",
+        "Teclast",
+        "Maxsun",
+        "Haiguang Integrated Circuit Design",
+        "RamCENTER Technology",
+        "Phison Electronics Corporation",
+        "Guizhou Huaxintong Semi-Conductor",
+        "Network Intelligence",
+        "Continental Technology (Holdings)",
+        "Guangzhou Huayan Suning Electronic",
+        "Guangzhou Zhouji Electronic Co Ltd",
+        "Shenzhen Giant Hui Kang Tech Co Ltd",
+        "Shenzhen Yilong Innovative Co Ltd",
+        "Neo Forza",
+        "Lyontek Inc",
+        "Shanghai Kuxin Microelectronics Ltd",
+        "Shenzhen Larix Technology Co Ltd",
+        "Qbit Semiconductor Ltd",
+        "Insignis Technology Corporation",
+        "Lanson Memory Co Ltd",
+        "Shenzhen Superway Electronics Co Ltd",
+        "Canaan-Creative Co Ltd",
+        "Black Diamond Memory",
+        "Shenzhen City Parker Baking Electronics",
+        "Shenzhen Baihong Technology Co Ltd",
+        "GEO Semiconductors",
+        "OCPC",
+        "Artery Technology Co Ltd",
+        "Jinyu",
+        "ShenzhenYing Chi Technology Development",
+        "Shenzhen Pengcheng Xin Technology",
+        "Pegasus Semiconductor (Shanghai) Co",
+        "Mythic Inc",
+        "Elmos Semiconductor AG",
+        "Kllisre",
+        "Shenzhen Winconway Technology",
+        "Shenzhen Xingmem Technology Corp",
+        "Gold Key Technology Co Ltd",
+        "Habana Labs Ltd",
+        "Hoodisk Electronics Co Ltd",
+        "SemsoTai (SZ) Technology Co Ltd",
+        "OM Nanotech Pvt. Ltd",
+        "Shenzhen Zhifeng Weiye Technology",
+        "Xinshirui (Shenzhen) Electronics Co",
+        "Guangzhou Zhong Hao Tian Electronic",
+        "Shenzhen Longsys Electronics Co Ltd",
+        "Deciso B.V.",
+        "Puya Semiconductor (Shenzhen)",
+        "Shenzhen Veineda Technology Co Ltd",
+        "Antec Memory",
+        "Cortus SAS",
+        "Dust Leopard",
+        "MyWo AS",
+        "J&A Information Inc",
+        "Shenzhen JIEPEI Technology Co Ltd",
+        "Heidelberg University",
+        "Flexxon PTE Ltd",
+        "Wiliot",
+        "Raysun Electronics International Ltd",
+        "Aquarius Production Company LLC",
+        "MACNICA DHW LTDA",
+        "Intelimem",
+        "Zbit Semiconductor Inc",
+        "Shenzhen Technology Co Ltd",
+        "Signalchip",
+        "Shenzen Recadata Storage Technology",
+        "Hyundai Technology",
+        "Shanghai Fudi Investment Development",
+        "Aixi Technology",
+        "Tecon MT",
+        "Onda Electric Co Ltd",
+        "Jinshen",
+        "Kimtigo Semiconductor (HK) Limited",
+        "IIT Madras",
+        "Shenshan (Shenzhen) Electronic",
+        "Hefei Core Storage Electronic Limited",
+        "Colorful Technology Ltd",
+        "Visenta (Xiamen) Technology Co Ltd",
+        "Roa Logic BV",
+        "NSITEXE Inc",
+        "Hong Kong Hyunion Electronics",
+        "ASK Technology Group Limited",
+        "GIGA-BYTE Technology Co Ltd",
+        "Terabyte Co Ltd",
+        "Hyundai Inc",
+        "EXCELERAM",
+        "PsiKick",
+        "Netac Technology Co Ltd",
+        "PCCOOLER",
+        "Jiangsu Huacun Electronic Technology",
+        "Shenzhen Micro Innovation Industry",
+        "Beijing Tongfang Microelectronics Co",
+        "XZN Storage Technology",
+        "ChipCraft Sp. z.o.o.",
+        "ALLFLASH Technology Limited",
+    ],
+    [
+        "Foerd Technology Co Ltd",
+        "KingSpec",
+        "Codasip GmbH",
+        "SL Link Co Ltd",
+        "Shenzhen Kefu Technology Co Limited",
+        "Shenzhen ZST Electronics Technology",
+        "Kyokuto Electronic Inc",
+        "Warrior Technology",
+        "TRINAMIC Motion Control GmbH & Co",
+        "PixelDisplay Inc",
+        "Shenzhen Futian District Bo Yueda Elec",
+        "Richtek Power",
+        "Shenzhen LianTeng Electronics Co Ltd",
+        "AITC Memory",
+        "UNIC Memory Technology Co Ltd",
+        "Shenzhen Huafeng Science Technology",
+        "CXMT",
+        "",
+        "SambaNova Systems",
+        "V-GEN",
+        "Jump Trading",
+        "Ampere Computing",
+        "Shenzhen Zhongshi Technology Co Ltd",
+        "Shenzhen Zhongtian Bozhong Technology",
+        "Tri-Tech International",
+        "Silicon Intergrated Systems Corporation",
+        "Shenzhen HongDingChen Information",
+        "Plexton Holdings Limited",
+        "AMS (Jiangsu Advanced Memory Semi)",
+        "Wuhan Jing Tian Interconnected Tech Co",
+        "Axia Memory Technology",
+        "Chipset Technology Holding Limited",
+        "Shenzhen Xinshida Technology Co Ltd",
+        "Shenzhen Chuangshifeida Technology",
+        "Guangzhou MiaoYuanJi Technology",
+        "ADVAN Inc",
+        "",
+        "Guangzhou Guang Xie Cheng Trading",
+        "StarRam International Co Ltd",
+        "Shen Zhen XinShenHua Tech Co Ltd",
+        "UltraMemory Inc",
+        "New Coastline Global Tech Industry Co",
+        "Sinker",
+        "Diamond",
+        "PUSKILL",
+        "Guangzhou Hao Jia Ye Technology Co",
+        "Ming Xin Limited",
+        "Barefoot Networks",
+        "Biwin Semiconductor (HK) Co Ltd",
+        "UD INFO Corporation",
+        "Trek Technology (S) PTE Ltd",
+        "Xiamen Kingblaze Technology Co Ltd",
+        "Shenzhen Lomica Technology Co Ltd",
+        "Nuclei System Technology Co Ltd",
+        "Wuhan Xun Zhan Electronic Technology",
+        "Shenzhen Ingacom Semiconductor Ltd",
+        "Zotac Technology Ltd",
+        "Foxline",
+        "Shenzhen Farasia Science Technology",
+        "Efinix Inc",
+        "Hua Nan San Xian Technology Co Ltd",
+        "Goldtech Electronics Co Ltd",
+        "Shanghai Han Rong Microelectronics Co",
+        "Shenzhen Zhongguang Yunhe Trading",
+        "Smart Shine(QingDao) Microelectronics",
+        "Thermaltake Technology Co Ltd",
+        "Shenzhen O’Yang Maile Technology Ltd",
+        "UPMEM",
+        "Chun Well Technology Holding Limited",
+        "Astera Labs Inc",
+        "Winconway",
+        "Advantech Co Ltd",
+        "Chengdu Fengcai Electronic Technology",
+        "The Boeing Company",
+        "Blaize Inc",
+        "Ramonster Technology Co Ltd",
+        "Wuhan Naonongmai Technology Co Ltd",
+        "Shenzhen Hui ShingTong Technology",
+        "Yourlyon",
+        "Fabu Technology",
+        "Shenzhen Yikesheng Technology Co Ltd",
+        "NOR-MEM",
+        "Cervoz Co Ltd",
+        "Bitmain Technologies Inc.",
+        "Facebook Inc",
+        "Shenzhen Longsys Electronics Co Ltd",
+        "Guangzhou Siye Electronic Technology",
+        "Silergy",
+        "Adamway",
+        "PZG",
+        "Shenzhen King Power Electronics",
+        "Guangzhou ZiaoFu Tranding Co Ltd",
+        "Shenzhen SKIHOTAR Semiconductor",
+        "PulseRain Technology",
+        "Seeker Technology Limited",
+        "Shenzhen OSCOO Tech Co Ltd",
+        "Shenzhen Yze Technology Co Ltd",
+        "Shenzhen Jieshuo Electronic Commerce",
+        "Gazda",
+        "Hua Wei Technology Co Ltd",
+        "Esperanto Technologies",
+        "JinSheng Electronic (Shenzhen) Co Ltd",
+        "Shenzhen Shi Bolunshuai Technology",
+        "Shanghai Ruixuan Information Tech",
+        "Fraunhofer IIS",
+        "Kandou Bus SA",
+        "Acer",
+        "Artmem Technology Co Ltd",
+        "Gstar Semiconductor Co Ltd",
+        "ShineDisk",
+        "Shenzhen CHN Technology Co Ltd",
+        "UnionChip Semiconductor Co Ltd",
+        "Tanbassh",
+        "Shenzhen Tianyu Jieyun Intl Logistics",
+        "MCLogic Inc",
+        "Eorex Corporation",
+        "Arm Technology (China) Co Ltd",
+        "Lexar Co Limited",
+        "QinetiQ Group plc",
+        "Exascend",
+        "Hong Kong Hyunion Electronics Co Ltd",
+        "Shenzhen Banghong Electronics Co Ltd",
+        "MBit Wireless Inc",
+        "Hex Five Security Inc",
+        "ShenZhen Juhor Precision Tech Co Ltd",
+        "Shenzhen Reeinno Technology Co Ltd",
+    ],
+    [
+        "ABIT Electronics (Shenzhen) Co Ltd",
+        "Semidrive",
+        "MyTek Electronics Corp",
+        "Wxilicon Technology Co Ltd",
+        "Shenzhen Meixin Electronics Ltd",
+        "Ghost Wolf",
+        "LiSion Technologies Inc",
+        "Power Active Co Ltd",
+        "Pioneer High Fidelity Taiwan Co. Ltd",
+        "LuoSilk",
+        "Shenzhen Chuangshifeida Technology",
+        "Black Sesame Technologies Inc",
+        "Jiangsu Xinsheng Intelligent Technology",
+        "MLOONG",
+        "Quadratica LLC",
+        "Anpec Electronics",
+        "Xi’an Morebeck Semiconductor Tech Co",
+        "Kingbank Technology Co Ltd",
+        "ITRenew Inc",
+        "Shenzhen Eaget Innovation Tech Ltd",
+        "Jazer",
+        "Xiamen Semiconductor Investment Group",
+        "Guangzhou Longdao Network Tech Co",
+        "Shenzhen Futian SEC Electronic Market",
+        "Allegro Microsystems LLC",
+        "Hunan RunCore Innovation Technology",
+        "C-Corsa Technology",
+        "Zhuhai Chuangfeixin Technology Co Ltd",
+        "Beijing InnoMem Technologies Co Ltd",
+        "YooTin",
+        "Shenzhen Pengxiong Technology Co Ltd",
+        "Dongguan Yingbang Commercial Trading Co",
+        "Shenzhen Ronisys Electronics Co Ltd",
+        "Hongkong Xinlan Guangke Co Ltd",
+        "Apex Microelectronics Co Ltd",
+        "Beijing Hongda Jinming Technology Co Ltd",
+        "Ling Rui Technology (Shenzhen) Co Ltd",
+        "Hongkong Hyunion Electronics Co Ltd",
+        "Starsystems Inc",
+        "Shenzhen Yingjiaxun Industrial Co Ltd",
+        "Dongguan Crown Code Electronic Commerce",
+        "Monolithic Power Systems Inc",
+        "WuHan SenNaiBo E-Commerce Co Ltd",
+        "Hangzhou Hikstorage Technology Co",
+        "Shenzhen Goodix Technology Co Ltd",
+        "Aigo Electronic Technology Co Ltd",
+        "Hefei Konsemi Storage Technology Co Ltd",
+        "Cactus Technologies Limited",
+        "DSIN",
+        "Blu Wireless Technology",
+        "Nanjing UCUN Technology Inc",
+        "Acacia Communications",
+        "Beijinjinshengyihe Technology Co Ltd",
+        "Zyzyx",
+        "C-SKY Microsystems Co Ltd",
+        "Shenzhen Hystou Technology Co Ltd",
+        "Syzexion",
+        "Kembona",
+        "Qingdao Thunderobot Technology Co Ltd",
+        "Morse Micro",
+        "Shenzhen Envida  Technology Co Ltd",
+        "UDStore Solution Limited",
+        "Shunlie",
+        "Shenzhen Xin Hong Rui Tech Ltd",
+        "Shenzhen Yze Technology Co Ltd",
+        "Shenzhen Huang Pu He Xin Technology",
+        "Xiamen Pengpai Microelectronics Co Ltd",
+        "JISHUN",
+        "Shenzhen WODPOSIT Technology Co",
+        "Unistar",
+        "UNICORE Electronic (Suzhou) Co Ltd",
+        "Axonne Inc",
+        "Shenzhen SOVERECA Technology Co",
+        "Dire Wolf",
+        "Whampoa Core Technology Co Ltd",
+        "CSI Halbleiter GmbH",
+        "ONE Semiconductor",
+        "SimpleMachines Inc",
+        "Shenzhen Chengyi Qingdian Electronic",
+        "Shenzhen Xinlianxin Network Technology",
+        "Vayyar Imaging Ltd",
+        "Paisen Network Technology Co Ltd",
+        "Shenzhen Fengwensi Technology Co Ltd",
+        "Caplink Technology Limited",
+        "JJT Solution Co Ltd",
+        "HOSIN Global Electronics Co Ltd",
+        "Shenzhen KingDisk Century Technology",
+        "SOYO",
+        "DIT Technology Co Ltd",
+        "iFound",
+        "Aril Computer Company",
+        "ASUS",
+        "Shenzhen Ruiyingtong Technology Co",
+        "HANA Micron",
+        "RANSOR",
+        "Axiado Corporation",
+        "Tesla Corporation",
+        "Pingtouge (Shanghai) Semiconductor Co",
+        "S3Plus Technologies SA",
+        "Integrated Silicon Solution Israel Ltd",
+        "GreenWaves Technologies",
+        "NUVIA Inc",
+        "Guangzhou Shuvrwine Technology Co",
+        "Shenzhen Hangshun Chip Technology",
+        "Chengboliwei Electronic Business",
+        "Kowin Technology HK Limited",
+        "Euronet Technology Inc",
+        "SCY",
+        "Shenzhen Xinhongyusheng Electrical",
+        "PICOCOM",
+        "Shenzhen Toooogo Memory Technology",
+        "VLSI Solution",
+        "Costar Electronics Inc",
+        "Shenzhen Huatop Technology Co Ltd",
+        "Inspur Electronic Information Industry",
+        "Shenzhen Boyuan Computer Technology",
+        "Beijing Welldisk Electronics Co Ltd",
+        "Suzhou EP Semicon Co Ltd",
+        "Zhejiang Dahua Memory Technology",
+        "Virtu Financial",
+        "Datotek International Co Ltd",
+        "Telecom and Microelectronics Industries",
+        "Echow Technology Ltd",
+        "APEX-INFO",
+        "Yingpark",
+        "Shenzhen Bigway Tech Co Ltd",
+    ],
+    [
+        "Beijing Haawking Technology Co Ltd",
+        "Open HW Group",
+        "JHICC",
+        "ncoder AG",
+        "ThinkTech Information Technology Co",
+        "Shenzhen Chixingzhe Technology Co Ltd",
+        "Biao Ram Technology Co Ltd",
+        "Shenzhen Kaizhuoyue Electronics Co Ltd",
+        "Shenzhen YC Storage Technology Co Ltd",
+        "Shenzhen Chixingzhe Technology Co",
+        "Wink Semiconductor  (Shenzhen) Co Ltd",
+        "AISTOR",
+        "Palma Ceia SemiDesign",
+        "EM Microelectronic-Marin SA",
+        "Shenzhen Monarch Memory Technology",
+        "Reliance Memory Inc",
+        "Jesis",
+        "Espressif Systems (Shanghai)  Co Ltd",
+        "Shenzhen Sati Smart Technology Co Ltd",
+        "NeuMem Co Ltd",
+        "Lifelong",
+        "Beijing Oitech Technology Co Ltd",
+        "Groupe LDLC",
+        "Semidynamics Technology Services SLU",
+        "swordbill",
+        "YIREN",
+        "Shenzhen Yinxiang Technology Co Ltd",
+        "PoweV Electronic Technology Co Ltd",
+        "LEORICE",
+        "Waymo LLC",
+        "Ventana Micro Systems",
+        "Hefei Guangxin Microelectronics Co Ltd",
+        "Shenzhen Sooner Industrial Co Ltd",
+        "Horizon Robotics",
+        "Tangem AG",
+        "FuturePath Technology (Shenzhen) Co",
+        "RC Module",
+        "Timetec International Inc",
+        "ICMAX Technologies Co Limited",
+        "Lynxi Technologies Ltd Co",
+        "Guangzhou Taisupanke Computer Equipment",
+        "Ceremorphic Inc",
+        "Biwin Storage Technology Co Ltd",
+        "Beijing ESWIN Computing Technology",
+        "WeForce Co Ltd",
+        "Shenzhen Fanxiang Information Technology",
+        "Unisoc",
+        "YingChu",
+        "GUANCUN",
+        "IPASON",
+        "Ayar Labs",
+        "Amazon",
+        "Shenzhen Xinxinshun Technology Co",
+        "Galois Inc",
+        "Ubilite Inc",
+        "Shenzhen Quanxing Technology Co Ltd",
+        "Group RZX Technology LTDA",
+        "Yottac Technology (XI’AN) Cooperation",
+        "Shenzhen RuiRen Technology Co Ltd",
+        "Group Star Technology Co Ltd",
+        "RWA (Hong Kong) Ltd",
+        "Genesys Logic Inc",
+        "T3 Robotics Inc.",
+        "Biostar Microtech International Corp",
+        "Shenzhen SXmicro Technology Co Ltd",
+        "Shanghai Yili Computer Technology Co",
+        "Zhixin Semicoducotor Co Ltd",
+        "uFound",
+        "Aigo Data Security Technology Co. Ltd",
+        ".GXore Technologies",
+        "Shenzhen Pradeon Intelligent Technology",
+        "Power LSI",
+        "PRIME",
+        "Shenzhen Juyang Innovative Technology",
+        "CERVO",
+        "SiEngine Technology Co., Ltd.",
+        "Beijing Unigroup Tsingteng MicroSystem",
+        "Brainsao GmbH",
+        "Credo Technology Group Ltd",
+        "Shanghai Biren Technology Co Ltd",
+        "Nucleu Semiconductor",
+        "Shenzhen Guangshuo Electronics Co Ltd",
+        "ZhongsihangTechnology Co Ltd",
+        "Suzhou Mainshine Electronic Co Ltd.",
+        "Guangzhou Riss Electronic Technology",
+        "Shenzhen Cloud Security Storage  Co",
+        "ROG",
+        "Perceive",
+        "e-peas",
+        "Fraunhofer IPMS",
+        "Shenzhen Daxinlang Electronic Tech Co",
+        "Abacus Peripherals Private Limited",
+        "OLOy Technology",
+        "Wuhan P&S Semiconductor Co Ltd",
+        "Sitrus Technology",
+        "AnHui Conner Storage Co Ltd",
+        "Rochester Electronics",
+        "Wuxi Smart Memories Technologies Co",
+        "Star Memory",
+        "Agile Memory Technology Co Ltd",
+        "MEJEC",
+        "Rockchip Electronics Co Ltd",
+        "Dongguan Guanma e-commerce Co Ltd",
+        "Rayson Hi-Tech (SZ) Limited",
+        "MINRES Technologies GmbH",
+        "Himax Technologies Inc",
+        "Shenzhen Cwinner Technology Co Ltd",
+        "Tecmiyo",
+        "Shenzhen Suhuicun Technology Co Ltd",
+        "Vickter Electronics Co. Ltd.",
+        "lowRISC",
+        "EXEGate FZE",
+        "Shenzhen 9 Chapter Technologies Co",
+        "Addlink",
+        "Starsway",
+        "Pensando Systems Inc.",
+        "AirDisk",
+        "Shenzhen Speedmobile Technology Co",
+        "PEZY Computing",
+        "Extreme Engineering Solutions Inc",
+        "Shangxin Technology Co Ltd",
+        "Shanghai Zhaoxin Semiconductor Co",
+        "Xsight Labs Ltd",
+        "Hangzhou Hikstorage Technology Co",
+        "Dell Technologies",
+        "Guangdong StarFive Technology Co",
+    ],
+    [
+        "TECOTON",
+        "Abko Co Ltd",
+        "Shenzhen Feisrike Technology Co Ltd",
+        "Shenzhen Sunhome Electronics Co Ltd",
+        "Global Mixed-mode Technology Inc",
+        "Shenzhen Weien Electronics Co Ltd.",
+        "Shenzhen Cooyes Technology Co Ltd",
+        "ShenZhen ChaoYing ZhiNeng Technology",
+        "E-Rockic Technology Company Limited",
+        "Aerospace Science Memory Shenzhen",
+        "Shenzhen Quanji Technology Co Ltd",
+        "Dukosi",
+        "Maxell Corporation of America",
+        "Shenshen Xinxintao Electronics Co Ltd",
+        "Zhuhai Sanxia Semiconductor Co Ltd",
+        "Groq Inc",
+        "AstraTek",
+        "Shenzhen Xinyuze Technology  Co Ltd",
+        "All Bit Semiconductor",
+        "ACFlow",
+        "Shenzhen Sipeed Technology Co Ltd",
+        "Linzhi Hong Kong Co Limited",
+        "Supreme Wise Limited",
+        "Blue Cheetah Analog Design Inc",
+        "Hefei Laiku Technology Co Ltd",
+        "Zord",
+        "SBO Hearing A/S",
+        "Regent Sharp International Limited",
+        "Permanent Potential Limited",
+        "Creative World International Limited",
+        "Base Creation International Limited",
+        "Shenzhen Zhixin Chuanglian Technology",
+        "Protected Logic Corporation",
+        "Sabrent",
+        "Union Memory",
+        "NEUCHIPS Corporation",
+        "Ingenic Semiconductor Co Ltd",
+        "SiPearl",
+        "Shenzhen Actseno Information Technology",
+        "RIVAI Technologies (Shenzhen) Co Ltd",
+        "Shenzhen Sunny Technology Co Ltd",
+        "Cott Electronics Ltd",
+        "Shanghai Synsense Technologies Co Ltd",
+        "Shenzhen Jintang Fuming Optoelectronics",
+        "CloudBEAR LLC",
+        "Emzior, LLC",
+        "Ehiway Microelectronic Science Tech Co",
+        "UNIM Innovation Technology (Wu XI)",
+        "GDRAMARS",
+        "Meminsights Technology",
+        "Zhuzhou Hongda Electronics Corp Ltd",
+        "Luminous Computing Inc",
+        "PROXMEM",
+        "Draper Labs",
+        "ORICO  Technologies Co. Ltd.",
+        "Space Exploration Technologies Corp",
+        "AONDEVICES Inc",
+        "Shenzhen Netforward Micro Electronic",
+        "Syntacore Ltd",
+        "Shenzhen Secmem Microelectronics Co",
+        "ONiO As",
+        "Shenzhen Peladn Technology Co Ltd",
+        "O-Cubes Shanghai Microelectronics",
+        "ASTC",
+        "UMIS",
+        "Paradromics",
+        "Sinh Micro Co Ltd",
+        "Metorage Semiconductor Technology Co",
+        "Aeva Inc",
+        "HongKong Hyunion Electronics Co Ltd",
+        "China Flash Co Ltd",
+        "Sunplus Technology Co Ltd",
+        "Idaho Scientific",
+        "Suzhou SF Micro Electronics Co Ltd",
+        "IMEX Cap AG",
+        "Fitipower Integrated  Technology Co Ltd",
+        "ShenzhenWooacme Technology Co Ltd",
+        "KeepData Original Chips",
+        "Rivos Inc",
+        "Big Innovation Company Limited",
+        "Wuhan YuXin Semiconductor Co Ltd",
+        "United Memory Technology (Jiangsu)",
+        "PQShield Ltd",
+        "ArchiTek Corporation",
+        "ShenZhen AZW Technology Co Ltd",
+        "Hengchi Zhixin (Dongguan) Technology",
+        "Eggtronic  Engineering Spa",
+        "Fusontai Technology",
+        "PULP Platform",
+        "Koitek Electronic Technology (Shenzhen) Co",
+        "Shenzhen Jiteng Network Technology Co",
+        "Aviva Links Inc",
+        "Trilinear Technologies Inc",
+        "Shenzhen Developer Microelectronics Co",
+        "Guangdong OPPO Mobile Telecommunication",
+        "Akeana",
+        "Lyczar",
+        "QJTEK",
+        "Shenzhen Shangzhaoyuan Technology",
+        "Han Stor",
+        "China Micro Semicon Co., Ltd.",
+        "Shenzhen Zhuqin Technology Co Ltd",
+        "Shanghai Ningyuan Electronic Technology",
+        "Auradine",
+        "Suzhou Yishuo Electronics Co Ltd",
+        "Faurecia Clarion Electronics",
+        "SiMa Technologies",
+        "CFD Sales Inc",
+        "Suzhou Comay Information Co Ltd",
+        "Yentek",
+        "Qorvo Inc",
+        "Shenzhen Youzhi Computer Technology",
+        "Sychw Technology (Shenzhen) Co Ltd",
+        "MK Founder Technology Co Ltd",
+        "Siliconwaves Technologies Co Ltd",
+        "Hongkong Hyunion Electronics Co Ltd",
+        "Shenzhen Xinxinzhitao Electronics Business",
+        "Shenzhen HenQi Electronic Commerce Co",
+        "Shenzhen Jingyi Technology Co Ltd",
+        "Xiaohua Semiconductor Co. Ltd.",
+        "Shenzhen Dalu Semiconductor Technology",
+        "Shenzhen Ninespeed Electronics Co Ltd",
+        "ICYC Semiconductor Co Ltd",
+        "Shenzhen Jaguar Microsystems Co Ltd",
+        "Beijing EC-Founder Co Ltd",
+        "Shenzhen Taike Industrial Automation Co",
+    ],
+    [
+        "Kalray SA",
+        "Shanghai Iluvatar CoreX Semiconductor Co",
+        "Fungible Inc",
+        "Song Industria E Comercio de Eletronicos",
+        "DreamBig Semiconductor Inc",
+        "ChampTek Electronics Corp",
+        "Fusontai Technology",
+        "Endress Hauser AG",
+        "altec ComputerSysteme GmbH",
+        "UltraRISC Technology (Shanghai) Co Ltd",
+        "Shenzhen Jing Da Kang Technology Co Ltd",
+        "Hangzhou Hongjun Microelectronics Co Ltd",
+        "Pliops Ltd",
+        "Cix Technology (Shanghai) Co Ltd",
+        "TeraDevices Inc",
+        "SpacemiT (Hangzhou)Technology Co Ltd",
+        "InnoPhase loT Inc",
+        "InnoPhase loT Inc",
+        "Yunhight Microelectronics",
+        "Samnix",
+        "HKC Storage Co Ltd",
+        "Chiplego Technology (Shanghai) Co Ltd",
+        "StoreSkill",
+        "Shenzhen Astou Technology Company",
+        "Guangdong LeafFive Technology Limited",
+        "Jin JuQuan",
+        "Huaxuan Technology (Shenzhen) Co Ltd",
+        "Gigastone Corporation",
+        "Kinsotin",
+        "PengYing",
+        "Shenzhen Xunhi Technology Co Ltd",
+        "FOXX Storage Inc",
+        "Shanghai Belling Corporation Ltd",
+        "Glenfy Tech Co Ltd",
+        "Sahasra Semiconductors Pvt Ltd",
+        "Chongqing SeekWave Technology Co Ltd",
+        "Shenzhen Zhixing Intelligent Manufacturing",
+        "Ethernovia",
+        "Shenzhen Xinrongda Technology Co Ltd",
+        "Hangzhou Clounix Technology Limited",
+        "JGINYUE",
+        "Shenzhen Xinwei Semiconductor Co Ltd",
+        "COLORFIRE Technology Co Ltd",
+        "B LKE",
+        "ZHUDIAN",
+        "REECHO",
+        "Enphase Energy Inc",
+        "Shenzhen Yingrui Storage Technology Co Ltd",
+        "Shenzhen Sinomos Semiconductor Technology",
+        "O2micro International Limited",
+        "Axelera AI BV",
+        "Silicon Legend Technology (Suzhou) Co Ltd",
+        "Suzhou Novosense Microelectronics Co Ltd",
+        "Pirateman",
+        "Yangtze MasonSemi",
+        "Shanghai Yunsilicon Technology Co Ltd",
+        "Rayson",
+        "Alphawave IP",
+        "Shenzhen Visions Chip Electronic Technology",
+        "KYO Group",
+        "Shenzhen Aboison Technology Co Ltd",
+        "Shenzhen JingSheng Semiconducto Co Ltd",
+        "Shenzhen Dingsheng Technology Co Ltd",
+        "EVAS Intelligence Co Ltd",
+        "Kaibright Electronic Technologies",
+        "Fraunhofer IMS",
+        "Shenzhen Xinrui Renhe Technology",
+        "Beijing Vcore Technology Co Ltd",
+        "Silicon Innovation Technologies Co Ltd",
+        "Shenzhen Zhengxinda Technology Co Ltd",
+        "Shenzhen Remai Electronics Co Lttd",
+        "Shenzhen Xinruiyan Electronics Co Ltd",
+        "CEC Huada Electronic Design Co Ltd",
+        "Westberry Technology Inc",
+        "Tongxin Microelectronics Co Ltd",
+        "UNIM Semiconductor (Shang Hai) Co Ltd",
+        "Shenzhen Qiaowenxingyu Industrial Co Ltd",
+        "ICC",
+        "Enfabrica Corporation",
+        "Niobium Microsystems Inc",
+        "Xiaoli AI Electronics (Shenzhen) Co Ltd",
+        "Silicon Mitus",
+        "Ajiatek Inc",
+        "HomeNet",
+        "Shenzhen Shubang Technology Co Ltd",
+        "Exacta Technologies Ltd",
+        "Synology",
+        "Trium Elektronik Bilgi Islem San Ve Dis",
+        "Wuxi HippStor Technology Co Ltd",
+        "SSCT",
+        "Sichuan Heentai Semiconductor Co Ltd",
+        "Zhejiang University",
+        "www.shingroup.cn",
+        "Suzhou Nano Mchip Technology Company",
+        "Feature Integration Technology Inc",
+        "d-Matrix",
+        "Golden Memory",
+        "Qingdao Thunderobot Technology Co Ltd",
+        "Shenzhen Tianxiang Chuangxin Technology",
+        "HYPHY USA",
+        "Valkyrie",
+        "Suzhou Hesetc Electronic Technology Co",
+        "Hainan Zhongyuncun Technology Co Ltd",
+        "Shenzhen Yousheng Bona Technology Co",
+        "Shenzhen Xinle Chuang Technology Co",
+        "DEEPX",
+        "iStarChip CA LLC",
+        "Shenzhen Vinreada Technology Co Ltd",
+        "Novatek Microelectronics Corp",
+        "Chemgdu EG Technology Co Ltd",
+        "AGI Technology",
+        "Syntiant",
+        "AOC",
+        "GamePP",
+        "Yibai Electronic Technologies",
+        "Hangzhou Rencheng Trading Co Ltd",
+        "HOGE Technology Co Ltd",
+        "United Micro Technology (Shenzhen) Co",
+        "Fabric of Truth Inc",
+        "Elpitech",
+        "Elitestek",
+        "Cornelis Networks Inc",
+        "WingSemi Technologies Co Ltd",
+        "ForwardEdge ASIC",
+        "Beijing Future Signet Technology Co Ltd",
+        "Fine Made Microelectronics Group Co Ltd",
+    ],
+    [
+        "Changxin Memory Technology (Shanghai)",
+        "Synconv",
+        "MULTIUNIT",
+        "Zero ASIC Corporation",
+        "NTT Innovative Devices Corporation",
+        "Xbstor",
+        "Shenzhen South Electron Co Ltd",
+        "Iontra Inc",
+        "SIEFFI Inc",
+        "HK Winston Electronics Co Limited",
+        "Anhui SunChip Semiconductor Technology",
+        "HaiLa Technologies Inc",
+        "AUTOTALKS",
+        "Shenzhen Ranshuo Technology Co Limited",
+        "ScaleFlux",
+        "XC Memory",
+        "Guangzhou Beimu Technology Co Ltd",
+        "Rays Semiconductor Nanjing Co Ltd",
+        "Milli-Centi Intelligence Technology Jiangsu",
+        "Zilia Technologies",
+        "Incore Semiconductors",
+        "Kinetic Technologies",
+        "Nanjing Houmo Technology Co Ltd",
+        "Suzhou Yige Technology Co Ltd",
+        "Shenzhen Techwinsemi Technology Co Ltd",
+        "Pure Array Technology (Shanghai) Co Ltd",
+        "Shenzhen Techwinsemi Technology Udstore",
+        "RISE MODE",
+        "NEWREESTAR",
+        "Hangzhou Hualan Microeletronique Co Ltd",
+        "Senscomm Semiconductor Co Ltd",
+        "Holt Integrated Circuits",
+        "Tenstorrent Inc",
+        "SkyeChip",
+        "Guangzhou Kaishile Trading Co Ltd",
+        "Jing Pai Digital Technology (Shenzhen) Co",
+        "Memoritek",
+        "Zhejiang Hikstor Technology Co Ltd",
+        "Memoritek PTE Ltd",
+        "Longsailing Semiconductor Co Ltd",
+        "LX Semicon",
+        "Shenzhen Techwinsemi Technology Co Ltd",
+        "AOC",
+        "GOEPEL Electronic GmbH",
+        "Shenzhen G-Bong Technology Co Ltd",
+        "Openedges Technology Inc",
+        "EA Semi Shangahi Limited",
+        "EMBCORF",
+        "Shenzhen MicroBT Electronics Technology",
+        "Shanghai Simor Chip Semiconductor Co",
+        "Xllbyte",
+        "Guangzhou Maidite Electronics Co Ltd.",
+        "Zhejiang Changchun Technology Co Ltd",
+        "Beijing Cloud Security Technology Co Ltd",
+        "SSTC Technology and Distribution Inc",
+        "Shenzhen Panmin Technology Co Ltd",
+        "ITE Tech Inc",
+        "Beijing Zettastone Technology Co Ltd",
+        "Powerchip Micro Device",
+        "Shenzhen Ysemi Computing Co Ltd",
+        "Shenzhen Titan Micro Electronics Co Ltd",
+        "Shenzhen Macroflash Technology Co Ltd",
+        "Advantech Group",
+        "Shenzhen Xingjiachen Electronics Co Ltd",
+        "CHUQI",
+        "Dongguan Liesun Trading Co Ltd",
+        "Shenzhen Miuman Technology Co Ltd",
+        "Shenzhen Techwinsemi Technology Twsc",
+        "Encharge AI Inc",
+        "Shenzhen Zhenchuang Electronics Co Ltd",
+        "Giant Chip Co. Ltd",
+        "Shenzhen Runner Semiconductor Co Ltd",
+        "Scalinx",
+        "Shenzhen Lanqi Electronics Co Ltd",
+        "CoreComm Technology Co Ltd",
+        "DLI Memory",
+        "Shenzhen Fidat Technology Co Ltd",
+        "Hubei  Yangtze Mason Semiconductor Tech",
+        "Flastor",
+        "PIRATEMAN",
+        "Barrie Technologies Co Ltd",
+        "Dynacard Co Ltd",
+        "Rivian Automotive",
+        "Shenzhen Fidat Technology Co Ltd",
+        "Zhejang Weiming Semiconductor Co Ltd",
+        "Shenzhen Xinhua Micro Technology Co Ltd",
+        "Duvonn Electronic Technology Co Ltd",
+        "Shenzhen Xinchang Technology Co Ltd",
+        "Leidos",
+        "Keepixo",
+        "Applied Brain Research Inc",
+        "Maxio Technology (Hangzhou) Co Ltd",
+        "HK DCHIP Technology Limited",
+        "Hitachi-LG Data Storage",
+        "Shenzhen Huadian Communication Co Ltd",
+        "Achieve Memory Technology (Suzhou) Co",
+        "Shenzhen Think Future Semiconductor Co",
+        "Innosilicon",
+        "Shenzhen Weilida Technology Co Ltd",
+        "Agrade Storage (Shenzhen) Co Ltd",
+        "Shenzhen Worldshine Data Technology Co",
+        "Mindgrove Technologies",
+        "BYD Semiconductor Co Ltd",
+        "Chipsine Semiconductor (Suzhou) Co Ltd",
+        "Shen Zhen Shi Xun He Shi Ji Dian Zi You",
+        "Shenzhen Jindacheng Computer Co Ltd",
+        "Shenzhen Baina Haichuan Technology Co",
+        "Shanghai Hengshi Electronic Technology",
+        "Beijing Boyu Tuxian Technology Co Ltd",
+        "China Chips Star Semiconductor Co Ltd",
+        "Shenzhen Shenghuacan Technology Co",
+        "Kinara Inc",
+        "TRASNA Semiconductor",
+        "KEYSOM",
+        "Shenzhen YYF Info Tech Co Ltd",
+        "Sharetronics Data Technology Co Ltd",
+        "AptCore Limited",
+        "Uchampion Semiconductor Co Ltd",
+        "YCT Semiconductor",
+        "FADU Inc",
+        "Hefei CLT Microelectronics Co LTD",
+        "Smart Technologies (BD) Ltd",
+        "Zhangdian District Qunyuan Computer Firm",
+        "Silicon Xpandas Electronics Co Ltd",
+        "PC Components Y Multimedia S",
+        "Shenzhen Tanlr Technology Group Co Ltd",
+    ],
+    [
+        "Shenzhen JIEQING Technology Co Ltd",
+        "Orionix",
+        "JoulWatt Technology Co Ltd",
+        "Tenstorrent",
+        "Unis Flash Memory Technology (Chengdu)",
+        "Huatu Stars",
+        "Ardor Gaming",
+        "QuanZhou KunFang Semiconductor Co Ltd",
+        "EIAI PLANET",
+        "Ningbo Lingkai Semiconductor Technology Inc",
+        "Shenzhen Hancun Technology Co Ltd",
+        "Hongkong Manyi Technology Co Limited",
+        "Shenzhen Storgon Technology Co Ltd",
+        "YUNTU Microelectronics",
+        "Essencore",
+        "Shenzhen Xingyun Lianchuang Computer Tech",
+        "ShenZhen Aoscar Digital Tech Co Ltd",
+        "XOC Technologies Inc",
+        "BOS Semiconductors",
+        "Eliyan Corp",
+        "Hangzhou Lishu Technology Co Ltd",
+        "Tier IV Inc",
+        "Wuhan Xuanluzhe Network Technology Co",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+    ],
+];
+
+/// The name for `continuation` 0x7F bytes (bank - 1) and `id`, with or
+/// without its parity bit.
+pub fn manufacturer(continuation: u8, id: u8) -> Option<&'static str> {
+    let id = usize::from(id & 0x7F);
+    let bank = BANKS.get(usize::from(continuation & 0x7F))?;
+    bank.get(id.checked_sub(1)?)
+        .copied()
+        .filter(|name| !name.is_empty())
+}
+
+/// Decodes the hex manufacturer IDs firmware puts in SMBIOS memory device
+/// strings. Vendors write the same code several ways:
+///
+/// - `80CE`, `80CE000080CE`: bank byte with parity, then the ID
+/// - `0198`, `029E`: continuation count, then the ID
+/// - `CE00000000000000`: the ID alone, bank 1
+/// - `7F7F9E000000`: continuation bytes, then the ID
+pub fn decode(raw: &str) -> Option<&'static str> {
+    let hex: String = raw.chars().filter(|c| !c.is_whitespace()).collect();
+    let hex = hex.strip_prefix("0x").unwrap_or(&hex);
+    if hex.len() < 4 || !hex.len().is_multiple_of(2) || !hex.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        return None;
+    }
+    let bytes: Vec<u8> = (0..hex.len())
+        .step_by(2)
+        .filter_map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect();
+    let continuations = bytes.iter().take_while(|&&b| b == 0x7F).count();
+    if continuations > 0 {
+        return manufacturer(continuations as u8, *bytes.get(continuations)?);
+    }
+    match (bytes[0], bytes[1]) {
+        (id, 0) => manufacturer(0, id),
+        (bank, id) => manufacturer(bank, id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smbios_manufacturer_ids() {
+        assert_eq!(decode("80CE000080CE"), Some("Samsung"));
+        assert_eq!(decode("00AD"), Some("SK Hynix"));
+        assert_eq!(decode("802C"), Some("Micron Technology"));
+        assert_eq!(decode("0198"), Some("Kingston"));
+        assert_eq!(decode("04CB"), Some("A-DATA Technology"));
+        assert_eq!(decode("059B"), Some("Crucial Technology"));
+        assert_eq!(decode("CE00000000000000"), Some("Samsung"));
+        assert_eq!(decode("7F7F9E000000"), Some("Corsair"));
+        assert_eq!(decode("Samsung"), None);
+        assert_eq!(decode("0000"), None);
+    }
+
+    #[test]
+    fn spd_codes() {
+        // DDR4 SPD bytes 320-321: continuation count with parity, then the ID
+        assert_eq!(manufacturer(0x80, 0xCE), Some("Samsung"));
+        assert_eq!(manufacturer(0x01, 0x98), Some("Kingston"));
+        assert_eq!(manufacturer(20, 0x01), None);
+    }
+}
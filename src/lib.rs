@@ -1,7 +1,55 @@
+pub mod alerts;
+pub mod audit;
+pub mod bench;
+pub mod capture;
 pub mod cli;
+pub mod cloud;
 pub mod config;
+pub mod connectivity;
+pub mod daemon;
 pub mod discovery;
+pub mod email;
+pub mod env;
 pub mod exporter;
+pub mod fleet;
 pub mod formatter;
+pub mod grafana;
+pub mod graph;
+pub mod history;
+pub mod i18n;
+pub mod influx;
+pub mod inxi;
+pub mod jedec;
+pub mod lshw;
 pub mod model;
+pub mod monitor;
+pub mod mounts;
+pub mod mqtt;
+pub mod pdf;
+pub mod plugins;
+pub mod privilege;
+pub mod progress;
+pub mod query;
+pub mod redact;
+pub mod remote;
+#[cfg(feature = "async")]
+pub mod runtime;
+pub mod service;
+pub mod signing;
+pub mod smbios;
+#[cfg(feature = "snmp")]
+pub mod snmp;
+pub mod spd;
+pub mod stress;
+pub mod summary;
+pub mod sysfs;
+pub mod syslog;
+pub mod template;
+pub mod theme;
 pub mod tui;
+pub mod validate;
+pub mod verify;
+pub mod watch;
+pub mod webhook;
+pub mod xml;
+pub mod zabbix;
@@ -0,0 +1,207 @@
+//! `--format lshw-json`: the report as the node tree `lshw -json` prints, so
+//! scripts written against lshw keep working. Nodes carry lshw's `id`,
+//! `class`, `description`, `product`/`vendor`/`serial`, `logicalname`,
+//! `units`/`size` and string-valued `configuration`; what hw-checker does
+//! not collect (handles, physids, capabilities) is left out.
+
+use crate::model::HardwareReport;
+use anyhow::Result;
+use serde_json::{Value, json};
+
+pub fn render_lshw(report: &HardwareReport) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&system_node(report))?)
+}
+
+fn system_node(report: &HardwareReport) -> Value {
+    let mut core = node("core", "bus", "Motherboard");
+    let mut children = Vec::new();
+    if let Some(board) = &report.motherboard {
+        set(&mut core, "product", &board.product);
+        set(&mut core, "vendor", &board.vendor);
+        let mut firmware = node("firmware", "memory", "BIOS");
+        set(&mut firmware, "vendor", &board.bios_vendor);
+        set(&mut firmware, "version", &board.bios_version);
+        set(&mut firmware, "date", &board.bios_date);
+        children.push(firmware);
+    }
+
+    for (i, package) in report.cpu.iter().enumerate() {
+        let mut cpu = node(&indexed("cpu", i, report.cpu.len()), "processor", "CPU");
+        set(&mut cpu, "product", &package.model);
+        set(&mut cpu, "vendor", &package.vendor);
+        cpu["businfo"] = json!(format!("cpu@{}", i));
+        if let Some(mhz) = package.cores.iter().map(|c| c.frequency).max() {
+            cpu["units"] = json!("Hz");
+            cpu["size"] = json!(mhz * 1_000_000);
+        }
+        cpu["configuration"] = json!({
+            "cores": package.physical_cores.to_string(),
+            "enabledcores": package.physical_cores.to_string(),
+            "threads": package.logical_cores.to_string(),
+        });
+        children.push(cpu);
+    }
+
+    let mut memory = node("memory", "memory", "System Memory");
+    memory["units"] = json!("bytes");
+    memory["size"] = json!(report.ram.total);
+    let banks: Vec<Value> = report
+        .ram
+        .sticks
+        .iter()
+        .enumerate()
+        .map(|(i, stick)| {
+            let mut bank = node(&format!("bank:{}", i), "memory", "DIMM");
+            set_opt(&mut bank, "product", &stick.part_number);
+            set_opt(&mut bank, "vendor", &stick.manufacturer);
+            set_opt(&mut bank, "serial", &stick.serial_number);
+            if let Some(mts) = stick.speed {
+                bank["clock"] = json!(u64::from(mts) * 1_000_000);
+            }
+            bank
+        })
+        .collect();
+    if !banks.is_empty() {
+        memory["children"] = json!(banks);
+    }
+    children.push(memory);
+
+    for (i, gpu) in report.gpus.iter().enumerate() {
+        let mut display = node(
+            &indexed("display", i, report.gpus.len()),
+            "display",
+            "VGA compatible controller",
+        );
+        set(&mut display, "product", &gpu.name);
+        display["businfo"] = json!(format!("pci@{}", gpu.slot));
+        if let Some(driver) = &gpu.driver {
+            display["configuration"] = json!({ "driver": driver });
+        }
+        children.push(display);
+    }
+
+    let interfaces: Vec<_> = report.network.iter().filter(|n| n.name != "lo").collect();
+    for (i, interface) in interfaces.iter().enumerate() {
+        let mut network = node(
+            &indexed("network", i, interfaces.len()),
+            "network",
+            "Ethernet interface",
+        );
+        network["logicalname"] = json!(interface.name);
+        set(&mut network, "serial", &interface.mac_address);
+        children.push(network);
+    }
+
+    for (i, disk) in report.disks.iter().enumerate() {
+        let mut disk_node = node(&indexed("disk", i, report.disks.len()), "disk", "Disk");
+        disk_node["logicalname"] = json!(format!("/dev/{}", disk.name));
+        set_opt(&mut disk_node, "product", &disk.model_name);
+        set_opt(&mut disk_node, "vendor", &disk.vendor);
+        set_opt(&mut disk_node, "serial", &disk.serial_number);
+        disk_node["units"] = json!("bytes");
+        disk_node["size"] = json!(disk.size);
+        let volumes: Vec<Value> = report
+            .filesystems
+            .iter()
+            .filter(|fs| fs.disk.as_deref() == Some(disk.name.as_str()))
+            .enumerate()
+            .map(|(i, fs)| {
+                let mut volume = node(&format!("volume:{}", i), "volume", "Volume");
+                volume["logicalname"] = json!([fs.name, fs.mount_point]);
+                volume["units"] = json!("bytes");
+                volume["size"] = json!(fs.total);
+                volume["configuration"] = json!({
+                    "filesystem": fs.filesystem,
+                    "mount.fstype": fs.filesystem,
+                    "state": "mounted",
+                });
+                volume
+            })
+            .collect();
+        if !volumes.is_empty() {
+            disk_node["children"] = json!(volumes);
+        }
+        children.push(disk_node);
+    }
+
+    for (i, battery) in report.battery.iter().enumerate() {
+        let mut power = node(
+            &indexed("battery", i, report.battery.len()),
+            "power",
+            "Battery",
+        );
+        set(&mut power, "product", &battery.name);
+        children.push(power);
+    }
+
+    core["children"] = json!(children);
+    let mut system = node(&report.hostname, "system", "Computer");
+    if let Some(board) = &report.motherboard {
+        set(&mut system, "product", &board.product);
+        set(&mut system, "vendor", &board.vendor);
+    }
+    if let Some(details) = &report.os_details {
+        let width = if details.architecture.contains("64") {
+            64
+        } else {
+            32
+        };
+        system["width"] = json!(width);
+    }
+    system["children"] = json!([core]);
+    system
+}
+
+fn node(id: &str, class: &str, description: &str) -> Value {
+    json!({
+        "id": id,
+        "class": class,
+        "claimed": true,
+        "description": description,
+    })
+}
+
+/// lshw only numbers ids when there is more than one of a kind: `cpu`, but
+/// `cpu:0` and `cpu:1`.
+fn indexed(id: &str, i: usize, count: usize) -> String {
+    if count > 1 {
+        format!("{}:{}", id, i)
+    } else {
+        id.to_string()
+    }
+}
+
+fn set(node: &mut Value, key: &str, value: &str) {
+    if let Value::Object(map) = node
+        && !value.is_empty()
+    {
+        map.insert(key.to_string(), json!(value));
+    }
+}
+
+fn set_opt(node: &mut Value, key: &str, value: &Option<String>) {
+    if let Some(value) = value {
+        set(node, key, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_numbered_only_when_repeated() {
+        assert_eq!(indexed("cpu", 0, 1), "cpu");
+        assert_eq!(indexed("cpu", 1, 2), "cpu:1");
+    }
+
+    #[test]
+    fn empty_strings_are_left_out() {
+        let mut n = node("core", "bus", "Motherboard");
+        set(&mut n, "vendor", "");
+        set(&mut n, "product", "X570");
+        let map = n.as_object().unwrap();
+        assert!(!map.contains_key("vendor"));
+        assert_eq!(map["product"], "X570");
+    }
+}
@@ -1,69 +1,452 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use clap::Parser;
-use hw_checker::cli::{Args, OutputFormat};
-use hw_checker::config::Config;
-use hw_checker::discovery::get_hardware_report;
+use colored::Colorize;
+use hw_checker::alerts::run_check;
+use hw_checker::audit::{AuditOutcome, audit_report, run_audit};
+use hw_checker::bench::run_bench;
+use hw_checker::capture::run_capture;
+use hw_checker::cli::{Args, Command, OutputFormat};
+use hw_checker::cloud::query_instance;
+use hw_checker::config::{Config, Sections, find_default_config, run_config};
+use hw_checker::connectivity::check_connectivity;
+use hw_checker::daemon::{default_socket_path, fetch_report, run_daemon};
+use hw_checker::discovery::{
+    get_cloud_provider, get_disk_io, get_gpu_processes, get_hardware_report, get_iommu, get_irqs,
+    get_network_config, get_spd_modules,
+};
 use hw_checker::exporter::export_report;
+use hw_checker::fleet::run_fleet;
 use hw_checker::formatter::{
-    print_cpu, print_health, print_network, print_pci, print_ram, print_report, print_storage,
-    print_usb,
+    print_board_layout, print_connectivity, print_container, print_cpu, print_custom, print_gpus,
+    print_health, print_io, print_iommu, print_irqs, print_limits, print_network, print_pci,
+    print_pressure, print_ram, print_report, print_slot_map, print_storage, print_thunderbolt,
+    print_time, print_timings, print_usb, print_virtualization,
 };
+use hw_checker::grafana::run_grafana;
+use hw_checker::graph::run_graph;
+use hw_checker::history::{run_history, run_record};
+use hw_checker::i18n::{self, Locale};
+use hw_checker::model::CloudInstance;
+use hw_checker::monitor::run_monitor;
+use hw_checker::mounts::filter_mounts;
+use hw_checker::mqtt::run_mqtt;
+use hw_checker::plugins::run_plugins;
+use hw_checker::privilege::{elevate, is_elevated, sudo_hint};
+use hw_checker::progress;
+use hw_checker::query::run_get;
+use hw_checker::redact::redact_report;
+use hw_checker::remote::run_remote;
+use hw_checker::service::run_install_service;
+use hw_checker::signing::{ReportSigner, run_verify_report};
+#[cfg(feature = "snmp")]
+use hw_checker::snmp::run_snmp;
+use hw_checker::stress::run_stress;
+use hw_checker::summary::render_summary;
+use hw_checker::sysfs::{self, SysfsRoot};
+use hw_checker::template::render_template;
+use hw_checker::theme;
 use hw_checker::tui::run_tui;
+use hw_checker::validate::run_validate;
+use hw_checker::verify::run_verify;
+use hw_checker::watch::run_watch;
+use std::time::Duration;
 
 fn main() -> Result<()> {
     let args = Args::parse();
+    if args.elevate && !is_elevated() {
+        elevate()?;
+    }
+
+    // Runs before loading the config, so a broken one can be validated or replaced
+    if let Some(Command::Config(config_args)) = &args.command {
+        return run_config(
+            config_args,
+            args.config.as_deref(),
+            args.profile.as_deref(),
+            &args.set,
+        );
+    }
+
+    let config_path = args.config.clone().or_else(find_default_config);
+    let mut config = Config::load(config_path.clone(), args.profile.as_deref(), &args.set)?;
+    config.mounts.all |= args.all_mounts;
+    config.all_interfaces |= args.all_interfaces;
+    if let Some(mode) = args.redact {
+        config.redact.enabled = true;
+        if let Some(mode) = mode {
+            config.redact.mode = mode;
+        }
+    }
+
+    let locale = args
+        .lang
+        .or(config.locale)
+        .or_else(Locale::from_env)
+        .unwrap_or_default();
+    i18n::set_locale(locale);
+    theme::set_theme(config.theme.resolve());
+    if let Some(dir) = &args.from_sysfs {
+        if !cfg!(target_os = "linux") {
+            bail!("--from-sysfs replays Linux captures and needs a Linux build");
+        }
+        sysfs::set_root(SysfsRoot::open(dir)?);
+    }
+    let connect = args
+        .connect
+        .clone()
+        .map(|socket| socket.unwrap_or_else(default_socket_path));
+
+    if let Some(command) = &args.command {
+        return match command {
+            Command::Monitor(monitor_args) => run_monitor(monitor_args),
+            Command::Watch(watch_args) => run_watch(watch_args, &config),
+            Command::Check(check_args) => run_check(check_args, &config),
+            Command::Mqtt(mqtt_args) => run_mqtt(mqtt_args, &config),
+            #[cfg(feature = "snmp")]
+            Command::Snmp(snmp_args) => run_snmp(snmp_args, &config),
+            Command::Remote(remote_args) => run_remote(remote_args, args.format, &config),
+            Command::Fleet(fleet_args) => run_fleet(fleet_args, &config),
+            Command::Verify(verify_args) => run_verify(verify_args, args.format),
+            Command::VerifyReport(verify_report_args) => run_verify_report(verify_report_args),
+            Command::Stress(stress_args) => run_stress(stress_args, &config),
+            Command::Validate(validate_args) => run_validate(validate_args, args.format),
+            Command::Capture(capture_args) => run_capture(capture_args, &config),
+            Command::Config(_) => unreachable!("handled before loading the config"),
+            Command::Bench(bench_args) => run_bench(bench_args),
+            Command::Record(record_args) => run_record(record_args, &config),
+            Command::History(history_args) => run_history(history_args, &config),
+            Command::Graph(graph_args) => run_graph(graph_args, &config),
+            Command::Grafana(grafana_args) => run_grafana(grafana_args, &config),
+            Command::Get(get_args) => run_get(get_args, connect.as_deref(), &config),
+            Command::Audit(audit_args) => run_audit(audit_args, &config.audit),
+            Command::InstallService(service_args) => run_install_service(
+                service_args,
+                config_path.as_deref(),
+                args.profile.as_deref(),
+                &args.set,
+            ),
+            Command::Daemon(daemon_args) => run_daemon(daemon_args, &config),
+        };
+    }
+
+    if args.logo && args.format != OutputFormat::Summary {
+        bail!("--logo needs --format summary");
+    }
+    let signer = args.sign.as_deref().map(ReportSigner::load).transpose()?;
+    if signer.is_some() && !matches!(args.format, OutputFormat::Json | OutputFormat::Yaml) {
+        bail!("--sign needs --format json or yaml");
+    }
 
-    let config = if let Some(path) = args.config {
-        Config::load_from_file(path)?
+    // A section asked for on the command line runs even if the config disables it
+    let mut sections = config.sections.clone();
+    sections.cpu |= args.cpu;
+    sections.ram |= args.ram || args.spd;
+    sections.storage |= args.storage;
+    sections.network |= args.network;
+    sections.usb |= args.usb;
+    sections.thunderbolt |= args.usb;
+    sections.pci |= args.pci;
+    sections.gpu |= args.gpu;
+    sections.limits |= args.limits;
+    sections.time |= args.time;
+    sections.motherboard |= args.health || args.slots;
+    sections.battery |= args.health;
+    // `eval "$(hw-checker --format env --cpu --ram)"` sets only what was asked for
+    if args.format == OutputFormat::Env
+        && (args.cpu
+            || args.ram
+            || args.storage
+            || args.network
+            || args.usb
+            || args.pci
+            || args.gpu
+            || args.limits
+            || args.time
+            || args.health)
+    {
+        sections = Sections {
+            cpu: args.cpu,
+            ram: args.ram,
+            storage: args.storage,
+            network: args.network,
+            usb: args.usb,
+            thunderbolt: args.usb,
+            pci: args.pci,
+            gpu: args.gpu,
+            limits: args.limits,
+            time: args.time,
+            motherboard: args.health,
+            battery: args.health,
+            sensors: args.health,
+        };
+    }
+
+    if (args.cloud || config.cloud)
+        && !cfg!(any(
+            target_os = "linux",
+            target_os = "freebsd",
+            target_os = "openbsd"
+        ))
+    {
+        eprintln!(
+            "{}",
+            i18n::t("--cloud: cloud VMs are recognized from SMBIOS strings, which are only read on Linux and FreeBSD/OpenBSD")
+                .color(theme::theme().warning)
+        );
+    }
+    // A replayed tree carries the EEPROMs as captured
+    if args.spd && sysfs::root().is_live() && !is_elevated() {
+        bail!(
+            "--spd reads the modules' EEPROMs, which only root can do; run with sudo or --elevate"
+        );
+    }
+    progress::start();
+    let mut report = match &connect {
+        Some(socket) => fetch_report(socket)?,
+        None => get_hardware_report(&sections, &config.probe_timeouts),
+    };
+    if args.io || args.full {
+        report.disk_io = progress::step("disk_io", || get_disk_io(Duration::from_secs(1)));
+    }
+    if args.irq || args.full {
+        report.irqs = progress::step("irqs", || get_irqs(Duration::from_secs(1)));
+    }
+    if args.iommu || args.full {
+        report.iommu = progress::step("iommu", get_iommu);
+    }
+    if args.spd {
+        report.ram.spd = progress::step("spd", get_spd_modules);
+        if report.ram.spd.is_empty() {
+            eprintln!(
+                "{}",
+                i18n::t("--spd: no SPD EEPROMs found; load the ee1004 (DDR4) or spd5118 (DDR5) kernel module")
+                    .color(theme::theme().warning)
+            );
+        }
+    }
+    if args.gpu || args.tui {
+        report.gpu_processes = progress::step("gpu_processes", get_gpu_processes);
+    }
+    if args.connectivity {
+        let network_config = report.network_config.clone().or_else(get_network_config);
+        report.connectivity = Some(progress::step("connectivity", || {
+            check_connectivity(&config.connectivity, network_config.as_ref())
+        }));
+    }
+    if args.cloud || config.cloud {
+        // A replayed tree describes another machine than the metadata service would
+        report.cloud = progress::step("cloud", || {
+            get_cloud_provider().map(|provider| {
+                if sysfs::root().is_live() {
+                    query_instance(provider)
+                } else {
+                    CloudInstance::unknown(provider)
+                }
+            })
+        });
+    }
+    // The daemon's report has the plugin output already
+    if connect.is_none() && (!config.plugins.is_empty() || !config.collectors.is_empty()) {
+        progress::step("custom", || {
+            run_plugins(
+                &config.plugins,
+                &config.collectors,
+                &config.probe_timeouts,
+                &mut report,
+            )
+        });
+    }
+    progress::finish();
+    if args.timings {
+        report.diagnostics.timings = progress::take_timings();
+    }
+    filter_mounts(&mut report.filesystems, &config.mounts);
+    redact_report(&mut report, &config.redact);
+    // After redaction, so the state file and log hold no more than the
+    // report does. Hashed serials still compare from one run to the next.
+    let audit = if args.audit {
+        Some(audit_report(&report, &sections, &config.audit)?)
     } else {
-        Config::default()
+        None
     };
-
-    let report = get_hardware_report();
+    report.asset = config.asset.clone();
+    if let Some(tag) = &args.asset_tag {
+        report.asset.asset_tag = Some(tag.clone());
+    }
+    if let Some(location) = &args.location {
+        report.asset.location = Some(location.clone());
+    }
+    if let Some(owner) = &args.owner {
+        report.asset.owner = Some(owner.clone());
+    }
+    report.asset.custom.extend(args.meta.iter().cloned());
 
     if args.tui {
-        run_tui(report)?;
+        run_tui(report, config, Duration::from_millis(args.interval))?;
         return Ok(());
     }
 
-    if args.format == OutputFormat::Table {
+    if let Some(template) = &args.template {
+        print!("{}", render_template(&report, template)?);
+    } else if args.format == OutputFormat::Summary {
+        println!("{}", render_summary(&report, args.logo));
+    } else if args.format == OutputFormat::Table {
         let any_filter = args.cpu
             || args.ram
+            || args.spd
             || args.storage
+            || args.io
+            || args.irq
+            || args.iommu
             || args.network
+            || args.connectivity
             || args.usb
             || args.pci
+            || args.gpu
+            || args.limits
+            || args.time
             || args.health
+            || args.slots
             || args.full;
 
         if any_filter {
-            if args.cpu || args.full {
-                print_cpu(&report.cpu, &config.cpu_thresholds);
+            if args.cpu || (args.full && sections.cpu) {
+                print_cpu(
+                    &report.cpu,
+                    report.cpu_load.as_ref(),
+                    &config.cpu_thresholds,
+                );
+                if let Some(support) = &report.virtualization {
+                    print_virtualization(support);
+                }
             }
-            if args.ram || args.full {
+            if args.ram || args.spd || (args.full && sections.ram) {
                 print_ram(&report.ram, &config.ram_thresholds);
             }
-            if args.storage || args.full {
-                print_storage(&report.storage, &config.storage_thresholds);
+            if (args.cpu || args.ram || args.full)
+                && let Some(limits) = &report.container
+            {
+                print_container(limits, &report, &config.ram_thresholds);
+            }
+            if (args.cpu || args.ram || args.storage || args.full)
+                && let Some(pressure) = &report.pressure
+            {
+                print_pressure(pressure, &config.pressure);
+            }
+            if args.storage || (args.full && sections.storage) {
+                print_storage(
+                    &report.disks,
+                    &report.filesystems,
+                    &config.storage_thresholds,
+                    &config.inode_thresholds,
+                );
+            }
+            if args.io || args.full {
+                print_io(&report.disk_io);
             }
-            if args.network || args.full {
-                print_network(&report.network);
+            if args.irq || args.full {
+                print_irqs(&report.irqs);
             }
-            if args.usb || args.full {
+            if args.network || (args.full && sections.network) {
+                print_network(
+                    &report.network,
+                    report.network_config.as_ref(),
+                    config.all_interfaces,
+                );
+            }
+            if let Some(connectivity) = &report.connectivity {
+                print_connectivity(connectivity);
+            }
+            if args.usb || (args.full && sections.usb) {
                 print_usb(&report.usb);
             }
-            if args.pci || args.full {
+            if args.usb || (args.full && sections.thunderbolt) {
+                print_thunderbolt(&report.thunderbolt);
+            }
+            if args.pci || (args.full && sections.pci) {
                 print_pci(&report.pci);
             }
+            if args.iommu || args.full {
+                print_iommu(report.iommu.as_ref());
+            }
+            if args.gpu || (args.full && sections.gpu) {
+                print_gpus(&report.gpus, &report.gpu_processes);
+            }
+            if args.limits || (args.full && sections.limits) {
+                print_limits(&report.limits);
+            }
+            if args.time || (args.full && sections.time) {
+                print_time(report.time.as_ref());
+            }
             if args.health || args.full {
-                print_health(report.motherboard.as_ref(), &report.battery);
+                print_health(
+                    report.motherboard.as_ref(),
+                    &report.battery,
+                    report.platform.as_ref(),
+                    &config.battery,
+                );
+                if let Some(layout) = &report.board_layout {
+                    print_board_layout(layout);
+                }
+            } else if args.slots
+                && let Some(layout) = &report.board_layout
+            {
+                print_slot_map(&layout.slots);
+            }
+            if args.full {
+                print_custom(&report.custom);
             }
         } else {
             print_report(&report, &config);
         }
+        if args.timings {
+            print_timings(&report.diagnostics.timings);
+        }
+    } else if let Some(signer) = &signer {
+        println!("{}", signer.render(&report, args.format)?);
     } else {
         export_report(&report, args.format)?;
     }
 
+    // stderr, so the hint does not end up in redirected json/yaml output
+    if let Some(hint) = sudo_hint(&report.limitations) {
+        eprintln!("\n{}", hint.color(theme::theme().warning).italic());
+    }
+    if !report.diagnostics.timed_out.is_empty() {
+        eprintln!(
+            "\n{} {} (probe_timeouts)",
+            i18n::t("Timed out:").color(theme::theme().warning),
+            report.diagnostics.timed_out.join(", ")
+        );
+    }
+    match audit {
+        Some(AuditOutcome::Baseline(path)) => eprintln!(
+            "\n{} {}",
+            i18n::t("Audit baseline recorded in"),
+            path.display()
+        ),
+        Some(AuditOutcome::Changes { since, events }) if events.is_empty() => {
+            eprintln!("\n{} {}", i18n::t("No hardware changes since"), since)
+        }
+        Some(AuditOutcome::Changes { since, events }) => {
+            eprintln!(
+                "\n{} {}:",
+                i18n::t("Hardware changes since").color(theme::theme().warning),
+                since
+            );
+            for event in &events {
+                eprintln!("  {}", event.message());
+            }
+        }
+        None => {}
+    }
+    for error in &report.diagnostics.plugin_errors {
+        eprintln!(
+            "{} {}",
+            i18n::t("Plugin failed:").color(theme::theme().warning),
+            error
+        );
+    }
+
     Ok(())
 }
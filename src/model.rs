@@ -1,16 +1,268 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
+/// Core type on hybrid CPUs (Intel P/E cores, ARM big.LITTLE).
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum CoreKind {
+    Performance,
+    Efficiency,
+}
+
+/// Clock (MHz) and load of one logical core.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct CpuInfo {
-    pub model: String,
-    pub vendor_id: String,
-    pub brand: String,
-    pub cores: usize,
+pub struct CoreMetrics {
+    pub id: usize,
+    /// Only set on hybrid CPUs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<CoreKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cluster: Option<u32>,
     pub frequency: u64,
     pub usage: f32,
-    pub l1_cache: Option<String>,
-    pub l2_cache: Option<String>,
-    pub l3_cache: Option<String>,
+}
+
+/// The logical cores of one type on a hybrid CPU, with its rated clocks (MHz).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CoreGroup {
+    pub kind: CoreKind,
+    pub logical_cores: usize,
+    pub base_mhz: Option<u64>,
+    pub boost_mhz: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheKind {
+    Data,
+    Instruction,
+    #[default]
+    Unified,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CpuCache {
+    pub level: u8,
+    #[serde(default)]
+    pub kind: CacheKind,
+    pub size_kb: u64,
+    /// Logical cores sharing one instance of the cache, 0 if unknown.
+    #[serde(default)]
+    pub shared_by: usize,
+}
+
+/// One physical CPU (socket) and its logical cores.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CpuPackage {
+    pub model: String,
+    pub vendor: String,
+    #[serde(default)]
+    pub caches: Vec<CpuCache>,
+    pub physical_cores: usize,
+    pub logical_cores: usize,
+    /// Empty unless the package mixes core types.
+    #[serde(default)]
+    pub core_groups: Vec<CoreGroup>,
+    pub cores: Vec<CoreMetrics>,
+}
+
+/// How busy the CPUs are beyond a usage percentage: the run queue, and
+/// whether time goes to I/O waits or to other guests of the hypervisor.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CpuLoad {
+    /// 1, 5 and 15 minute load averages
+    pub load_average: Option<[f64; 3]>,
+    pub times: Option<CpuTimes>,
+}
+
+/// Share of all CPU time, in percent. `user` includes nice, `system` includes
+/// hard and soft interrupts.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+pub struct CpuTimes {
+    pub user: f32,
+    pub system: f32,
+    pub iowait: f32,
+    /// Time a VM was ready to run but the hypervisor ran someone else
+    pub steal: f32,
+    pub idle: f32,
+}
+
+/// Whether this machine can run VMs with hardware assistance.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct VirtualizationSupport {
+    /// "VT-x" or "AMD-V"; `None` when the CPU has neither
+    pub extension: Option<String>,
+    /// The CPU has the extension but firmware switched it off
+    pub disabled_in_firmware: bool,
+    /// EPT (Intel) or NPT (AMD)
+    pub nested_paging: Option<bool>,
+    /// `/dev/kvm` is there; `None` off Linux
+    pub kvm: Option<bool>,
+    /// KVM lets its guests run hypervisors of their own
+    pub nested_virtualization: Option<bool>,
+}
+
+impl CpuPackage {
+    /// e.g. "48 KB D + 32 KB I" for `level` 1, "2048 KB" for a unified cache.
+    pub fn cache_label(&self, level: u8) -> Option<String> {
+        let parts: Vec<String> = self
+            .caches
+            .iter()
+            .filter(|c| c.level == level)
+            .map(|c| match c.kind {
+                CacheKind::Data => format!("{} KB D", c.size_kb),
+                CacheKind::Instruction => format!("{} KB I", c.size_kb),
+                CacheKind::Unified => format!("{} KB", c.size_kb),
+            })
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(" + "))
+    }
+
+    /// Whether each core has its own cache at `level` (as opposed to one shared
+    /// by several cores), if known.
+    pub fn cache_per_core(&self, level: u8) -> Option<bool> {
+        let shared_by = self
+            .caches
+            .iter()
+            .filter(|c| c.level == level)
+            .map(|c| c.shared_by)
+            .max()
+            .filter(|&n| n > 0)?;
+        let threads_per_core = (self.logical_cores / self.physical_cores.max(1)).max(1);
+        Some(shared_by <= threads_per_core)
+    }
+
+    /// Size of the cache holding data at `level`, i.e. skipping L1I.
+    pub fn data_cache_kb(&self, level: u8) -> Option<u64> {
+        self.caches
+            .iter()
+            .find(|c| c.level == level && c.kind != CacheKind::Instruction)
+            .map(|c| c.size_kb)
+    }
+}
+
+/// Reports list CPUs under `cpu_packages`, and still carry the per-logical-core
+/// `cpu` array of earlier versions for existing consumers. A report with only
+/// the old array is read back as one package.
+mod legacy_cpu {
+    use super::{CacheKind, CoreMetrics, CpuCache, CpuPackage};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct LegacyCpu {
+        model: String,
+        vendor_id: String,
+        brand: String,
+        cores: usize,
+        frequency: u64,
+        usage: f32,
+        l1_cache: Option<String>,
+        l2_cache: Option<String>,
+        l3_cache: Option<String>,
+    }
+
+    #[derive(Serialize)]
+    struct Written<'a> {
+        cpu: Vec<LegacyCpu>,
+        cpu_packages: &'a [CpuPackage],
+    }
+
+    #[derive(Deserialize)]
+    struct Read {
+        #[serde(default)]
+        cpu: Vec<LegacyCpu>,
+        #[serde(default)]
+        cpu_packages: Option<Vec<CpuPackage>>,
+    }
+
+    pub fn serialize<S: Serializer>(
+        packages: &[CpuPackage],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let physical_cores = packages.iter().map(|p| p.physical_cores).sum();
+        let cpu = packages
+            .iter()
+            .flat_map(|package| {
+                package.cores.iter().map(move |core| LegacyCpu {
+                    model: package.model.clone(),
+                    vendor_id: package.vendor.clone(),
+                    brand: package.vendor.clone(),
+                    cores: physical_cores,
+                    frequency: core.frequency,
+                    usage: core.usage,
+                    l1_cache: legacy_label(package, 1),
+                    l2_cache: legacy_label(package, 2),
+                    l3_cache: legacy_label(package, 3),
+                })
+            })
+            .collect();
+        Written {
+            cpu,
+            cpu_packages: packages,
+        }
+        .serialize(serializer)
+    }
+
+    /// Older reports carried one "N KB" size per level.
+    fn legacy_label(package: &CpuPackage, level: u8) -> Option<String> {
+        package
+            .data_cache_kb(level)
+            .map(|size_kb| format!("{} KB", size_kb))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<CpuPackage>, D::Error> {
+        let read = Read::deserialize(deserializer)?;
+        if let Some(packages) = read.cpu_packages {
+            return Ok(packages);
+        }
+        let Some(first) = read.cpu.first() else {
+            return Ok(Vec::new());
+        };
+        let caches = [&first.l1_cache, &first.l2_cache, &first.l3_cache]
+            .into_iter()
+            .zip(1..)
+            .filter_map(|(label, level)| {
+                let size_kb = label
+                    .as_deref()?
+                    .trim_end_matches("KB")
+                    .trim()
+                    .parse()
+                    .ok()?;
+                Some(CpuCache {
+                    level,
+                    kind: if level == 1 {
+                        CacheKind::Data
+                    } else {
+                        CacheKind::Unified
+                    },
+                    size_kb,
+                    shared_by: 0,
+                })
+            })
+            .collect();
+        Ok(vec![CpuPackage {
+            model: first.model.clone(),
+            vendor: first.vendor_id.clone(),
+            caches,
+            physical_cores: first.cores,
+            logical_cores: read.cpu.len(),
+            core_groups: Vec::new(),
+            cores: read
+                .cpu
+                .iter()
+                .enumerate()
+                .map(|(id, cpu)| CoreMetrics {
+                    id,
+                    kind: None,
+                    cluster: None,
+                    frequency: cpu.frequency,
+                    usage: cpu.usage,
+                })
+                .collect(),
+        }])
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -21,7 +273,7 @@ pub struct RamStick {
     pub speed: Option<u16>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct RamInfo {
     pub total: u64,
     pub used: u64,
@@ -29,21 +281,371 @@ pub struct RamInfo {
     pub swap_total: u64,
     pub swap_used: u64,
     pub sticks: Vec<RamStick>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<MemorySettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slots: Option<MemorySlots>,
+    /// Module EEPROMs, read with `--spd`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spd: Vec<SpdInfo>,
+    /// Set on a virtual machine, whose memory has no physical modules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub virtualized: Option<VirtualMemory>,
+}
+
+/// Guest memory and what the hypervisor resizes it through.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct VirtualMemory {
+    /// "KVM", "VMware", "Hyper-V", "Xen", ...; `None` when the guest only
+    /// knows it runs under one
+    pub hypervisor: Option<String>,
+    /// Balloon and hotplug devices: "virtio-balloon", "virtio-mem",
+    /// "hv_balloon", "vmw_balloon", "xen-balloon"
+    pub devices: Vec<String>,
+}
+
+impl VirtualMemory {
+    /// Stand-ins for the modules a guest has no SMBIOS records of: one per
+    /// memory device, or a single one when there are none.
+    pub fn sticks(&self) -> Vec<RamStick> {
+        let stick = |device: Option<&String>| RamStick {
+            manufacturer: self.hypervisor.clone(),
+            part_number: Some(device.map_or("virtual memory", |d| d.as_str()).to_string()),
+            serial_number: None,
+            speed: None,
+        };
+        if self.devices.is_empty() {
+            return vec![stick(None)];
+        }
+        self.devices.iter().map(|d| stick(Some(d))).collect()
+    }
+}
+
+/// What a module's SPD EEPROM says about it.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SpdInfo {
+    /// i2c device, e.g. "0-0050"
+    pub device: String,
+    /// "DDR4" or "DDR5"
+    pub memory_type: String,
+    /// "UDIMM", "SO-DIMM", "RDIMM", ...
+    pub module_type: Option<String>,
+    /// Bytes
+    pub size: Option<u64>,
+    /// Module vendor and the vendor of the DRAM dies on it
+    pub manufacturer: Option<String>,
+    pub dram_manufacturer: Option<String>,
+    pub part_number: Option<String>,
+    /// JEDEC base speed, MT/s
+    pub speed: Option<u32>,
+    pub timings: Option<SpdTimings>,
+    /// XMP or EXPO overclocking profiles
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<SpdProfile>,
+}
+
+/// Primary timings, in clocks.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+pub struct SpdTimings {
+    pub cl: u32,
+    pub trcd: u32,
+    pub trp: u32,
+    pub tras: u32,
+}
+
+impl std::fmt::Display for SpdTimings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}-{}-{}", self.cl, self.trcd, self.trp, self.tras)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SpdProfile {
+    /// "XMP 1", "XMP 2", "XMP 3.0", "EXPO"
+    pub name: String,
+    pub speed: Option<u32>,
+    pub timings: Option<SpdTimings>,
+    /// Volts
+    pub voltage: Option<f32>,
+}
+
+impl SpdProfile {
+    /// A profile known only to be there.
+    pub fn named(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Memory slots as firmware lists them, filled or not, and what the board
+/// accepts.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct MemorySlots {
+    pub total: usize,
+    pub populated: usize,
+    /// Largest total the board supports, bytes
+    pub max_capacity: Option<u64>,
+    /// Fastest rated speed among the installed modules, MT/s; firmware
+    /// states no limit for the board itself
+    pub max_speed: Option<u32>,
+    pub slots: Vec<MemorySlot>,
+}
+
+impl MemorySlots {
+    pub fn installed(&self) -> u64 {
+        self.slots.iter().filter_map(|s| s.size).sum()
+    }
+
+    /// Bytes that can still be added before reaching `max_capacity`.
+    pub fn headroom(&self) -> Option<u64> {
+        Some(self.max_capacity?.saturating_sub(self.installed()))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct StorageInfo {
+pub struct MemorySlot {
+    /// Device locator, e.g. "DIMM_A1"
+    pub locator: String,
+    pub bank: Option<String>,
+    pub channel: Option<String>,
+    /// Bytes; `None` for an empty slot
+    pub size: Option<u64>,
+    /// "DDR4", "DDR5", ...
+    pub memory_type: Option<String>,
+    /// Rated and configured speed, MT/s
+    pub speed: Option<u32>,
+    pub configured_speed: Option<u32>,
+}
+
+/// Kernel memory settings audited on database and virtualization hosts (Linux).
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct MemorySettings {
+    /// Persistent hugepages of the default size
+    pub hugepages_total: u64,
+    pub hugepages_free: u64,
+    /// Bytes, usually 2 MiB
+    pub hugepage_size: u64,
+    /// Transparent hugepage mode: "always", "madvise" or "never"
+    pub transparent_hugepages: Option<String>,
+    /// `vm.overcommit_memory`: 0 heuristic, 1 always, 2 strict
+    pub overcommit_memory: Option<u8>,
+    /// `vm.overcommit_ratio`, the share of RAM counted towards the strict limit
+    pub overcommit_ratio: Option<u32>,
+    /// Bytes that can be committed under strict overcommit
+    pub commit_limit: Option<u64>,
+    /// Bytes currently committed (`Committed_AS`)
+    pub committed: Option<u64>,
+}
+
+impl MemorySettings {
+    pub fn overcommit_label(&self) -> Option<&'static str> {
+        match self.overcommit_memory? {
+            0 => Some("heuristic"),
+            1 => Some("always"),
+            2 => Some("strict"),
+            _ => None,
+        }
+    }
+}
+
+/// A kernel limit next to current usage, e.g. `fs.file-max` against the file
+/// handles allocated system-wide.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct KernelLimit {
+    /// The sysctl, or `RLIMIT_NOFILE` for this process's own limit
     pub name: String,
-    pub mount_point: String,
-    pub total: u64,
-    pub used: u64,
-    pub free: u64,
-    pub filesystem: String,
+    pub used: Option<u64>,
+    pub limit: u64,
+}
+
+impl KernelLimit {
+    pub fn usage_percent(&self) -> Option<f32> {
+        let used = self.used?;
+        (self.limit > 0).then(|| used as f32 * 100.0 / self.limit as f32)
+    }
+}
+
+/// Clock source and time synchronization. A drifting or unsynchronized clock
+/// shows up as TLS, Kerberos and consensus failures that look like hardware
+/// faults.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TimeHealth {
+    /// e.g. "tsc", "kvm-clock", "hpet"
+    pub clocksource: Option<String>,
+    #[serde(default)]
+    pub available_clocksources: Vec<String>,
+    /// "chrony" or "timedatectl", whichever answered
+    pub sync_daemon: Option<String>,
+    pub ntp_synchronized: Option<bool>,
+    /// The server chrony is locked to
+    pub ntp_server: Option<String>,
+    /// Seconds the system clock is ahead of NTP time (negative: behind)
+    pub ntp_offset: Option<f64>,
+    /// Driver of the first RTC, e.g. "rtc_cmos"; `None` without one
+    pub rtc: Option<String>,
+    /// Seconds the RTC is ahead of the system clock
+    pub rtc_offset: Option<i64>,
+}
+
+/// Pressure Stall Information from /proc/pressure (Linux 4.20+): how much of
+/// the time tasks were held up waiting for a resource.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Pressure {
+    pub cpu: Option<PressureStall>,
+    pub memory: Option<PressureStall>,
+    pub io: Option<PressureStall>,
+}
+
+impl Pressure {
+    /// Each resource that has readings, by name.
+    pub fn resources(&self) -> impl Iterator<Item = (&'static str, &PressureStall)> {
+        [
+            ("cpu", &self.cpu),
+            ("memory", &self.memory),
+            ("io", &self.io),
+        ]
+        .into_iter()
+        .filter_map(|(name, stall)| Some((name, stall.as_ref()?)))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default)]
+pub struct PressureStall {
+    /// Percent of time some tasks stalled, averaged over 10, 60 and 300 seconds
+    pub some: [f32; 3],
+    /// Percent of time all non-idle tasks stalled at once; `None` for CPU
+    /// before Linux 5.13
+    pub full: Option<[f32; 3]>,
+}
+
+/// Limits of the cgroup hw-checker runs in (Docker, Kubernetes, systemd
+/// units), which the host-wide CPU and RAM figures do not reflect.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ContainerLimits {
+    pub cgroup_version: u8,
+    /// e.g. "/docker/3f2a..." or "/" inside a cgroup namespace
+    pub path: String,
+    /// CPU time the quota allows, in CPUs (e.g. 1.5)
+    pub cpu_quota: Option<f64>,
+    /// CPUs the cpuset confines the cgroup to, when fewer than are online
+    pub cpuset_cpus: Option<usize>,
+    pub memory_limit: Option<u64>,
+    /// Excludes reclaimable page cache, like `docker stats`
+    pub memory_used: Option<u64>,
+}
+
+impl ContainerLimits {
+    /// The CPUs the cgroup can actually keep busy.
+    pub fn effective_cpus(&self) -> Option<f64> {
+        match (self.cpu_quota, self.cpuset_cpus) {
+            (Some(quota), Some(cpus)) => Some(quota.min(cpus as f64)),
+            (quota, cpus) => quota.or(cpus.map(|c| c as f64)),
+        }
+    }
+}
+
+/// A drive, as opposed to the filesystems mounted from it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PhysicalDisk {
+    /// Kernel name, e.g. "sda", "nvme0n1" or "disk0"
+    pub name: String,
+    pub size: u64,
     pub vendor: Option<String>,
     pub model_name: Option<String>,
     pub serial_number: Option<String>,
     pub disk_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotational: Option<bool>,
+    /// Transport: "SATA", "SAS", "NVMe", "USB", "SD", "virtio", ...
     pub interface: Option<String>,
+    /// Negotiated link speed, e.g. "6.0 Gb/s" or "PCIe 16.0 GT/s x4"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_speed: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub smart: Option<SmartHealth>,
+}
+
+impl PhysicalDisk {
+    /// "SATA 6.0 Gb/s", or just the transport when the speed is unknown.
+    pub fn interface_label(&self) -> Option<String> {
+        let interface = self.interface.clone()?;
+        Some(match &self.link_speed {
+            // PCIe speeds already name their bus
+            Some(speed) if speed.starts_with("PCIe") => format!("{} ({})", interface, speed),
+            Some(speed) => format!("{} {}", interface, speed),
+            None => interface,
+        })
+    }
+}
+
+/// Overall SMART verdict and a few attributes, as reported by smartctl.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SmartHealth {
+    pub passed: bool,
+    pub temperature: Option<f32>,
+    pub power_on_hours: Option<u64>,
+}
+
+/// A mounted filesystem.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Filesystem {
+    pub name: String,
+    pub mount_point: String,
+    pub total: u64,
+    pub used: u64,
+    pub free: u64,
+    pub filesystem: String,
+    /// [`PhysicalDisk::name`] of the drive it lives on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk: Option<String>,
+    /// APFS container (macOS) whose space this volume shares
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    /// Absent for filesystems without a fixed inode table (btrfs, ZFS report 0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inodes: Option<InodeUsage>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct InodeUsage {
+    pub total: u64,
+    pub used: u64,
+    pub free: u64,
+}
+
+impl InodeUsage {
+    /// `None` for an empty inode table, which live discovery leaves out but
+    /// a saved or received report may still carry.
+    pub fn usage_percent(&self) -> Option<f32> {
+        (self.total > 0).then(|| self.used as f32 * 100.0 / self.total as f32)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DiskIoInfo {
+    pub name: String,
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    pub read_iops: f64,
+    pub write_iops: f64,
+}
+
+/// One /proc/interrupts source over the sample interval.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IrqInfo {
+    /// IRQ number, or the kernel's name for a per-CPU source such as "LOC"
+    pub irq: String,
+    /// The device queues on the line (e.g. "nvme0q3"), or what the source is
+    pub name: String,
+    /// Interrupts per second on all CPUs
+    pub rate: f64,
+    /// Interrupts per second on each CPU
+    pub per_cpu: Vec<f64>,
+    /// CPUs the IRQ may be delivered to, e.g. "0-3"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub affinity: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -52,6 +654,150 @@ pub struct NetworkInfo {
     pub received: u64,
     pub transmitted: u64,
     pub mac_address: String,
+    /// Only known on Linux.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<InterfaceKind>,
+    /// Ports of a bond or bridge.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<InterfaceMembers>,
+}
+
+impl NetworkInfo {
+    pub fn is_virtual(&self) -> bool {
+        self.kind
+            .is_some_and(|kind| kind != InterfaceKind::Physical)
+    }
+}
+
+/// Default routes and name servers.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct NetworkConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gateway_v4: Option<Gateway>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gateway_v6: Option<Gateway>,
+    #[serde(default)]
+    pub dns_servers: Vec<String>,
+    /// The interface of the IPv4 default route, or else the IPv6 one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_interface: Option<String>,
+}
+
+impl NetworkConfig {
+    pub fn new(
+        gateway_v4: Option<Gateway>,
+        gateway_v6: Option<Gateway>,
+        dns_servers: Vec<String>,
+    ) -> Option<Self> {
+        if gateway_v4.is_none() && gateway_v6.is_none() && dns_servers.is_empty() {
+            return None;
+        }
+        let primary_interface = gateway_v4
+            .as_ref()
+            .or(gateway_v6.as_ref())
+            .map(|gateway| gateway.interface.clone());
+        Some(Self {
+            gateway_v4,
+            gateway_v6,
+            dns_servers,
+            primary_interface,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Gateway {
+    pub address: String,
+    pub interface: String,
+}
+
+/// Results of `--connectivity`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Connectivity {
+    /// Not set when there is no default route
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<PingResult>,
+    pub target: PingResult,
+    pub dns: DnsCheck,
+    pub verdict: ConnectivityVerdict,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PingResult {
+    pub host: String,
+    pub sent: u32,
+    pub received: u32,
+    pub loss_percent: f32,
+    pub avg_latency_ms: Option<f64>,
+    /// Set when `ping` could not be run at all
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DnsCheck {
+    pub name: String,
+    pub resolved: bool,
+    pub latency_ms: Option<f64>,
+    pub addresses: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectivityVerdict {
+    Online,
+    /// Packet loss, or either the target or DNS failing
+    Degraded,
+    /// Neither the target nor DNS answering
+    Offline,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InterfaceMembers {
+    /// Bonding mode, e.g. "active-backup" or "802.3ad"; not set for bridges
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    /// The slave carrying traffic in active-backup style modes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<String>,
+    pub ports: Vec<MemberPort>,
+}
+
+impl InterfaceMembers {
+    pub fn down(&self) -> impl Iterator<Item = &MemberPort> {
+        self.ports.iter().filter(|port| !port.is_up())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MemberPort {
+    pub name: String,
+    /// The bonding MII status, or else the kernel's operstate ("up", "down", ...)
+    pub state: String,
+}
+
+impl MemberPort {
+    /// Drivers that do not track their carrier report "unknown".
+    pub fn is_up(&self) -> bool {
+        matches!(self.state.as_str(), "up" | "unknown")
+    }
+}
+
+/// What a network interface is backed by.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum InterfaceKind {
+    Physical,
+    Loopback,
+    Bridge,
+    Bond,
+    Vlan,
+    Veth,
+    Tun,
+    Tap,
+    WireGuard,
+    /// Other software devices: dummy, macvlan, tunnels, ...
+    Virtual,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -64,6 +810,14 @@ pub struct UsbDevice {
     pub product: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ThunderboltDevice {
+    pub name: String,
+    pub vendor: Option<String>,
+    /// Link speed as reported by the OS, e.g. "40 Gb/s"
+    pub speed: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PciDevice {
     pub slot: String,
@@ -72,6 +826,112 @@ pub struct PciDevice {
     pub vendor_name: Option<String>,
     pub device_name: Option<String>,
     pub class_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<u8>,
+    /// Board or card vendor's IDs for the function, e.g. 1043:8694 on an ASUS board
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subsystem_vendor_id: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subsystem_device_id: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub numa_node: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iommu_group: Option<u32>,
+}
+
+impl PciDevice {
+    /// How much is known about the device, to pick the richer of two listings.
+    pub fn known_fields(&self) -> usize {
+        1 + [
+            self.vendor_name.is_some(),
+            self.device_name.is_some(),
+            self.class_name.is_some(),
+            self.revision.is_some(),
+            self.subsystem_vendor_id.is_some(),
+            self.subsystem_device_id.is_some(),
+            self.numa_node.is_some(),
+            self.iommu_group.is_some(),
+        ]
+        .into_iter()
+        .filter(|known| *known)
+        .count()
+    }
+}
+
+/// DMA remapping state and the groups the IOMMU isolates devices in; a
+/// group is the smallest set of devices that can be passed to a VM.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IommuInfo {
+    pub enabled: bool,
+    /// "Intel VT-d", "AMD-Vi", "ARM SMMU", ...
+    pub kind: Option<String>,
+    /// IOMMU options on the kernel command line, e.g. "intel_iommu=on", "iommu=pt"
+    pub kernel_options: Vec<String>,
+    pub groups: Vec<IommuGroup>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IommuGroup {
+    pub id: u32,
+    /// Default domain: "DMA", "DMA-FQ", or "identity" for passthrough
+    pub domain: Option<String>,
+    pub devices: Vec<IommuDevice>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IommuDevice {
+    pub slot: String,
+    pub name: Option<String>,
+    /// 24-bit PCI class code, e.g. 0x030000 for a VGA controller
+    pub class: Option<u32>,
+    /// Bound driver; "vfio-pci" once reserved for a VM
+    pub driver: Option<String>,
+}
+
+impl IommuDevice {
+    pub fn is_gpu(&self) -> bool {
+        self.class.is_some_and(|class| class >> 16 == 0x03)
+    }
+
+    fn is_bridge(&self) -> bool {
+        self.class.is_some_and(|class| class >> 8 == 0x0604)
+    }
+
+    /// Domain, bus and device without the function: "0000:01:00"
+    fn device_address(&self) -> &str {
+        self.slot
+            .rsplit_once('.')
+            .map_or(&self.slot, |(device, _)| device)
+    }
+}
+
+impl IommuGroup {
+    /// Devices that would have to go to the VM along with `device`: all but
+    /// its sibling functions and the PCIe bridges, which stay with the host.
+    pub fn companions(&self, device: &IommuDevice) -> Vec<&IommuDevice> {
+        self.devices
+            .iter()
+            .filter(|other| other.device_address() != device.device_address() && !other.is_bridge())
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GpuInfo {
+    pub slot: String,
+    pub name: String,
+    pub driver: Option<String>,
+    pub vram_total: Option<u64>,
+}
+
+/// A process holding a GPU open.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GpuProcess {
+    /// PCI slot of the GPU, as in [`GpuInfo::slot`]
+    pub slot: String,
+    pub pid: u32,
+    pub name: String,
+    pub vram_used: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -83,11 +943,258 @@ pub struct MotherboardInfo {
     pub bios_date: String,
 }
 
+/// The board as firmware (SMBIOS) describes it: CPU sockets, caches, port
+/// connectors and expansion slots, including the ones the OS has no driver
+/// for or that are empty.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BoardLayout {
+    #[serde(default)]
+    pub sockets: Vec<ProcessorSocket>,
+    #[serde(default)]
+    pub caches: Vec<FirmwareCache>,
+    #[serde(default)]
+    pub ports: Vec<PortConnector>,
+    #[serde(default)]
+    pub slots: Vec<ExpansionSlot>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProcessorSocket {
+    pub designation: String,
+    /// Socket type, e.g. "AM5" or "LGA1700"
+    pub socket: Option<String>,
+    pub populated: bool,
+    pub manufacturer: Option<String>,
+    pub version: Option<String>,
+    /// MHz
+    pub max_speed: Option<u16>,
+    pub current_speed: Option<u16>,
+    pub cores: Option<u16>,
+    pub threads: Option<u16>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FirmwareCache {
+    pub designation: String,
+    pub level: u8,
+    /// "Instruction", "Data" or "Unified"
+    pub kind: Option<String>,
+    /// Installed size in bytes
+    pub size: Option<u64>,
+    pub max_size: Option<u64>,
+    pub associativity: Option<String>,
+    pub enabled: bool,
+    /// Designation of the processor socket the cache belongs to
+    pub socket: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PortConnector {
+    /// Board reference, e.g. "JUSB1"
+    pub internal: Option<String>,
+    /// Label on the case, e.g. "USB 3.2 Gen2 (rear)"
+    pub external: Option<String>,
+    pub connector: Option<String>,
+    pub port_type: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExpansionSlot {
+    pub designation: String,
+    /// e.g. "PCI Express Gen 4" or "M.2 Socket 3"
+    pub slot_type: String,
+    pub generation: Option<u8>,
+    /// Physical width in lanes (the slot's length)
+    pub width: Option<u8>,
+    /// Lanes actually wired, often fewer than `width`
+    pub lanes: Option<u8>,
+    /// Firmware's "current usage"; not every BIOS keeps it right
+    pub in_use: Option<bool>,
+    /// PCI address the slot is wired to, e.g. "0000:00:01.1"
+    pub address: Option<String>,
+    /// PCI address of the card found in the slot
+    pub device: Option<String>,
+    pub device_name: Option<String>,
+    /// PCIe generation and width the card's link trained at
+    #[serde(default)]
+    pub link_generation: Option<u8>,
+    #[serde(default)]
+    pub link_width: Option<u8>,
+}
+
+impl ExpansionSlot {
+    /// Nothing found in the slot and firmware does not claim it either.
+    pub fn is_free(&self) -> bool {
+        self.device.is_none() && self.in_use != Some(true)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BatteryInfo {
     pub name: String,
     pub status: String,
     pub capacity: u8,
+    #[serde(default)]
+    pub power_watts: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycle_count: Option<u32>,
+    /// Capacity lost against the design capacity, in percent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wear_percent: Option<f32>,
+    /// Estimated seconds until empty while discharging, until full while charging.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_remaining: Option<u64>,
+}
+
+/// Laptop state behind many "my machine is slow" reports: a closed lid or a
+/// dock moves work to external displays, and throttling caps the CPU clock.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct PlatformState {
+    /// "open" or "closed", from the ACPI lid button
+    pub lid: Option<String>,
+    /// Attached to an ACPI docking station
+    pub docked: Option<bool>,
+    /// A processor cooling device is active, i.e. the CPU is slowed down right now
+    pub throttling: Option<bool>,
+    /// Thermal throttling events since boot
+    pub throttle_events: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TemperatureSensor {
+    pub label: String,
+    pub temperature: Option<f32>,
+    pub max: Option<f32>,
+    pub critical: Option<f32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FanInfo {
+    pub label: String,
+    pub rpm: u32,
+}
+
+/// Data discovery could not read with the current privileges.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Limitation {
+    /// What is missing, e.g. "RAM module details (SMBIOS)"
+    pub probe: String,
+    pub reason: String,
+}
+
+/// How discovery went, as opposed to what it found.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Diagnostics {
+    /// Steps abandoned after their `probe_timeouts`, named like the report
+    /// field they fill; those sections are empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub timed_out: Vec<String>,
+    /// Only collected for `--timings`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub timings: Vec<StepTiming>,
+    /// Plugins left out of `custom`, as `name: reason`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plugin_errors: Vec<String>,
+}
+
+impl Diagnostics {
+    pub fn is_empty(&self) -> bool {
+        self.timed_out.is_empty() && self.timings.is_empty() && self.plugin_errors.is_empty()
+    }
+}
+
+/// How long one discovery step took.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StepTiming {
+    pub step: String,
+    /// Milliseconds, to a tenth
+    pub ms: f64,
+}
+
+/// OS context beyond name and kernel version.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct OsDetails {
+    /// e.g. "x86_64", "aarch64"
+    pub architecture: String,
+    /// RFC 3339
+    pub boot_time: Option<String>,
+    pub kernel_cmdline: Option<String>,
+    pub kernel_modules: Option<usize>,
+    /// e.g. "SELinux (enforcing)" or "AppArmor"
+    pub security_module: Option<String>,
+    pub systemd_version: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CloudProvider {
+    Aws,
+    Gcp,
+    Azure,
+}
+
+/// The cloud VM the report was taken on, from the provider's instance
+/// metadata service. Only the provider is known when the service is blocked.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CloudInstance {
+    pub provider: CloudProvider,
+    /// e.g. "m6i.large", "e2-standard-4" or "Standard_D4s_v5"
+    pub instance_type: Option<String>,
+    pub region: Option<String>,
+    pub zone: Option<String>,
+    pub instance_id: Option<String>,
+}
+
+impl CloudInstance {
+    pub fn unknown(provider: CloudProvider) -> Self {
+        Self {
+            provider,
+            instance_type: None,
+            region: None,
+            zone: None,
+            instance_id: None,
+        }
+    }
+}
+
+/// Operator-provided inventory metadata (the `asset:` config section, or
+/// `--asset-tag`, `--location`, `--owner` and `--meta`).
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct AssetInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset_tag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, String>,
+}
+
+impl AssetInfo {
+    pub fn is_empty(&self) -> bool {
+        self.asset_tag.is_none()
+            && self.location.is_none()
+            && self.owner.is_none()
+            && self.custom.is_empty()
+    }
+
+    /// Label/value pairs for display, fixed fields first.
+    pub fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = Vec::new();
+        for (label, value) in [
+            ("Asset tag", &self.asset_tag),
+            ("Location", &self.location),
+            ("Owner", &self.owner),
+        ] {
+            if let Some(value) = value {
+                fields.push((crate::i18n::t(label).to_string(), value.clone()));
+            }
+        }
+        fields.extend(self.custom.iter().map(|(k, v)| (k.clone(), v.clone())));
+        fields
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -97,12 +1204,143 @@ pub struct HardwareReport {
     pub kernel_version: String,
     pub hostname: String,
     pub uptime: u64,
-    pub cpu: Vec<CpuInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub os_details: Option<OsDetails>,
+    #[serde(default, skip_serializing_if = "AssetInfo::is_empty")]
+    pub asset: AssetInfo,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<CloudInstance>,
+    #[serde(flatten, with = "legacy_cpu")]
+    pub cpu: Vec<CpuPackage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_load: Option<CpuLoad>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub virtualization: Option<VirtualizationSupport>,
     pub ram: RamInfo,
-    pub storage: Vec<StorageInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<ContainerLimits>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pressure: Option<Pressure>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub limits: Vec<KernelLimit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<TimeHealth>,
+    #[serde(default)]
+    pub disks: Vec<PhysicalDisk>,
+    /// Reports before the split into disks and filesystems called this `storage`
+    #[serde(alias = "storage")]
+    pub filesystems: Vec<Filesystem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disk_io: Vec<DiskIoInfo>,
+    /// Only collected for `--irq`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub irqs: Vec<IrqInfo>,
     pub network: Vec<NetworkInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_config: Option<NetworkConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connectivity: Option<Connectivity>,
     pub usb: Vec<UsbDevice>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub thunderbolt: Vec<ThunderboltDevice>,
     pub pci: Vec<PciDevice>,
+    /// Only collected for `--iommu`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iommu: Option<IommuInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gpus: Vec<GpuInfo>,
+    /// Only collected for `--gpu`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gpu_processes: Vec<GpuProcess>,
     pub motherboard: Option<MotherboardInfo>,
     pub battery: Vec<BatteryInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub platform: Option<PlatformState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub board_layout: Option<BoardLayout>,
+    #[serde(default)]
+    pub temperatures: Vec<TemperatureSensor>,
+    #[serde(default)]
+    pub fans: Vec<FanInfo>,
+    /// Output of the `plugins:` and `collectors:` from the config, by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub limitations: Vec<Limitation>,
+    #[serde(default, skip_serializing_if = "Diagnostics::is_empty")]
+    pub diagnostics: Diagnostics,
+}
+
+impl HardwareReport {
+    /// The logical cores of every package.
+    pub fn cores(&self) -> impl Iterator<Item = &CoreMetrics> {
+        self.cpu.iter().flat_map(|p| &p.cores)
+    }
+
+    pub fn cores_mut(&mut self) -> impl Iterator<Item = &mut CoreMetrics> {
+        self.cpu.iter_mut().flat_map(|p| &mut p.cores)
+    }
+
+    /// The drive a filesystem lives on, if known.
+    pub fn disk_for(&self, fs: &Filesystem) -> Option<&PhysicalDisk> {
+        let name = fs.disk.as_ref()?;
+        self.disks.iter().find(|d| &d.name == name)
+    }
+
+    /// Average load over all logical cores.
+    pub fn cpu_usage(&self) -> Option<f32> {
+        let (count, total) = self
+            .cores()
+            .fold((0, 0.0), |(n, sum), c| (n + 1, sum + c.usage));
+        (count > 0).then(|| total / count as f32)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HotplugEvent {
+    pub timestamp: String,
+    pub action: String,
+    pub subsystem: String,
+    pub devtype: Option<String>,
+    pub sysname: String,
+    pub devnode: Option<String>,
+    pub driver: Option<String>,
+    pub vendor_id: Option<String>,
+    pub product_id: Option<String>,
+    pub vendor: Option<String>,
+    pub model: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DiskUsageSample {
+    pub mount_point: String,
+    pub used: u64,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TemperatureSample {
+    pub label: String,
+    pub celsius: f32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NetworkCounterSample {
+    pub name: String,
+    pub received: u64,
+    pub transmitted: u64,
+}
+
+/// The dynamic (non-inventory) metrics captured at a single point in time.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MetricsSample {
+    pub timestamp: String,
+    pub cpu_usage: f32,
+    pub ram_used: u64,
+    pub ram_total: u64,
+    pub swap_used: u64,
+    pub swap_total: u64,
+    pub disks: Vec<DiskUsageSample>,
+    pub temperatures: Vec<TemperatureSample>,
+    pub network: Vec<NetworkCounterSample>,
 }
@@ -0,0 +1,135 @@
+use crate::cli::{MonitorArgs, StreamFormat};
+use crate::i18n::t;
+use crate::model::HotplugEvent;
+use crate::theme::theme;
+use anyhow::Result;
+use colored::Colorize;
+
+pub fn run_monitor(args: &MonitorArgs) -> Result<()> {
+    if args.format == StreamFormat::Table {
+        println!(
+            "{}",
+            format!(
+                "{:<8}  {:<7}  {:<10}  {:<16}  {}",
+                t("TIME"),
+                t("ACTION"),
+                t("SUBSYSTEM"),
+                t("DEVICE"),
+                t("DETAILS")
+            )
+            .bold()
+            .color(theme().header)
+        );
+    }
+    watch(&args.subsystem, |event| print_event(&event, args.format))
+}
+
+fn print_event(event: &HotplugEvent, format: StreamFormat) -> Result<()> {
+    match format {
+        StreamFormat::Jsonl => println!("{}", serde_json::to_string(event)?),
+        StreamFormat::Table => {
+            let time = event.timestamp.get(11..19).unwrap_or(&event.timestamp);
+            let action = format!("{:<7}", event.action);
+            let action = match event.action.as_str() {
+                "add" | "bind" => action.color(theme().ok),
+                "remove" | "unbind" => action.color(theme().critical),
+                _ => action.color(theme().warning),
+            };
+            let device = event.devnode.as_deref().unwrap_or(&event.sysname);
+            let mut details = Vec::new();
+            if let (Some(vendor), Some(product)) = (&event.vendor_id, &event.product_id) {
+                details.push(format!("{}:{}", vendor, product));
+            }
+            let name = [event.vendor.as_deref(), event.model.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" ");
+            if !name.is_empty() {
+                details.push(name);
+            }
+            if let Some(driver) = &event.driver {
+                details.push(format!("driver={}", driver));
+            }
+            if let Some(devtype) = &event.devtype {
+                details.push(format!("type={}", devtype));
+            }
+            println!(
+                "{:<8}  {}  {:<10}  {:<16}  {}",
+                time,
+                action,
+                event.subsystem,
+                device,
+                details.join(" ")
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn watch(
+    subsystems: &[String],
+    mut on_event: impl FnMut(HotplugEvent) -> Result<()>,
+) -> Result<()> {
+    use anyhow::Context;
+    use std::os::fd::AsRawFd;
+
+    let mut builder = udev::MonitorBuilder::new().context("failed to create udev monitor")?;
+    for subsystem in subsystems {
+        builder = builder.match_subsystem(subsystem)?;
+    }
+    let socket = builder
+        .listen()
+        .context("failed to listen for udev events")?;
+
+    let mut fds = [libc::pollfd {
+        fd: socket.as_raw_fd(),
+        events: libc::POLLIN,
+        revents: 0,
+    }];
+    loop {
+        // The socket is non-blocking; wait until udev has something for us.
+        let ready = unsafe { libc::poll(fds.as_mut_ptr(), fds.len() as libc::nfds_t, -1) };
+        if ready < 0 {
+            let err = std::io::Error::last_os_error();
+            if err.kind() == std::io::ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(err.into());
+        }
+        for event in socket.iter() {
+            on_event(to_hotplug_event(&event))?;
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn to_hotplug_event(event: &udev::Event) -> HotplugEvent {
+    let property = |name: &str| {
+        event
+            .property_value(name)
+            .map(|v| v.to_string_lossy().into_owned())
+    };
+    HotplugEvent {
+        timestamp: chrono::Local::now().to_rfc3339(),
+        action: event.event_type().to_string(),
+        subsystem: event
+            .subsystem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        devtype: event.devtype().map(|s| s.to_string_lossy().into_owned()),
+        sysname: event.sysname().to_string_lossy().into_owned(),
+        devnode: event.devnode().map(|p| p.display().to_string()),
+        driver: event.driver().map(|s| s.to_string_lossy().into_owned()),
+        vendor_id: property("ID_VENDOR_ID"),
+        product_id: property("ID_MODEL_ID"),
+        vendor: property("ID_VENDOR_FROM_DATABASE").or_else(|| property("ID_VENDOR")),
+        model: property("ID_MODEL_FROM_DATABASE").or_else(|| property("ID_MODEL")),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn watch(_subsystems: &[String], _on_event: impl FnMut(HotplugEvent) -> Result<()>) -> Result<()> {
+    anyhow::bail!("hotplug monitoring requires udev and is only available on Linux")
+}
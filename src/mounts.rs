@@ -0,0 +1,68 @@
+use crate::model::Filesystem;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The `mounts:` config section: filesystems left out of the storage section
+/// unless `all` is set (also by `--all-mounts`).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields, default)]
+pub struct MountsConfig {
+    pub all: bool,
+    /// Filesystem types with no disk space of their own
+    pub hide_types: Vec<String>,
+    /// Loop devices, e.g. the squashfs images of snap packages under /snap
+    pub hide_loop: bool,
+    /// Show a device mounted at several places (bind mounts, btrfs
+    /// subvolumes) once, under its shortest mount point
+    pub collapse_binds: bool,
+}
+
+impl Default for MountsConfig {
+    fn default() -> Self {
+        Self {
+            all: false,
+            hide_types: [
+                "tmpfs",
+                "devtmpfs",
+                "ramfs",
+                "overlay",
+                "squashfs",
+                "fuse.snapfuse",
+                "autofs",
+                "devfs",
+                "fdescfs",
+                "nullfs",
+            ]
+            .map(str::to_string)
+            .to_vec(),
+            hide_loop: true,
+            collapse_binds: true,
+        }
+    }
+}
+
+pub fn filter_mounts(filesystems: &mut Vec<Filesystem>, config: &MountsConfig) {
+    if config.all {
+        return;
+    }
+    filesystems.retain(|fs| {
+        let hidden_type = config
+            .hide_types
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(&fs.filesystem));
+        let loop_device = config.hide_loop && fs.name.starts_with("/dev/loop");
+        !hidden_type && !loop_device
+    });
+    if config.collapse_binds {
+        let mut shortest: HashMap<String, String> = HashMap::new();
+        for fs in filesystems.iter() {
+            let mount = shortest
+                .entry(fs.name.clone())
+                .or_insert_with(|| fs.mount_point.clone());
+            if fs.mount_point.len() < mount.len() {
+                *mount = fs.mount_point.clone();
+            }
+        }
+        filesystems.retain(|fs| shortest.get(&fs.name) == Some(&fs.mount_point));
+    }
+}
@@ -0,0 +1,386 @@
+use crate::cli::MqttArgs;
+use crate::config::Config;
+use crate::discovery::MetricsSampler;
+use crate::i18n::t;
+use crate::model::MetricsSample;
+use anyhow::Result;
+#[cfg(feature = "async")]
+use rumqttc::AsyncClient;
+#[cfg(not(feature = "async"))]
+use rumqttc::Client;
+use rumqttc::{Event, LastWill, MqttOptions, Packet, QoS};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// The `mqtt:` config section. The password is read from the environment.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct MqttConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub broker: Option<String>,
+    #[serde(default = "MqttConfig::default_port")]
+    pub port: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(default = "MqttConfig::default_password_env")]
+    pub password_env: String,
+    /// State topics are `<topic_prefix>/<host>/<metric>`.
+    #[serde(default = "MqttConfig::default_topic_prefix")]
+    pub topic_prefix: String,
+    #[serde(default = "MqttConfig::default_interval")]
+    pub interval_secs: u64,
+    /// Publish Home Assistant MQTT discovery payloads.
+    #[serde(default = "MqttConfig::default_discovery")]
+    pub discovery: bool,
+    #[serde(default = "MqttConfig::default_discovery_prefix")]
+    pub discovery_prefix: String,
+}
+
+impl MqttConfig {
+    fn default_port() -> u16 {
+        1883
+    }
+
+    fn default_password_env() -> String {
+        "HW_CHECKER_MQTT_PASSWORD".to_string()
+    }
+
+    fn default_topic_prefix() -> String {
+        "hwchecker".to_string()
+    }
+
+    fn default_interval() -> u64 {
+        30
+    }
+
+    fn default_discovery() -> bool {
+        true
+    }
+
+    fn default_discovery_prefix() -> String {
+        "homeassistant".to_string()
+    }
+}
+
+impl Default for MqttConfig {
+    fn default() -> Self {
+        Self {
+            broker: None,
+            port: Self::default_port(),
+            username: None,
+            password_env: Self::default_password_env(),
+            topic_prefix: Self::default_topic_prefix(),
+            interval_secs: Self::default_interval(),
+            discovery: Self::default_discovery(),
+            discovery_prefix: Self::default_discovery_prefix(),
+        }
+    }
+}
+
+/// One published value: `path` is the topic suffix, e.g. `disk/root/usage`.
+struct MqttMetric {
+    path: String,
+    name: String,
+    value: String,
+    unit: &'static str,
+    device_class: Option<&'static str>,
+    state_class: &'static str,
+}
+
+// Topic- and id-safe form of a mount point, sensor label or interface name.
+fn slug(name: &str) -> String {
+    let slug: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let slug = slug.trim_matches('_').to_string();
+    if slug.is_empty() {
+        "root".to_string()
+    } else {
+        slug
+    }
+}
+
+fn percent(used: u64, total: u64) -> Option<String> {
+    (total > 0).then(|| format!("{:.1}", used as f64 / total as f64 * 100.0))
+}
+
+fn metrics(sample: &MetricsSample) -> Vec<MqttMetric> {
+    let usage = |path: String, name: String, value: String| MqttMetric {
+        path,
+        name,
+        value,
+        unit: "%",
+        device_class: None,
+        state_class: "measurement",
+    };
+    let mut metrics = vec![usage(
+        "cpu/usage".to_string(),
+        "CPU usage".to_string(),
+        format!("{:.1}", sample.cpu_usage),
+    )];
+    if let Some(ram) = percent(sample.ram_used, sample.ram_total) {
+        metrics.push(usage("ram/usage".to_string(), "RAM usage".to_string(), ram));
+    }
+    if let Some(swap) = percent(sample.swap_used, sample.swap_total) {
+        metrics.push(usage(
+            "swap/usage".to_string(),
+            "Swap usage".to_string(),
+            swap,
+        ));
+    }
+    for disk in &sample.disks {
+        if let Some(used) = percent(disk.used, disk.total) {
+            metrics.push(usage(
+                format!("disk/{}/usage", slug(&disk.mount_point)),
+                format!("Disk {} usage", disk.mount_point),
+                used,
+            ));
+        }
+    }
+    for temp in &sample.temperatures {
+        metrics.push(MqttMetric {
+            path: format!("temp/{}/celsius", slug(&temp.label)),
+            name: format!("{} temperature", temp.label),
+            value: format!("{:.1}", temp.celsius),
+            unit: "°C",
+            device_class: Some("temperature"),
+            state_class: "measurement",
+        });
+    }
+    for net in &sample.network {
+        for (direction, label, bytes) in [
+            ("rx_bytes", "received", net.received),
+            ("tx_bytes", "transmitted", net.transmitted),
+        ] {
+            metrics.push(MqttMetric {
+                path: format!("net/{}/{}", slug(&net.name), direction),
+                name: format!("{} {}", net.name, label),
+                value: bytes.to_string(),
+                unit: "B",
+                device_class: Some("data_size"),
+                state_class: "total_increasing",
+            });
+        }
+    }
+    metrics
+}
+
+pub fn run_mqtt(args: &MqttArgs, config: &Config) -> Result<()> {
+    let settings = &config.mqtt;
+    let Some(broker) = args.broker.clone().or_else(|| settings.broker.clone()) else {
+        anyhow::bail!("no MQTT broker given (use --broker or `mqtt.broker` in the config)");
+    };
+    let port = args.port.unwrap_or(settings.port);
+    let interval = args
+        .interval
+        .unwrap_or(Duration::from_secs(settings.interval_secs))
+        .max(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    let discovery = settings.discovery && !args.no_discovery;
+
+    let host = config
+        .redact
+        .hostname(&sysinfo::System::host_name().unwrap_or_else(|| "unknown".to_string()));
+    let node = slug(&host);
+    let base = format!("{}/{}", settings.topic_prefix, node);
+    let availability = format!("{}/status", base);
+
+    let mut options = MqttOptions::new(format!("hw-checker-{}", node), &broker, port);
+    options.set_keep_alive(Duration::from_secs(30));
+    options.set_last_will(LastWill::new(
+        &availability,
+        "offline",
+        QoS::AtLeastOnce,
+        true,
+    ));
+    if let Some(username) = &settings.username {
+        let password = std::env::var(&settings.password_env).unwrap_or_default();
+        options.set_credentials(username, password);
+    }
+    eprintln!(
+        "{} {}:{} ({}/#, {} {})",
+        t("Publishing metrics to"),
+        broker,
+        port,
+        base,
+        t("every"),
+        humantime::format_duration(interval)
+    );
+
+    let publisher = Publisher {
+        host,
+        node,
+        base,
+        availability,
+        discovery,
+        discovery_prefix: settings.discovery_prefix.clone(),
+        announced: HashSet::new(),
+    };
+    publish_loop(options, publisher, interval)
+}
+
+/// One MQTT publish, built once and sent by either event loop.
+struct Message {
+    topic: String,
+    qos: QoS,
+    retain: bool,
+    payload: String,
+}
+
+/// Turns samples into state messages, preceded by a discovery config the
+/// first time each metric shows up.
+struct Publisher {
+    host: String,
+    node: String,
+    base: String,
+    availability: String,
+    discovery: bool,
+    discovery_prefix: String,
+    announced: HashSet<String>,
+}
+
+impl Publisher {
+    fn online(&self) -> Message {
+        Message {
+            topic: self.availability.clone(),
+            qos: QoS::AtLeastOnce,
+            retain: true,
+            payload: "online".to_string(),
+        }
+    }
+
+    fn messages(&mut self, sample: &MetricsSample) -> Vec<Message> {
+        let mut messages = Vec::new();
+        for metric in metrics(sample) {
+            let state_topic = format!("{}/{}", self.base, metric.path);
+            if self.discovery && self.announced.insert(metric.path.clone()) {
+                let object_id = format!("{}_{}", self.node, slug(&metric.path));
+                let mut payload = json!({
+                    "name": metric.name,
+                    "unique_id": format!("hwchecker_{}", object_id),
+                    "object_id": format!("hwchecker_{}", object_id),
+                    "state_topic": state_topic,
+                    "unit_of_measurement": metric.unit,
+                    "state_class": metric.state_class,
+                    "availability_topic": self.availability,
+                    "device": {
+                        "identifiers": [format!("hwchecker_{}", self.node)],
+                        "name": self.host,
+                        "manufacturer": "hw-checker",
+                        "sw_version": env!("CARGO_PKG_VERSION"),
+                    },
+                });
+                if let Some(class) = metric.device_class {
+                    payload["device_class"] = json!(class);
+                }
+                messages.push(Message {
+                    topic: format!(
+                        "{}/sensor/hwchecker_{}/{}/config",
+                        self.discovery_prefix, self.node, object_id
+                    ),
+                    qos: QoS::AtLeastOnce,
+                    retain: true,
+                    payload: payload.to_string(),
+                });
+            }
+            messages.push(Message {
+                topic: state_topic,
+                qos: QoS::AtMostOnce,
+                retain: false,
+                payload: metric.value,
+            });
+        }
+        messages
+    }
+}
+
+#[cfg(not(feature = "async"))]
+fn publish_loop(options: MqttOptions, mut publisher: Publisher, interval: Duration) -> Result<()> {
+    let (client, mut connection) = Client::new(options, 64);
+
+    // rumqttc makes progress (and reconnects) only while its event loop is polled.
+    // The last will marks us offline on disconnect, so go back online after every (re)connect.
+    let status = client.clone();
+    let online = publisher.online();
+    std::thread::spawn(move || {
+        for event in connection.iter() {
+            match event {
+                Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                    let _ = status.publish(
+                        &online.topic,
+                        online.qos,
+                        online.retain,
+                        online.payload.clone(),
+                    );
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    eprintln!("MQTT: {}", err);
+                    std::thread::sleep(Duration::from_secs(5));
+                }
+            }
+        }
+    });
+
+    let mut sampler = MetricsSampler::new();
+    loop {
+        std::thread::sleep(interval);
+        for message in publisher.messages(&sampler.sample()) {
+            client.publish(message.topic, message.qos, message.retain, message.payload)?;
+        }
+    }
+}
+
+/// The same loop on the shared runtime: the event loop is a task next to the
+/// publisher instead of a thread of its own.
+#[cfg(feature = "async")]
+fn publish_loop(options: MqttOptions, mut publisher: Publisher, interval: Duration) -> Result<()> {
+    crate::runtime::runtime().block_on(async move {
+        let (client, mut eventloop) = AsyncClient::new(options, 64);
+
+        let status = client.clone();
+        let online = publisher.online();
+        tokio::spawn(async move {
+            loop {
+                match eventloop.poll().await {
+                    Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                        let _ = status
+                            .publish(
+                                &online.topic,
+                                online.qos,
+                                online.retain,
+                                online.payload.clone(),
+                            )
+                            .await;
+                    }
+                    Ok(_) => {}
+                    Err(err) => {
+                        eprintln!("MQTT: {}", err);
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                    }
+                }
+            }
+        });
+
+        // Sampling reads procfs and sysfs, so it runs on the blocking pool
+        // rather than stalling the event loop task
+        let mut sampler = MetricsSampler::new();
+        loop {
+            tokio::time::sleep(interval).await;
+            let sample;
+            (sampler, sample) = tokio::task::spawn_blocking(move || {
+                let sample = sampler.sample();
+                (sampler, sample)
+            })
+            .await?;
+            for message in publisher.messages(&sample) {
+                client
+                    .publish(message.topic, message.qos, message.retain, message.payload)
+                    .await?;
+            }
+        }
+    })
+}
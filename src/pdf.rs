@@ -0,0 +1,427 @@
+//! `--format pdf`: the report tables laid out on A4 pages with a branded
+//! header, page numbers and an acceptance block, for handing a machine over
+//! to a customer.
+//!
+//! Uses the PDF base-14 fonts, so no font files are embedded; they cover
+//! Latin-1 only, see [`latin1`].
+
+use crate::exporter::{ReportTable, report_tables};
+use crate::i18n::t;
+use crate::model::HardwareReport;
+use anyhow::{Result, anyhow};
+use printpdf::path::PaintMode;
+use printpdf::{
+    BuiltinFont, Color, IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference,
+    PdfLayerReference, Point, Rect, Rgb,
+};
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+/// Below the header band
+const CONTENT_TOP: f32 = PAGE_HEIGHT - 28.0;
+const CONTENT_BOTTOM: f32 = 18.0;
+
+const CELL_FONT_SIZE: f32 = 7.5;
+/// Courier glyphs are 0.6 em wide; 1 pt = 0.3528 mm
+const CHAR_WIDTH: f32 = CELL_FONT_SIZE * 0.6 * 0.3528;
+const LINE_HEIGHT: f32 = 3.4;
+const CELL_PADDING: f32 = 1.2;
+const MIN_COLUMN_CHARS: usize = 6;
+
+/// The teal of the HTML report headings
+fn brand() -> Color {
+    Color::Rgb(Rgb::new(0.04, 0.42, 0.54, None))
+}
+
+fn grey(level: f32) -> Color {
+    Color::Rgb(Rgb::new(level, level, level, None))
+}
+
+struct Fonts {
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    mono: IndirectFontRef,
+    mono_bold: IndirectFontRef,
+}
+
+/// Lays the report out page by page, moving `y` down from the top.
+struct Writer {
+    doc: PdfDocumentReference,
+    fonts: Fonts,
+    layers: Vec<PdfLayerReference>,
+    y: f32,
+}
+
+pub fn render_pdf(report: &HardwareReport) -> Result<Vec<u8>> {
+    let title = format!("{}: {}", t("Hardware Report"), report.hostname);
+    let (doc, page, layer) =
+        PdfDocument::new(latin1(&title), Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "content");
+    let font = |font| doc.add_builtin_font(font).map_err(|e| anyhow!("{}", e));
+    let fonts = Fonts {
+        regular: font(BuiltinFont::Helvetica)?,
+        bold: font(BuiltinFont::HelveticaBold)?,
+        mono: font(BuiltinFont::Courier)?,
+        mono_bold: font(BuiltinFont::CourierBold)?,
+    };
+    let first = doc.get_page(page).get_layer(layer);
+    let mut writer = Writer {
+        doc,
+        fonts,
+        layers: vec![first],
+        y: CONTENT_TOP,
+    };
+
+    writer.text(t("Hardware Report"), 20.0, MARGIN, true);
+    writer.y -= 9.0;
+    writer.text(&report.hostname, 13.0, MARGIN, false);
+    writer.y -= 6.0;
+    let generated = format!(
+        "{}: {}",
+        t("Generated"),
+        chrono::Local::now().format("%Y-%m-%d %H:%M %Z")
+    );
+    writer.text(&generated, 9.0, MARGIN, false);
+    writer.y -= 8.0;
+
+    for table in report_tables(report) {
+        writer.table(&table);
+    }
+    writer.acceptance();
+    writer.finish(&report.hostname)
+}
+
+impl Writer {
+    fn layer(&self) -> &PdfLayerReference {
+        self.layers.last().expect("the document starts with a page")
+    }
+
+    fn text(&self, text: &str, size: f32, x: f32, bold: bool) {
+        let font = if bold {
+            &self.fonts.bold
+        } else {
+            &self.fonts.regular
+        };
+        self.layer()
+            .use_text(latin1(text), size, Mm(x), Mm(self.y), font);
+    }
+
+    fn new_page(&mut self) {
+        let (page, layer) = self
+            .doc
+            .add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "content");
+        self.layers.push(self.doc.get_page(page).get_layer(layer));
+        self.y = CONTENT_TOP;
+    }
+
+    /// Starts a new page unless `height` more millimetres fit on this one.
+    fn reserve(&mut self, height: f32) -> bool {
+        if self.y - height < CONTENT_BOTTOM {
+            self.new_page();
+            return true;
+        }
+        false
+    }
+
+    fn rule(&self, y: f32, color: Color) {
+        let layer = self.layer();
+        layer.set_outline_color(color);
+        layer.set_outline_thickness(0.4);
+        layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(MARGIN), Mm(y)), false),
+                (Point::new(Mm(PAGE_WIDTH - MARGIN), Mm(y)), false),
+            ],
+            is_closed: false,
+        });
+    }
+
+    fn table(&mut self, table: &ReportTable) {
+        let widths = column_widths(table);
+        let header: Vec<String> = table.headers.iter().map(|h| h.to_string()).collect();
+        // Keep the title with the header and at least one row
+        self.reserve(8.0 + 3.0 * (LINE_HEIGHT + 2.0 * CELL_PADDING));
+        self.layer().set_fill_color(brand());
+        self.text(table.title, 11.0, MARGIN, true);
+        self.layer().set_fill_color(grey(0.0));
+        self.y -= 3.0;
+        self.row(&header, &widths, true);
+        for row in &table.rows {
+            let height = row_height(row, &widths);
+            if self.reserve(height) {
+                self.row(&header, &widths, true);
+            }
+            self.row(row, &widths, false);
+        }
+        self.y -= 6.0;
+    }
+
+    fn row(&mut self, cells: &[String], widths: &[usize], header: bool) {
+        let height = row_height(cells, widths);
+        let layer = self.layer().clone();
+        if header {
+            layer.set_fill_color(grey(0.9));
+            layer.add_rect(
+                Rect::new(
+                    Mm(MARGIN),
+                    Mm(self.y - height),
+                    Mm(PAGE_WIDTH - MARGIN),
+                    Mm(self.y),
+                )
+                .with_mode(PaintMode::Fill),
+            );
+            layer.set_fill_color(grey(0.0));
+        }
+        let font = if header {
+            &self.fonts.mono_bold
+        } else {
+            &self.fonts.mono
+        };
+        let mut x = MARGIN;
+        for (cell, &width) in cells.iter().zip(widths) {
+            let mut line_y = self.y - CELL_PADDING - LINE_HEIGHT * 0.75;
+            for line in wrap(cell, width) {
+                layer.use_text(
+                    latin1(&line),
+                    CELL_FONT_SIZE,
+                    Mm(x + CELL_PADDING),
+                    Mm(line_y),
+                    font,
+                );
+                line_y -= LINE_HEIGHT;
+            }
+            x += column_mm(width);
+        }
+        self.y -= height;
+        self.rule(self.y, grey(0.75));
+    }
+
+    /// Signature lines for the party delivering and the party accepting the
+    /// machine.
+    fn acceptance(&mut self) {
+        self.reserve(45.0);
+        self.layer().set_fill_color(brand());
+        self.text(t("Acceptance"), 11.0, MARGIN, true);
+        self.layer().set_fill_color(grey(0.0));
+        self.y -= 14.0;
+        let half = (PAGE_WIDTH - 2.0 * MARGIN) / 2.0;
+        for label in [t("Delivered by"), t("Accepted by")] {
+            for (i, field) in [label, t("Date")].into_iter().enumerate() {
+                let x = MARGIN + i as f32 * half;
+                let layer = self.layer();
+                layer.set_outline_color(grey(0.3));
+                layer.set_outline_thickness(0.4);
+                layer.add_line(Line {
+                    points: vec![
+                        (Point::new(Mm(x), Mm(self.y)), false),
+                        (Point::new(Mm(x + half - 10.0), Mm(self.y)), false),
+                    ],
+                    is_closed: false,
+                });
+                self.y -= 4.0;
+                self.text(field, 8.0, x, false);
+                self.y += 4.0;
+            }
+            self.y -= 16.0;
+        }
+    }
+
+    /// Draws the header band and "page N of M" on every page, now that the
+    /// page count is known.
+    fn finish(self, hostname: &str) -> Result<Vec<u8>> {
+        let pages = self.layers.len();
+        for (i, layer) in self.layers.iter().enumerate() {
+            layer.set_fill_color(brand());
+            layer.add_rect(
+                Rect::new(
+                    Mm(0.0),
+                    Mm(PAGE_HEIGHT - 16.0),
+                    Mm(PAGE_WIDTH),
+                    Mm(PAGE_HEIGHT),
+                )
+                .with_mode(PaintMode::Fill),
+            );
+            layer.set_fill_color(grey(1.0));
+            layer.use_text(
+                "hw-checker",
+                14.0,
+                Mm(MARGIN),
+                Mm(PAGE_HEIGHT - 10.5),
+                &self.fonts.bold,
+            );
+            let host = latin1(hostname);
+            layer.use_text(
+                &host,
+                10.0,
+                Mm(PAGE_WIDTH - MARGIN - text_width(&host, 10.0)),
+                Mm(PAGE_HEIGHT - 10.5),
+                &self.fonts.regular,
+            );
+            layer.set_fill_color(grey(0.4));
+            let footer = latin1(&format!("{} {} / {}", t("Page"), i + 1, pages));
+            layer.use_text(
+                &footer,
+                8.0,
+                Mm(PAGE_WIDTH - MARGIN - text_width(&footer, 8.0)),
+                Mm(10.0),
+                &self.fonts.regular,
+            );
+        }
+        self.doc
+            .save_to_bytes()
+            .map_err(|e| anyhow!("failed to write PDF: {}", e))
+    }
+}
+
+fn column_mm(chars: usize) -> f32 {
+    chars as f32 * CHAR_WIDTH + 2.0 * CELL_PADDING
+}
+
+/// Rough width of Helvetica text, for right-aligning short strings.
+fn text_width(text: &str, size: f32) -> f32 {
+    text.chars().count() as f32 * size * 0.5 * 0.3528
+}
+
+/// Column widths in characters: each column's widest cell, with the widest
+/// columns narrowed until the table fits between the margins.
+fn column_widths(table: &ReportTable) -> Vec<usize> {
+    let mut widths: Vec<usize> = table
+        .headers
+        .iter()
+        .map(|h| h.chars().count().max(MIN_COLUMN_CHARS))
+        .collect();
+    for row in &table.rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let available = PAGE_WIDTH - 2.0 * MARGIN;
+    while widths.iter().map(|&w| column_mm(w)).sum::<f32>() > available {
+        let Some(widest) = widths.iter_mut().max() else {
+            break;
+        };
+        if *widest <= MIN_COLUMN_CHARS {
+            break;
+        }
+        *widest -= 1;
+    }
+    widths
+}
+
+fn row_height(cells: &[String], widths: &[usize]) -> f32 {
+    let lines = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, &width)| wrap(cell, width).len())
+        .max()
+        .unwrap_or(1);
+    lines as f32 * LINE_HEIGHT + 2.0 * CELL_PADDING
+}
+
+/// Greedy word wrap to `width` characters, splitting words that are longer
+/// than a whole line.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > width {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(word.drain(..width).collect());
+        }
+        let line_len = line.chars().count();
+        if !line.is_empty() && line_len + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut line));
+        } else if !line.is_empty() {
+            line.push(' ');
+        }
+        line.extend(word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// The base-14 fonts use WinAnsiEncoding; anything beyond Latin-1 would be
+/// dropped silently. Vietnamese letters lose their extra diacritics instead
+/// ("Bộ nhớ" prints as "Bo nho"), so `--lang vi` stays readable.
+fn latin1(text: &str) -> String {
+    text.chars()
+        .map(|c| match c as u32 {
+            0..=0xff => c,
+            0x102 | 0x103 => fold_case('a', c),
+            0x110 | 0x111 => fold_case('d', c),
+            0x128 | 0x129 => fold_case('i', c),
+            0x168 | 0x169 => fold_case('u', c),
+            0x1a0 | 0x1a1 => fold_case('o', c),
+            0x1af | 0x1b0 => fold_case('u', c),
+            // Latin Extended Additional: upper/lower pairs, grouped by vowel
+            code @ 0x1ea0..=0x1ef9 => {
+                let base = match code {
+                    0x1ea0..=0x1eb7 => 'a',
+                    0x1eb8..=0x1ec7 => 'e',
+                    0x1ec8..=0x1ecb => 'i',
+                    0x1ecc..=0x1ee3 => 'o',
+                    0x1ee4..=0x1ef1 => 'u',
+                    _ => 'y',
+                };
+                if code % 2 == 0 {
+                    base.to_ascii_uppercase()
+                } else {
+                    base
+                }
+            }
+            _ => '?',
+        })
+        .collect()
+}
+
+fn fold_case(base: char, c: char) -> char {
+    if c.is_uppercase() {
+        base.to_ascii_uppercase()
+    } else {
+        base
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_words_and_long_tokens() {
+        assert_eq!(wrap("", 10), vec![""]);
+        assert_eq!(
+            wrap("tsc (available: tsc, hpet)", 12),
+            vec!["tsc", "(available:", "tsc, hpet)"]
+        );
+        assert_eq!(
+            wrap("0123456789abcdef", 6),
+            vec!["012345", "6789ab", "cdef"]
+        );
+    }
+
+    #[test]
+    fn vietnamese_folds_to_latin1() {
+        assert_eq!(latin1("Bộ nhớ đã dùng"), "Bo nho dã dùng");
+        assert_eq!(latin1("Chi tiết hệ điều hành"), "Chi tiet he dieu hành");
+        assert_eq!(latin1("Ổ ĐĨA 25°C"), "O DIA 25°C");
+        assert_eq!(latin1("中"), "?");
+    }
+
+    #[test]
+    fn wide_tables_fit_the_page() {
+        let table = ReportTable {
+            title: "Kernel",
+            headers: vec!["Setting", "Value"],
+            rows: vec![vec!["Kernel command line".to_string(), "x".repeat(500)]],
+        };
+        let widths = column_widths(&table);
+        let total: f32 = widths.iter().map(|&w| column_mm(w)).sum();
+        assert!(total <= PAGE_WIDTH - 2.0 * MARGIN);
+        assert_eq!(widths[0], "Kernel command line".len());
+    }
+}
@@ -0,0 +1,258 @@
+//! Site-specific probes without a fork: executables declared under `plugins:`
+//! that print one JSON document on stdout. Each document lands in the
+//! report's `custom` section under the plugin's name, so industrial sensors
+//! or a vendor RAID CLI show up next to the built-in sections and in every
+//! export.
+//!
+//! `collectors:` are the lightweight version: a shell command whose stdout
+//! is attached as text (or parsed as JSON) under the collector's name, for a
+//! one-liner such as `ipmitool sdr | wc -l` that is not worth a plugin.
+//!
+//! A plugin or collector that fails, times out or prints something other
+//! than the expected JSON is left out and named under
+//! `diagnostics.plugin_errors`.
+
+use crate::config::ProbeTimeouts;
+use crate::model::HardwareReport;
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct PluginConfig {
+    /// Key under `custom` in the report
+    pub name: String,
+    /// Executed directly, not through a shell
+    pub command: PathBuf,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    /// Seconds before the plugin is killed; defaults to `probe_timeouts.default_secs`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+}
+
+/// One entry of the `collectors:` map, keyed by the name it is reported under.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct CollectorConfig {
+    /// Run by `sh -c` (`cmd /C` on Windows)
+    pub command: String,
+    #[serde(default)]
+    pub format: CollectorFormat,
+    /// Seconds before the command is killed; defaults to `probe_timeouts.default_secs`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CollectorFormat {
+    /// stdout as a string, trailing whitespace trimmed
+    #[default]
+    Raw,
+    Json,
+}
+
+/// Runs every plugin and then every collector in turn, filling `report.custom`.
+pub fn run_plugins(
+    plugins: &[PluginConfig],
+    collectors: &BTreeMap<String, CollectorConfig>,
+    timeouts: &ProbeTimeouts,
+    report: &mut HardwareReport,
+) {
+    for plugin in plugins {
+        let mut command = Command::new(&plugin.command);
+        command.args(&plugin.args);
+        let result = run_command(command, timeout(plugin.timeout_secs, timeouts))
+            .and_then(|stdout| parse_json(&stdout));
+        attach(report, &plugin.name, result);
+    }
+    for (name, collector) in collectors {
+        let result = run_command(
+            shell(&collector.command),
+            timeout(collector.timeout_secs, timeouts),
+        )
+        .and_then(|stdout| match collector.format {
+            CollectorFormat::Raw => Ok(Value::String(
+                String::from_utf8_lossy(&stdout).trim_end().to_string(),
+            )),
+            CollectorFormat::Json => parse_json(&stdout),
+        });
+        attach(report, name, result);
+    }
+}
+
+fn timeout(secs: Option<u64>, timeouts: &ProbeTimeouts) -> Option<Duration> {
+    match secs {
+        Some(0) => None,
+        Some(secs) => Some(Duration::from_secs(secs)),
+        None => timeouts.for_step("custom"),
+    }
+}
+
+fn parse_json(stdout: &[u8]) -> Result<Value> {
+    serde_json::from_slice(stdout).context("did not print a JSON document")
+}
+
+fn attach(report: &mut HardwareReport, name: &str, result: Result<Value>) {
+    match result {
+        Ok(value) => {
+            report.custom.insert(name.to_string(), value);
+        }
+        Err(err) => report
+            .diagnostics
+            .plugin_errors
+            .push(format!("{}: {:#}", name, err)),
+    }
+}
+
+fn shell(script: &str) -> Command {
+    let (program, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    let mut command = Command::new(program);
+    command.args([flag, script]);
+    command
+}
+
+/// Runs `command` with no stdin and returns its stdout, killing it once it
+/// outlasts `timeout`. A non-zero exit is an error carrying the first line
+/// of stderr.
+pub fn run_command(mut command: Command, timeout: Option<Duration>) -> Result<Vec<u8>> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("failed to run {}", command.get_program().to_string_lossy()))?;
+
+    // Drained on threads so a chatty command cannot fill a pipe and stall
+    let drain = |mut pipe: Box<dyn Read + Send>| {
+        std::thread::spawn(move || {
+            let mut out = Vec::new();
+            let _ = pipe.read_to_end(&mut out);
+            out
+        })
+    };
+    let stdout = drain(Box::new(child.stdout.take().expect("stdout is piped")));
+    let stderr = drain(Box::new(child.stderr.take().expect("stderr is piped")));
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if let Some(limit) = timeout
+            && started.elapsed() >= limit
+        {
+            let _ = child.kill();
+            let _ = child.wait();
+            bail!("timed out after {}", humantime::format_duration(limit));
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    if !status.success() {
+        let stderr = stderr.join().unwrap_or_default();
+        let stderr = String::from_utf8_lossy(&stderr);
+        match stderr.lines().map(str::trim).find(|line| !line.is_empty()) {
+            Some(line) => bail!("{}: {}", status, line),
+            None => bail!("{}", status),
+        }
+    }
+    Ok(stdout)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn plugin(name: &str, script: &str, timeout_secs: Option<u64>) -> PluginConfig {
+        PluginConfig {
+            name: name.to_string(),
+            command: PathBuf::from("sh"),
+            args: vec!["-c".to_string(), script.to_string()],
+            timeout_secs,
+        }
+    }
+
+    fn report() -> HardwareReport {
+        serde_json::from_str(
+            r#"{"os_name": "Debian GNU/Linux", "os_version": "12", "kernel_version": "6.1.0",
+                "hostname": "db-1", "uptime": 3600, "cpu_packages": [],
+                "ram": {"total": 0, "used": 0, "free": 0, "swap_total": 0, "swap_used": 0,
+                    "sticks": []},
+                "filesystems": [], "network": [], "usb": [], "pci": [], "motherboard": null,
+                "battery": []}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn plugins_fill_custom() {
+        let mut report = report();
+        let plugins = [
+            plugin(
+                "raid",
+                r#"echo '{"arrays": [{"name": "md0", "state": "clean"}]}'"#,
+                None,
+            ),
+            plugin("broken", "echo 'no such controller' >&2; exit 3", None),
+            plugin("text", "echo hello", None),
+            plugin("slow", "sleep 5", Some(1)),
+        ];
+        run_plugins(
+            &plugins,
+            &BTreeMap::new(),
+            &ProbeTimeouts::default(),
+            &mut report,
+        );
+
+        assert_eq!(report.custom["raid"]["arrays"][0]["state"], "clean");
+        assert_eq!(report.custom.len(), 1);
+        let errors = &report.diagnostics.plugin_errors;
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].starts_with("broken: exit status: 3: no such controller"));
+        assert!(errors[1].starts_with("text: did not print a JSON document"));
+        assert_eq!(errors[2], "slow: timed out after 1s");
+    }
+
+    #[test]
+    fn collectors_attach_text_or_json() {
+        let collector = |command: &str, format| CollectorConfig {
+            command: command.to_string(),
+            format,
+            timeout_secs: None,
+        };
+        let collectors = BTreeMap::from([
+            (
+                "entries".to_string(),
+                collector("echo 42 | tr -d x", CollectorFormat::Raw),
+            ),
+            (
+                "ports".to_string(),
+                collector("echo '[22, 443]'", CollectorFormat::Json),
+            ),
+            (
+                "bad".to_string(),
+                collector("echo nope", CollectorFormat::Json),
+            ),
+        ]);
+        let mut report = report();
+        run_plugins(&[], &collectors, &ProbeTimeouts::default(), &mut report);
+
+        assert_eq!(report.custom["entries"], "42");
+        assert_eq!(report.custom["ports"][1], 443);
+        assert!(!report.custom.contains_key("bad"));
+        assert!(report.diagnostics.plugin_errors[0].starts_with("bad: did not print"));
+    }
+}
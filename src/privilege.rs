@@ -0,0 +1,52 @@
+//! Root detection for the consolidated "run with sudo" hint and `--elevate`.
+
+use crate::i18n::t;
+use crate::model::Limitation;
+use anyhow::Result;
+
+/// Whether the process runs as root. Outside Unix nothing is known to need
+/// elevation, so this is always true there.
+pub fn is_elevated() -> bool {
+    #[cfg(unix)]
+    {
+        unsafe { libc::geteuid() == 0 }
+    }
+    #[cfg(not(unix))]
+    {
+        true
+    }
+}
+
+/// One line naming everything root would add, or `None` when nothing is missing.
+pub fn sudo_hint(limitations: &[Limitation]) -> Option<String> {
+    if limitations.is_empty() {
+        return None;
+    }
+    let missing: Vec<&str> = limitations.iter().map(|l| l.probe.as_str()).collect();
+    Some(format!(
+        "{} {}",
+        t("Run with sudo (or --elevate) to also get:"),
+        missing.join(", ")
+    ))
+}
+
+/// Replaces the process with the same command line run through sudo.
+pub fn elevate() -> Result<()> {
+    #[cfg(unix)]
+    {
+        use anyhow::Context;
+        use std::os::unix::process::CommandExt;
+
+        let exe = std::env::current_exe().context("failed to locate the hw-checker binary")?;
+        let err = std::process::Command::new("sudo")
+            .arg("--")
+            .arg(exe)
+            .args(std::env::args_os().skip(1))
+            .exec();
+        Err(err).context("failed to run sudo")
+    }
+    #[cfg(not(unix))]
+    {
+        anyhow::bail!("--elevate is only supported on Unix")
+    }
+}
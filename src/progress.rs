@@ -0,0 +1,67 @@
+//! A spinner on stderr naming the discovery step that is running and how long
+//! it has been at it. Full discovery with USB and SMART can take seconds with
+//! no other output, so it is shown whenever stdout and stderr are terminals.
+//! Every step's duration is kept for `--timings`.
+
+use crate::model::StepTiming;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::io::IsTerminal;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+static SPINNER: Mutex<Option<ProgressBar>> = Mutex::new(None);
+static TIMINGS: Mutex<Vec<StepTiming>> = Mutex::new(Vec::new());
+
+/// Shows the spinner until [`finish`]; a no-op when output is redirected.
+pub fn start() {
+    if let Ok(mut timings) = TIMINGS.lock() {
+        timings.clear();
+    }
+    if !std::io::stdout().is_terminal() || !std::io::stderr().is_terminal() {
+        return;
+    }
+    let spinner = ProgressBar::new_spinner().with_style(
+        ProgressStyle::with_template("{spinner:.cyan} {msg} ({elapsed})")
+            .expect("valid spinner template"),
+    );
+    spinner.enable_steady_tick(Duration::from_millis(100));
+    if let Ok(mut slot) = SPINNER.lock() {
+        *slot = Some(spinner);
+    }
+}
+
+/// Runs one discovery step, naming it on the spinner while it runs.
+pub fn step<T>(name: &str, probe: impl FnOnce() -> T) -> T {
+    if let Ok(slot) = SPINNER.lock()
+        && let Some(spinner) = slot.as_ref()
+    {
+        spinner.set_message(format!("{} {}", crate::i18n::t("Probing"), name));
+        spinner.reset_elapsed();
+    }
+    let started = Instant::now();
+    let value = probe();
+    if let Ok(mut timings) = TIMINGS.lock() {
+        timings.push(StepTiming {
+            step: name.to_string(),
+            ms: (started.elapsed().as_secs_f64() * 10_000.0).round() / 10.0,
+        });
+    }
+    value
+}
+
+/// The steps run since [`start`], in order.
+pub fn take_timings() -> Vec<StepTiming> {
+    TIMINGS
+        .lock()
+        .map(|mut timings| std::mem::take(&mut *timings))
+        .unwrap_or_default()
+}
+
+/// Clears the spinner before the report is printed.
+pub fn finish() {
+    if let Ok(mut slot) = SPINNER.lock()
+        && let Some(spinner) = slot.take()
+    {
+        spinner.finish_and_clear();
+    }
+}
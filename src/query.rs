@@ -0,0 +1,132 @@
+//! `hw-checker get <path>...`: single fields of the JSON report by dotted
+//! path (`ram.total`, `cpu_packages.0.model`, `disks.1.serial_number`), printed
+//! raw, one per line, for shell scripts that need one value and no jq.
+
+use crate::cli::GetArgs;
+use crate::config::{Config, Sections};
+use crate::daemon::fetch_report;
+use crate::discovery::get_hardware_report;
+use crate::mounts::filter_mounts;
+use crate::plugins::run_plugins;
+use crate::redact::redact_report;
+use anyhow::{Result, bail};
+use serde_json::Value;
+use std::path::Path;
+
+pub fn run_get(args: &GetArgs, connect: Option<&Path>, config: &Config) -> Result<()> {
+    let mut sections = Sections {
+        cpu: false,
+        ram: false,
+        storage: false,
+        network: false,
+        usb: false,
+        thunderbolt: false,
+        pci: false,
+        gpu: false,
+        limits: false,
+        time: false,
+        motherboard: false,
+        battery: false,
+        sensors: false,
+    };
+    for path in &args.paths {
+        enable_section(&mut sections, path);
+    }
+
+    let mut report = match connect {
+        Some(socket) => fetch_report(socket)?,
+        None => get_hardware_report(&sections, &config.probe_timeouts),
+    };
+    if connect.is_none()
+        && args
+            .paths
+            .iter()
+            .any(|path| path.split('.').next() == Some("custom"))
+    {
+        run_plugins(
+            &config.plugins,
+            &config.collectors,
+            &config.probe_timeouts,
+            &mut report,
+        );
+    }
+    filter_mounts(&mut report.filesystems, &config.mounts);
+    redact_report(&mut report, &config.redact);
+    report.asset = config.asset.clone();
+    let value = serde_json::to_value(&report)?;
+
+    for path in &args.paths {
+        match lookup(&value, path) {
+            None => bail!("no field `{}` in the report", path),
+            Some(Value::Null) => bail!("`{}` is not known on this machine", path),
+            Some(Value::String(s)) => println!("{}", s),
+            Some(value @ (Value::Object(_) | Value::Array(_))) => {
+                println!("{}", serde_json::to_string_pretty(value)?)
+            }
+            Some(scalar) => println!("{}", scalar),
+        }
+    }
+    Ok(())
+}
+
+/// Walks `path` through objects by key and arrays by index.
+pub fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |value, segment| match value {
+            Value::Object(fields) => fields.get(segment),
+            Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            _ => None,
+        })
+}
+
+/// Collects only what the path's top-level field needs; the system fields
+/// (hostname, os_*, uptime) are always there.
+fn enable_section(sections: &mut Sections, path: &str) {
+    match path.split('.').next().unwrap_or_default() {
+        "cpu" | "cpu_packages" | "cpu_load" => sections.cpu = true,
+        "ram" => sections.ram = true,
+        "container" | "pressure" => {
+            sections.cpu = true;
+            sections.ram = true;
+        }
+        "disks" | "filesystems" => sections.storage = true,
+        "network" | "network_config" => sections.network = true,
+        "usb" => sections.usb = true,
+        "thunderbolt" => sections.thunderbolt = true,
+        "pci" => sections.pci = true,
+        "gpus" => sections.gpu = true,
+        "limits" => sections.limits = true,
+        "time" => sections.time = true,
+        "motherboard" | "board_layout" => sections.motherboard = true,
+        "battery" | "platform" => sections.battery = true,
+        "temperatures" | "fans" => sections.sensors = true,
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn dotted_paths() {
+        let report = json!({
+            "hostname": "db-1",
+            "ram": {"total": 68719476736u64},
+            "cpu_packages": [{"model": "EPYC 7313", "cores": []}],
+            "motherboard": null
+        });
+        assert_eq!(lookup(&report, "hostname"), Some(&json!("db-1")));
+        assert_eq!(lookup(&report, "ram.total"), Some(&json!(68719476736u64)));
+        assert_eq!(
+            lookup(&report, "cpu_packages.0.model"),
+            Some(&json!("EPYC 7313"))
+        );
+        assert_eq!(lookup(&report, "motherboard"), Some(&Value::Null));
+        assert_eq!(lookup(&report, "cpu_packages.1.model"), None);
+        assert_eq!(lookup(&report, "cpu_packages.first"), None);
+        assert_eq!(lookup(&report, "hostname.length"), None);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::model::{HardwareReport, MetricsSample, NetworkInfo, PhysicalDisk};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum RedactMode {
+    /// Replace identifiers with a short hash, so the same disk or host can
+    /// still be recognised across reports
+    #[default]
+    Hash,
+    /// Drop identifiers entirely
+    Remove,
+}
+
+/// The `redact:` config section, also enabled by `--redact`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RedactConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub mode: RedactMode,
+    /// Mixed into every hash. Without it, short identifiers such as MAC
+    /// addresses can be recovered by hashing every candidate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salt: Option<String>,
+}
+
+impl RedactConfig {
+    pub fn redact(&self, value: &str) -> String {
+        match self.mode {
+            RedactMode::Hash => {
+                let mut hasher = Sha256::new();
+                hasher.update(self.salt.as_deref().unwrap_or_default());
+                hasher.update(value);
+                let digest = hasher.finalize();
+                let hex: String = digest[..6].iter().map(|b| format!("{:02x}", b)).collect();
+                format!("redacted-{}", hex)
+            }
+            RedactMode::Remove => "redacted".to_string(),
+        }
+    }
+
+    /// The hostname as every output should show it.
+    pub fn hostname(&self, hostname: &str) -> String {
+        if self.enabled && !hostname.is_empty() {
+            self.redact(hostname)
+        } else {
+            hostname.to_string()
+        }
+    }
+
+    /// An interface name, redacted when it spells out a MAC address as
+    /// systemd names USB adapters (`enx00e04c680123`).
+    fn interface(&self, name: &str) -> String {
+        let embeds_mac = name
+            .strip_prefix("enx")
+            .is_some_and(|mac| mac.len() == 12 && mac.chars().all(|c| c.is_ascii_hexdigit()));
+        if embeds_mac {
+            self.redact(name)
+        } else {
+            name.to_string()
+        }
+    }
+
+    fn redact_optional(&self, value: &mut Option<String>) {
+        *value = match (self.mode, value.as_deref()) {
+            (_, None) | (_, Some("")) => value.take(),
+            (RedactMode::Hash, Some(v)) => Some(self.redact(v)),
+            (RedactMode::Remove, Some(_)) => None,
+        };
+    }
+
+    /// A kernel command line with its filesystem and partition UUIDs (e.g.
+    /// `root=UUID=...`, `rd.luks.uuid=...`) redacted.
+    pub fn redact_cmdline(&self, cmdline: &str) -> String {
+        cmdline
+            .split_whitespace()
+            .map(|arg| {
+                let Some((key, value)) = arg.split_once('=') else {
+                    return arg.to_string();
+                };
+                if let Some((kind @ ("UUID" | "PARTUUID"), id)) = value.split_once('=') {
+                    format!("{}={}={}", key, kind, self.redact(id))
+                } else if key.to_ascii_lowercase().ends_with("uuid") {
+                    format!("{}={}", key, self.redact(value))
+                } else {
+                    arg.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+pub fn redact_disks(disks: &mut [PhysicalDisk], config: &RedactConfig) {
+    if !config.enabled {
+        return;
+    }
+    for disk in disks {
+        config.redact_optional(&mut disk.serial_number);
+    }
+}
+
+pub fn redact_network(networks: &mut [NetworkInfo], config: &RedactConfig) {
+    if !config.enabled {
+        return;
+    }
+    for net in networks {
+        net.mac_address = match config.mode {
+            RedactMode::Hash => config.redact(&net.mac_address),
+            RedactMode::Remove => String::new(),
+        };
+    }
+}
+
+/// Hashes or removes serial numbers, MAC addresses, the hostname and the
+/// UUIDs on the kernel command line.
+pub fn redact_report(report: &mut HardwareReport, config: &RedactConfig) {
+    if !config.enabled {
+        return;
+    }
+    report.hostname = config.hostname(&report.hostname);
+    if let Some(cmdline) = report
+        .os_details
+        .as_mut()
+        .and_then(|details| details.kernel_cmdline.as_mut())
+    {
+        *cmdline = config.redact_cmdline(cmdline);
+    }
+    if let Some(cloud) = &mut report.cloud {
+        config.redact_optional(&mut cloud.instance_id);
+    }
+    for stick in &mut report.ram.sticks {
+        config.redact_optional(&mut stick.serial_number);
+    }
+    redact_disks(&mut report.disks, config);
+    redact_network(&mut report.network, config);
+}
+
+/// Redacts the interface names of a metrics sample that embed a MAC address.
+pub fn redact_sample(sample: &mut MetricsSample, config: &RedactConfig) {
+    if !config.enabled {
+        return;
+    }
+    for net in &mut sample.network {
+        net.name = config.interface(&net.name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cmdline_uuids() {
+        let config = RedactConfig {
+            enabled: true,
+            mode: RedactMode::Remove,
+            salt: None,
+        };
+        assert_eq!(
+            config.redact_cmdline(
+                "BOOT_IMAGE=/vmlinuz root=UUID=0a1b-2c3d ro resume=PARTUUID=77aa rd.luks.uuid=luks-9f quiet"
+            ),
+            "BOOT_IMAGE=/vmlinuz root=UUID=redacted ro resume=PARTUUID=redacted rd.luks.uuid=redacted quiet"
+        );
+        assert_eq!(
+            config.redact_cmdline("root=/dev/sda2 ro"),
+            "root=/dev/sda2 ro"
+        );
+    }
+
+    #[test]
+    fn interface_names() {
+        let config = RedactConfig {
+            enabled: true,
+            mode: RedactMode::Remove,
+            salt: None,
+        };
+        assert_eq!(config.interface("enx00e04c680123"), "redacted");
+        assert_eq!(config.interface("enp3s0"), "enp3s0");
+        assert_eq!(config.interface("enxbad"), "enxbad");
+    }
+}
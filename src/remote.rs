@@ -0,0 +1,187 @@
+use crate::cli::{OutputFormat, RemoteArgs};
+use crate::config::Config;
+use crate::exporter::export_report;
+use crate::formatter::print_report;
+use crate::i18n::t;
+use crate::model::HardwareReport;
+use crate::redact::redact_report;
+use crate::theme::theme;
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Quotes `s` for the remote POSIX shell.
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+fn read_hosts(path: &Path) -> Result<Vec<String>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read hosts file {}", path.display()))?;
+    Ok(text
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Runs hw-checker on `host` over ssh and parses its JSON report. With `copy`,
+/// the binary is streamed over the same connection into a temp file and removed afterwards.
+fn collect(host: &str, args: &RemoteArgs, copy: Option<&Path>) -> Result<HardwareReport> {
+    if host.starts_with('-') {
+        bail!("invalid host '{}'", host);
+    }
+    let sudo = if args.sudo { "sudo -n " } else { "" };
+    let report_args = "--full --format json";
+    let remote_command = if copy.is_some() {
+        format!(
+            "f=$(mktemp) && cat > \"$f\" && chmod +x \"$f\" && {}\"$f\" {}; s=$?; rm -f \"$f\"; exit $s",
+            sudo, report_args
+        )
+    } else {
+        format!("{}{} {}", sudo, shell_quote(&args.remote_path), report_args)
+    };
+
+    let mut command = Command::new("ssh");
+    command.args(["-o", "BatchMode=yes", "-o", "ConnectTimeout=10"]);
+    for option in &args.ssh_option {
+        command.arg("-o").arg(option);
+    }
+    command.arg(host).arg("--").arg(remote_command);
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+    match copy {
+        Some(binary) => command.stdin(
+            std::fs::File::open(binary)
+                .with_context(|| format!("failed to open {}", binary.display()))?,
+        ),
+        None => command.stdin(Stdio::null()),
+    };
+
+    let output = command.output().context("failed to run ssh")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!(
+            "{} ({})",
+            stderr.lines().last().unwrap_or_default().trim(),
+            output.status
+        );
+    }
+    serde_json::from_slice(&output.stdout)
+        .context("the remote hw-checker did not return a JSON report")
+}
+
+pub fn run_remote(args: &RemoteArgs, format: OutputFormat, config: &Config) -> Result<()> {
+    let hosts = match (&args.host, &args.hosts) {
+        (_, Some(path)) => read_hosts(path)?,
+        (Some(host), None) => vec![host.clone()],
+        (None, None) => bail!("give a host or --hosts <FILE>"),
+    };
+    if hosts.is_empty() {
+        bail!("no hosts to collect from");
+    }
+    if hosts.len() > 1
+        && matches!(
+            format,
+            OutputFormat::Html
+                | OutputFormat::Kv
+                | OutputFormat::Env
+                | OutputFormat::ZabbixLld
+                | OutputFormat::Xml
+                | OutputFormat::LshwJson
+                | OutputFormat::Msgpack
+                | OutputFormat::Cbor
+                | OutputFormat::Pdf
+        )
+    {
+        bail!(
+            "this output format covers a single host; use --save-dir to keep one report per host"
+        );
+    }
+    let copy: Option<PathBuf> = match &args.copy {
+        Some(Some(path)) => Some(path.clone()),
+        Some(None) => Some(std::env::current_exe()?),
+        None => None,
+    };
+
+    // A small worker pool: each worker takes the next host until none are left.
+    let results: Vec<Mutex<Option<Result<HardwareReport>>>> =
+        hosts.iter().map(|_| Mutex::new(None)).collect();
+    let next = AtomicUsize::new(0);
+    std::thread::scope(|scope| {
+        for _ in 0..(args.parallel as usize).min(hosts.len()) {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::SeqCst);
+                    let Some(host) = hosts.get(i) else { break };
+                    let result = collect(host, args, copy.as_deref());
+                    if let Ok(mut slot) = results[i].lock() {
+                        *slot = Some(result);
+                    }
+                }
+            });
+        }
+    });
+
+    let mut reports: BTreeMap<String, HardwareReport> = BTreeMap::new();
+    let mut failed = 0;
+    for (host, result) in hosts.iter().zip(results) {
+        match result.into_inner().ok().flatten() {
+            Some(Ok(mut report)) => {
+                redact_report(&mut report, &config.redact);
+                if let Some(dir) = &args.save_dir {
+                    std::fs::create_dir_all(dir)?;
+                    let path = dir.join(format!("{}.json", host.replace(['/', '\\'], "_")));
+                    std::fs::write(&path, serde_json::to_string_pretty(&report)?)?;
+                }
+                reports.insert(host.clone(), report);
+            }
+            Some(Err(err)) => {
+                failed += 1;
+                eprintln!(
+                    "{} {}: {:#}",
+                    t("Failed to collect from").color(theme().critical),
+                    host,
+                    err
+                );
+            }
+            None => failed += 1,
+        }
+    }
+
+    match format {
+        OutputFormat::Table => {
+            for host in &hosts {
+                if let Some(report) = reports.get(host) {
+                    if hosts.len() > 1 {
+                        println!(
+                            "\n{}",
+                            format!("== {} ==", host).bold().color(theme().title)
+                        );
+                    }
+                    print_report(report, config);
+                }
+            }
+        }
+        OutputFormat::Json if hosts.len() > 1 => {
+            println!("{}", serde_json::to_string_pretty(&reports)?)
+        }
+        OutputFormat::Yaml if hosts.len() > 1 => print!("{}", serde_yaml::to_string(&reports)?),
+        _ => {
+            for host in &hosts {
+                if let Some(report) = reports.get(host) {
+                    export_report(report, format)?;
+                }
+            }
+        }
+    }
+
+    if failed > 0 {
+        bail!("{} of {} hosts failed", failed, hosts.len());
+    }
+    Ok(())
+}
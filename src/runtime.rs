@@ -0,0 +1,23 @@
+//! The tokio runtime behind the `async` feature. One current-thread runtime
+//! is shared by the MQTT publisher, webhook delivery, the daemon's socket and
+//! the `grafana` server, so integrations are tasks on it rather than a thread
+//! each. Blocking work (sampling, SQLite, ureq's HTTP posts) goes to its
+//! blocking pool.
+
+use std::sync::OnceLock;
+use tokio::runtime::{Builder, Runtime};
+
+// Blocking calls (ureq has no async API, nor has SQLite) run on this many pool threads at most.
+const MAX_BLOCKING_THREADS: usize = 8;
+
+pub fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        Builder::new_current_thread()
+            .enable_all()
+            .max_blocking_threads(MAX_BLOCKING_THREADS)
+            .thread_name("hw-checker-io")
+            .build()
+            .expect("failed to start the tokio runtime")
+    })
+}
@@ -0,0 +1,286 @@
+//! `hw-checker install-service`: turns the one-shot tool into ongoing
+//! monitoring. `check` runs from a systemd timer (or cron) every interval;
+//! `record` samples on its own, so it gets a long-running service (or an
+//! `@reboot` cron entry). Either runs this binary with the config, profile
+//! and `--set` overrides of the installing command line.
+
+use crate::cli::{InstallServiceArgs, ServiceCommand};
+use crate::i18n::t;
+use anyhow::{Context, Result, bail};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+/// One file to write, and where.
+#[derive(Debug)]
+pub struct ServiceFile {
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+pub fn run_install_service(
+    args: &InstallServiceArgs,
+    config_path: Option<&Path>,
+    profile: Option<&str>,
+    set: &[String],
+) -> Result<()> {
+    if !cfg!(unix) {
+        bail!(
+            "install-service writes systemd units or cron entries; on Windows use Task Scheduler"
+        );
+    }
+    if !args.cron && !cfg!(target_os = "linux") {
+        bail!("systemd units are Linux only; use --cron");
+    }
+    if args.command == ServiceCommand::Check && args.interval < Duration::from_secs(60) {
+        bail!("--interval must be at least 1m for check");
+    }
+
+    let exe = std::env::current_exe().context("failed to locate the hw-checker binary")?;
+    let exe = exe.canonicalize().unwrap_or(exe);
+    let mut command = vec![exe.to_string_lossy().into_owned()];
+    // The config in use now, made absolute, so the unit does not depend on
+    // its working directory or on the installing user's $HOME
+    if let Some(path) = config_path {
+        let path = std::path::absolute(path)
+            .with_context(|| format!("failed to resolve {}", path.display()))?;
+        command.extend(["--config".to_string(), path.to_string_lossy().into_owned()]);
+    }
+    if let Some(profile) = profile {
+        command.extend(["--profile".to_string(), profile.to_string()]);
+    }
+    for item in set {
+        command.extend(["--set".to_string(), item.clone()]);
+    }
+    match args.command {
+        ServiceCommand::Check => command.push("check".to_string()),
+        ServiceCommand::Record => command.extend([
+            "record".to_string(),
+            "--interval".to_string(),
+            humantime::format_duration(args.interval).to_string(),
+        ]),
+    }
+
+    let files = if args.cron {
+        vec![cron_file(args, &command)?]
+    } else {
+        systemd_files(args, &command)?
+    };
+
+    if args.dry_run {
+        for file in &files {
+            println!("# {}\n{}", file.path.display(), file.contents);
+        }
+        return Ok(());
+    }
+    for file in &files {
+        if let Some(dir) = file.path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        std::fs::write(&file.path, &file.contents).with_context(|| {
+            format!(
+                "failed to write {} (system units need root; try --user)",
+                file.path.display()
+            )
+        })?;
+        println!("{} {}", t("Wrote"), file.path.display());
+    }
+
+    if args.cron {
+        return Ok(());
+    }
+    let unit = match args.command {
+        ServiceCommand::Check => "hw-checker-check.timer",
+        ServiceCommand::Record => "hw-checker-record.service",
+    };
+    let systemctl = |extra: &[&str]| -> Result<()> {
+        let mut systemctl = Command::new("systemctl");
+        if args.user {
+            systemctl.arg("--user");
+        }
+        let status = systemctl
+            .args(extra)
+            .status()
+            .context("failed to run systemctl")?;
+        if !status.success() {
+            bail!("systemctl {} failed ({})", extra.join(" "), status);
+        }
+        Ok(())
+    };
+    if args.no_enable {
+        println!(
+            "{} systemctl{} daemon-reload && systemctl{} enable --now {}",
+            t("Enable with:"),
+            if args.user { " --user" } else { "" },
+            if args.user { " --user" } else { "" },
+            unit
+        );
+        return Ok(());
+    }
+    systemctl(&["daemon-reload"])?;
+    systemctl(&["enable", "--now", unit])?;
+    println!("{} {}", t("Enabled"), unit);
+    Ok(())
+}
+
+/// The service (and for `check`, the timer) for `command`.
+pub fn systemd_files(args: &InstallServiceArgs, command: &[String]) -> Result<Vec<ServiceFile>> {
+    let dir = if args.user {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .context("no $HOME for the user unit directory")?
+            .join("systemd/user")
+    } else {
+        PathBuf::from("/etc/systemd/system")
+    };
+    let exec = command
+        .iter()
+        .map(|arg| systemd_quote(arg))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let interval = humantime::format_duration(args.interval);
+    let wanted_by = if args.user {
+        "default.target"
+    } else {
+        "multi-user.target"
+    };
+
+    Ok(match args.command {
+        // Exits 1 on warnings and 2 on critical breaches, so the unit shows
+        // as failed in `systemctl --failed` until the next clean run
+        ServiceCommand::Check => vec![
+            ServiceFile {
+                path: dir.join("hw-checker-check.service"),
+                contents: format!(
+                    "[Unit]\nDescription=hw-checker threshold check\n\n[Service]\nType=oneshot\nExecStart={}\n",
+                    exec
+                ),
+            },
+            ServiceFile {
+                path: dir.join("hw-checker-check.timer"),
+                contents: format!(
+                    "[Unit]\nDescription=Run hw-checker check every {}\n\n[Timer]\nOnBootSec={}\nOnUnitActiveSec={}\n\n[Install]\nWantedBy=timers.target\n",
+                    interval, interval, interval
+                ),
+            },
+        ],
+        ServiceCommand::Record => vec![ServiceFile {
+            path: dir.join("hw-checker-record.service"),
+            contents: format!(
+                "[Unit]\nDescription=hw-checker metrics recorder\nAfter=network.target\n\n[Service]\nType=simple\nExecStart={}\nRestart=on-failure\nRestartSec=30\n\n[Install]\nWantedBy={}\n",
+                exec, wanted_by
+            ),
+        }],
+    })
+}
+
+/// `/etc/cron.d/hw-checker-<command>`, or the user's crontab line under
+/// `--user` (printed: a user crontab is only edited through `crontab`).
+pub fn cron_file(args: &InstallServiceArgs, command: &[String]) -> Result<ServiceFile> {
+    let schedule = match args.command {
+        ServiceCommand::Check => cron_schedule(args.interval)?,
+        ServiceCommand::Record => "@reboot".to_string(),
+    };
+    let line = command
+        .iter()
+        .map(|arg| shell_quote(arg))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let name = match args.command {
+        ServiceCommand::Check => "hw-checker-check",
+        ServiceCommand::Record => "hw-checker-record",
+    };
+    if args.user {
+        if !args.dry_run {
+            bail!("add user cron entries with `crontab -e`; see them with --dry-run");
+        }
+        return Ok(ServiceFile {
+            path: PathBuf::from("crontab -e"),
+            contents: format!("{} {}\n", schedule, line),
+        });
+    }
+    Ok(ServiceFile {
+        path: PathBuf::from("/etc/cron.d").join(name),
+        contents: format!(
+            "# Written by hw-checker install-service\n{} root {}\n",
+            schedule, line
+        ),
+    })
+}
+
+/// A cron schedule for `interval`: whole minutes dividing an hour, or whole
+/// hours dividing a day.
+pub fn cron_schedule(interval: Duration) -> Result<String> {
+    let secs = interval.as_secs();
+    let minutes = secs / 60;
+    if secs.is_multiple_of(60) && (1..60).contains(&minutes) && 60u64.is_multiple_of(minutes) {
+        return Ok(format!("*/{} * * * *", minutes));
+    }
+    let hours = secs / 3600;
+    if secs.is_multiple_of(3600) && (1..=24).contains(&hours) && 24u64.is_multiple_of(hours) {
+        return Ok(if hours == 24 {
+            "0 0 * * *".to_string()
+        } else {
+            format!("0 */{} * * *", hours)
+        });
+    }
+    bail!(
+        "cron cannot repeat every {}; use a divisor of an hour (e.g. 15m) or of a day (e.g. 6h), or a systemd timer",
+        humantime::format_duration(interval)
+    )
+}
+
+// systemd splits ExecStart= like a shell, but only understands quotes and
+// backslashes; `%` starts a specifier and `$` a variable
+fn systemd_quote(arg: &str) -> String {
+    let escaped = arg.replace('%', "%%").replace('$', "$$");
+    if !escaped.is_empty()
+        && !escaped.contains(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '\\' | ';'))
+    {
+        return escaped;
+    }
+    format!("\"{}\"", escaped.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn shell_quote(arg: &str) -> String {
+    // `%` is a newline in crontab lines
+    let arg = arg.replace('%', "\\%");
+    if !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:,@+\\%".contains(c))
+    {
+        return arg;
+    }
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cron_schedules() {
+        let minutes = |m: u64| Duration::from_secs(m * 60);
+        assert_eq!(cron_schedule(minutes(15)).unwrap(), "*/15 * * * *");
+        assert_eq!(cron_schedule(minutes(1)).unwrap(), "*/1 * * * *");
+        assert_eq!(cron_schedule(minutes(360)).unwrap(), "0 */6 * * *");
+        assert_eq!(cron_schedule(minutes(1440)).unwrap(), "0 0 * * *");
+        assert!(cron_schedule(minutes(7)).is_err());
+        assert!(cron_schedule(minutes(90)).is_err());
+    }
+
+    #[test]
+    fn exec_quoting() {
+        assert_eq!(systemd_quote("/usr/bin/hw-checker"), "/usr/bin/hw-checker");
+        assert_eq!(
+            systemd_quote("/srv/my configs/hw.yaml"),
+            "\"/srv/my configs/hw.yaml\""
+        );
+        assert_eq!(systemd_quote("alerts.email.to=a%b"), "alerts.email.to=a%%b");
+        assert_eq!(shell_quote("check"), "check");
+        assert_eq!(shell_quote("it's here"), "'it'\\''s here'");
+    }
+}
@@ -0,0 +1,177 @@
+use crate::cli::{OutputFormat, VerifyReportArgs};
+use crate::i18n::t;
+use crate::model::HardwareReport;
+use crate::theme::theme;
+use anyhow::{Context, Result, anyhow, bail};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use colored::Colorize;
+use ring::signature::{ED25519, Ed25519KeyPair, KeyPair, UnparsedPublicKey};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// Top-level key holding the signature in a signed JSON/YAML report.
+pub const SIGNATURE_FIELD: &str = "signature";
+const ALGORITHM: &str = "ed25519";
+// DER prefix of an ed25519 SubjectPublicKeyInfo; the raw 32-byte key follows.
+const SPKI_PREFIX: [u8; 12] = [
+    0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00,
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReportSignature {
+    pub algorithm: String,
+    /// Raw 32-byte public key, base64
+    pub public_key: String,
+    /// Signature over the canonical report, base64
+    pub value: String,
+}
+
+fn read_pem(path: &Path, label: &str) -> Result<Vec<u8>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let begin = format!("-----BEGIN {}-----", label);
+    let end = format!("-----END {}-----", label);
+    let body = text
+        .split_once(&begin)
+        .and_then(|(_, rest)| rest.split_once(&end))
+        .map(|(body, _)| body)
+        .ok_or_else(|| anyhow!("{} has no {} PEM block", path.display(), label))?;
+    let body: String = body.chars().filter(|c| !c.is_whitespace()).collect();
+    STANDARD
+        .decode(body)
+        .with_context(|| format!("invalid PEM in {}", path.display()))
+}
+
+/// The signed bytes: compact JSON of the report without its signature. Object
+/// keys are sorted, so JSON and YAML copies of a report sign the same bytes.
+fn canonical(report: &Value) -> Result<Vec<u8>> {
+    let mut report = report.clone();
+    if let Some(object) = report.as_object_mut() {
+        object.remove(SIGNATURE_FIELD);
+    }
+    Ok(serde_json::to_vec(&report)?)
+}
+
+/// Short SHA-256 fingerprint of a raw public key, for comparing keys by eye.
+pub fn fingerprint(public_key: &[u8]) -> String {
+    let digest = Sha256::digest(public_key);
+    digest[..16]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+pub struct ReportSigner {
+    key_pair: Ed25519KeyPair,
+}
+
+impl ReportSigner {
+    /// Loads a PKCS#8 ed25519 key, e.g. from `openssl genpkey -algorithm ed25519`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let der = read_pem(path, "PRIVATE KEY")?;
+        let key_pair = Ed25519KeyPair::from_pkcs8_maybe_unchecked(&der)
+            .map_err(|err| anyhow!("{} is not an ed25519 private key: {}", path.display(), err))?;
+        Ok(Self { key_pair })
+    }
+
+    /// Renders `report` as JSON or YAML with a `signature` field added at the top level.
+    pub fn render(&self, report: &HardwareReport, format: OutputFormat) -> Result<String> {
+        let mut value = serde_json::to_value(report)?;
+        let signature = ReportSignature {
+            algorithm: ALGORITHM.to_string(),
+            public_key: STANDARD.encode(self.key_pair.public_key().as_ref()),
+            value: STANDARD.encode(self.key_pair.sign(&canonical(&value)?).as_ref()),
+        };
+        if let Some(object) = value.as_object_mut() {
+            object.insert(
+                SIGNATURE_FIELD.to_string(),
+                serde_json::to_value(signature)?,
+            );
+        }
+        match format {
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(&value)?),
+            OutputFormat::Yaml => Ok(serde_yaml::to_string(&value)?),
+            _ => bail!("only json and yaml reports can be signed"),
+        }
+    }
+}
+
+fn load_public_key(path: &Path) -> Result<Vec<u8>> {
+    let der = read_pem(path, "PUBLIC KEY")?;
+    match der.strip_prefix(&SPKI_PREFIX[..]) {
+        Some(key) if key.len() == 32 => Ok(key.to_vec()),
+        _ => bail!("{} is not an ed25519 public key", path.display()),
+    }
+}
+
+/// Checks a signed report, returning the signer's public key. Fails if the
+/// report is unsigned, was modified, or (with `expected_key`) was signed by another key.
+pub fn verify_report(text: &str, expected_key: Option<&[u8]>) -> Result<Vec<u8>> {
+    // YAML is a superset of JSON, so one parser reads both formats.
+    let value: Value =
+        serde_yaml::from_str(text).context("the file is not a JSON or YAML report")?;
+    let signature = value
+        .get(SIGNATURE_FIELD)
+        .ok_or_else(|| anyhow!("the report is not signed"))?;
+    let signature: ReportSignature =
+        serde_json::from_value(signature.clone()).context("malformed signature field")?;
+    if signature.algorithm != ALGORITHM {
+        bail!("unsupported signature algorithm '{}'", signature.algorithm);
+    }
+    let public_key = STANDARD
+        .decode(&signature.public_key)
+        .context("malformed public key")?;
+    if let Some(expected) = expected_key
+        && expected != public_key.as_slice()
+    {
+        bail!(
+            "signed by {}, not by the expected key {}",
+            fingerprint(&public_key),
+            fingerprint(expected)
+        );
+    }
+    let signed = STANDARD
+        .decode(&signature.value)
+        .context("malformed signature")?;
+    UnparsedPublicKey::new(&ED25519, &public_key)
+        .verify(&canonical(&value)?, &signed)
+        .map_err(|_| anyhow!("the signature does not match: the report was modified"))?;
+    Ok(public_key)
+}
+
+pub fn run_verify_report(args: &VerifyReportArgs) -> Result<()> {
+    let text = std::fs::read_to_string(&args.file)
+        .with_context(|| format!("failed to read {}", args.file.display()))?;
+    let expected = args.key.as_deref().map(load_public_key).transpose()?;
+    match verify_report(&text, expected.as_deref()) {
+        Ok(public_key) => {
+            println!(
+                "{} ({} {})",
+                t("Signature valid").color(theme().ok).bold(),
+                t("key"),
+                fingerprint(&public_key)
+            );
+            if expected.is_none() {
+                println!(
+                    "{}",
+                    t(
+                        "The key was taken from the report itself; pass --key to require a known signer"
+                    )
+                );
+            }
+            Ok(())
+        }
+        Err(err) => {
+            println!(
+                "{}: {:#}",
+                t("Signature invalid").color(theme().critical).bold(),
+                err
+            );
+            std::process::exit(1);
+        }
+    }
+}
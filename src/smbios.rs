@@ -0,0 +1,705 @@
+//! SMBIOS structures smbios-lib only hands over raw: processor sockets
+//! (type 4), caches (7), port connectors (8), expansion slots (9) and the
+//! memory arrays and slots behind them (16 and 17). Fields
+//! are read by their offset in the DMTF spec (DSP0134), so the short
+//! structures of an old BIOS just leave the later fields empty.
+
+use crate::model::{
+    BoardLayout, ExpansionSlot, FirmwareCache, MemorySlot, MemorySlots, PortConnector,
+    ProcessorSocket,
+};
+use smbioslib::UndefinedStruct;
+use std::collections::HashMap;
+
+/// Decodes the structures of one SMBIOS table; caches are matched to the
+/// processor socket that links them.
+pub fn board_layout<'a>(structs: impl IntoIterator<Item = &'a UndefinedStruct>) -> BoardLayout {
+    let mut layout = BoardLayout::default();
+    let mut cache_owner: HashMap<u16, String> = HashMap::new();
+    let mut caches = Vec::new();
+    for s in structs {
+        match s.header.struct_type() {
+            4 => {
+                let socket = processor_socket(s);
+                for offset in [0x1A, 0x1C, 0x1E] {
+                    if let Some(handle) = s.get_field_handle(offset)
+                        && handle.0 != 0xFFFF
+                    {
+                        cache_owner.insert(handle.0, socket.designation.clone());
+                    }
+                }
+                layout.sockets.push(socket);
+            }
+            7 => caches.push((s.header.handle().0, cache(s))),
+            8 => layout.ports.push(port_connector(s)),
+            9 => layout.slots.push(expansion_slot(s)),
+            _ => {}
+        }
+    }
+    layout.caches = caches
+        .into_iter()
+        .map(|(handle, mut cache)| {
+            cache.socket = cache_owner.get(&handle).cloned();
+            cache
+        })
+        .collect();
+    layout
+}
+
+/// Memory slots of the system memory arrays (type 16 with use "System
+/// Memory"), leaving out flash, video and cache arrays. `None` when the
+/// firmware describes no system memory array.
+pub fn memory_slots<'a>(
+    structs: impl IntoIterator<Item = &'a UndefinedStruct>,
+) -> Option<MemorySlots> {
+    let mut arrays: HashMap<u16, Option<u64>> = HashMap::new();
+    let mut devices = Vec::new();
+    for s in structs {
+        match s.header.struct_type() {
+            16 if s.get_field_byte(0x05) == Some(0x03) => {
+                // KiB, or 0x8000_0000 for "see the 64-bit byte count"
+                let max = match s.get_field_dword(0x07) {
+                    Some(0x8000_0000) => s.get_field_qword(0x0F),
+                    Some(kib) => Some(u64::from(kib) * 1024),
+                    None => None,
+                };
+                arrays.insert(s.header.handle().0, max.filter(|&bytes| bytes > 0));
+            }
+            17 => devices.push(s),
+            _ => {}
+        }
+    }
+    if arrays.is_empty() {
+        return None;
+    }
+    let slots: Vec<MemorySlot> = devices
+        .into_iter()
+        .filter(|s| {
+            s.get_field_handle(0x04)
+                .is_some_and(|array| arrays.contains_key(&array.0))
+        })
+        .map(memory_slot)
+        .collect();
+    let max_capacity = arrays
+        .values()
+        .try_fold(0u64, |sum, max| Some(sum + (*max)?));
+    Some(MemorySlots {
+        total: slots.len(),
+        populated: slots.iter().filter(|s| s.size.is_some()).count(),
+        max_capacity,
+        max_speed: slots.iter().filter_map(|s| s.speed).max(),
+        slots,
+    })
+}
+
+fn memory_slot(s: &UndefinedStruct) -> MemorySlot {
+    // 0 is an empty slot, 0x7FFF "see the extended size" (MiB), and the top
+    // bit picks KiB over MiB units
+    let size = match s.get_field_word(0x0C).unwrap_or_default() {
+        0 | 0xFFFF => None,
+        0x7FFF => s
+            .get_field_dword(0x1C)
+            .map(|mib| u64::from(mib & 0x7FFF_FFFF) << 20),
+        kib if kib & 0x8000 != 0 => Some(u64::from(kib & 0x7FFF) << 10),
+        mib => Some(u64::from(mib) << 20),
+    };
+    // 0xFFFF: the speed is in the 32-bit field (SMBIOS 3.3)
+    let speed = |word, dword| match s.get_field_word(word)? {
+        0 => None,
+        0xFFFF => s.get_field_dword(dword).filter(|&mts| mts > 0),
+        mts => Some(u32::from(mts)),
+    };
+    let locator = text(s, 0x10).unwrap_or_default();
+    let bank = text(s, 0x11);
+    MemorySlot {
+        channel: channel(&locator, bank.as_deref()),
+        locator,
+        bank,
+        size,
+        memory_type: size
+            .and(s.get_field_byte(0x12))
+            .and_then(memory_type)
+            .map(String::from),
+        speed: size.and(speed(0x15, 0x54)),
+        configured_speed: size.and(speed(0x20, 0x58)),
+    }
+}
+
+/// The memory channel from a slot's locators, which vendors spell "P0
+/// CHANNEL A" (bank), "ChannelA-DIMM0" or "DIMM_A1" (device).
+fn channel(locator: &str, bank: Option<&str>) -> Option<String> {
+    for text in [bank.unwrap_or_default(), locator] {
+        let lower = text.to_ascii_lowercase();
+        if let Some(i) = lower.find("channel") {
+            let name: String = text[i + "channel".len()..]
+                .trim_start_matches([' ', '_', '-'])
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .collect();
+            if !name.is_empty() {
+                return Some(name.to_ascii_uppercase());
+            }
+        }
+    }
+    // "DIMM_A1", "CPU1_DIMM_B2", "DIMMA1", "A1": a letter, then the slot number
+    let last = locator.rsplit(['_', '-', ' ']).next()?;
+    let last = last.strip_prefix("DIMM").unwrap_or(last);
+    let mut chars = last.chars();
+    let letter = chars.next().filter(char::is_ascii_alphabetic)?;
+    let rest = chars.as_str();
+    (!rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit()))
+        .then(|| letter.to_ascii_uppercase().to_string())
+}
+
+fn processor_socket(s: &UndefinedStruct) -> ProcessorSocket {
+    let speed = |offset| s.get_field_word(offset).filter(|&mhz| mhz > 0);
+    // A byte count of 0xFF means "see the 16-bit field" (SMBIOS 3.0)
+    let count = |offset, wide| match s.get_field_byte(offset) {
+        Some(0xFF) => s.get_field_word(wide).filter(|&n| n > 0),
+        Some(0) | None => None,
+        Some(n) => Some(u16::from(n)),
+    };
+    ProcessorSocket {
+        designation: text(s, 0x04).unwrap_or_default(),
+        socket: s
+            .get_field_byte(0x19)
+            .and_then(socket_name)
+            .map(String::from),
+        populated: s
+            .get_field_byte(0x18)
+            .is_some_and(|status| status & 0x40 != 0),
+        manufacturer: text(s, 0x07),
+        version: text(s, 0x10),
+        max_speed: speed(0x14),
+        current_speed: speed(0x16),
+        cores: count(0x23, 0x2A),
+        threads: count(0x25, 0x2E),
+    }
+}
+
+fn cache(s: &UndefinedStruct) -> FirmwareCache {
+    let config = s.get_field_word(0x05).unwrap_or_default();
+    FirmwareCache {
+        designation: text(s, 0x04).unwrap_or_default(),
+        level: (config & 0x7) as u8 + 1,
+        enabled: config & 0x80 != 0,
+        kind: match s.get_field_byte(0x11) {
+            Some(3) => Some("Instruction".to_string()),
+            Some(4) => Some("Data".to_string()),
+            Some(5) => Some("Unified".to_string()),
+            _ => None,
+        },
+        size: cache_size(s, 0x09, 0x17),
+        max_size: cache_size(s, 0x07, 0x13),
+        associativity: s
+            .get_field_byte(0x12)
+            .and_then(associativity)
+            .map(String::from),
+        socket: None,
+    }
+}
+
+/// Bytes from a size word, or from the 32-bit field when the word is
+/// saturated (caches of 2 GiB and up). The top bit picks 64K over 1K units.
+fn cache_size(s: &UndefinedStruct, word: usize, dword: usize) -> Option<u64> {
+    let kib = match s.get_field_word(word)? {
+        0xFFFF => {
+            let size = s.get_field_dword(dword)?;
+            let granularity = if size & 0x8000_0000 != 0 { 64 } else { 1 };
+            u64::from(size & 0x7FFF_FFFF) * granularity
+        }
+        size => {
+            let granularity = if size & 0x8000 != 0 { 64 } else { 1 };
+            u64::from(size & 0x7FFF) * granularity
+        }
+    };
+    (kib > 0).then_some(kib * 1024)
+}
+
+fn port_connector(s: &UndefinedStruct) -> PortConnector {
+    let external = s.get_field_byte(0x07).unwrap_or_default();
+    let connector = if external != 0 {
+        external
+    } else {
+        s.get_field_byte(0x05).unwrap_or_default()
+    };
+    PortConnector {
+        internal: text(s, 0x04),
+        external: text(s, 0x06),
+        connector: connector_name(connector).map(String::from),
+        port_type: s
+            .get_field_byte(0x08)
+            .and_then(port_type_name)
+            .map(String::from),
+    }
+}
+
+fn expansion_slot(s: &UndefinedStruct) -> ExpansionSlot {
+    let (slot_type, generation, width) = slot_type(s.get_field_byte(0x05).unwrap_or_default());
+    // Bus and device/function 0xFF: the slot has no fixed address
+    let address = match (
+        s.get_field_word(0x0D),
+        s.get_field_byte(0x0F),
+        s.get_field_byte(0x10),
+    ) {
+        (Some(segment), Some(bus), Some(devfn)) if bus != 0xFF && devfn != 0xFF => Some(format!(
+            "{:04x}:{:02x}:{:02x}.{}",
+            if segment == 0xFFFF { 0 } else { segment },
+            bus,
+            devfn >> 3,
+            devfn & 0x7
+        )),
+        _ => None,
+    };
+    ExpansionSlot {
+        designation: text(s, 0x04).unwrap_or_default(),
+        slot_type,
+        generation,
+        width,
+        lanes: s.get_field_byte(0x06).and_then(lanes),
+        in_use: match s.get_field_byte(0x07) {
+            Some(3) => Some(false),
+            Some(4) => Some(true),
+            _ => None,
+        },
+        address,
+        device: None,
+        device_name: None,
+        link_generation: None,
+        link_width: None,
+    }
+}
+
+/// A string field, without the placeholders firmware leaves unfilled.
+fn text(s: &UndefinedStruct, offset: usize) -> Option<String> {
+    let value = s.get_field_string(offset).to_utf8_lossy()?;
+    let value = value.trim();
+    match value.to_lowercase().as_str() {
+        "" | "unknown" | "not specified" | "to be filled by o.e.m." | "default string" => None,
+        _ => Some(value.to_string()),
+    }
+}
+
+/// Slot type (offset 0x05): name, PCIe generation and physical width.
+fn slot_type(code: u8) -> (String, Option<u8>, Option<u8>) {
+    const PCIE: [(u8, u8); 5] = [(0xA5, 1), (0xAB, 2), (0xB1, 3), (0xB8, 4), (0xBE, 5)];
+    if let Some(&(first, generation)) = PCIE
+        .iter()
+        .find(|(first, _)| (*first..first + 6).contains(&code))
+    {
+        let width = [None, Some(1), Some(2), Some(4), Some(8), Some(16)][usize::from(code - first)];
+        return (
+            format!("PCI Express Gen {}", generation),
+            Some(generation),
+            width,
+        );
+    }
+    let (name, generation, width) = match code {
+        0x03 => ("ISA", None, None),
+        0x06 => ("PCI", None, None),
+        0x0E => ("PCI 66MHz", None, None),
+        0x0F => ("AGP", None, Some(1)),
+        0x10 => ("AGP", None, Some(2)),
+        0x11 => ("AGP", None, Some(4)),
+        0x12 => ("PCI-X", None, None),
+        0x13 => ("AGP", None, Some(8)),
+        0x14 => ("M.2 Socket 1-DP", None, None),
+        0x15 => ("M.2 Socket 1-SD", None, None),
+        0x16 => ("M.2 Socket 2", None, None),
+        0x17 => ("M.2 Socket 3", None, None),
+        0x18..=0x1E => ("MXM", None, None),
+        0x1F => ("U.2 (SFF-8639)", Some(2), Some(4)),
+        0x20 => ("U.2 (SFF-8639)", Some(3), Some(4)),
+        0x21..=0x23 => ("PCI Express Mini", None, Some(1)),
+        0x24 => ("U.2 (SFF-8639)", Some(4), Some(4)),
+        0x25 => ("U.2 (SFF-8639)", Some(5), Some(4)),
+        0x26..=0x28 => ("OCP NIC", None, None),
+        0x30 => ("CXL Flexbus", None, None),
+        0xC4 => ("PCI Express Gen 6", Some(6), None),
+        0xC5 | 0xC6 => ("EDSFF", None, None),
+        _ => ("Other", None, None),
+    };
+    (name.to_string(), generation, width)
+}
+
+/// Data bus width (offset 0x06) in PCIe lanes; the parallel bus widths
+/// (8 to 128 bit) have none.
+fn lanes(code: u8) -> Option<u8> {
+    match code {
+        0x08 => Some(1),
+        0x09 => Some(2),
+        0x0A => Some(4),
+        0x0B => Some(8),
+        0x0C => Some(12),
+        0x0D => Some(16),
+        0x0E => Some(32),
+        _ => None,
+    }
+}
+
+fn memory_type(code: u8) -> Option<&'static str> {
+    Some(match code {
+        0x0F => "SDRAM",
+        0x11 => "RDRAM",
+        0x12 => "DDR",
+        0x13 | 0x14 => "DDR2",
+        0x18 => "DDR3",
+        0x1A => "DDR4",
+        0x1B => "LPDDR",
+        0x1C => "LPDDR2",
+        0x1D => "LPDDR3",
+        0x1E => "LPDDR4",
+        0x20 => "HBM",
+        0x21 => "HBM2",
+        0x22 => "DDR5",
+        0x23 => "LPDDR5",
+        0x24 => "HBM3",
+        _ => return None,
+    })
+}
+
+fn associativity(code: u8) -> Option<&'static str> {
+    Some(match code {
+        0x03 => "Direct Mapped",
+        0x04 => "2-way",
+        0x05 => "4-way",
+        0x06 => "Fully Associative",
+        0x07 => "8-way",
+        0x08 => "16-way",
+        0x09 => "12-way",
+        0x0A => "24-way",
+        0x0B => "32-way",
+        0x0C => "48-way",
+        0x0D => "64-way",
+        0x0E => "20-way",
+        _ => return None,
+    })
+}
+
+/// Processor upgrade (offset 0x19), i.e. the socket the CPU sits in.
+fn socket_name(code: u8) -> Option<&'static str> {
+    const SOCKETS: [&str; 0x50] = [
+        "Other",
+        "Unknown",
+        "Daughter Board",
+        "ZIF Socket",
+        "Replaceable Piggy Back",
+        "None",
+        "LIF Socket",
+        "Slot 1",
+        "Slot 2",
+        "370-pin Socket",
+        "Slot A",
+        "Slot M",
+        "Socket 423",
+        "Socket A (462)",
+        "Socket 478",
+        "Socket 754",
+        "Socket 940",
+        "Socket 939",
+        "Socket mPGA604",
+        "LGA771",
+        "LGA775",
+        "Socket S1",
+        "AM2",
+        "Socket F (1207)",
+        "LGA1366",
+        "G34",
+        "AM3",
+        "C32",
+        "LGA1156",
+        "LGA1567",
+        "PGA988A",
+        "BGA1288",
+        "rPGA988B",
+        "BGA1023",
+        "BGA1224",
+        "LGA1155",
+        "LGA1356",
+        "LGA2011",
+        "FS1",
+        "FS2",
+        "FM1",
+        "FM2",
+        "LGA2011-3",
+        "LGA1356-3",
+        "LGA1150",
+        "BGA1168",
+        "BGA1234",
+        "BGA1364",
+        "AM4",
+        "LGA1151",
+        "BGA1356",
+        "BGA1440",
+        "BGA1515",
+        "LGA3647-1",
+        "SP3",
+        "SP3r2",
+        "LGA2066",
+        "BGA1392",
+        "BGA1510",
+        "BGA1528",
+        "LGA4189",
+        "LGA1200",
+        "LGA4677",
+        "LGA1700",
+        "BGA1744",
+        "BGA1781",
+        "BGA1211",
+        "BGA2422",
+        "LGA1211",
+        "LGA2422",
+        "LGA5773",
+        "BGA5773",
+        "AM5",
+        "SP5",
+        "SP6",
+        "BGA883",
+        "BGA1190",
+        "BGA4129",
+        "LGA4710",
+        "LGA7529",
+    ];
+    match code {
+        // "Other" and "Unknown" say nothing
+        0..=2 => None,
+        code => SOCKETS.get(usize::from(code) - 1).copied(),
+    }
+}
+
+fn connector_name(code: u8) -> Option<&'static str> {
+    Some(match code {
+        0x00 => return None,
+        0x01 | 0x02 | 0x1D | 0x1E => "Centronics",
+        0x04 | 0x05 => "DB-25",
+        0x06 | 0x07 => "DB-15",
+        0x08 | 0x09 => "DB-9",
+        0x0A => "RJ-11",
+        0x0B => "RJ-45",
+        0x0C => "Mini SCSI",
+        0x0D => "Mini DIN",
+        0x0E => "Micro DIN",
+        0x0F => "PS/2",
+        0x10 => "Infrared",
+        0x12 => "USB",
+        0x14 | 0x15 => "Circular DIN-8",
+        0x16 => "On Board IDE",
+        0x17 => "On Board Floppy",
+        0x18..=0x1B => "Dual Inline",
+        0x1C => "On Board Sound Input",
+        0x1F => "Mini-jack",
+        0x20 => "BNC",
+        0x21 => "IEEE 1394",
+        0x22 => "SAS/SATA",
+        0x23 => "USB-C",
+        _ => "Other",
+    })
+}
+
+fn port_type_name(code: u8) -> Option<&'static str> {
+    Some(match code {
+        0x00 => return None,
+        0x01..=0x05 => "Parallel",
+        0x06..=0x09 | 0xA0 | 0xA1 => "Serial",
+        0x0A | 0x0F | 0x17 | 0x18 => "SCSI",
+        0x0B => "MIDI",
+        0x0C => "Joystick",
+        0x0D => "Keyboard",
+        0x0E => "Mouse",
+        0x10 => "USB",
+        0x11 => "FireWire",
+        0x12..=0x15 => "PCMCIA",
+        0x1C => "Video",
+        0x1D => "Audio",
+        0x1E => "Modem",
+        0x1F => "Network",
+        0x20 => "SATA",
+        0x21 => "SAS",
+        0x22 => "MFDP",
+        0x23 => "Thunderbolt",
+        _ => "Other",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A raw structure: header, formatted fields, then the string-set.
+    fn raw(kind: u8, handle: u16, fields: &[u8], strings: &[&str]) -> UndefinedStruct {
+        let mut data = vec![kind, fields.len() as u8 + 4];
+        data.extend(handle.to_le_bytes());
+        data.extend(fields);
+        for s in strings {
+            data.extend(s.as_bytes());
+            data.push(0);
+        }
+        if strings.is_empty() {
+            data.push(0);
+        }
+        data.push(0);
+        UndefinedStruct::new(&data)
+    }
+
+    #[test]
+    fn processor_with_caches() {
+        let mut cpu = vec![0u8; 0x30 - 4];
+        let mut set = |offset: usize, bytes: &[u8]| {
+            cpu[offset - 4..offset - 4 + bytes.len()].copy_from_slice(bytes)
+        };
+        set(0x04, &[1]);
+        set(0x10, &[2]);
+        set(0x14, &5700u16.to_le_bytes());
+        set(0x16, &4500u16.to_le_bytes());
+        set(0x18, &[0x41]);
+        set(0x19, &[0x49]);
+        set(0x1A, &0x10u16.to_le_bytes());
+        set(0x1C, &0x11u16.to_le_bytes());
+        set(0x1E, &0xFFFFu16.to_le_bytes());
+        set(0x23, &[0xFF]);
+        set(0x25, &[32]);
+        set(0x2A, &16u16.to_le_bytes());
+        let cpu = raw(4, 0x20, &cpu, &["AM5", "AMD Ryzen 9 7950X"]);
+
+        // L2, 1 MiB in 1K units, unified, 8-way; L1 data, 32K
+        let mut l2 = vec![1, 0x81, 0x01];
+        l2.extend(1024u16.to_le_bytes());
+        l2.extend(1024u16.to_le_bytes());
+        l2.extend([0; 6]);
+        l2.extend([5, 7]);
+        let l2 = raw(7, 0x11, &l2, &["L2 - Cache"]);
+        let mut l1 = vec![1, 0x80, 0x01];
+        l1.extend(32u16.to_le_bytes());
+        l1.extend(32u16.to_le_bytes());
+        l1.extend([0; 6]);
+        l1.extend([4, 7]);
+        let l1 = raw(7, 0x10, &l1, &["L1 - Cache"]);
+
+        let layout = board_layout([&cpu, &l1, &l2]);
+        let socket = &layout.sockets[0];
+        assert_eq!(socket.designation, "AM5");
+        assert_eq!(socket.socket.as_deref(), Some("AM5"));
+        assert!(socket.populated);
+        assert_eq!(socket.version.as_deref(), Some("AMD Ryzen 9 7950X"));
+        assert_eq!(socket.manufacturer, None);
+        assert_eq!(
+            (socket.max_speed, socket.current_speed),
+            (Some(5700), Some(4500))
+        );
+        assert_eq!((socket.cores, socket.threads), (Some(16), Some(32)));
+
+        let l1 = &layout.caches[0];
+        assert_eq!((l1.level, l1.kind.as_deref()), (1, Some("Data")));
+        assert_eq!(l1.size, Some(32 * 1024));
+        assert_eq!(l1.socket.as_deref(), Some("AM5"));
+        let l2 = &layout.caches[1];
+        assert_eq!((l2.level, l2.kind.as_deref()), (2, Some("Unified")));
+        assert_eq!(l2.size, Some(1024 * 1024));
+        assert_eq!(l2.associativity.as_deref(), Some("8-way"));
+        assert!(l2.enabled);
+    }
+
+    #[test]
+    fn memory_population() {
+        // 128 GiB system array with two slots; a flash array is left out
+        let mut array = vec![3, 3, 3];
+        array.extend((128u32 << 20).to_le_bytes());
+        array.extend(0xFFFEu16.to_le_bytes());
+        array.extend(2u16.to_le_bytes());
+        let array = raw(16, 0x30, &array, &[]);
+        let flash = raw(16, 0x31, &[3, 9, 3, 0, 0, 0x10, 0, 0xFE, 0xFF, 1, 0], &[]);
+
+        let dimm = |array: u16, size: u16, speed: u16| {
+            let mut fields = vec![0u8; 0x28 - 4];
+            let mut set = |offset: usize, bytes: &[u8]| {
+                fields[offset - 4..offset - 4 + bytes.len()].copy_from_slice(bytes)
+            };
+            set(0x04, &array.to_le_bytes());
+            set(0x0C, &size.to_le_bytes());
+            set(0x10, &[1, 2]);
+            set(0x12, &[0x22]);
+            set(0x15, &speed.to_le_bytes());
+            set(0x20, &4800u16.to_le_bytes());
+            fields
+        };
+        let a1 = raw(
+            17,
+            0x40,
+            &dimm(0x30, 16384, 6000),
+            &["DIMM_A1", "P0 CHANNEL A"],
+        );
+        let b1 = raw(17, 0x41, &dimm(0x30, 0, 0), &["DIMM_B1", "P0 CHANNEL B"]);
+        let bios = raw(17, 0x42, &dimm(0x31, 16, 0), &["SPI"]);
+
+        let slots = memory_slots([&array, &flash, &a1, &b1, &bios]).unwrap();
+        assert_eq!((slots.total, slots.populated), (2, 1));
+        assert_eq!(slots.max_capacity, Some(128 << 30));
+        assert_eq!(slots.max_speed, Some(6000));
+        let a1 = &slots.slots[0];
+        assert_eq!(a1.size, Some(16 << 30));
+        assert_eq!(a1.memory_type.as_deref(), Some("DDR5"));
+        assert_eq!((a1.speed, a1.configured_speed), (Some(6000), Some(4800)));
+        assert_eq!(a1.channel.as_deref(), Some("A"));
+        let b1 = &slots.slots[1];
+        assert_eq!(
+            (b1.size, b1.speed, b1.memory_type.as_deref()),
+            (None, None, None)
+        );
+    }
+
+    #[test]
+    fn memory_channels() {
+        assert_eq!(
+            channel("ChannelA-DIMM0", Some("BANK 0")).as_deref(),
+            Some("A")
+        );
+        assert_eq!(
+            channel("DIMM 0", Some("P0 CHANNEL B")).as_deref(),
+            Some("B")
+        );
+        assert_eq!(channel("CPU1_DIMM_C2", None).as_deref(), Some("C"));
+        assert_eq!(channel("DIMMB1", None).as_deref(), Some("B"));
+        assert_eq!(channel("DIMM 0", Some("BANK 0")), None);
+    }
+
+    #[test]
+    fn ports_and_slots() {
+        let port = raw(8, 1, &[1, 0, 2, 0x23, 0x10], &["J1", "USB-C 1"]);
+        // PCIe Gen4 x16 with x4 wired, in use at 0000:00:01.1; then an M.2
+        // socket without an address
+        let gpu = raw(
+            9,
+            2,
+            &[1, 0xBD, 0x0A, 4, 3, 1, 0, 0, 0, 0, 0, 0x00, 0x09],
+            &["PCIEX16_2"],
+        );
+        let m2 = raw(
+            9,
+            3,
+            &[1, 0x17, 0x0A, 3, 3, 2, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF],
+            &["M2_1"],
+        );
+        let layout = board_layout([&port, &gpu, &m2]);
+
+        let port = &layout.ports[0];
+        assert_eq!(port.internal.as_deref(), Some("J1"));
+        assert_eq!(port.external.as_deref(), Some("USB-C 1"));
+        assert_eq!(port.connector.as_deref(), Some("USB-C"));
+        assert_eq!(port.port_type.as_deref(), Some("USB"));
+
+        let gpu = &layout.slots[0];
+        assert_eq!(gpu.slot_type, "PCI Express Gen 4");
+        assert_eq!(
+            (gpu.generation, gpu.width, gpu.lanes),
+            (Some(4), Some(16), Some(4))
+        );
+        assert_eq!(gpu.in_use, Some(true));
+        assert_eq!(gpu.address.as_deref(), Some("0000:00:01.1"));
+        let m2 = &layout.slots[1];
+        assert_eq!(
+            (m2.slot_type.as_str(), m2.in_use),
+            ("M.2 Socket 3", Some(false))
+        );
+        assert_eq!(m2.address, None);
+    }
+}
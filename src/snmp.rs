@@ -0,0 +1,573 @@
+use crate::cli::SnmpArgs;
+use crate::config::Config;
+use crate::discovery::MetricsSampler;
+use crate::i18n::t;
+use crate::model::MetricsSample;
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The `snmp:` config section for the AgentX subagent.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SnmpConfig {
+    /// AgentX master socket: a Unix socket path or `tcp:host:port`.
+    #[serde(default = "SnmpConfig::default_master")]
+    pub master: String,
+    /// Root of the HW-CHECKER-MIB subtree (defaults to the net-snmp playpen).
+    #[serde(default = "SnmpConfig::default_base_oid")]
+    pub base_oid: String,
+    #[serde(default = "SnmpConfig::default_refresh")]
+    pub refresh_secs: u64,
+}
+
+impl SnmpConfig {
+    fn default_master() -> String {
+        "/var/agentx/master".to_string()
+    }
+
+    fn default_base_oid() -> String {
+        "1.3.6.1.4.1.8072.9999.9999.7".to_string()
+    }
+
+    fn default_refresh() -> u64 {
+        10
+    }
+}
+
+impl Default for SnmpConfig {
+    fn default() -> Self {
+        Self {
+            master: Self::default_master(),
+            base_oid: Self::default_base_oid(),
+            refresh_secs: Self::default_refresh(),
+        }
+    }
+}
+
+type Oid = Vec<u32>;
+
+#[derive(Clone, Debug)]
+enum Value {
+    Integer(i32),
+    OctetString(String),
+    Gauge32(u32),
+}
+
+// AgentX (RFC 2741) PDU types and the bits of the protocol used here.
+const PDU_OPEN: u8 = 1;
+const PDU_CLOSE: u8 = 2;
+const PDU_REGISTER: u8 = 3;
+const PDU_GET: u8 = 5;
+const PDU_GET_NEXT: u8 = 6;
+const PDU_GET_BULK: u8 = 7;
+const PDU_TEST_SET: u8 = 8;
+const PDU_COMMIT_SET: u8 = 9;
+const PDU_UNDO_SET: u8 = 10;
+const PDU_RESPONSE: u8 = 18;
+
+const FLAG_NON_DEFAULT_CONTEXT: u8 = 0x08;
+const FLAG_NETWORK_BYTE_ORDER: u8 = 0x10;
+
+const TYPE_INTEGER: u16 = 2;
+const TYPE_OCTET_STRING: u16 = 4;
+const TYPE_GAUGE32: u16 = 66;
+const TYPE_NO_SUCH_OBJECT: u16 = 128;
+const TYPE_END_OF_MIB_VIEW: u16 = 130;
+
+const ERROR_NOT_WRITABLE: u16 = 17;
+
+fn parse_oid(text: &str) -> Result<Oid> {
+    text.trim_start_matches('.')
+        .split('.')
+        .map(|part| part.parse::<u32>())
+        .collect::<Result<Oid, _>>()
+        .with_context(|| format!("invalid OID '{}'", text))
+}
+
+fn to_mib(bytes: u64) -> u32 {
+    (bytes / 1024 / 1024).min(u32::MAX as u64) as u32
+}
+
+// Percentages and temperatures are exported in tenths, as SNMP has no floats.
+fn tenths(value: f64) -> i32 {
+    (value * 10.0).round() as i32
+}
+
+/// Lays the sample out as HW-CHECKER-MIB (see `mibs/HW-CHECKER-MIB.txt`), sorted by OID.
+fn build_table(base: &[u32], hostname: &str, sample: &MetricsSample) -> Vec<(Oid, Value)> {
+    let oid = |suffix: &[u32]| [base, suffix].concat();
+    let percent = |used: u64, total: u64| {
+        if total == 0 {
+            0
+        } else {
+            tenths(used as f64 / total as f64 * 100.0).max(0) as u32
+        }
+    };
+    let mut table = vec![
+        (oid(&[1, 1]), Value::OctetString(hostname.to_string())),
+        (
+            oid(&[1, 2]),
+            Value::Gauge32(tenths(sample.cpu_usage as f64).max(0) as u32),
+        ),
+        (oid(&[1, 3]), Value::Gauge32(to_mib(sample.ram_total))),
+        (oid(&[1, 4]), Value::Gauge32(to_mib(sample.ram_used))),
+        (oid(&[1, 5]), Value::Gauge32(to_mib(sample.swap_total))),
+        (oid(&[1, 6]), Value::Gauge32(to_mib(sample.swap_used))),
+    ];
+    for (i, disk) in sample.disks.iter().enumerate() {
+        let index = i as u32 + 1;
+        table.push((
+            oid(&[2, 1, 2, index]),
+            Value::OctetString(disk.mount_point.clone()),
+        ));
+        table.push((oid(&[2, 1, 3, index]), Value::Gauge32(to_mib(disk.total))));
+        table.push((oid(&[2, 1, 4, index]), Value::Gauge32(to_mib(disk.used))));
+        table.push((
+            oid(&[2, 1, 5, index]),
+            Value::Gauge32(percent(disk.used, disk.total)),
+        ));
+    }
+    for (i, temp) in sample.temperatures.iter().enumerate() {
+        let index = i as u32 + 1;
+        table.push((
+            oid(&[3, 1, 2, index]),
+            Value::OctetString(temp.label.clone()),
+        ));
+        table.push((
+            oid(&[3, 1, 3, index]),
+            Value::Integer(tenths(temp.celsius as f64)),
+        ));
+    }
+    table.sort_by(|a, b| a.0.cmp(&b.0));
+    table
+}
+
+#[derive(Default)]
+struct Encoder(Vec<u8>);
+
+impl Encoder {
+    fn u8(&mut self, v: u8) {
+        self.0.push(v);
+    }
+
+    fn u16(&mut self, v: u16) {
+        self.0.extend_from_slice(&v.to_be_bytes());
+    }
+
+    fn u32(&mut self, v: u32) {
+        self.0.extend_from_slice(&v.to_be_bytes());
+    }
+
+    fn oid(&mut self, oid: &[u32], include: bool) {
+        // 1.3.6.1.<n>.* is sent with `n` as the prefix byte.
+        let (prefix, rest) = match oid {
+            [1, 3, 6, 1, n, rest @ ..] if (1..=255).contains(n) => (*n as u8, rest),
+            _ => (0, oid),
+        };
+        self.u8(rest.len() as u8);
+        self.u8(prefix);
+        self.u8(include as u8);
+        self.u8(0);
+        for sub in rest {
+            self.u32(*sub);
+        }
+    }
+
+    fn octets(&mut self, data: &[u8]) {
+        self.u32(data.len() as u32);
+        self.0.extend_from_slice(data);
+        while !self.0.len().is_multiple_of(4) {
+            self.0.push(0);
+        }
+    }
+
+    fn varbind(&mut self, oid: &[u32], value: Option<&Value>, missing: u16) {
+        let kind = match value {
+            Some(Value::Integer(_)) => TYPE_INTEGER,
+            Some(Value::OctetString(_)) => TYPE_OCTET_STRING,
+            Some(Value::Gauge32(_)) => TYPE_GAUGE32,
+            None => missing,
+        };
+        self.u16(kind);
+        self.u16(0);
+        self.oid(oid, false);
+        match value {
+            Some(Value::Integer(v)) => self.u32(*v as u32),
+            Some(Value::OctetString(s)) => self.octets(s.as_bytes()),
+            Some(Value::Gauge32(v)) => self.u32(*v),
+            None => {}
+        }
+    }
+}
+
+struct Header {
+    kind: u8,
+    flags: u8,
+    session: u32,
+    transaction: u32,
+    packet: u32,
+}
+
+fn encode_pdu(kind: u8, session: u32, transaction: u32, packet: u32, payload: &[u8]) -> Vec<u8> {
+    let mut out = Encoder::default();
+    out.u8(1);
+    out.u8(kind);
+    out.u8(FLAG_NETWORK_BYTE_ORDER);
+    out.u8(0);
+    out.u32(session);
+    out.u32(transaction);
+    out.u32(packet);
+    out.u32(payload.len() as u32);
+    out.0.extend_from_slice(payload);
+    out.0
+}
+
+struct Decoder<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    big_endian: bool,
+}
+
+impl Decoder<'_> {
+    fn take(&mut self, n: usize) -> Result<&[u8]> {
+        let Some(bytes) = self.buf.get(self.pos..self.pos + n) else {
+            bail!("truncated AgentX PDU");
+        };
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        let bytes: [u8; 2] = self.take(2)?.try_into()?;
+        Ok(if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let bytes: [u8; 4] = self.take(4)?.try_into()?;
+        Ok(if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+
+    fn oid(&mut self) -> Result<(Oid, bool)> {
+        let n = self.u8()?;
+        let prefix = self.u8()?;
+        let include = self.u8()? != 0;
+        self.u8()?;
+        let mut oid = if prefix != 0 {
+            vec![1, 3, 6, 1, prefix as u32]
+        } else {
+            Vec::new()
+        };
+        for _ in 0..n {
+            oid.push(self.u32()?);
+        }
+        Ok((oid, include))
+    }
+
+    fn octets(&mut self) -> Result<Vec<u8>> {
+        let len = self.u32()? as usize;
+        let data = self.take(len)?.to_vec();
+        self.take((4 - len % 4) % 4)?;
+        Ok(data)
+    }
+
+    fn done(&self) -> bool {
+        self.pos >= self.buf.len()
+    }
+}
+
+enum Transport {
+    #[cfg(unix)]
+    Unix(std::os::unix::net::UnixStream),
+    Tcp(std::net::TcpStream),
+}
+
+impl Transport {
+    fn connect(master: &str) -> Result<Self> {
+        if let Some(addr) = master.strip_prefix("tcp:") {
+            return Ok(Transport::Tcp(std::net::TcpStream::connect(addr)?));
+        }
+        #[cfg(unix)]
+        return Ok(Transport::Unix(std::os::unix::net::UnixStream::connect(
+            master,
+        )?));
+        #[cfg(not(unix))]
+        bail!("AgentX over a Unix socket is not supported here; use tcp:host:port");
+    }
+
+    fn stream(&mut self) -> &mut dyn ReadWrite {
+        match self {
+            #[cfg(unix)]
+            Transport::Unix(s) => s,
+            Transport::Tcp(s) => s,
+        }
+    }
+}
+
+trait ReadWrite: Read + Write {}
+impl<T: Read + Write> ReadWrite for T {}
+
+struct Session {
+    transport: Transport,
+    id: u32,
+    next_packet: u32,
+}
+
+impl Session {
+    fn read_pdu(&mut self) -> Result<(Header, Vec<u8>)> {
+        let mut raw = [0u8; 20];
+        self.transport.stream().read_exact(&mut raw)?;
+        let mut header = Decoder {
+            buf: &raw,
+            pos: 0,
+            big_endian: raw[2] & FLAG_NETWORK_BYTE_ORDER != 0,
+        };
+        header.u8()?;
+        let kind = header.u8()?;
+        let flags = header.u8()?;
+        header.u8()?;
+        let session = header.u32()?;
+        let transaction = header.u32()?;
+        let packet = header.u32()?;
+        let len = header.u32()? as usize;
+        let mut payload = vec![0u8; len];
+        self.transport.stream().read_exact(&mut payload)?;
+        Ok((
+            Header {
+                kind,
+                flags,
+                session,
+                transaction,
+                packet,
+            },
+            payload,
+        ))
+    }
+
+    fn send(&mut self, bytes: &[u8]) -> Result<()> {
+        self.transport.stream().write_all(bytes)?;
+        Ok(())
+    }
+
+    // Sends an administrative PDU and waits for the master's response, returning its session id.
+    fn request(&mut self, kind: u8, payload: &[u8]) -> Result<u32> {
+        self.next_packet += 1;
+        let pdu = encode_pdu(kind, self.id, 0, self.next_packet, payload);
+        self.send(&pdu)?;
+        loop {
+            let (header, body) = self.read_pdu()?;
+            if header.kind != PDU_RESPONSE || header.packet != self.next_packet {
+                continue;
+            }
+            let mut response = Decoder {
+                buf: &body,
+                pos: 0,
+                big_endian: header.flags & FLAG_NETWORK_BYTE_ORDER != 0,
+            };
+            response.u32()?;
+            let error = response.u16()?;
+            if error != 0 {
+                bail!("AgentX master rejected the request (error {})", error);
+            }
+            return Ok(header.session);
+        }
+    }
+}
+
+fn respond(header: &Header, error: u16, index: u16, varbinds: &Encoder) -> Vec<u8> {
+    let mut payload = Encoder::default();
+    payload.u32(0);
+    payload.u16(error);
+    payload.u16(index);
+    payload.0.extend_from_slice(&varbinds.0);
+    encode_pdu(
+        PDU_RESPONSE,
+        header.session,
+        header.transaction,
+        header.packet,
+        &payload.0,
+    )
+}
+
+// First entry after `start` (or at it, when `include` is set) and before `end`, if any.
+fn next_entry<'a>(
+    table: &'a [(Oid, Value)],
+    start: &[u32],
+    include: bool,
+    end: &[u32],
+) -> Option<&'a (Oid, Value)> {
+    table
+        .iter()
+        .find(|(oid, _)| {
+            if include {
+                oid.as_slice() >= start
+            } else {
+                oid.as_slice() > start
+            }
+        })
+        .filter(|(oid, _)| end.is_empty() || oid.as_slice() < end)
+}
+
+fn handle(header: &Header, payload: &[u8], table: &[(Oid, Value)]) -> Result<Option<Vec<u8>>> {
+    let mut request = Decoder {
+        buf: payload,
+        pos: 0,
+        big_endian: header.flags & FLAG_NETWORK_BYTE_ORDER != 0,
+    };
+    if header.flags & FLAG_NON_DEFAULT_CONTEXT != 0 {
+        request.octets()?;
+    }
+    let mut varbinds = Encoder::default();
+    match header.kind {
+        PDU_GET => {
+            while !request.done() {
+                let (oid, _) = request.oid()?;
+                request.oid()?;
+                let value = table.iter().find(|(o, _)| *o == oid).map(|(_, v)| v);
+                varbinds.varbind(&oid, value, TYPE_NO_SUCH_OBJECT);
+            }
+        }
+        PDU_GET_NEXT => {
+            while !request.done() {
+                let (start, include) = request.oid()?;
+                let (end, _) = request.oid()?;
+                match next_entry(table, &start, include, &end) {
+                    Some((oid, value)) => varbinds.varbind(oid, Some(value), 0),
+                    None => varbinds.varbind(&start, None, TYPE_END_OF_MIB_VIEW),
+                }
+            }
+        }
+        PDU_GET_BULK => {
+            let non_repeaters = request.u16()? as usize;
+            let max_repetitions = request.u16()? as usize;
+            let mut ranges = Vec::new();
+            while !request.done() {
+                let (start, include) = request.oid()?;
+                let (end, _) = request.oid()?;
+                ranges.push((start, include, end));
+            }
+            let split = non_repeaters.min(ranges.len());
+            let (singles, repeaters) = ranges.split_at_mut(split);
+            for (start, include, end) in singles.iter() {
+                match next_entry(table, start, *include, end) {
+                    Some((oid, value)) => varbinds.varbind(oid, Some(value), 0),
+                    None => varbinds.varbind(start, None, TYPE_END_OF_MIB_VIEW),
+                }
+            }
+            for _ in 0..max_repetitions {
+                if repeaters.is_empty() {
+                    break;
+                }
+                for (start, include, end) in repeaters.iter_mut() {
+                    match next_entry(table, start, *include, end) {
+                        Some((oid, value)) => {
+                            varbinds.varbind(oid, Some(value), 0);
+                            *start = oid.clone();
+                            *include = false;
+                        }
+                        None => varbinds.varbind(start, None, TYPE_END_OF_MIB_VIEW),
+                    }
+                }
+            }
+        }
+        // Everything we export is read-only.
+        PDU_TEST_SET => return Ok(Some(respond(header, ERROR_NOT_WRITABLE, 1, &varbinds))),
+        PDU_COMMIT_SET | PDU_UNDO_SET => return Ok(Some(respond(header, 0, 0, &varbinds))),
+        PDU_CLOSE => bail!("AgentX master closed the session"),
+        _ => return Ok(None),
+    }
+    Ok(Some(respond(header, 0, 0, &varbinds)))
+}
+
+fn serve(master: &str, base: &[u32], table: &Mutex<Vec<(Oid, Value)>>) -> Result<()> {
+    let transport = Transport::connect(master)
+        .with_context(|| format!("failed to connect to AgentX master at {}", master))?;
+    let mut session = Session {
+        transport,
+        id: 0,
+        next_packet: 0,
+    };
+
+    let mut open = Encoder::default();
+    open.u32(0); // default timeout + reserved
+    open.oid(&[], false);
+    open.octets(b"hw-checker hardware metrics");
+    session.id = session.request(PDU_OPEN, &open.0)?;
+
+    let mut register = Encoder::default();
+    register.u8(0); // default timeout
+    register.u8(127); // default priority
+    register.u8(0); // no range
+    register.u8(0);
+    register.oid(base, false);
+    session.request(PDU_REGISTER, &register.0)?;
+    eprintln!(
+        "{} {} ({})",
+        t("Serving SNMP subtree"),
+        base.iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .join("."),
+        master
+    );
+
+    loop {
+        let (header, payload) = session.read_pdu()?;
+        let snapshot = table.lock().map(|t| t.clone()).unwrap_or_default();
+        if let Some(response) = handle(&header, &payload, &snapshot)? {
+            session.send(&response)?;
+        }
+    }
+}
+
+pub fn run_snmp(args: &SnmpArgs, config: &Config) -> Result<()> {
+    let settings = &config.snmp;
+    let master = args
+        .master
+        .clone()
+        .unwrap_or_else(|| settings.master.clone());
+    let base = parse_oid(&settings.base_oid)?;
+    let refresh = Duration::from_secs(settings.refresh_secs.max(1));
+
+    let table = Arc::new(Mutex::new(Vec::new()));
+    {
+        let table = Arc::clone(&table);
+        let base = base.clone();
+        let hostname = config
+            .redact
+            .hostname(&sysinfo::System::host_name().unwrap_or_default());
+        std::thread::spawn(move || {
+            let mut sampler = MetricsSampler::new();
+            std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+            loop {
+                let rows = build_table(&base, &hostname, &sampler.sample());
+                if let Ok(mut table) = table.lock() {
+                    *table = rows;
+                }
+                std::thread::sleep(refresh);
+            }
+        });
+    }
+
+    // Keep serving across snmpd restarts.
+    loop {
+        if let Err(err) = serve(&master, &base, &table) {
+            eprintln!("AgentX: {:#}", err);
+        }
+        std::thread::sleep(Duration::from_secs(5));
+    }
+}
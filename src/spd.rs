@@ -0,0 +1,292 @@
+//! Serial presence detect: the EEPROM on every DDR4/DDR5 module, as the
+//! kernel's `ee1004` and `spd5118` drivers expose it. Gives the JEDEC timings,
+//! overclocking profiles and the DRAM die vendor that SMBIOS leaves out.
+//! Offsets follow JESD21-C (DDR4) and JESD400-5 (DDR5).
+
+use crate::jedec;
+use crate::model::{SpdInfo, SpdProfile, SpdTimings};
+
+const DDR4: u8 = 0x0C;
+const DDR5: u8 = 0x12;
+
+/// Decodes the EEPROM of i2c device `device` (e.g. "0-0050"); `None` for
+/// anything but a DDR4 or DDR5 module.
+pub fn decode(device: &str, eeprom: &[u8]) -> Option<SpdInfo> {
+    match *eeprom.get(2)? {
+        DDR4 if eeprom.len() >= 384 => Some(ddr4(device, eeprom)),
+        DDR5 if eeprom.len() >= 640 => Some(ddr5(device, eeprom)),
+        _ => None,
+    }
+}
+
+fn ddr4(device: &str, b: &[u8]) -> SpdInfo {
+    // Medium and fine timebase: 125 ps and 1 ps, the only ones defined
+    let time = |mtb: usize, ftb: usize| i64::from(b[mtb]) * 125 + i64::from(b[ftb] as i8);
+    let tck = time(18, 125);
+    let tras = (i64::from(b[27] & 0x0F) << 8 | i64::from(b[28])) * 125;
+    let timings = timings(tck, time(24, 123), time(25, 122), time(26, 121), tras);
+
+    let die_mbit = 256u64 << (b[4] & 0x0F);
+    let device_width = 4u64 << (b[12] & 0x07);
+    let ranks = u64::from((b[12] >> 3) & 0x07) + 1;
+    let bus_width = 8u64 << (b[13] & 0x07);
+    // 3DS stacks count each die
+    let dies = if b[6] & 0x03 == 0x02 {
+        u64::from((b[6] >> 4) & 0x07) + 1
+    } else {
+        1
+    };
+    let size = (die_mbit << 20) / 8 * (bus_width / device_width) * ranks * dies;
+
+    SpdInfo {
+        device: device.to_string(),
+        memory_type: "DDR4".to_string(),
+        module_type: module_type(b[3]),
+        size: Some(size),
+        manufacturer: b
+            .get(320..322)
+            .and_then(|id| jedec::manufacturer(id[0], id[1]))
+            .map(String::from),
+        dram_manufacturer: b
+            .get(350..352)
+            .and_then(|id| jedec::manufacturer(id[0], id[1]))
+            .map(String::from),
+        part_number: b.get(329..349).and_then(ascii),
+        speed: speed(tck),
+        timings,
+        profiles: xmp2(b),
+    }
+}
+
+/// Intel XMP 2.0: up to two profiles at 393 and 440, each with the timings in
+/// the same medium/fine timebase split as the base SPD.
+fn xmp2(b: &[u8]) -> Vec<SpdProfile> {
+    if b.get(384..386) != Some(&[0x0C, 0x4A]) {
+        return Vec::new();
+    }
+    let enabled = b[386];
+    [393, 440]
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| enabled & (1 << i) != 0)
+        .filter_map(|(i, base)| {
+            let p = b.get(base..base + 47)?;
+            let time = |mtb: usize, ftb: usize| i64::from(p[mtb]) * 125 + i64::from(p[ftb] as i8);
+            let tck = time(3, 38);
+            let tras = (i64::from(p[11] & 0x0F) << 8 | i64::from(p[12])) * 125;
+            Some(SpdProfile {
+                name: format!("XMP {}", i + 1),
+                speed: speed(tck),
+                timings: timings(tck, time(8, 37), time(9, 36), time(10, 35), tras),
+                // Bit 7 is whole volts, the rest hundredths
+                voltage: Some(f32::from(p[0] >> 7) + f32::from(p[0] & 0x7F) / 100.0),
+            })
+        })
+        .collect()
+}
+
+fn ddr5(device: &str, b: &[u8]) -> SpdInfo {
+    // Timings are plain picoseconds
+    let ps = |offset: usize| i64::from(u16::from_le_bytes([b[offset], b[offset + 1]]));
+    let tck = ps(20);
+    let timings = timings(tck, ps(30), ps(32), ps(34), ps(36));
+
+    let die_gbit = match b[4] & 0x1F {
+        1 => 4,
+        2 => 8,
+        3 => 12,
+        4 => 16,
+        5 => 24,
+        6 => 32,
+        7 => 48,
+        8 => 64,
+        _ => 0,
+    };
+    let device_width = 4u64 << ((b[6] >> 5) & 0x03);
+    let ranks = u64::from((b[234] >> 3) & 0x07) + 1;
+    let bus_width = 8u64 << (b[235] & 0x07);
+    let channels = u64::from((b[235] >> 5) & 0x03) + 1;
+    let size = (die_gbit > 0)
+        .then(|| (die_gbit << 30) / 8 * channels * (bus_width / device_width) * ranks);
+
+    let mut profiles = Vec::new();
+    // XMP 3.0 and AMD EXPO blocks; their profile layouts are vendor
+    // documents, so only their presence is reported
+    if b.get(640..642) == Some(&[0x0C, 0x4A]) {
+        profiles.push(SpdProfile::named("XMP 3.0"));
+    }
+    if b.get(832..836) == Some(b"EXPO") {
+        profiles.push(SpdProfile::named("EXPO"));
+    }
+
+    SpdInfo {
+        device: device.to_string(),
+        memory_type: "DDR5".to_string(),
+        module_type: module_type(b[3]),
+        size,
+        manufacturer: b
+            .get(512..514)
+            .and_then(|id| jedec::manufacturer(id[0], id[1]))
+            .map(String::from),
+        dram_manufacturer: b
+            .get(552..554)
+            .and_then(|id| jedec::manufacturer(id[0], id[1]))
+            .map(String::from),
+        part_number: b.get(521..551).and_then(ascii),
+        speed: speed(tck),
+        timings,
+        profiles,
+    }
+}
+
+/// Timings in clocks at cycle time `tck`, each rounded up the way JEDEC does
+/// (with a 2.5% guard band against timebase rounding).
+fn timings(tck: i64, taa: i64, trcd: i64, trp: i64, tras: i64) -> Option<SpdTimings> {
+    if tck <= 0 {
+        return None;
+    }
+    let clocks = |t: i64| ((t as f64 / tck as f64) - 0.025).ceil().max(0.0) as u32;
+    Some(SpdTimings {
+        cl: clocks(taa),
+        trcd: clocks(trcd),
+        trp: clocks(trp),
+        tras: clocks(tras),
+    })
+}
+
+/// Transfer rate in MT/s from the cycle time, snapped to the nearest
+/// standard rate: cycle times are stored rounded, so DDR4-2133 reads as 2134.
+fn speed(tck: i64) -> Option<u32> {
+    const RATES: [u32; 27] = [
+        1600, 1866, 2133, 2400, 2666, 2933, 3000, 3200, 3466, 3600, 3733, 3866, 4000, 4266, 4400,
+        4800, 5200, 5600, 6000, 6400, 6800, 7200, 7600, 8000, 8400, 8800, 9200,
+    ];
+    if tck <= 0 {
+        return None;
+    }
+    let rate = 2_000_000.0 / tck as f64;
+    let nearest = RATES.iter().copied().min_by(|a, b| {
+        (f64::from(*a) - rate)
+            .abs()
+            .total_cmp(&(f64::from(*b) - rate).abs())
+    })?;
+    Some(if (f64::from(nearest) - rate).abs() <= rate * 0.005 {
+        nearest
+    } else {
+        rate.round() as u32
+    })
+}
+
+fn module_type(byte: u8) -> Option<String> {
+    let name = match byte & 0x0F {
+        0x01 => "RDIMM",
+        0x02 => "UDIMM",
+        0x03 => "SO-DIMM",
+        0x04 => "LRDIMM",
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+/// A space-padded ASCII field.
+fn ascii(bytes: &[u8]) -> Option<String> {
+    let text: String = bytes
+        .iter()
+        .filter(|b| b.is_ascii_graphic() || **b == b' ')
+        .map(|&b| char::from(b))
+        .collect();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ddr4_module() {
+        let mut b = vec![0u8; 512];
+        b[2] = DDR4;
+        b[3] = 0x02;
+        // 8 Gb x8 dies, 2 ranks, 64-bit: 16 GiB
+        b[4] = 0x45;
+        b[12] = 0x09;
+        b[13] = 0x03;
+        // DDR4-3200 22-22-22-52: tCK 0.625 ns, tAA/tRCD/tRP 13.75 ns, tRAS 32 ns
+        b[18] = 5;
+        for (mtb, ftb) in [(24, 123), (25, 122), (26, 121)] {
+            b[mtb] = 110;
+            b[ftb] = 0;
+        }
+        b[27] = 0x01;
+        b[28] = 0x00;
+        b[320..322].copy_from_slice(&[0x80, 0xCE]);
+        b[350..352].copy_from_slice(&[0x80, 0xAD]);
+        b[329..349].copy_from_slice(b"M378A2G43AB3-CWE    ");
+        // XMP profile 1: DDR4-3600 16-19-19-39 at 1.35 V
+        b[384..388].copy_from_slice(&[0x0C, 0x4A, 0x01, 0x20]);
+        b[393] = 0x80 | 35;
+        b[396] = 5;
+        b[393 + 38] = -69i8 as u8;
+        b[401] = 71;
+        b[393 + 37] = 0;
+        b[402] = 84;
+        b[403] = 84;
+        b[393 + 11] = 0x00;
+        b[393 + 12] = 173;
+
+        let spd = decode("0-0050", &b).unwrap();
+        assert_eq!(spd.memory_type, "DDR4");
+        assert_eq!(spd.module_type.as_deref(), Some("UDIMM"));
+        assert_eq!(spd.size, Some(16 << 30));
+        assert_eq!(spd.manufacturer.as_deref(), Some("Samsung"));
+        assert_eq!(spd.dram_manufacturer.as_deref(), Some("SK Hynix"));
+        assert_eq!(spd.part_number.as_deref(), Some("M378A2G43AB3-CWE"));
+        assert_eq!(spd.speed, Some(3200));
+        let t = spd.timings.unwrap();
+        assert_eq!((t.cl, t.trcd, t.trp, t.tras), (22, 22, 22, 52));
+
+        let xmp = &spd.profiles[0];
+        assert_eq!(xmp.name, "XMP 1");
+        assert_eq!(xmp.speed, Some(3600));
+        let t = xmp.timings.as_ref().unwrap();
+        assert_eq!((t.cl, t.trcd, t.trp, t.tras), (16, 19, 19, 39));
+        assert_eq!(xmp.voltage, Some(1.35));
+    }
+
+    #[test]
+    fn ddr5_module() {
+        let mut b = vec![0u8; 1024];
+        b[2] = DDR5;
+        b[3] = 0x03;
+        // 16 Gb x8 dies, 1 rank, 2 x 32-bit sub-channels: 16 GiB
+        b[4] = 0x04;
+        b[6] = 0x20;
+        b[234] = 0x00;
+        b[235] = 0x22;
+        // DDR5-4800 40-39-39: tCK 416 ps
+        b[20..22].copy_from_slice(&416u16.to_le_bytes());
+        b[30..32].copy_from_slice(&16_640u16.to_le_bytes());
+        b[32..34].copy_from_slice(&16_000u16.to_le_bytes());
+        b[34..36].copy_from_slice(&16_000u16.to_le_bytes());
+        b[36..38].copy_from_slice(&32_000u16.to_le_bytes());
+        b[512..514].copy_from_slice(&[0x80, 0x2C]);
+        b[832..836].copy_from_slice(b"EXPO");
+
+        let spd = decode("1-0051", &b).unwrap();
+        assert_eq!(spd.module_type.as_deref(), Some("SO-DIMM"));
+        assert_eq!(spd.size, Some(16 << 30));
+        assert_eq!(spd.manufacturer.as_deref(), Some("Micron Technology"));
+        assert_eq!(spd.speed, Some(4800));
+        let t = spd.timings.unwrap();
+        assert_eq!((t.cl, t.trcd, t.trp, t.tras), (40, 39, 39, 77));
+        assert_eq!(spd.profiles[0].name, "EXPO");
+    }
+
+    #[test]
+    fn other_memory_is_skipped() {
+        let mut b = vec![0u8; 256];
+        b[2] = 0x0B;
+        assert!(decode("0-0050", &b).is_none());
+    }
+}
@@ -0,0 +1,241 @@
+use crate::cli::StressArgs;
+use crate::config::Config;
+use crate::discovery::{get_temperatures, read_throttle_count};
+use crate::i18n::t;
+use crate::theme::theme;
+use anyhow::Result;
+use colored::Colorize;
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::{Cell, Color, Table};
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use sysinfo::{CpuRefreshKind, RefreshKind, System};
+
+const PROGRESS_EVERY: Duration = Duration::from_secs(5);
+const MEMORY_BUFFER: usize = 64 * 1024 * 1024;
+
+fn cpu_worker(stop: &AtomicBool) {
+    let mut x = 1.0f64;
+    while !stop.load(Ordering::Relaxed) {
+        for _ in 0..100_000 {
+            x = black_box(x.mul_add(1.000_000_1, 1e-9).sqrt() + 1.0);
+        }
+    }
+}
+
+// Copies between two buffers larger than any cache, counting bytes read + written.
+fn memory_worker(stop: &AtomicBool, bytes: &AtomicU64) {
+    let mut src = vec![1u8; MEMORY_BUFFER];
+    let mut dst = vec![0u8; MEMORY_BUFFER];
+    while !stop.load(Ordering::Relaxed) {
+        dst.copy_from_slice(black_box(&src));
+        std::mem::swap(&mut src, &mut dst);
+        bytes.fetch_add(2 * MEMORY_BUFFER as u64, Ordering::Relaxed);
+    }
+}
+
+struct SensorPeak {
+    max: f32,
+    limit: Option<f32>,
+}
+
+pub fn run_stress(args: &StressArgs, config: &Config) -> Result<()> {
+    let mut sys =
+        System::new_with_specifics(RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()));
+    let threads = args
+        .threads
+        .map(usize::from)
+        .unwrap_or(sys.cpus().len().max(1));
+    let cpu_load = args.cpu || !args.memory;
+    let cpu_workers = if cpu_load { threads } else { 0 };
+    // A few threads already saturate memory bandwidth.
+    let memory_workers = if args.memory { (threads / 2).max(1) } else { 0 };
+
+    println!(
+        "{} {} ({} CPU, {} {}); {}",
+        t("Stress test for").bold().color(theme().title),
+        humantime::format_duration(args.duration),
+        cpu_workers,
+        memory_workers,
+        t("memory"),
+        t("press Ctrl-C to abort")
+    );
+
+    let throttle_before = read_throttle_count();
+    let stop = Arc::new(AtomicBool::new(false));
+    let copied = Arc::new(AtomicU64::new(0));
+    let mut handles = Vec::new();
+    for _ in 0..cpu_workers {
+        let stop = Arc::clone(&stop);
+        handles.push(std::thread::spawn(move || cpu_worker(&stop)));
+    }
+    for _ in 0..memory_workers {
+        let stop = Arc::clone(&stop);
+        let copied = Arc::clone(&copied);
+        handles.push(std::thread::spawn(move || memory_worker(&stop, &copied)));
+    }
+
+    let started = Instant::now();
+    let mut peaks: BTreeMap<String, SensorPeak> = BTreeMap::new();
+    let mut peak_freq = 0u64;
+    let mut lowest_freq = u64::MAX;
+    let mut last_progress = Instant::now();
+    println!(
+        "{:>8}  {:>6}  {:>9}  {:>8}",
+        t("TIME"),
+        "CPU",
+        "MHz",
+        t("TEMP")
+    );
+    while started.elapsed() < args.duration {
+        std::thread::sleep(
+            Duration::from_secs(1).min(args.duration.saturating_sub(started.elapsed())),
+        );
+        sys.refresh_cpu_all();
+
+        let freqs: Vec<u64> = sys.cpus().iter().map(|c| c.frequency()).collect();
+        let avg_freq = freqs.iter().sum::<u64>() / freqs.len().max(1) as u64;
+        if avg_freq > 0 {
+            peak_freq = peak_freq.max(avg_freq);
+            // The first seconds ramp up from idle clocks, so droop is only tracked afterwards.
+            if started.elapsed() > Duration::from_secs(3) {
+                lowest_freq = lowest_freq.min(avg_freq);
+            }
+        }
+
+        let mut hottest: Option<f32> = None;
+        for sensor in get_temperatures() {
+            let Some(temp) = sensor.temperature else {
+                continue;
+            };
+            hottest = Some(hottest.map_or(temp, |h: f32| h.max(temp)));
+            let peak = peaks.entry(sensor.label.clone()).or_insert(SensorPeak {
+                max: temp,
+                limit: sensor.critical,
+            });
+            peak.max = peak.max.max(temp);
+        }
+
+        if last_progress.elapsed() >= PROGRESS_EVERY || started.elapsed() >= args.duration {
+            last_progress = Instant::now();
+            println!(
+                "{:>8}  {:>5.1}%  {:>9}  {:>8}",
+                humantime::format_duration(Duration::from_secs(started.elapsed().as_secs()))
+                    .to_string(),
+                sys.global_cpu_usage(),
+                avg_freq,
+                hottest
+                    .map(|h| format!("{:.1}°C", h))
+                    .unwrap_or_else(|| "N/A".to_string())
+            );
+        }
+    }
+    stop.store(true, Ordering::Relaxed);
+    for handle in handles {
+        let _ = handle.join();
+    }
+    let elapsed = started.elapsed();
+    let throttle_events = match (throttle_before, read_throttle_count()) {
+        (Some(before), Some(after)) => Some(after.saturating_sub(before)),
+        _ => None,
+    };
+
+    // A sensor hits its limit at its own critical point, or the configured critical threshold.
+    let configured = config.temperature_thresholds.critical;
+    let mut limits_hit: Vec<String> = peaks
+        .iter()
+        .filter(|(_, p)| p.max >= p.limit.unwrap_or(configured))
+        .map(|(label, p)| format!("{} {:.1}°C", label, p.max))
+        .collect();
+    if let Some(events) = throttle_events
+        && events > 0
+    {
+        limits_hit.push(format!("{} {}", events, t("throttling events")));
+    }
+
+    println!("\n{}", t("Stress Test Results").bold().color(theme().title));
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .apply_modifier(UTF8_ROUND_CORNERS)
+        .set_header(vec![t("Metric"), t("Value")]);
+    table.add_row(vec![
+        t("Duration").to_string(),
+        humantime::format_duration(Duration::from_secs(elapsed.as_secs())).to_string(),
+    ]);
+    if let Some((label, peak)) = peaks.iter().max_by(|a, b| a.1.max.total_cmp(&b.1.max)) {
+        table.add_row(vec![
+            t("Max temperature").to_string(),
+            format!("{:.1}°C ({})", peak.max, label),
+        ]);
+    }
+    if peak_freq > 0 && lowest_freq != u64::MAX {
+        table.add_row(vec![
+            t("Peak frequency").to_string(),
+            format!("{} MHz", peak_freq),
+        ]);
+        table.add_row(vec![
+            t("Lowest frequency").to_string(),
+            format!("{} MHz", lowest_freq),
+        ]);
+        table.add_row(vec![
+            t("Frequency droop").to_string(),
+            format!(
+                "{:.1}%",
+                (peak_freq - lowest_freq.min(peak_freq)) as f64 / peak_freq as f64 * 100.0
+            ),
+        ]);
+    }
+    table.add_row(vec![
+        t("Throttling events").to_string(),
+        throttle_events
+            .map(|e| e.to_string())
+            .unwrap_or_else(|| "N/A".to_string()),
+    ]);
+    if memory_workers > 0 {
+        let gb_per_sec = copied.load(Ordering::Relaxed) as f64 / elapsed.as_secs_f64() / 1e9;
+        table.add_row(vec![
+            t("Memory bandwidth").to_string(),
+            format!("{:.2} GB/s", gb_per_sec),
+        ]);
+    }
+    println!("{table}");
+
+    if !peaks.is_empty() {
+        let mut sensors = Table::new();
+        sensors
+            .load_preset(UTF8_FULL)
+            .apply_modifier(UTF8_ROUND_CORNERS)
+            .set_header(vec![t("Sensor"), t("Max"), t("Critical")]);
+        for (label, peak) in &peaks {
+            let limit = peak.limit.unwrap_or(configured);
+            let color = if peak.max >= limit {
+                theme().critical
+            } else {
+                theme().level(peak.max, &config.temperature_thresholds)
+            };
+            sensors.add_row(vec![
+                Cell::new(label),
+                Cell::new(format!("{:.1}°C", peak.max)).fg(Color::from(color)),
+                Cell::new(format!("{:.1}°C", limit)),
+            ]);
+        }
+        println!("{sensors}");
+    }
+
+    if limits_hit.is_empty() {
+        println!("{}", t("No thermal limits hit").color(theme().ok).bold());
+        Ok(())
+    } else {
+        println!(
+            "{}: {}",
+            t("Thermal limits hit").color(theme().critical).bold(),
+            limits_hit.join(", ")
+        );
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,199 @@
+//! `--format summary`: a neofetch-style overview that fits on one screen,
+//! one aligned `Label: value` line per host fact, CPU package, GPU and disk,
+//! optionally next to an ASCII logo of the OS (`--logo`).
+
+use crate::formatter::format_uptime;
+use crate::i18n::t;
+use crate::model::HardwareReport;
+use crate::theme::theme;
+use colored::Colorize;
+use std::fmt::Write as _;
+
+pub fn render_summary(report: &HardwareReport, logo: bool) -> String {
+    let mut lines: Vec<(&str, String)> = Vec::new();
+    let os = format!("{} {}", report.os_name, report.os_version);
+    match &report.os_details {
+        Some(details) => lines.push((t("OS"), format!("{} {}", os, details.architecture))),
+        None => lines.push((t("OS"), os)),
+    }
+    lines.push((t("Kernel"), report.kernel_version.clone()));
+    lines.push((t("Uptime"), format_uptime(report.uptime)));
+    if let Some(board) = &report.motherboard {
+        lines.push((
+            t("Motherboard"),
+            format!("{} {}", board.vendor, board.product),
+        ));
+    }
+    for package in &report.cpu {
+        let mut cpu = format!(
+            "{} ({}C/{}T)",
+            package.model.trim(),
+            package.physical_cores,
+            package.logical_cores
+        );
+        if let Some(mhz) = package.cores.iter().map(|c| c.frequency).max()
+            && mhz > 0
+        {
+            let _ = write!(cpu, " @ {:.2} GHz", mhz as f64 / 1000.0);
+        }
+        lines.push(("CPU", cpu));
+    }
+    for gpu in &report.gpus {
+        lines.push(("GPU", gpu.name.clone()));
+    }
+    let ram = &report.ram;
+    if ram.total > 0 {
+        lines.push((t("Memory"), usage(ram.used, ram.total)));
+    }
+    if ram.swap_total > 0 {
+        lines.push((t("Swap"), usage(ram.swap_used, ram.swap_total)));
+    }
+    for disk in &report.disks {
+        let mut line = format!("{} {:.1} GiB", disk.name, gib(disk.size));
+        if let Some(model) = &disk.model_name {
+            let _ = write!(line, " {}", model.trim());
+        }
+        if let Some(kind) = &disk.disk_type {
+            let _ = write!(line, " ({})", kind);
+        }
+        lines.push((t("Disk"), line));
+    }
+    for battery in &report.battery {
+        lines.push((
+            t("Battery"),
+            format!("{}% {}", battery.capacity, battery.status),
+        ));
+    }
+
+    let width = lines
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut text = vec![
+        report.hostname.bold().color(theme().title).to_string(),
+        "-".repeat(report.hostname.chars().count().max(1)),
+    ];
+    for (label, value) in lines {
+        let padding = " ".repeat(width - label.chars().count());
+        text.push(format!(
+            "{}:{} {}",
+            label.bold().color(theme().accent),
+            padding,
+            value
+        ));
+    }
+
+    let art: &[&str] = if logo { os_logo(report) } else { &[] };
+    let art_width = art.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let mut out = String::new();
+    for i in 0..text.len().max(art.len()) {
+        if !art.is_empty() {
+            let line = art.get(i).copied().unwrap_or_default();
+            let padding = " ".repeat(art_width - line.chars().count());
+            let _ = write!(out, "{}{}   ", line.color(theme().accent), padding);
+        }
+        let _ = writeln!(
+            out,
+            "{}",
+            text.get(i).map(String::as_str).unwrap_or_default()
+        );
+    }
+    out.truncate(out.trim_end().len());
+    out
+}
+
+fn usage(used: u64, total: u64) -> String {
+    format!(
+        "{:.1} GiB / {:.1} GiB ({:.0}%)",
+        gib(used),
+        gib(total),
+        used as f64 * 100.0 / total as f64
+    )
+}
+
+fn gib(bytes: u64) -> f64 {
+    bytes as f64 / 1024.0 / 1024.0 / 1024.0
+}
+
+/// Picked from the OS name; Tux for any Linux without its own logo.
+fn os_logo(report: &HardwareReport) -> &'static [&'static str] {
+    let name = report.os_name.to_lowercase();
+    if name.contains("debian") {
+        &[
+            "  _____  ",
+            " /  __ \\ ",
+            "|  /    |",
+            "|  \\___- ",
+            "-_       ",
+            "  --_    ",
+        ]
+    } else if name.contains("ubuntu") {
+        &[
+            "         _ ",
+            "     ---(_)",
+            " _/  ---  \\",
+            "(_) |   |  ",
+            "  \\  --- _/",
+            "     ---(_)",
+        ]
+    } else if name.contains("fedora") {
+        &[
+            "      _____ ",
+            "     /   __)\\",
+            "     |  /  \\ \\",
+            "  ___|  |__/ /",
+            " / (_    _)_/",
+            "/ /  |  |",
+            "\\ \\__/  |",
+            " \\(_____/",
+        ]
+    } else if name.contains("arch") {
+        &[
+            "      /\\",
+            "     /  \\",
+            "    /\\   \\",
+            "   /      \\",
+            "  /   ,,   \\",
+            " /   |  |  -\\",
+            "/_-''    ''-_\\",
+        ]
+    } else if name.contains("mac") || name.contains("darwin") {
+        &[
+            "        .:'",
+            "    __ :'__",
+            " .'`  `-'  ``.",
+            ":          .-'",
+            ":         :",
+            " :         `-;",
+            "  `.__.-.__.'",
+        ]
+    } else if name.contains("windows") {
+        &[
+            " ______ ______",
+            "|      |      |",
+            "|______|______|",
+            "|      |      |",
+            "|______|______|",
+        ]
+    } else if name.contains("bsd") {
+        &[
+            "/\\,-'''''-,/\\",
+            "\\_)       (_/",
+            "|           |",
+            "|           |",
+            " ;         ;",
+            "  '-_____-'",
+        ]
+    } else {
+        &[
+            "    .--.",
+            "   |o_o |",
+            "   |:_/ |",
+            "  //   \\ \\",
+            " (|     | )",
+            "/'\\_   _/`\\",
+            "\\___)=(___/",
+        ]
+    }
+}
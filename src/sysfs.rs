@@ -0,0 +1,73 @@
+//! Root of the `/sys`, `/proc` and `/etc` files the Linux backend reads: `/`
+//! on a live system, or a captured tree replayed with `--from-sysfs`.
+
+use anyhow::{Result, bail};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SysfsRoot {
+    base: PathBuf,
+}
+
+impl SysfsRoot {
+    pub fn live() -> Self {
+        Self {
+            base: PathBuf::from("/"),
+        }
+    }
+
+    /// A captured tree, i.e. a directory holding `sys/` and/or `proc/`.
+    pub fn open(dir: &Path) -> Result<Self> {
+        if !dir.join("sys").is_dir() && !dir.join("proc").is_dir() {
+            bail!("{} has no sys/ or proc/ directory", dir.display());
+        }
+        Ok(Self {
+            base: dir.to_path_buf(),
+        })
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.base == Path::new("/")
+    }
+
+    pub fn base(&self) -> &Path {
+        &self.base
+    }
+
+    /// Maps an absolute system path such as `/sys/class/net` into this root.
+    pub fn path(&self, path: impl AsRef<Path>) -> PathBuf {
+        let path = path.as_ref();
+        self.base.join(path.strip_prefix("/").unwrap_or(path))
+    }
+
+    /// The trimmed contents of a file, or `None` if it is missing or unreadable.
+    pub fn read(&self, path: impl AsRef<Path>) -> Option<String> {
+        fs::read_to_string(self.path(path))
+            .ok()
+            .map(|s| s.trim().to_string())
+    }
+
+    pub fn read_dir(&self, path: impl AsRef<Path>) -> io::Result<fs::ReadDir> {
+        fs::read_dir(self.path(path))
+    }
+}
+
+impl Default for SysfsRoot {
+    fn default() -> Self {
+        Self::live()
+    }
+}
+
+static ROOT: OnceLock<SysfsRoot> = OnceLock::new();
+
+pub fn set_root(root: SysfsRoot) {
+    let _ = ROOT.set(root);
+}
+
+/// The root discovery reads from: `/` unless `--from-sysfs` was given.
+pub fn root() -> &'static SysfsRoot {
+    ROOT.get_or_init(SysfsRoot::live)
+}
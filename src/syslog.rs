@@ -0,0 +1,139 @@
+use crate::alerts::{AlertLevel, AlertSink, ThresholdEvent};
+use crate::model::MetricsSample;
+use anyhow::Result;
+
+const IDENTIFIER: &str = "hw-checker";
+// LOG_DAEMON
+const FACILITY: u8 = 3;
+
+fn severity(level: AlertLevel) -> u8 {
+    match level {
+        AlertLevel::Critical => 2, // LOG_CRIT
+        AlertLevel::Warning => 4,  // LOG_WARNING
+        AlertLevel::Ok => 5,       // LOG_NOTICE
+    }
+}
+
+/// Sends threshold events to journald (with `HWCHECKER_*` fields) when it is
+/// running, falling back to the classic syslog socket.
+#[cfg(unix)]
+pub struct SyslogSink {
+    socket: std::os::unix::net::UnixDatagram,
+    journald: bool,
+}
+
+#[cfg(unix)]
+impl SyslogSink {
+    const JOURNALD_SOCKET: &str = "/run/systemd/journal/socket";
+    const SYSLOG_SOCKETS: [&str; 2] = ["/dev/log", "/var/run/syslog"];
+
+    pub fn new() -> Result<Self> {
+        let socket = std::os::unix::net::UnixDatagram::unbound()?;
+        if socket.connect(Self::JOURNALD_SOCKET).is_ok() {
+            return Ok(Self {
+                socket,
+                journald: true,
+            });
+        }
+        for path in Self::SYSLOG_SOCKETS {
+            if socket.connect(path).is_ok() {
+                return Ok(Self {
+                    socket,
+                    journald: false,
+                });
+            }
+        }
+        anyhow::bail!(
+            "no journald or syslog socket found (tried {} and {})",
+            Self::JOURNALD_SOCKET,
+            Self::SYSLOG_SOCKETS.join(", ")
+        )
+    }
+}
+
+#[cfg(unix)]
+impl AlertSink for SyslogSink {
+    fn send(&mut self, events: &[ThresholdEvent], _sample: &MetricsSample) -> Result<()> {
+        for event in events {
+            let payload = if self.journald {
+                journald_payload(event)
+            } else {
+                syslog_line(event)
+            };
+            self.socket.send(payload.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(unix))]
+pub struct SyslogSink;
+
+#[cfg(not(unix))]
+impl SyslogSink {
+    pub fn new() -> Result<Self> {
+        anyhow::bail!("syslog alerts are only available on Unix")
+    }
+}
+
+#[cfg(not(unix))]
+impl AlertSink for SyslogSink {
+    fn send(&mut self, _events: &[ThresholdEvent], _sample: &MetricsSample) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn structured_fields(event: &ThresholdEvent) -> Vec<(&'static str, String)> {
+    let mut fields = vec![
+        ("HWCHECKER_HOST", event.hostname.clone()),
+        ("HWCHECKER_METRIC", event.metric.clone()),
+        ("HWCHECKER_VALUE", format!("{:.2}", event.value)),
+        ("HWCHECKER_UNIT", event.unit.to_string()),
+        ("HWCHECKER_LEVEL", event.level.as_str().to_string()),
+        (
+            "HWCHECKER_PREVIOUS_LEVEL",
+            event.previous.as_str().to_string(),
+        ),
+    ];
+    if let Some(threshold) = event.threshold {
+        fields.push(("HWCHECKER_THRESHOLD", threshold.to_string()));
+    }
+    fields
+}
+
+// journald's native protocol: one `KEY=value` per line (values must not contain newlines).
+fn journald_payload(event: &ThresholdEvent) -> String {
+    let mut fields = vec![
+        ("MESSAGE", event.message()),
+        ("PRIORITY", severity(event.level).to_string()),
+        ("SYSLOG_FACILITY", FACILITY.to_string()),
+        ("SYSLOG_IDENTIFIER", IDENTIFIER.to_string()),
+    ];
+    fields.extend(structured_fields(event));
+    fields
+        .iter()
+        .map(|(key, value)| format!("{}={}\n", key, value.replace('\n', " ")))
+        .collect()
+}
+
+// RFC 3164 line; the structured fields are appended as key=value pairs.
+fn syslog_line(event: &ThresholdEvent) -> String {
+    let fields: Vec<String> = structured_fields(event)
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                "{}=\"{}\"",
+                key.trim_start_matches("HWCHECKER_").to_lowercase(),
+                value.replace('"', "'")
+            )
+        })
+        .collect();
+    format!(
+        "<{}>{}[{}]: {} {}",
+        FACILITY * 8 + severity(event.level),
+        IDENTIFIER,
+        std::process::id(),
+        event.message(),
+        fields.join(" ")
+    )
+}
@@ -0,0 +1,65 @@
+//! `--template <FILE>`: renders a Tera template (Jinja2-like syntax) with the
+//! report as its context, for formats hw-checker has no exporter for: wiki
+//! tables, Ansible inventory snippets, asset labels.
+//!
+//! Every top-level report field is a variable (`hostname`, `ram.total`,
+//! `cpu_packages[0].model`, ...), exactly as in `--format json`. Autoescaping
+//! is off, since the output is rarely HTML.
+
+use crate::model::HardwareReport;
+use anyhow::{Context as _, Result};
+use std::path::Path;
+use tera::{Context, Tera};
+
+pub fn render_template(report: &HardwareReport, path: &Path) -> Result<String> {
+    let source = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    render(report, &path.display().to_string(), &source)
+}
+
+fn render(report: &HardwareReport, name: &str, source: &str) -> Result<String> {
+    let mut tera = Tera::default();
+    tera.autoescape_on(Vec::new());
+    // Tera's own message is only "Failed to parse/render 'name'"; the cause
+    // with line and column is in the source chain, which anyhow prints.
+    tera.add_raw_template(name, source)?;
+    let context = Context::from_serialize(report)?;
+    Ok(tera.render(name, &context)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> HardwareReport {
+        serde_json::from_str(
+            r#"{"os_name": "Debian GNU/Linux", "os_version": "12", "kernel_version": "6.1.0",
+                "hostname": "db-1", "uptime": 3600,
+                "ram": {"total": 68719476736, "used": 0, "free": 0, "swap_total": 0,
+                    "swap_used": 0, "sticks": []},
+                "filesystems": [], "network": [], "usb": [], "pci": [],
+                "motherboard": null, "battery": []}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn report_fields_are_variables() {
+        let out = render(
+            &report(),
+            "inventory",
+            "{{ hostname }} ansible_memtotal_mb={{ ram.total / 1048576 }} os=\"{{ os_name }}\"",
+        )
+        .unwrap();
+        assert_eq!(
+            out,
+            "db-1 ansible_memtotal_mb=65536 os=\"Debian GNU/Linux\""
+        );
+    }
+
+    #[test]
+    fn syntax_errors_name_the_template() {
+        let err = render(&report(), "label.tera", "{{ hostname ").unwrap_err();
+        assert!(format!("{:#}", err).contains("label.tera"));
+    }
+}
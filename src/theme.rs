@@ -0,0 +1,275 @@
+use crate::alerts::AlertLevel;
+use crate::config::Thresholds;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// A color usable by both the TUI and the CLI tables: a basic ANSI name or `#rrggbb`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum ThemeColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+    DarkGray,
+    White,
+    Rgb(u8, u8, u8),
+}
+
+impl TryFrom<String> for ThemeColor {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if let Some(hex) = value.strip_prefix('#') {
+            let channel = |i: usize| {
+                hex.get(i..i + 2)
+                    .and_then(|c| u8::from_str_radix(c, 16).ok())
+            };
+            return match (hex.len(), channel(0), channel(2), channel(4)) {
+                (6, Some(r), Some(g), Some(b)) => Ok(ThemeColor::Rgb(r, g, b)),
+                _ => Err(format!("invalid hex color '{}'", value)),
+            };
+        }
+        match value.to_lowercase().replace(['_', '-', ' '], "").as_str() {
+            "black" => Ok(ThemeColor::Black),
+            "red" => Ok(ThemeColor::Red),
+            "green" => Ok(ThemeColor::Green),
+            "yellow" => Ok(ThemeColor::Yellow),
+            "blue" => Ok(ThemeColor::Blue),
+            "magenta" => Ok(ThemeColor::Magenta),
+            "cyan" => Ok(ThemeColor::Cyan),
+            "gray" | "grey" => Ok(ThemeColor::Gray),
+            "darkgray" | "darkgrey" => Ok(ThemeColor::DarkGray),
+            "white" => Ok(ThemeColor::White),
+            _ => Err(format!("unknown color '{}'", value)),
+        }
+    }
+}
+
+impl From<ThemeColor> for String {
+    fn from(color: ThemeColor) -> String {
+        match color {
+            ThemeColor::Black => "black".to_string(),
+            ThemeColor::Red => "red".to_string(),
+            ThemeColor::Green => "green".to_string(),
+            ThemeColor::Yellow => "yellow".to_string(),
+            ThemeColor::Blue => "blue".to_string(),
+            ThemeColor::Magenta => "magenta".to_string(),
+            ThemeColor::Cyan => "cyan".to_string(),
+            ThemeColor::Gray => "gray".to_string(),
+            ThemeColor::DarkGray => "darkgray".to_string(),
+            ThemeColor::White => "white".to_string(),
+            ThemeColor::Rgb(r, g, b) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        }
+    }
+}
+
+impl From<ThemeColor> for ratatui::style::Color {
+    fn from(color: ThemeColor) -> Self {
+        use ratatui::style::Color;
+        match color {
+            ThemeColor::Black => Color::Black,
+            ThemeColor::Red => Color::Red,
+            ThemeColor::Green => Color::Green,
+            ThemeColor::Yellow => Color::Yellow,
+            ThemeColor::Blue => Color::Blue,
+            ThemeColor::Magenta => Color::Magenta,
+            ThemeColor::Cyan => Color::Cyan,
+            ThemeColor::Gray => Color::Gray,
+            ThemeColor::DarkGray => Color::DarkGray,
+            ThemeColor::White => Color::White,
+            ThemeColor::Rgb(r, g, b) => Color::Rgb(r, g, b),
+        }
+    }
+}
+
+impl From<ThemeColor> for comfy_table::Color {
+    fn from(color: ThemeColor) -> Self {
+        use comfy_table::Color;
+        match color {
+            ThemeColor::Black => Color::Black,
+            ThemeColor::Red => Color::Red,
+            ThemeColor::Green => Color::Green,
+            ThemeColor::Yellow => Color::Yellow,
+            ThemeColor::Blue => Color::Blue,
+            ThemeColor::Magenta => Color::Magenta,
+            ThemeColor::Cyan => Color::Cyan,
+            ThemeColor::Gray => Color::Grey,
+            ThemeColor::DarkGray => Color::DarkGrey,
+            ThemeColor::White => Color::White,
+            ThemeColor::Rgb(r, g, b) => Color::Rgb { r, g, b },
+        }
+    }
+}
+
+impl From<ThemeColor> for colored::Color {
+    fn from(color: ThemeColor) -> Self {
+        use colored::Color;
+        match color {
+            ThemeColor::Black => Color::Black,
+            ThemeColor::Red => Color::Red,
+            ThemeColor::Green => Color::Green,
+            ThemeColor::Yellow => Color::Yellow,
+            ThemeColor::Blue => Color::Blue,
+            ThemeColor::Magenta => Color::Magenta,
+            ThemeColor::Cyan => Color::Cyan,
+            ThemeColor::Gray => Color::White,
+            ThemeColor::DarkGray => Color::BrightBlack,
+            ThemeColor::White => Color::BrightWhite,
+            ThemeColor::Rgb(r, g, b) => Color::TrueColor { r, g, b },
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreset {
+    #[default]
+    Dark,
+    Light,
+    Solarized,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    /// Section titles and the TUI banner.
+    pub title: ThemeColor,
+    /// Table column headers and the active tab.
+    pub header: ThemeColor,
+    /// Focused borders, selections, charts and popups.
+    pub accent: ThemeColor,
+    /// Secondary series (e.g. TX throughput).
+    pub info: ThemeColor,
+    pub gauge: ThemeColor,
+    pub ok: ThemeColor,
+    pub warning: ThemeColor,
+    pub critical: ThemeColor,
+    /// Inactive tabs, axes and missing values.
+    pub muted: ThemeColor,
+    /// Text drawn on top of the accent color (selected rows).
+    pub selection: ThemeColor,
+}
+
+impl ThemePreset {
+    pub fn theme(self) -> Theme {
+        match self {
+            ThemePreset::Dark => Theme {
+                title: ThemeColor::Cyan,
+                header: ThemeColor::Yellow,
+                accent: ThemeColor::Cyan,
+                info: ThemeColor::Blue,
+                gauge: ThemeColor::Yellow,
+                ok: ThemeColor::Green,
+                warning: ThemeColor::Yellow,
+                critical: ThemeColor::Red,
+                muted: ThemeColor::DarkGray,
+                selection: ThemeColor::Black,
+            },
+            ThemePreset::Light => Theme {
+                title: ThemeColor::Rgb(0x00, 0x5f, 0x87),
+                header: ThemeColor::Rgb(0x87, 0x5f, 0x00),
+                accent: ThemeColor::Rgb(0x00, 0x5f, 0xaf),
+                info: ThemeColor::Rgb(0x5f, 0x00, 0xaf),
+                gauge: ThemeColor::Rgb(0x00, 0x5f, 0xaf),
+                ok: ThemeColor::Rgb(0x00, 0x87, 0x00),
+                warning: ThemeColor::Rgb(0xaf, 0x5f, 0x00),
+                critical: ThemeColor::Rgb(0xd7, 0x00, 0x00),
+                muted: ThemeColor::Rgb(0x8a, 0x8a, 0x8a),
+                selection: ThemeColor::White,
+            },
+            ThemePreset::Solarized => Theme {
+                title: ThemeColor::Rgb(0x26, 0x8b, 0xd2),
+                header: ThemeColor::Rgb(0xb5, 0x89, 0x00),
+                accent: ThemeColor::Rgb(0x2a, 0xa1, 0x98),
+                info: ThemeColor::Rgb(0x6c, 0x71, 0xc4),
+                gauge: ThemeColor::Rgb(0x26, 0x8b, 0xd2),
+                ok: ThemeColor::Rgb(0x85, 0x99, 0x00),
+                warning: ThemeColor::Rgb(0xcb, 0x4b, 0x16),
+                critical: ThemeColor::Rgb(0xdc, 0x32, 0x2f),
+                muted: ThemeColor::Rgb(0x58, 0x6e, 0x75),
+                selection: ThemeColor::Rgb(0xfd, 0xf6, 0xe3),
+            },
+        }
+    }
+}
+
+impl Theme {
+    /// Picks ok/warning/critical for a value measured against `thresholds`.
+    pub fn level(&self, value: f32, thresholds: &Thresholds) -> ThemeColor {
+        if value > thresholds.critical {
+            self.critical
+        } else if value > thresholds.warning {
+            self.warning
+        } else {
+            self.ok
+        }
+    }
+
+    pub fn alert(&self, level: AlertLevel) -> ThemeColor {
+        match level {
+            AlertLevel::Ok => self.ok,
+            AlertLevel::Warning => self.warning,
+            AlertLevel::Critical => self.critical,
+        }
+    }
+}
+
+/// The `theme:` config section: a preset plus optional per-role overrides.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeConfig {
+    #[serde(default)]
+    pub preset: ThemePreset,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gauge: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ok: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted: Option<ThemeColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selection: Option<ThemeColor>,
+}
+
+impl ThemeConfig {
+    pub fn resolve(&self) -> Theme {
+        let base = self.preset.theme();
+        Theme {
+            title: self.title.unwrap_or(base.title),
+            header: self.header.unwrap_or(base.header),
+            accent: self.accent.unwrap_or(base.accent),
+            info: self.info.unwrap_or(base.info),
+            gauge: self.gauge.unwrap_or(base.gauge),
+            ok: self.ok.unwrap_or(base.ok),
+            warning: self.warning.unwrap_or(base.warning),
+            critical: self.critical.unwrap_or(base.critical),
+            muted: self.muted.unwrap_or(base.muted),
+            selection: self.selection.unwrap_or(base.selection),
+        }
+    }
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+pub fn set_theme(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+pub fn theme() -> &'static Theme {
+    THEME.get_or_init(|| ThemePreset::Dark.theme())
+}
@@ -1,39 +1,311 @@
 use anyhow::Result;
+use chrono::{DateTime, Local};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{
     Frame, Terminal,
     backend::{Backend, CrosstermBackend},
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Position, Rect},
     style::{Color, Modifier, Style},
-    text::Line,
-    widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, Tabs},
+    symbols,
+    text::{Line, Span},
+    widgets::{
+        Axis, Block, Borders, Cell, Chart, Clear, Dataset, Gauge, GraphType, Paragraph, Row,
+        Sparkline, Table, TableState, Tabs,
+    },
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    io,
+    path::Path,
+    time::{Duration, Instant},
+};
+use sysinfo::{
+    CpuRefreshKind, Networks, Pid, ProcessRefreshKind, ProcessesToUpdate, RefreshKind, Signal,
+    System, UpdateKind, Users,
 };
-use std::{io, time::Duration};
-use sysinfo::{CpuRefreshKind, Networks, RefreshKind, System};
 
-use crate::model::HardwareReport;
+use crate::alerts;
+use crate::config::{Config, Thresholds};
+use crate::discovery::{
+    CpuTicks, DiskStats, cpu_time_shares, disk_io_rates, get_battery_info, get_disks, get_fans,
+    get_filesystems, get_gpu_processes, get_gpus, get_network, get_network_config, get_pressure,
+    get_temperatures, get_thunderbolt_devices, get_usb_devices, get_usb_ids, read_cpu_ticks,
+    read_diskstats, read_load_average,
+};
+use crate::exporter::save_report;
+use crate::formatter::{battery_time_left, cache_cell, core_kind_label};
+use crate::i18n::t;
+use crate::model::{
+    CoreKind, CpuLoad, DiskIoInfo, Filesystem, HardwareReport, NetworkInfo, UsbDevice,
+};
+use crate::mounts::filter_mounts;
+use crate::redact::{redact_disks, redact_network};
+use crate::theme::theme;
 
-const TABS: [&str; 4] = [
-    " 1: Overview ",
-    " 2: CPU & RAM ",
-    " 3: Storage & Network ",
-    " 4: PCI & USB ",
+const TABS: [&str; 7] = [
+    "Overview",
+    "CPU & RAM",
+    "Storage & Network",
+    "PCI & USB",
+    "Thermal & Power",
+    "GPU",
+    "Processes",
 ];
 
+const GPU_TAB: usize = 5;
+const PROCESSES_TAB: usize = 6;
+
+// Number of samples kept for the rolling usage graphs (one per refresh tick).
+const HISTORY_LEN: usize = 60;
+
+// Rows moved by PageUp/PageDown.
+const PAGE_SIZE: isize = 10;
+
+// Rows moved per mouse wheel notch.
+const WHEEL_STEP: isize = 3;
+
+// Border plus header row plus the header's bottom margin.
+const TABLE_BODY_OFFSET: u16 = 3;
+
+// How long plugged/unplugged rows stay highlighted.
+const HOTPLUG_HIGHLIGHT: Duration = Duration::from_secs(5);
+
+// How often the inventory probes that are not polled every tick run again.
+const REDISCOVER_INTERVAL: Duration = Duration::from_secs(10);
+
+// Entries kept in the alert log.
+const ALERT_LOG_LEN: usize = 100;
+
+// How long a status message stays in the footer.
+const STATUS_TIMEOUT: Duration = Duration::from_secs(5);
+
+// Scrollable tables that can hold keyboard focus.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Pane {
+    Dimms,
+    Storage,
+    Network,
+    Pci,
+    Usb,
+    Sensors,
+    Gpus,
+    Processes,
+}
+
+const PANE_COUNT: usize = 8;
+
+impl Pane {
+    fn for_tab(tab: usize) -> &'static [Pane] {
+        match tab {
+            1 => &[Pane::Dimms],
+            2 => &[Pane::Storage, Pane::Network],
+            3 => &[Pane::Pci, Pane::Usb],
+            4 => &[Pane::Sensors],
+            GPU_TAB => &[Pane::Gpus],
+            PROCESSES_TAB => &[Pane::Processes],
+            _ => &[],
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
 struct App {
     report: HardwareReport,
+    config: Config,
     active_tab: usize,
     sys: System,
     networks: Networks,
     last_refresh: std::time::Instant,
+    cpu_history: Vec<VecDeque<u64>>,
+    cpu_total_history: VecDeque<u64>,
+    net_history: HashMap<String, NetHistory>,
+    diskstats: HashMap<String, DiskStats>,
+    disk_io: HashMap<String, DiskIoInfo>,
+    cpu_ticks: Option<CpuTicks>,
+    focus: usize,
+    table_states: [TableState; PANE_COUNT],
+    // Report indices of the (filtered) rows shown in each pane as of the last draw.
+    visible_rows: [Vec<usize>; PANE_COUNT],
+    search: String,
+    search_input: bool,
+    // Filename being typed after pressing 's'.
+    save_input: Option<String>,
+    status: Option<(String, Instant)>,
+    interval: Duration,
+    paused: bool,
+    // Screen areas from the last draw, used for mouse hit-testing.
+    tab_areas: Vec<Rect>,
+    pane_areas: [Rect; PANE_COUNT],
+    // Whether the detail popup for the focused pane's selected row is open.
+    detail: bool,
+    help: bool,
+    users: Users,
+    processes: Vec<ProcessRow>,
+    process_sort: ProcessSort,
+    process_sort_desc: bool,
+    pending_signal: Option<PendingSignal>,
+    // Newest first.
+    alerts: VecDeque<Alert>,
+    // Last level seen per metric, so only threshold crossings are logged.
+    alert_levels: HashMap<String, AlertLevel>,
+    // Hotplugged rows, highlighted for HOTPLUG_HIGHLIGHT after they change.
+    added_rows: HashMap<String, Instant>,
+    removed_usb: Vec<(UsbDevice, Instant)>,
+    removed_storage: Vec<(Filesystem, Instant)>,
+    last_rediscovery: Instant,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum ProcessSort {
+    Pid,
+    User,
+    Cpu,
+    Memory,
+    Name,
+}
+
+impl ProcessSort {
+    const ALL: [ProcessSort; 5] = [
+        ProcessSort::Pid,
+        ProcessSort::User,
+        ProcessSort::Cpu,
+        ProcessSort::Memory,
+        ProcessSort::Name,
+    ];
+
+    fn next(self) -> ProcessSort {
+        let i = Self::ALL.iter().position(|s| *s == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+}
+
+struct ProcessRow {
+    pid: Pid,
+    user: String,
+    cpu: f32,
+    rss: u64,
+    name: String,
+}
+
+// A signal waiting for the user to confirm with 'y'.
+struct PendingSignal {
+    pid: Pid,
+    name: String,
+    signal: Signal,
+    label: &'static str,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum AlertLevel {
+    Ok,
+    Warning,
+    Critical,
+    // Not a threshold crossing, e.g. a device being plugged in.
+    Info,
+}
+
+impl AlertLevel {
+    fn classify(value: f32, thresholds: &Thresholds) -> AlertLevel {
+        if value > thresholds.critical {
+            AlertLevel::Critical
+        } else if value > thresholds.warning {
+            AlertLevel::Warning
+        } else {
+            AlertLevel::Ok
+        }
+    }
+}
+
+struct Alert {
+    time: DateTime<Local>,
+    level: AlertLevel,
+    message: String,
+}
+
+fn usb_id(device: &UsbDevice) -> (u8, u8, u16, u16) {
+    (
+        device.bus,
+        device.address,
+        device.vendor_id,
+        device.product_id,
+    )
+}
+
+fn usb_key(device: &UsbDevice) -> String {
+    format!(
+        "usb:{:03}:{:03}:{:04x}:{:04x}",
+        device.bus, device.address, device.vendor_id, device.product_id
+    )
+}
+
+fn usb_label(device: &UsbDevice) -> String {
+    let name = device
+        .product
+        .clone()
+        .or_else(|| device.manufacturer.clone())
+        .unwrap_or_default();
+    format!(
+        "{:04x}:{:04x} {}",
+        device.vendor_id, device.product_id, name
+    )
+    .trim_end()
+    .to_string()
+}
+
+fn storage_key(fs: &Filesystem) -> String {
+    format!("disk:{}:{}", fs.name, fs.mount_point)
+}
+
+fn network_key(net: &NetworkInfo) -> String {
+    format!("net:{}", net.name)
+}
+
+// Rows only in `new` (added) and rows only in `old` (removed), matched by `key`.
+fn diff_rows<T: Clone>(old: &[T], new: &[T], key: fn(&T) -> String) -> (Vec<T>, Vec<T>) {
+    let old_keys: HashSet<String> = old.iter().map(key).collect();
+    let new_keys: HashSet<String> = new.iter().map(key).collect();
+    let added = new
+        .iter()
+        .filter(|row| !old_keys.contains(&key(row)))
+        .cloned()
+        .collect();
+    let removed = old
+        .iter()
+        .filter(|row| !new_keys.contains(&key(row)))
+        .cloned()
+        .collect();
+    (added, removed)
+}
+
+// Per-interface throughput in bytes/sec, one sample per refresh tick.
+#[derive(Default)]
+struct NetHistory {
+    rx: VecDeque<u64>,
+    tx: VecDeque<u64>,
+}
+
+fn push_sample(history: &mut VecDeque<u64>, value: u64) {
+    if history.len() == HISTORY_LEN {
+        history.pop_front();
+    }
+    history.push_back(value);
 }
 
 impl App {
-    fn new(report: HardwareReport) -> App {
+    fn new(mut report: HardwareReport, config: Config, interval: Duration) -> App {
+        if !config.all_interfaces {
+            report.network.retain(|net| !net.is_virtual());
+        }
         let mut sys = System::new_with_specifics(
             RefreshKind::nothing()
                 .with_cpu(CpuRefreshKind::everything())
@@ -41,12 +313,741 @@ impl App {
         );
         sys.refresh_cpu_all();
 
+        let mut cpu_history = vec![VecDeque::with_capacity(HISTORY_LEN); report.cores().count()];
+        for (history, core) in cpu_history.iter_mut().zip(report.cores()) {
+            push_sample(history, core.usage as u64);
+        }
+        let mut cpu_total_history = VecDeque::with_capacity(HISTORY_LEN);
+        if let Some(total) = report.cpu_usage() {
+            push_sample(&mut cpu_total_history, total as u64);
+        }
+
         App {
             report,
+            config,
             active_tab: 0,
             sys,
             networks: Networks::new_with_refreshed_list(),
             last_refresh: std::time::Instant::now(),
+            cpu_history,
+            cpu_total_history,
+            net_history: HashMap::new(),
+            diskstats: read_diskstats(),
+            disk_io: HashMap::new(),
+            cpu_ticks: read_cpu_ticks(),
+            focus: 0,
+            table_states: Default::default(),
+            visible_rows: Default::default(),
+            search: String::new(),
+            search_input: false,
+            save_input: None,
+            status: None,
+            interval,
+            paused: false,
+            tab_areas: Vec::new(),
+            pane_areas: [Rect::default(); PANE_COUNT],
+            detail: false,
+            help: false,
+            users: Users::new_with_refreshed_list(),
+            processes: Vec::new(),
+            process_sort: ProcessSort::Cpu,
+            process_sort_desc: true,
+            pending_signal: None,
+            alerts: VecDeque::new(),
+            alert_levels: HashMap::new(),
+            added_rows: HashMap::new(),
+            removed_usb: Vec::new(),
+            removed_storage: Vec::new(),
+            last_rediscovery: Instant::now(),
+        }
+    }
+
+    fn check_alerts(&mut self) {
+        let mut readings = Vec::new();
+        if let Some(total) = self.report.cpu_usage() {
+            readings.push((
+                "CPU".to_string(),
+                total,
+                "%",
+                self.config.cpu_thresholds.clone(),
+            ));
+        }
+        if self.report.ram.total > 0 {
+            let used = self.report.ram.used as f32 / self.report.ram.total as f32 * 100.0;
+            readings.push((
+                "RAM".to_string(),
+                used,
+                "%",
+                self.config.ram_thresholds.clone(),
+            ));
+        }
+        for fs in &self.report.filesystems {
+            if fs.total > 0 {
+                let used = fs.used as f32 / fs.total as f32 * 100.0;
+                readings.push((
+                    fs.mount_point.clone(),
+                    used,
+                    "%",
+                    self.config.storage_thresholds.clone(),
+                ));
+            }
+        }
+        if let Some(pressure) = &self.report.pressure {
+            for (name, stall) in pressure.resources() {
+                readings.push((
+                    format!("PSI {}", name),
+                    stall.some[0],
+                    "%",
+                    self.config.pressure.thresholds(name).clone(),
+                ));
+            }
+        }
+        for sensor in &self.report.temperatures {
+            if let Some(c) = sensor.temperature {
+                readings.push((
+                    sensor.label.clone(),
+                    c,
+                    " °C",
+                    self.config.temperature_thresholds.clone(),
+                ));
+            }
+        }
+
+        for (metric, value, unit, thresholds) in readings {
+            let level = AlertLevel::classify(value, &thresholds);
+            let previous = self
+                .alert_levels
+                .insert(metric.clone(), level)
+                .unwrap_or(AlertLevel::Ok);
+            if level == previous {
+                continue;
+            }
+            let message = match level {
+                AlertLevel::Critical => format!(
+                    "{}: {:.1}{} > {} {}{}",
+                    metric,
+                    value,
+                    unit,
+                    t("critical"),
+                    thresholds.critical,
+                    unit
+                ),
+                AlertLevel::Warning => format!(
+                    "{}: {:.1}{} > {} {}{}",
+                    metric,
+                    value,
+                    unit,
+                    t("warning"),
+                    thresholds.warning,
+                    unit
+                ),
+                AlertLevel::Ok | AlertLevel::Info => {
+                    format!("{}: {:.1}{} {}", metric, value, unit, t("back to normal"))
+                }
+            };
+            self.log_alert(level, message);
+        }
+    }
+
+    fn log_alert(&mut self, level: AlertLevel, message: String) {
+        if self.alerts.len() == ALERT_LOG_LEN {
+            self.alerts.pop_back();
+        }
+        self.alerts.push_front(Alert {
+            time: Local::now(),
+            level,
+            message,
+        });
+    }
+
+    // Re-enumerates USB devices and mounted storage, highlighting what changed.
+    fn poll_hotplug(&mut self) {
+        let now = Instant::now();
+        self.added_rows
+            .retain(|_, at| now.duration_since(*at) < HOTPLUG_HIGHLIGHT);
+        self.removed_usb
+            .retain(|(_, at)| now.duration_since(*at) < HOTPLUG_HIGHLIGHT);
+        self.removed_storage
+            .retain(|(_, at)| now.duration_since(*at) < HOTPLUG_HIGHLIGHT);
+
+        let mut usb_ids = get_usb_ids();
+        let mut known_ids: Vec<_> = self.report.usb.iter().map(usb_id).collect();
+        usb_ids.sort_unstable();
+        known_ids.sort_unstable();
+        if usb_ids != known_ids {
+            let devices = get_usb_devices();
+            let (added, removed) = diff_rows(&self.report.usb, &devices, usb_key);
+            for device in added {
+                self.added_rows.insert(usb_key(&device), now);
+                self.log_alert(
+                    AlertLevel::Info,
+                    format!("{}: {}", t("USB connected"), usb_label(&device)),
+                );
+            }
+            for device in removed {
+                self.log_alert(
+                    AlertLevel::Info,
+                    format!("{}: {}", t("USB disconnected"), usb_label(&device)),
+                );
+                self.removed_usb.push((device, now));
+            }
+            self.report.usb = devices;
+        }
+
+        let mut storage = get_filesystems();
+        filter_mounts(&mut storage, &self.config.mounts);
+        let (added, removed) = diff_rows(&self.report.filesystems, &storage, storage_key);
+        if !added.is_empty() || !removed.is_empty() {
+            let mut disks = get_disks();
+            redact_disks(&mut disks, &self.config.redact);
+            self.report.disks = disks;
+            for disk in added {
+                self.added_rows.insert(storage_key(&disk), now);
+                self.log_alert(
+                    AlertLevel::Info,
+                    format!(
+                        "{}: {} ({})",
+                        t("Storage added"),
+                        disk.name,
+                        disk.mount_point
+                    ),
+                );
+            }
+            for disk in removed {
+                self.log_alert(
+                    AlertLevel::Info,
+                    format!(
+                        "{}: {} ({})",
+                        t("Storage removed"),
+                        disk.name,
+                        disk.mount_point
+                    ),
+                );
+                self.removed_storage.push((disk, now));
+            }
+        }
+        // Always take the fresh list so usage figures stay current too.
+        self.report.filesystems = storage;
+    }
+
+    /// Re-runs the inventory probes that mounts, batteries and USB polling
+    /// do not cover, so interfaces, docks, drives and GPUs that come and go
+    /// during a long session do not linger or stay missing.
+    fn rediscover(&mut self) {
+        let now = Instant::now();
+        let sections = self.config.sections.clone();
+        if sections.network {
+            let mut network = get_network();
+            if !self.config.all_interfaces {
+                network.retain(|net| !net.is_virtual());
+            }
+            redact_network(&mut network, &self.config.redact);
+            let (added, removed) = diff_rows(&self.report.network, &network, network_key);
+            for net in added {
+                self.added_rows.insert(network_key(&net), now);
+                self.log_alert(
+                    AlertLevel::Info,
+                    format!("{}: {}", t("Interface added"), net.name),
+                );
+            }
+            for net in removed {
+                self.net_history.remove(&net.name);
+                self.log_alert(
+                    AlertLevel::Info,
+                    format!("{}: {}", t("Interface removed"), net.name),
+                );
+            }
+            self.report.network = network;
+            self.report.network_config = get_network_config();
+        }
+        if sections.thunderbolt {
+            self.report.thunderbolt = get_thunderbolt_devices();
+        }
+        if sections.storage {
+            let mut disks = get_disks();
+            redact_disks(&mut disks, &self.config.redact);
+            self.report.disks = disks;
+        }
+        if sections.gpu {
+            self.report.gpus = get_gpus();
+        }
+        self.last_rediscovery = now;
+    }
+
+    fn row_style(&self, key: &str) -> Style {
+        if self.added_rows.contains_key(key) {
+            Style::default()
+                .fg(theme().ok.into())
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        }
+    }
+
+    fn set_status(&mut self, message: String) {
+        self.status = Some((message, Instant::now()));
+    }
+
+    fn save_snapshot(&mut self, filename: &str) {
+        match save_report(&self.report, Path::new(filename)) {
+            Ok(_) => self.set_status(format!("{} {}", t("Report saved to"), filename)),
+            Err(err) => self.set_status(format!("{}: {}", t("Save failed"), err)),
+        }
+    }
+
+    fn focused_pane(&self) -> Option<Pane> {
+        Pane::for_tab(self.active_tab).get(self.focus).copied()
+    }
+
+    fn set_tab(&mut self, tab: usize) {
+        self.active_tab = tab;
+        self.focus = 0;
+        if tab == PROCESSES_TAB && self.processes.is_empty() {
+            self.refresh_processes();
+        }
+        if tab == GPU_TAB {
+            self.report.gpu_processes = get_gpu_processes();
+        }
+    }
+
+    fn refresh_processes(&mut self) {
+        self.sys.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing()
+                .with_cpu()
+                .with_memory()
+                .with_user(UpdateKind::OnlyIfNotSet),
+        );
+        self.processes = self
+            .sys
+            .processes()
+            .values()
+            .map(|process| ProcessRow {
+                pid: process.pid(),
+                user: process
+                    .user_id()
+                    .and_then(|uid| self.users.get_user_by_id(uid))
+                    .map(|user| user.name().to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                cpu: process.cpu_usage(),
+                rss: process.memory(),
+                name: process.name().to_string_lossy().into_owned(),
+            })
+            .collect();
+        self.sort_processes();
+    }
+
+    fn sort_processes(&mut self) {
+        let sort = self.process_sort;
+        self.processes.sort_by(|a, b| {
+            let ordering = match sort {
+                ProcessSort::Pid => a.pid.cmp(&b.pid),
+                ProcessSort::User => a.user.cmp(&b.user),
+                ProcessSort::Cpu => a.cpu.total_cmp(&b.cpu),
+                ProcessSort::Memory => a.rss.cmp(&b.rss),
+                ProcessSort::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            };
+            ordering.then(a.pid.cmp(&b.pid))
+        });
+        if self.process_sort_desc {
+            self.processes.reverse();
+        }
+    }
+
+    fn request_signal(&mut self, signal: Signal, label: &'static str) {
+        if self.focused_pane() != Some(Pane::Processes) {
+            return;
+        }
+        let pane = Pane::Processes.index();
+        let Some(row) = self.table_states[pane]
+            .selected()
+            .and_then(|selected| self.visible_rows[pane].get(selected))
+            .and_then(|&index| self.processes.get(index))
+        else {
+            return;
+        };
+        self.pending_signal = Some(PendingSignal {
+            pid: row.pid,
+            name: row.name.clone(),
+            signal,
+            label,
+        });
+    }
+
+    fn send_signal(&mut self, pending: PendingSignal) {
+        let result = self
+            .sys
+            .process(pending.pid)
+            .map(|process| process.kill_with(pending.signal));
+        let message = match result {
+            Some(Some(true)) => format!(
+                "{} {} → {} ({})",
+                t("Sent"),
+                pending.label,
+                pending.name,
+                pending.pid
+            ),
+            Some(Some(false)) => format!(
+                "{} {} ({}): {}",
+                t("Failed to signal"),
+                pending.name,
+                pending.pid,
+                t("permission denied or process exited")
+            ),
+            Some(None) => format!(
+                "{}: {}",
+                t("Signal not supported on this platform"),
+                pending.label
+            ),
+            None => format!("{} ({})", t("Process no longer exists"), pending.pid),
+        };
+        self.set_status(message);
+        self.refresh_processes();
+    }
+
+    fn cycle_focus(&mut self, forward: bool) {
+        let panes = Pane::for_tab(self.active_tab).len();
+        if panes > 0 {
+            self.focus = if forward {
+                (self.focus + 1) % panes
+            } else {
+                (self.focus + panes - 1) % panes
+            };
+        }
+    }
+
+    fn scroll(&mut self, delta: isize) {
+        let Some(pane) = self.focused_pane() else {
+            return;
+        };
+        let len = self.visible_rows[pane.index()].len();
+        let state = &mut self.table_states[pane.index()];
+        if len == 0 {
+            state.select(None);
+            return;
+        }
+        let current = state.selected().unwrap_or(0) as isize;
+        let next = current.saturating_add(delta).clamp(0, len as isize - 1);
+        state.select(Some(next as usize));
+    }
+
+    fn reset_selection(&mut self) {
+        for state in self.table_states.iter_mut() {
+            state.select(None);
+            *state.offset_mut() = 0;
+        }
+    }
+
+    fn matches_search(&self, fields: &[&str]) -> bool {
+        if self.search.is_empty() {
+            return true;
+        }
+        let needle = self.search.to_lowercase();
+        fields.iter().any(|f| f.to_lowercase().contains(&needle))
+    }
+
+    // Title and every known field of the focused pane's selected device.
+    fn detail_fields(&self) -> Option<(String, Vec<(&'static str, String)>)> {
+        let pane = self.focused_pane()?;
+        let selected = self.table_states[pane.index()].selected()?;
+        let index = *self.visible_rows[pane.index()].get(selected)?;
+        let or_na = |v: &Option<String>| v.clone().unwrap_or_else(|| "N/A".to_string());
+        let gib = |bytes: u64| format!("{:.1} GiB", bytes as f64 / 1024.0 / 1024.0 / 1024.0);
+
+        match pane {
+            Pane::Dimms => {
+                let stick = self.report.ram.sticks.get(index)?;
+                Some((
+                    format!("DIMM {}", index),
+                    vec![
+                        (t("Slot"), index.to_string()),
+                        (t("Manufacturer"), or_na(&stick.manufacturer)),
+                        (t("Part Number"), or_na(&stick.part_number)),
+                        (t("Serial Number"), or_na(&stick.serial_number)),
+                        (
+                            t("Speed"),
+                            stick
+                                .speed
+                                .map(|s| format!("{} MT/s", s))
+                                .unwrap_or_else(|| "N/A".to_string()),
+                        ),
+                    ],
+                ))
+            }
+            Pane::Storage => {
+                let fs = self.report.filesystems.get(index)?;
+                let mut fields = vec![
+                    (t("Name"), fs.name.clone()),
+                    (t("Mount"), fs.mount_point.clone()),
+                    (t("Filesystem"), fs.filesystem.clone()),
+                    (t("Total"), gib(fs.total)),
+                    (t("Used"), gib(fs.used)),
+                    (t("Free"), gib(fs.free)),
+                ];
+                if let Some(inodes) = fs.inodes
+                    && let Some(usage) = inodes.usage_percent()
+                {
+                    fields.push((
+                        t("Inodes"),
+                        format!("{} / {} ({:.1}%)", inodes.used, inodes.total, usage),
+                    ));
+                }
+                if let Some(disk) = self.report.disk_for(fs) {
+                    fields.extend([
+                        (t("Disk"), disk.name.clone()),
+                        (t("Type"), or_na(&disk.disk_type)),
+                        (t("Interface"), or_na(&disk.interface_label())),
+                        (t("Vendor"), or_na(&disk.vendor)),
+                        (t("Model"), or_na(&disk.model_name)),
+                        (t("Serial Number"), or_na(&disk.serial_number)),
+                        (t("Size"), gib(disk.size)),
+                    ]);
+                    if let Some(smart) = &disk.smart {
+                        fields.push((
+                            "SMART",
+                            if smart.passed {
+                                t("Passed")
+                            } else {
+                                t("Failed")
+                            }
+                            .to_string(),
+                        ));
+                        if let Some(temperature) = smart.temperature {
+                            fields.push((t("Temperature"), format!("{:.0} °C", temperature)));
+                        }
+                        if let Some(hours) = smart.power_on_hours {
+                            fields.push((t("Power-on hours"), hours.to_string()));
+                        }
+                    }
+                }
+                if let Some(io) = self.disk_io.get(fs.name.trim_start_matches("/dev/")) {
+                    fields.push((t("Read/s"), format_rate(io.read_bytes_per_sec as u64)));
+                    fields.push((t("Write/s"), format_rate(io.write_bytes_per_sec as u64)));
+                    fields.push((t("Read IOPS"), format!("{:.0}", io.read_iops)));
+                    fields.push((t("Write IOPS"), format!("{:.0}", io.write_iops)));
+                }
+                Some((fs.name.clone(), fields))
+            }
+            Pane::Pci => {
+                let pci = self.report.pci.get(index)?;
+                Some((
+                    pci.slot.clone(),
+                    vec![
+                        (t("Slot"), pci.slot.clone()),
+                        (t("Vendor ID"), format!("0x{:04x}", pci.vendor_id)),
+                        (t("Device ID"), format!("0x{:04x}", pci.device_id)),
+                        (t("Vendor"), or_na(&pci.vendor_name)),
+                        (t("Device"), or_na(&pci.device_name)),
+                        (t("Class"), or_na(&pci.class_name)),
+                        (
+                            t("Revision"),
+                            pci.revision
+                                .map_or("N/A".to_string(), |r| format!("0x{:02x}", r)),
+                        ),
+                        (
+                            t("Subsystem"),
+                            match (pci.subsystem_vendor_id, pci.subsystem_device_id) {
+                                (Some(vendor), Some(device)) => {
+                                    format!("{:04x}:{:04x}", vendor, device)
+                                }
+                                _ => "N/A".to_string(),
+                            },
+                        ),
+                        (
+                            t("NUMA node"),
+                            pci.numa_node.map_or("N/A".to_string(), |n| n.to_string()),
+                        ),
+                        (
+                            t("IOMMU group"),
+                            pci.iommu_group.map_or("N/A".to_string(), |g| g.to_string()),
+                        ),
+                    ],
+                ))
+            }
+            Pane::Usb => {
+                let usb = self.report.usb.get(index)?;
+                Some((
+                    format!("USB {:03}:{:03}", usb.bus, usb.address),
+                    vec![
+                        (t("Bus"), usb.bus.to_string()),
+                        (t("Address"), usb.address.to_string()),
+                        (t("Vendor ID"), format!("0x{:04x}", usb.vendor_id)),
+                        (t("Product ID"), format!("0x{:04x}", usb.product_id)),
+                        (t("Manufacturer"), or_na(&usb.manufacturer)),
+                        (t("Product"), or_na(&usb.product)),
+                    ],
+                ))
+            }
+            Pane::Network | Pane::Sensors | Pane::Gpus | Pane::Processes => None,
+        }
+    }
+
+    fn open_detail(&mut self) {
+        self.detail = self.detail_fields().is_some();
+    }
+
+    // Returns true when the user asked to quit.
+    fn on_key(&mut self, key: KeyEvent) -> bool {
+        if let Some(pending) = self.pending_signal.take() {
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                self.send_signal(pending);
+            }
+            return false;
+        }
+
+        if self.detail {
+            if matches!(key.code, KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')) {
+                self.detail = false;
+            }
+            return false;
+        }
+
+        if self.help {
+            if matches!(
+                key.code,
+                KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q')
+            ) {
+                self.help = false;
+            }
+            return false;
+        }
+
+        if let Some(filename) = self.save_input.as_mut() {
+            match key.code {
+                KeyCode::Enter => {
+                    let filename = std::mem::take(filename);
+                    self.save_input = None;
+                    if !filename.trim().is_empty() {
+                        self.save_snapshot(filename.trim());
+                    }
+                }
+                KeyCode::Esc => self.save_input = None,
+                KeyCode::Backspace => {
+                    filename.pop();
+                }
+                KeyCode::Char(c) => filename.push(c),
+                _ => {}
+            }
+            return false;
+        }
+
+        if self.search_input {
+            match key.code {
+                KeyCode::Enter => self.search_input = false,
+                KeyCode::Esc => {
+                    self.search_input = false;
+                    self.search.clear();
+                }
+                KeyCode::Backspace => {
+                    self.search.pop();
+                }
+                KeyCode::Char(c) => self.search.push(c),
+                _ => return false,
+            }
+            self.reset_selection();
+            return false;
+        }
+
+        match key.code {
+            KeyCode::Char('q') => return true,
+            KeyCode::Esc => {
+                if self.search.is_empty() {
+                    return true;
+                }
+                self.search.clear();
+                self.reset_selection();
+            }
+            KeyCode::Right => self.set_tab((self.active_tab + 1) % TABS.len()),
+            KeyCode::Left => self.set_tab((self.active_tab + TABS.len() - 1) % TABS.len()),
+            KeyCode::Char(c @ '1'..='9') if ((c as u8 - b'1') as usize) < TABS.len() => {
+                self.set_tab((c as u8 - b'1') as usize)
+            }
+            KeyCode::Tab => self.cycle_focus(true),
+            KeyCode::BackTab => self.cycle_focus(false),
+            KeyCode::Up => self.scroll(-1),
+            KeyCode::Down => self.scroll(1),
+            KeyCode::PageUp => self.scroll(-PAGE_SIZE),
+            KeyCode::PageDown => self.scroll(PAGE_SIZE),
+            KeyCode::Home => self.scroll(isize::MIN),
+            KeyCode::End => self.scroll(isize::MAX),
+            KeyCode::Char('/') => self.search_input = true,
+            KeyCode::Char('?') => self.help = true,
+            KeyCode::Enter => self.open_detail(),
+            KeyCode::Char('s') => {
+                self.save_input = Some(format!("hw-report-{}.json", self.report.hostname))
+            }
+            KeyCode::Char('p') => self.paused = !self.paused,
+            KeyCode::Char('o') if self.active_tab == PROCESSES_TAB => {
+                self.process_sort = self.process_sort.next();
+                self.process_sort_desc =
+                    matches!(self.process_sort, ProcessSort::Cpu | ProcessSort::Memory);
+                self.sort_processes();
+            }
+            KeyCode::Char('r') if self.active_tab == PROCESSES_TAB => {
+                self.process_sort_desc = !self.process_sort_desc;
+                self.sort_processes();
+            }
+            KeyCode::Char('k') => self.request_signal(Signal::Term, "SIGTERM"),
+            KeyCode::Char('K') => self.request_signal(Signal::Kill, "SIGKILL"),
+            _ => {}
+        }
+        false
+    }
+
+    fn on_mouse(&mut self, mouse: MouseEvent) {
+        if self.detail || self.help {
+            if matches!(mouse.kind, MouseEventKind::Down(_)) {
+                self.detail = false;
+                self.help = false;
+            }
+            return;
+        }
+        let position = Position::new(mouse.column, mouse.row);
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(tab) = self.tab_areas.iter().position(|a| a.contains(position)) {
+                    self.set_tab(tab);
+                } else if let Some(pane) = self.pane_at(position) {
+                    self.select_row_at(pane, position.y);
+                }
+            }
+            MouseEventKind::ScrollDown if self.pane_at(position).is_some() => {
+                self.scroll(WHEEL_STEP)
+            }
+            MouseEventKind::ScrollUp if self.pane_at(position).is_some() => {
+                self.scroll(-WHEEL_STEP)
+            }
+            _ => {}
+        }
+    }
+
+    // Focuses and returns the visible pane under `position`, if any.
+    fn pane_at(&mut self, position: Position) -> Option<Pane> {
+        let (focus, pane) = Pane::for_tab(self.active_tab)
+            .iter()
+            .enumerate()
+            .find(|(_, pane)| self.pane_areas[pane.index()].contains(position))?;
+        self.focus = focus;
+        Some(*pane)
+    }
+
+    fn select_row_at(&mut self, pane: Pane, y: u16) {
+        let area = self.pane_areas[pane.index()];
+        let first_row = area.y + TABLE_BODY_OFFSET;
+        if y < first_row || y >= area.bottom().saturating_sub(1) {
+            return;
+        }
+        let state = &mut self.table_states[pane.index()];
+        let row = state.offset() + (y - first_row) as usize;
+        if row >= self.visible_rows[pane.index()].len() {
+            return;
+        }
+        // Clicking the already-selected row expands it.
+        if state.selected() == Some(row) {
+            self.open_detail();
+        } else {
+            state.select(Some(row));
         }
     }
 
@@ -54,14 +1055,45 @@ impl App {
         self.sys.refresh_cpu_all();
         self.sys.refresh_memory();
         self.networks.refresh(true);
+        if self.active_tab == PROCESSES_TAB {
+            self.refresh_processes();
+        }
+        if self.active_tab == GPU_TAB {
+            self.report.gpu_processes = get_gpu_processes();
+        }
 
         // Update CPU
-        for (i, cpu) in self.sys.cpus().iter().enumerate() {
-            if let Some(r_cpu) = self.report.cpu.get_mut(i) {
-                r_cpu.usage = cpu.cpu_usage();
-                r_cpu.frequency = cpu.frequency();
+        let cpus = self.sys.cpus();
+        for (i, core) in self.report.cores_mut().enumerate() {
+            let Some(cpu) = cpus.get(core.id) else {
+                continue;
+            };
+            core.usage = cpu.cpu_usage();
+            core.frequency = cpu.frequency();
+            if let Some(history) = self.cpu_history.get_mut(i) {
+                push_sample(history, cpu.cpu_usage() as u64);
             }
         }
+        push_sample(
+            &mut self.cpu_total_history,
+            self.sys.global_cpu_usage() as u64,
+        );
+
+        // Update load and the CPU time split since the previous refresh
+        let cpu_ticks = read_cpu_ticks();
+        let times = self
+            .cpu_ticks
+            .zip(cpu_ticks)
+            .and_then(|(before, after)| cpu_time_shares(&before, &after));
+        let load_average = read_load_average();
+        if load_average.is_some() || times.is_some() {
+            self.report.cpu_load = Some(CpuLoad {
+                load_average,
+                times,
+            });
+        }
+        self.cpu_ticks = cpu_ticks;
+        self.report.pressure = get_pressure();
 
         // Update RAM
         self.report.ram.used = self.sys.used_memory();
@@ -71,19 +1103,45 @@ impl App {
         // Update Uptime
         self.report.uptime = System::uptime();
 
-        // Update Network
+        // Update Network (rates are the delta since the previous refresh)
+        let elapsed = self.last_refresh.elapsed().as_secs_f64().max(0.001);
         for net in self.report.network.iter_mut() {
             if let Some((_, data)) = self.networks.iter().find(|(name, _)| *name == &net.name) {
                 net.received = data.total_received();
                 net.transmitted = data.total_transmitted();
+
+                let history = self.net_history.entry(net.name.clone()).or_default();
+                push_sample(&mut history.rx, (data.received() as f64 / elapsed) as u64);
+                push_sample(
+                    &mut history.tx,
+                    (data.transmitted() as f64 / elapsed) as u64,
+                );
             }
         }
 
+        // Update sensors and battery
+        self.report.temperatures = get_temperatures();
+        self.report.fans = get_fans();
+        self.report.battery = get_battery_info();
+
+        // Update Disk I/O
+        let diskstats = read_diskstats();
+        self.disk_io = disk_io_rates(&self.diskstats, &diskstats, self.last_refresh.elapsed())
+            .into_iter()
+            .map(|io| (io.name.clone(), io))
+            .collect();
+        self.diskstats = diskstats;
+
+        self.poll_hotplug();
+        if self.last_rediscovery.elapsed() >= REDISCOVER_INTERVAL {
+            self.rediscover();
+        }
+        self.check_alerts();
         self.last_refresh = std::time::Instant::now();
     }
 }
 
-pub fn run_tui(report: HardwareReport) -> Result<()> {
+pub fn run_tui(report: HardwareReport, config: Config, interval: Duration) -> Result<()> {
     // setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -92,7 +1150,8 @@ pub fn run_tui(report: HardwareReport) -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // create app and run it
-    let app = App::new(report);
+    let mut app = App::new(report, config, interval);
+    app.check_alerts();
     let res = run_app(&mut terminal, app);
 
     // restore terminal
@@ -116,38 +1175,25 @@ where
     B::Error: std::error::Error + Send + Sync + 'static,
 {
     loop {
-        terminal.draw(|f| ui(f, &app))?;
-
-        if event::poll(Duration::from_millis(250))? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
-                    KeyCode::Right | KeyCode::Tab => {
-                        app.active_tab = (app.active_tab + 1) % TABS.len()
-                    }
-                    KeyCode::Left => {
-                        if app.active_tab > 0 {
-                            app.active_tab -= 1;
-                        } else {
-                            app.active_tab = TABS.len() - 1;
-                        }
-                    }
-                    KeyCode::Char('1') => app.active_tab = 0,
-                    KeyCode::Char('2') => app.active_tab = 1,
-                    KeyCode::Char('3') => app.active_tab = 2,
-                    KeyCode::Char('4') => app.active_tab = 3,
-                    _ => {}
+        terminal.draw(|f| ui(f, &mut app))?;
+
+        if event::poll(app.interval.min(Duration::from_millis(250)))? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press && app.on_key(key) => {
+                    return Ok(());
                 }
+                Event::Mouse(mouse) => app.on_mouse(mouse),
+                _ => {}
             }
         }
 
-        if app.last_refresh.elapsed() >= Duration::from_secs(1) {
+        if !app.paused && app.last_refresh.elapsed() >= app.interval {
             app.update_metrics();
         }
     }
 }
 
-fn ui(f: &mut Frame, app: &App) {
+fn ui(f: &mut Frame, app: &mut App) {
     let size = f.area();
 
     let main_chunks = Layout::default()
@@ -156,44 +1202,68 @@ fn ui(f: &mut Frame, app: &App) {
             Constraint::Length(3), // Header
             Constraint::Length(3), // Tabs
             Constraint::Min(4),    // Content
-            Constraint::Length(5), // Footer
+            Constraint::Length(7), // Footer
         ])
         .split(size);
 
     // Header
     let header_text = format!(
-        " hwchecker v2.0 - TUI Mode | Host: {} | OS: {} {} ",
-        app.report.hostname, app.report.os_name, app.report.os_version
+        " hwchecker v2.0 - {} | {}: {} | {}: {} {} ",
+        t("TUI Mode"),
+        t("Host"),
+        app.report.hostname,
+        t("OS"),
+        app.report.os_name,
+        app.report.os_version
     );
     let header = Paragraph::new(header_text)
         .block(Block::default().borders(Borders::ALL))
         .alignment(Alignment::Center)
         .style(
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme().title.into())
                 .add_modifier(Modifier::BOLD),
         );
     f.render_widget(header, main_chunks[0]);
 
     // Tabs
-    let titles = TABS.iter().map(|t| Line::from(*t)).collect::<Vec<_>>();
+    let titles = TABS
+        .iter()
+        .enumerate()
+        .map(|(i, name)| Line::from(format!(" {}: {} ", i + 1, t(name))))
+        .collect::<Vec<_>>();
+    // Mirror the Tabs layout (1-cell padding on each side, 1-cell divider) for click targets.
+    let mut x = main_chunks[1].x + 1;
+    app.tab_areas = titles
+        .iter()
+        .map(|title| {
+            let width = title.width() as u16 + 2;
+            let area = Rect::new(x, main_chunks[1].y + 1, width, 1).intersection(main_chunks[1]);
+            x = x.saturating_add(width + 1);
+            area
+        })
+        .collect();
     let tabs = Tabs::new(titles)
         .block(Block::default().borders(Borders::ALL))
         .select(app.active_tab)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().fg(theme().muted.into()))
         .highlight_style(
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme().header.into())
                 .add_modifier(Modifier::BOLD),
         );
     f.render_widget(tabs, main_chunks[1]);
 
     // Content
+    app.pane_areas = [Rect::default(); PANE_COUNT];
     match app.active_tab {
         0 => render_overview(f, app, main_chunks[2]),
         1 => render_cpu_ram(f, app, main_chunks[2]),
         2 => render_storage_network(f, app, main_chunks[2]),
         3 => render_peripherals(f, app, main_chunks[2]),
+        4 => render_thermal(f, app, main_chunks[2]),
+        GPU_TAB => render_gpus(f, app, main_chunks[2]),
+        PROCESSES_TAB => render_processes(f, app, main_chunks[2]),
         _ => {}
     }
 
@@ -201,23 +1271,318 @@ fn ui(f: &mut Frame, app: &App) {
     let mut health_text = String::new();
     if let Some(mobo) = &app.report.motherboard {
         health_text.push_str(&format!(
-            " Motherboard: {} | Bios: {} ({})\n",
-            mobo.vendor, mobo.bios_version, mobo.bios_date
+            " {}: {} | BIOS: {} ({})\n",
+            t("Motherboard"),
+            mobo.vendor,
+            mobo.bios_version,
+            mobo.bios_date
         ));
     }
     for bat in &app.report.battery {
         health_text.push_str(&format!(
-            " Battery {}: {}% ({})\n",
-            bat.name, bat.capacity, bat.status
+            " {} {}: {}% ({})",
+            t("Battery"),
+            bat.name,
+            bat.capacity,
+            bat.status
         ));
+        if let Some(watts) = bat.power_watts.filter(|w| *w > 0.0) {
+            health_text.push_str(&format!(" {:.1} W", watts));
+        }
+        if let Some(left) = battery_time_left(bat) {
+            health_text.push_str(&format!(", {}", left));
+        }
+        health_text.push('\n');
     }
 
-    let health_block = Paragraph::new(health_text).block(
-        Block::default()
-            .title(" System Health / [Press 'q' or 'Esc' to quit, Arrows/1-4 to navigate] ")
-            .borders(Borders::ALL),
+    let status = app
+        .status
+        .as_ref()
+        .filter(|(_, at)| at.elapsed() < STATUS_TIMEOUT)
+        .map(|(message, _)| message.clone());
+    let footer_title = if let Some(filename) = &app.save_input {
+        format!(
+            " {}: {}_ ({}) ",
+            t("Save report as"),
+            filename,
+            t(".json/.yaml/.html, Enter to save, Esc to cancel")
+        )
+    } else if app.search_input {
+        format!(" {}: {}_ ", t("Search"), app.search)
+    } else if let Some(message) = status {
+        format!(" {} ", message)
+    } else if !app.search.is_empty() {
+        format!(
+            " {}: \"{}\" / [{}] ",
+            t("Filter"),
+            app.search,
+            t("Esc to clear")
+        )
+    } else {
+        format!(
+            " {} / [{}] ",
+            t("System Health"),
+            t("q: quit | ←→: tabs | Tab: focus | /: search | ?: help")
+        )
+    };
+    let refresh_title = if app.paused {
+        Line::from(format!(" {} ", t("Paused"))).style(
+            Style::default()
+                .fg(theme().warning.into())
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Line::from(format!(
+            " {}: {:.1}s ",
+            t("Refresh"),
+            app.interval.as_secs_f64()
+        ))
+    };
+    let footer_block = Block::default()
+        .title(footer_title)
+        .title(refresh_title.right_aligned())
+        .borders(Borders::ALL);
+    let footer_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(footer_block.inner(main_chunks[3]));
+    f.render_widget(footer_block, main_chunks[3]);
+    f.render_widget(Paragraph::new(health_text), footer_chunks[0]);
+    render_alert_log(f, app, footer_chunks[1]);
+
+    if app.detail {
+        render_detail_popup(f, app, size);
+    }
+    if app.pending_signal.is_some() {
+        render_signal_confirm(f, app, size);
+    }
+    if app.help {
+        render_help(f, app, size);
+    }
+}
+
+const KEYBINDINGS: [(&str, &str); 19] = [
+    ("q", "Quit"),
+    ("Esc", "Clear search, close popup, or quit"),
+    ("← → / 1-7", "Switch tab"),
+    ("Tab / Shift-Tab", "Move focus between tables"),
+    ("↑ ↓", "Move selection"),
+    ("PgUp / PgDn", "Move selection by a page"),
+    ("Home / End", "Jump to first / last row"),
+    ("Enter", "Show details for the selected device"),
+    ("/", "Search all tables"),
+    ("s", "Save the current report (JSON/YAML/HTML)"),
+    ("p", "Pause / resume live updates"),
+    ("o", "Processes: cycle sort column"),
+    ("r", "Processes: reverse sort order"),
+    ("k", "Processes: send SIGTERM"),
+    ("K", "Processes: send SIGKILL"),
+    ("?", "Toggle this help"),
+    ("Click", "Select a tab or row (click again for details)"),
+    ("Wheel", "Scroll the table under the cursor"),
+    ("y", "Confirm a pending signal"),
+];
+
+fn render_help(f: &mut Frame, app: &App, area: Rect) {
+    let key_style = Style::default().fg(theme().header.into());
+    let section_style = Style::default()
+        .fg(theme().title.into())
+        .add_modifier(Modifier::BOLD);
+    let key_width = KEYBINDINGS
+        .iter()
+        .map(|(key, _)| key.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = vec![Line::styled(
+        format!(" {}", t("Keybindings")),
+        section_style,
+    )];
+    for (key, action) in KEYBINDINGS {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<width$}  ", key, width = key_width), key_style),
+            Span::raw(t(action)),
+        ]));
+    }
+
+    let config = &app.config;
+    let thresholds = |th: &Thresholds| {
+        format!(
+            "{} {} / {} {}",
+            t("warning"),
+            th.warning,
+            t("critical"),
+            th.critical
+        )
+    };
+    let settings = [
+        (
+            t("Refresh interval"),
+            format!(
+                "{:.1}s{}",
+                app.interval.as_secs_f64(),
+                if app.paused {
+                    format!(" ({})", t("Paused"))
+                } else {
+                    String::new()
+                }
+            ),
+        ),
+        (t("CPU thresholds (%)"), thresholds(&config.cpu_thresholds)),
+        (t("RAM thresholds (%)"), thresholds(&config.ram_thresholds)),
+        (
+            t("Storage thresholds (%)"),
+            thresholds(&config.storage_thresholds),
+        ),
+        (
+            t("Inode thresholds (%)"),
+            thresholds(&config.inode_thresholds),
+        ),
+        (
+            t("Temperature thresholds (°C)"),
+            thresholds(&config.temperature_thresholds),
+        ),
+        (
+            t("Battery levels (%)"),
+            format!(
+                "{} < {} / {} < {}, {} > {}",
+                t("warning"),
+                config.battery.warning,
+                t("critical"),
+                config.battery.critical,
+                t("wear"),
+                config.battery.max_wear
+            ),
+        ),
+        (
+            t("Theme"),
+            format!("{:?}", config.theme.preset).to_lowercase(),
+        ),
+        (
+            t("Language"),
+            format!("{:?}", crate::i18n::locale()).to_lowercase(),
+        ),
+    ];
+    let setting_width = settings
+        .iter()
+        .map(|(name, _)| name.chars().count())
+        .max()
+        .unwrap_or(0);
+    lines.push(Line::raw(""));
+    lines.push(Line::styled(
+        format!(" {}", t("Configuration")),
+        section_style,
+    ));
+    for (name, value) in settings {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:<width$}  ", name, width = setting_width),
+                key_style,
+            ),
+            Span::raw(value),
+        ]));
+    }
+
+    let popup = popup_area(area, area.width * 4 / 5, lines.len() as u16 + 2);
+    let block = Block::default()
+        .title(format!(" {} ", t("Help")))
+        .title_bottom(Line::from(format!(" {} ", t("Esc: close"))).right_aligned())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().accent.into()));
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+fn render_alert_log(f: &mut Frame, app: &App, area: Rect) {
+    let mut lines = vec![Line::styled(
+        format!("{} ({})", t("Alerts"), app.alerts.len()),
+        Style::default()
+            .fg(theme().header.into())
+            .add_modifier(Modifier::BOLD),
+    )];
+    lines.extend(app.alerts.iter().map(|alert| {
+        let color = match alert.level {
+            AlertLevel::Critical => theme().critical,
+            AlertLevel::Warning => theme().warning,
+            AlertLevel::Ok => theme().ok,
+            AlertLevel::Info => theme().info,
+        };
+        Line::from(vec![
+            Span::styled(
+                format!("{} ", alert.time.format("%H:%M:%S")),
+                Style::default().fg(theme().muted.into()),
+            ),
+            Span::styled(alert.message.clone(), Style::default().fg(color.into())),
+        ])
+    }));
+    let log = Paragraph::new(lines).block(Block::default().borders(Borders::LEFT));
+    f.render_widget(log, area);
+}
+
+fn popup_area(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+fn render_signal_confirm(f: &mut Frame, app: &App, area: Rect) {
+    let Some(pending) = &app.pending_signal else {
+        return;
+    };
+    let text = format!(
+        " {} {} → {} (PID {})?\n\n {}",
+        t("Send"),
+        pending.label,
+        pending.name,
+        pending.pid,
+        t("y: confirm | any other key: cancel")
     );
-    f.render_widget(health_block, main_chunks[3]);
+    let popup = popup_area(area, area.width / 2, 5);
+    let block = Block::default()
+        .title(format!(" {} ", t("Confirm")))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().critical.into()));
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(text).block(block), popup);
+}
+
+fn render_detail_popup(f: &mut Frame, app: &mut App, area: Rect) {
+    let Some((title, fields)) = app.detail_fields() else {
+        // The selected row went away (e.g. the search changed).
+        app.detail = false;
+        return;
+    };
+    let label_width = fields
+        .iter()
+        .map(|(l, _)| l.chars().count())
+        .max()
+        .unwrap_or(0);
+    let lines: Vec<Line> = fields
+        .into_iter()
+        .map(|(label, value)| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:<width$}  ", label, width = label_width),
+                    Style::default().fg(theme().header.into()),
+                ),
+                Span::raw(value),
+            ])
+        })
+        .collect();
+
+    let popup = popup_area(area, area.width * 3 / 5, lines.len() as u16 + 2);
+    let block = Block::default()
+        .title(format!(" {} ", title))
+        .title_bottom(Line::from(format!(" {} ", t("Esc: close"))).right_aligned())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().accent.into()));
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block), popup);
 }
 
 fn render_overview(f: &mut Frame, app: &App, area: Rect) {
@@ -233,34 +1598,93 @@ fn render_overview(f: &mut Frame, app: &App, area: Rect) {
 
     // System Info Block
     let sys_text = format!(
-        "\n OS: {} {}\n Kernel: {}\n Uptime: {}s\n",
-        app.report.os_name, app.report.os_version, app.report.kernel_version, app.report.uptime
+        "\n {}: {} {}\n {}: {}\n {}: {}s\n",
+        t("OS"),
+        app.report.os_name,
+        app.report.os_version,
+        t("Kernel"),
+        app.report.kernel_version,
+        t("Uptime"),
+        app.report.uptime
     );
-    let sys_block = Paragraph::new(sys_text).block(
+    let mut sys_lines: Vec<Line> = sys_text
+        .lines()
+        .map(|l| Line::from(l.to_string()))
+        .collect();
+    if let Some(pressure) = &app.report.pressure {
+        let mut spans = vec![Span::raw(format!(" {}:", t("Pressure (10s)")))];
+        for (name, stall) in pressure.resources() {
+            let thresholds = app.config.pressure.thresholds(name);
+            spans.push(Span::styled(
+                format!(" {} {:.1}%", name, stall.some[0]),
+                Style::default().fg(theme().level(stall.some[0], thresholds).into()),
+            ));
+        }
+        sys_lines.push(Line::from(spans));
+    }
+    let sys_block = Paragraph::new(sys_lines).block(
         Block::default()
-            .title(" System Summary ")
+            .title(format!(" {} ", t("System Summary")))
             .borders(Borders::ALL),
     );
     f.render_widget(sys_block, top_chunks[0]);
 
-    // CPU Info Block (Simple)
+    // CPU Info Block (Summary + aggregate usage graph)
+    let cpu_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(5), Constraint::Min(0)])
+        .split(top_chunks[1]);
+
     let mut cpu_text = String::new();
-    if let Some(first_cpu) = app.report.cpu.first() {
-        cpu_text.push_str(&format!(" Model: {}\n", first_cpu.model));
-        cpu_text.push_str(&format!(" Physical Cores: {}\n\n", first_cpu.cores));
-    }
-    for (i, cpu) in app.report.cpu.iter().enumerate().take(8) {
+    if let Some(package) = app.report.cpu.first() {
+        cpu_text.push_str(&format!(" {}: {}\n", t("Model"), package.model));
         cpu_text.push_str(&format!(
-            " Core {}: {:>5.1}% | {} MHz\n",
-            i, cpu.usage, cpu.frequency
+            " {}: {}\n",
+            t("Physical Cores"),
+            app.report
+                .cpu
+                .iter()
+                .map(|p| p.physical_cores)
+                .sum::<usize>()
         ));
     }
-    if app.report.cpu.len() > 8 {
-        cpu_text.push_str(" ... (See CPU & RAM tab for more)\n");
-    }
-    let cpu_block =
-        Paragraph::new(cpu_text).block(Block::default().title(" CPU Info ").borders(Borders::ALL));
-    f.render_widget(cpu_block, top_chunks[1]);
+    cpu_text.push_str(&format!(
+        " {}: {}%\n",
+        t("Total Usage"),
+        app.cpu_total_history.back().copied().unwrap_or(0)
+    ));
+    let cpu_block = Paragraph::new(cpu_text).block(
+        Block::default()
+            .title(format!(" {} ", t("CPU Info")))
+            .borders(Borders::LEFT | Borders::RIGHT | Borders::TOP),
+    );
+    f.render_widget(cpu_block, cpu_chunks[0]);
+
+    let points: Vec<(f64, f64)> = app
+        .cpu_total_history
+        .iter()
+        .enumerate()
+        .map(|(i, usage)| (i as f64, *usage as f64))
+        .collect();
+    let dataset = Dataset::default()
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(theme().accent.into()))
+        .data(&points);
+    let chart = Chart::new(vec![dataset])
+        .block(Block::default().borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM))
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(theme().muted.into()))
+                .bounds([0.0, (HISTORY_LEN - 1) as f64]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(theme().muted.into()))
+                .bounds([0.0, 100.0])
+                .labels(["0%", "50%", "100%"]),
+        );
+    f.render_widget(chart, cpu_chunks[1]);
 
     let bot_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -282,11 +1706,14 @@ fn render_overview(f: &mut Frame, app: &App, area: Rect) {
         .split(bot_chunks[0]);
 
     let gauge = Gauge::default()
-        .block(Block::default().title(" RAM Usage ").borders(Borders::ALL))
+        .block(
+            Block::default()
+                .title(format!(" {} ", t("RAM Usage")))
+                .borders(Borders::ALL),
+        )
         .gauge_style(
             Style::default()
-                .fg(Color::Yellow)
-                .bg(Color::Black)
+                .fg(theme().gauge.into())
                 .add_modifier(Modifier::ITALIC),
         )
         .percent((ram_ratio * 100.0) as u16)
@@ -298,8 +1725,10 @@ fn render_overview(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(gauge, ram_chunks[0]);
 
     let ram_text = format!(
-        "\n Free: {:.1} GB\n (See CPU & RAM tab for DIMM details)\n",
-        app.report.ram.free as f64 / 1024.0 / 1024.0 / 1024.0
+        "\n {}: {:.1} GB\n {}\n",
+        t("Free"),
+        app.report.ram.free as f64 / 1024.0 / 1024.0 / 1024.0,
+        t("(See CPU & RAM tab for DIMM details)")
     );
     let ram_details = Paragraph::new(ram_text)
         .block(Block::default().borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM));
@@ -308,11 +1737,17 @@ fn render_overview(f: &mut Frame, app: &App, area: Rect) {
     // Storage Block
     let header_cells = ["Mount", "FS", "Total (GB)", "Used (%)"]
         .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+        .map(|h| Cell::from(t(h)).style(Style::default().fg(theme().header.into())));
     let header_row = Row::new(header_cells).height(1).bottom_margin(1);
 
     let mut rows = Vec::new();
-    for disk in app.report.storage.iter().take(5) {
+    for disk in app
+        .report
+        .filesystems
+        .iter()
+        .filter(|d| app.matches_search(&[&d.name, &d.mount_point, &d.filesystem]))
+        .take(5)
+    {
         let total_gb = disk.total as f64 / 1024.0 / 1024.0 / 1024.0;
         let usage = if disk.total > 0 {
             (disk.used as f64 / disk.total as f64) * 100.0
@@ -340,13 +1775,13 @@ fn render_overview(f: &mut Frame, app: &App, area: Rect) {
     .header(header_row)
     .block(
         Block::default()
-            .title(" Storage (Top 5) ")
+            .title(format!(" {} ", t("Storage (Top 5)")))
             .borders(Borders::ALL),
     );
     f.render_widget(storage_table, bot_chunks[1]);
 }
 
-fn render_cpu_ram(f: &mut Frame, app: &App, area: Rect) {
+fn render_cpu_ram(f: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
@@ -354,165 +1789,483 @@ fn render_cpu_ram(f: &mut Frame, app: &App, area: Rect) {
 
     // CPU Detail
     let mut cpu_text = String::new();
-    if let Some(first_cpu) = app.report.cpu.first() {
-        cpu_text.push_str(&format!(" Model: {}\n", first_cpu.model));
-        cpu_text.push_str(&format!(" Brand: {}\n", first_cpu.brand));
-        cpu_text.push_str(&format!(" Vendor: {}\n", first_cpu.vendor_id));
-        cpu_text.push_str(&format!(" Cores: {}\n\n", first_cpu.cores));
-
-        cpu_text.push_str(" Caches:\n");
-        cpu_text.push_str(&format!(
-            " L1: {}\n",
-            first_cpu.l1_cache.as_deref().unwrap_or("N/A")
-        ));
-        cpu_text.push_str(&format!(
-            " L2: {}\n",
-            first_cpu.l2_cache.as_deref().unwrap_or("N/A")
-        ));
-        cpu_text.push_str(&format!(
-            " L3: {}\n\n",
-            first_cpu.l3_cache.as_deref().unwrap_or("N/A")
-        ));
-    }
-    for (i, cpu) in app.report.cpu.iter().enumerate() {
+    if let Some(package) = app.report.cpu.first() {
+        cpu_text.push_str(&format!(" {}: {}\n", t("Model"), package.model));
+        cpu_text.push_str(&format!(" {}: {}\n", t("Vendor"), package.vendor));
+        cpu_text.push_str(&format!(" {}: {}\n", t("Sockets"), app.report.cpu.len()));
         cpu_text.push_str(&format!(
-            " Core {}: {:>5.1}% | {} MHz\n",
-            i, cpu.usage, cpu.frequency
+            " {}: {} / {}\n",
+            t("Cores"),
+            package.physical_cores,
+            package.logical_cores
         ));
+        for group in &package.core_groups {
+            let mhz = |value: Option<u64>| value.map_or("N/A".to_string(), |v| v.to_string());
+            cpu_text.push_str(&format!(
+                " {}: {} x {} / {} MHz\n",
+                core_kind_label(group.kind),
+                group.logical_cores,
+                mhz(group.base_mhz),
+                mhz(group.boost_mhz)
+            ));
+        }
+        if let Some(load) = &app.report.cpu_load {
+            if let Some([one, five, fifteen]) = load.load_average {
+                cpu_text.push_str(&format!(
+                    " {}: {:.2} {:.2} {:.2}\n",
+                    t("Load"),
+                    one,
+                    five,
+                    fifteen
+                ));
+            }
+            if let Some(times) = load.times {
+                cpu_text.push_str(&format!(
+                    " usr {:.1}% sys {:.1}% iowait {:.1}% steal {:.1}%\n",
+                    times.user, times.system, times.iowait, times.steal
+                ));
+            }
+        }
+        cpu_text.push('\n');
+
+        cpu_text.push_str(&format!(" {}:\n", t("Caches")));
+        for level in 1..=3 {
+            cpu_text.push_str(&format!(" L{}: {}\n", level, cache_cell(package, level)));
+        }
     }
+    let cpu_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(cpu_text.lines().count() as u16 + 2),
+            Constraint::Min(0),
+        ])
+        .split(chunks[0]);
+
     let cpu_block = Paragraph::new(cpu_text).block(
         Block::default()
-            .title(" CPU Details ")
+            .title(format!(" {} ", t("CPU Details")))
             .borders(Borders::ALL),
     );
-    f.render_widget(cpu_block, chunks[0]);
+    f.render_widget(cpu_block, cpu_chunks[0]);
+    render_core_sparklines(f, app, cpu_chunks[1]);
 
     // RAM Detail
     let mut ram_text = String::new();
     ram_text.push_str(&format!(
-        " Swap Total: {:.1} GB\n",
+        " {}: {:.1} GB\n",
+        t("Swap Total"),
         app.report.ram.swap_total as f64 / 1024.0 / 1024.0 / 1024.0
     ));
     ram_text.push_str(&format!(
-        " Swap Used:  {:.1} GB\n\n",
+        " {}: {:.1} GB\n",
+        t("Swap Used"),
         app.report.ram.swap_used as f64 / 1024.0 / 1024.0 / 1024.0
     ));
 
-    ram_text.push_str(" DIMM Details:\n");
-    for (i, stick) in app.report.ram.sticks.iter().enumerate() {
-        ram_text.push_str(&format!(" Slot {}:\n", i));
-        ram_text.push_str(&format!(
-            "   Manufacturer: {}\n",
-            stick.manufacturer.as_deref().unwrap_or("Unknown")
-        ));
-        ram_text.push_str(&format!(
-            "   Part Number:  {}\n",
-            stick.part_number.as_deref().unwrap_or("Unknown")
-        ));
-        ram_text.push_str(&format!(
-            "   Serial Num:   {}\n",
-            stick.serial_number.as_deref().unwrap_or("Unknown")
-        ));
-        ram_text.push_str(&format!(
-            "   Speed:        {} MT/s\n\n",
-            stick.speed.unwrap_or(0)
-        ));
-    }
+    let ram_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(4), Constraint::Min(0)])
+        .split(chunks[1]);
     let ram_block = Paragraph::new(ram_text).block(
         Block::default()
-            .title(" RAM & Swap Details ")
+            .title(format!(" {} ", t("RAM & Swap Details")))
             .borders(Borders::ALL),
     );
-    f.render_widget(ram_block, chunks[1]);
+    f.render_widget(ram_block, ram_chunks[0]);
+
+    // DIMM Table
+    let header = ["Slot", "Manufacturer", "Part Number", "Speed"]
+        .iter()
+        .map(|h| Cell::from(t(h)).style(Style::default().fg(theme().header.into())));
+    let header_row = Row::new(header).height(1).bottom_margin(1);
+
+    let mut rows = Vec::new();
+    let mut visible = Vec::new();
+    for (i, stick) in app.report.ram.sticks.iter().enumerate() {
+        let slot = format!("DIMM {}", i);
+        let manufacturer = stick.manufacturer.as_deref().unwrap_or(t("Unknown"));
+        let part_number = stick.part_number.as_deref().unwrap_or(t("Unknown"));
+        if !app.matches_search(&[&slot, manufacturer, part_number]) {
+            continue;
+        }
+        visible.push(i);
+        rows.push(Row::new(vec![
+            Cell::from(slot),
+            Cell::from(manufacturer.to_string()),
+            Cell::from(part_number.to_string()),
+            Cell::from(
+                stick
+                    .speed
+                    .map(|s| format!("{} MT/s", s))
+                    .unwrap_or_else(|| "N/A".to_string()),
+            ),
+        ]));
+    }
+    let shown = rows.len();
+    app.visible_rows[Pane::Dimms.index()] = visible;
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(15),
+            Constraint::Percentage(30),
+            Constraint::Percentage(35),
+            Constraint::Percentage(20),
+        ],
+    )
+    .header(header_row)
+    .block(pane_block(
+        app,
+        Pane::Dimms,
+        t("DIMM Details"),
+        shown,
+        app.report.ram.sticks.len(),
+    ))
+    .row_highlight_style(highlight_style(app, Pane::Dimms));
+    app.pane_areas[Pane::Dimms.index()] = ram_chunks[1];
+    f.render_stateful_widget(
+        table,
+        ram_chunks[1],
+        &mut app.table_states[Pane::Dimms.index()],
+    );
+}
+
+fn render_core_sparklines(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .title(format!(" {} ", t("Per-Core Usage (60s)")))
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Positions into cpu_history, performance cores ahead of efficiency cores
+    let all_cores: Vec<_> = app.report.cores().collect();
+    let mut order: Vec<usize> = (0..all_cores.len()).collect();
+    order.sort_by_key(|&i| (all_cores[i].kind, all_cores[i].id));
+    let core_count = all_cores.len();
+    if core_count == 0 || inner.height == 0 {
+        return;
+    }
+
+    // One line per core; wrap into extra columns when the pane is too short.
+    let rows = inner.height as usize;
+    let columns = core_count.div_ceil(rows);
+    let column_areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+        .split(inner);
+
+    for (column, column_area) in column_areas.iter().enumerate() {
+        let cores = (column * rows)..((column + 1) * rows).min(core_count);
+        let row_areas = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(1); cores.len()])
+            .split(*column_area);
+
+        for (row_area, i) in row_areas.iter().zip(cores.map(|row| order[row])) {
+            let cpu = all_cores[i];
+            let parts = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(14), Constraint::Min(0)])
+                .split(*row_area);
+
+            let color = Color::from(theme().level(cpu.usage, &app.config.cpu_thresholds));
+            let kind = match cpu.kind {
+                Some(CoreKind::Performance) => "P",
+                Some(CoreKind::Efficiency) => "E",
+                None => " ",
+            };
+            let label = Paragraph::new(format!("{}{:>3} {:>5.1}%", kind, cpu.id, cpu.usage))
+                .style(Style::default().fg(color));
+            f.render_widget(label, parts[0]);
+
+            let history = app.cpu_history.get(i);
+            let width = parts[1].width as usize;
+            let data: Vec<u64> = history
+                .map(|h| {
+                    h.iter()
+                        .skip(h.len().saturating_sub(width))
+                        .copied()
+                        .collect()
+                })
+                .unwrap_or_default();
+            let sparkline = Sparkline::default()
+                .data(&data)
+                .max(100)
+                .style(Style::default().fg(color));
+            f.render_widget(sparkline, parts[1]);
+        }
+    }
 }
 
-fn render_storage_network(f: &mut Frame, app: &App, area: Rect) {
+fn render_storage_network(f: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
     // Storage Table
-    let header_cells = ["Disk", "Mount", "FS", "Total", "Used", "Interface", "Model"]
-        .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+    let header_cells = [
+        "Disk",
+        "Mount",
+        "FS",
+        "Total",
+        "Used",
+        "Read/s",
+        "Write/s",
+        "IOPS",
+        "Inodes",
+        "Interface",
+        "Model",
+    ]
+    .iter()
+    .map(|h| Cell::from(t(h)).style(Style::default().fg(theme().header.into())));
     let header_row = Row::new(header_cells).height(1).bottom_margin(1);
 
     let mut rows = Vec::new();
-    for disk in &app.report.storage {
+    let mut visible = Vec::new();
+    for (i, disk) in app.report.filesystems.iter().enumerate() {
+        let drive = app.report.disk_for(disk);
+        let interface = drive.and_then(|d| d.interface.as_deref());
+        let model = drive.and_then(|d| d.model_name.as_deref());
+        if !app.matches_search(&[
+            &disk.name,
+            &disk.mount_point,
+            &disk.filesystem,
+            interface.unwrap_or_default(),
+            model.unwrap_or_default(),
+        ]) {
+            continue;
+        }
         let total_gb = disk.total as f64 / 1024.0 / 1024.0 / 1024.0;
         let usage = if disk.total > 0 {
             (disk.used as f64 / disk.total as f64) * 100.0
         } else {
             0.0
         };
+        let (read, write, iops) = app
+            .disk_io
+            .get(disk.name.trim_start_matches("/dev/"))
+            .map(|io| {
+                (
+                    format_rate(io.read_bytes_per_sec as u64),
+                    format_rate(io.write_bytes_per_sec as u64),
+                    format!("{:.0}", io.read_iops + io.write_iops),
+                )
+            })
+            .unwrap_or_else(|| ("-".to_string(), "-".to_string(), "-".to_string()));
 
-        rows.push(Row::new(vec![
-            Cell::from(disk.name.clone()),
-            Cell::from(disk.mount_point.clone()),
-            Cell::from(disk.filesystem.clone()),
-            Cell::from(format!("{:.1} GB", total_gb)),
-            Cell::from(format!("{:.1}%", usage)),
-            Cell::from(disk.interface.as_deref().unwrap_or("Unknown").to_string()),
-            Cell::from(disk.model_name.as_deref().unwrap_or("Unknown").to_string()),
-        ]));
+        visible.push(i);
+        rows.push(
+            Row::new(vec![
+                Cell::from(disk.name.clone()),
+                Cell::from(disk.mount_point.clone()),
+                Cell::from(disk.filesystem.clone()),
+                Cell::from(format!("{:.1} GB", total_gb)),
+                Cell::from(format!("{:.1}%", usage)),
+                Cell::from(read),
+                Cell::from(write),
+                Cell::from(iops),
+                match disk.inodes.and_then(|inodes| inodes.usage_percent()) {
+                    Some(usage) => Cell::from(format!("{:.1}%", usage)).style(
+                        Style::default()
+                            .fg(theme().level(usage, &app.config.inode_thresholds).into()),
+                    ),
+                    None => Cell::from("-"),
+                },
+                Cell::from(interface.unwrap_or(t("Unknown")).to_string()),
+                Cell::from(model.unwrap_or(t("Unknown")).to_string()),
+            ])
+            .style(app.row_style(&storage_key(disk))),
+        );
+    }
+    let shown = rows.len();
+    app.visible_rows[Pane::Storage.index()] = visible;
+    // Unplugged disks linger (unselectable) below the live rows for a moment.
+    for (disk, _) in &app.removed_storage {
+        rows.push(
+            Row::new(vec![
+                Cell::from(disk.name.clone()),
+                Cell::from(disk.mount_point.clone()),
+                Cell::from(disk.filesystem.clone()),
+                Cell::from(t("removed")),
+            ])
+            .style(removed_style()),
+        );
     }
     let storage_table = Table::new(
         rows,
         [
             Constraint::Percentage(10),
-            Constraint::Percentage(15),
+            Constraint::Percentage(12),
+            Constraint::Percentage(6),
+            Constraint::Percentage(8),
+            Constraint::Percentage(6),
             Constraint::Percentage(10),
             Constraint::Percentage(10),
+            Constraint::Percentage(6),
+            Constraint::Percentage(7),
             Constraint::Percentage(10),
             Constraint::Percentage(15),
-            Constraint::Percentage(30),
         ],
     )
     .header(header_row)
-    .block(
-        Block::default()
-            .title(" Storage Details ")
-            .borders(Borders::ALL),
+    .block(pane_block(
+        app,
+        Pane::Storage,
+        t("Storage Details"),
+        shown,
+        app.report.filesystems.len(),
+    ))
+    .row_highlight_style(highlight_style(app, Pane::Storage));
+    app.pane_areas[Pane::Storage.index()] = chunks[0];
+    f.render_stateful_widget(
+        storage_table,
+        chunks[0],
+        &mut app.table_states[Pane::Storage.index()],
     );
-    f.render_widget(storage_table, chunks[0]);
 
     // Network Table
-    let net_header = ["Interface", "MAC Address", "RX (MB)", "TX (MB)"]
+    let net_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(chunks[1]);
+
+    let net_header = ["Interface", "MAC Address", "RX/s", "TX/s"]
         .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+        .map(|h| Cell::from(t(h)).style(Style::default().fg(theme().header.into())));
     let net_header_row = Row::new(net_header).height(1).bottom_margin(1);
 
     let mut net_rows = Vec::new();
-    for net in &app.report.network {
-        let rx_mb = net.received as f64 / 1024.0 / 1024.0;
-        let tx_mb = net.transmitted as f64 / 1024.0 / 1024.0;
-        net_rows.push(Row::new(vec![
-            Cell::from(net.name.clone()),
-            Cell::from(net.mac_address.clone()),
-            Cell::from(format!("{:.2} MB", rx_mb)),
-            Cell::from(format!("{:.2} MB", tx_mb)),
-        ]));
+    let mut visible = Vec::new();
+    for (i, net) in app.report.network.iter().enumerate() {
+        if !app.matches_search(&[&net.name, &net.mac_address]) {
+            continue;
+        }
+        let (rx_rate, tx_rate) = app
+            .net_history
+            .get(&net.name)
+            .map(|h| {
+                (
+                    h.rx.back().copied().unwrap_or(0),
+                    h.tx.back().copied().unwrap_or(0),
+                )
+            })
+            .unwrap_or((0, 0));
+        visible.push(i);
+        net_rows.push(
+            Row::new(vec![
+                Cell::from(net.name.clone()),
+                Cell::from(net.mac_address.clone()),
+                Cell::from(format_rate(rx_rate)),
+                Cell::from(format_rate(tx_rate)),
+            ])
+            .style(app.row_style(&network_key(net))),
+        );
     }
+    let shown = net_rows.len();
+    app.visible_rows[Pane::Network.index()] = visible;
     let network_table = Table::new(
         net_rows,
         [
             Constraint::Percentage(25),
-            Constraint::Percentage(25),
-            Constraint::Percentage(25),
-            Constraint::Percentage(25),
+            Constraint::Percentage(35),
+            Constraint::Percentage(20),
+            Constraint::Percentage(20),
         ],
     )
     .header(net_header_row)
-    .block(
-        Block::default()
-            .title(" Network Interfaces ")
-            .borders(Borders::ALL),
+    .block(pane_block(
+        app,
+        Pane::Network,
+        t("Network Interfaces"),
+        shown,
+        app.report.network.len(),
+    ))
+    .row_highlight_style(highlight_style(app, Pane::Network));
+    app.pane_areas[Pane::Network.index()] = net_chunks[0];
+    f.render_stateful_widget(
+        network_table,
+        net_chunks[0],
+        &mut app.table_states[Pane::Network.index()],
     );
-    f.render_widget(network_table, chunks[1]);
+    render_net_sparklines(f, app, net_chunks[1]);
+}
+
+fn render_net_sparklines(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .title(format!(" {} ", t("Throughput (60s)")))
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Two lines (RX, TX) per interface, as many interfaces as fit.
+    let visible = (inner.height as usize / 2).min(app.report.network.len());
+    if visible == 0 {
+        return;
+    }
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(1); visible * 2])
+        .split(inner);
+
+    for (i, net) in app.report.network.iter().take(visible).enumerate() {
+        let Some(history) = app.net_history.get(&net.name) else {
+            continue;
+        };
+        // RX and TX share a scale so their relative magnitude is visible.
+        let max = history
+            .rx
+            .iter()
+            .chain(history.tx.iter())
+            .copied()
+            .max()
+            .unwrap_or(0)
+            .max(1);
+
+        for (line, (arrow, samples, color)) in [
+            ("↓", &history.rx, Color::from(theme().ok)),
+            ("↑", &history.tx, Color::from(theme().info)),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let parts = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(14), Constraint::Min(0)])
+                .split(row_areas[i * 2 + line]);
+
+            let label = Paragraph::new(format!(" {:.10} {}", net.name, arrow))
+                .style(Style::default().fg(color));
+            f.render_widget(label, parts[0]);
+
+            let width = parts[1].width as usize;
+            let data: Vec<u64> = samples
+                .iter()
+                .skip(samples.len().saturating_sub(width))
+                .copied()
+                .collect();
+            let sparkline = Sparkline::default()
+                .data(&data)
+                .max(max)
+                .style(Style::default().fg(color));
+            f.render_widget(sparkline, parts[1]);
+        }
+    }
+}
+
+fn format_rate(bytes_per_sec: u64) -> String {
+    let rate = bytes_per_sec as f64;
+    if rate >= 1024.0 * 1024.0 * 1024.0 {
+        format!("{:.2} GiB/s", rate / 1024.0 / 1024.0 / 1024.0)
+    } else if rate >= 1024.0 * 1024.0 {
+        format!("{:.2} MiB/s", rate / 1024.0 / 1024.0)
+    } else if rate >= 1024.0 {
+        format!("{:.1} KiB/s", rate / 1024.0)
+    } else {
+        format!("{} B/s", bytes_per_sec)
+    }
 }
 
-fn render_peripherals(f: &mut Frame, app: &App, area: Rect) {
+fn render_peripherals(f: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
@@ -521,11 +2274,12 @@ fn render_peripherals(f: &mut Frame, app: &App, area: Rect) {
     // PCI Table
     let pci_header = ["Slot", "Vendor", "Device"]
         .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+        .map(|h| Cell::from(t(h)).style(Style::default().fg(theme().header.into())));
     let pci_header_row = Row::new(pci_header).height(1).bottom_margin(1);
 
     let mut pci_rows = Vec::new();
-    for pci in &app.report.pci {
+    let mut visible = Vec::new();
+    for (i, pci) in app.report.pci.iter().enumerate() {
         let vendor = pci
             .vendor_name
             .clone()
@@ -534,12 +2288,18 @@ fn render_peripherals(f: &mut Frame, app: &App, area: Rect) {
             .device_name
             .clone()
             .unwrap_or_else(|| format!("0x{:04x}", pci.device_id));
+        if !app.matches_search(&[&pci.slot, &vendor, &device]) {
+            continue;
+        }
+        visible.push(i);
         pci_rows.push(Row::new(vec![
             Cell::from(pci.slot.clone()),
             Cell::from(vendor),
             Cell::from(device),
         ]));
     }
+    let shown = pci_rows.len();
+    app.visible_rows[Pane::Pci.index()] = visible;
     let pci_table = Table::new(
         pci_rows,
         [
@@ -549,21 +2309,30 @@ fn render_peripherals(f: &mut Frame, app: &App, area: Rect) {
         ],
     )
     .header(pci_header_row)
-    .block(
-        Block::default()
-            .title(" PCI Devices ")
-            .borders(Borders::ALL),
+    .block(pane_block(
+        app,
+        Pane::Pci,
+        t("PCI Devices"),
+        shown,
+        app.report.pci.len(),
+    ))
+    .row_highlight_style(highlight_style(app, Pane::Pci));
+    app.pane_areas[Pane::Pci.index()] = chunks[0];
+    f.render_stateful_widget(
+        pci_table,
+        chunks[0],
+        &mut app.table_states[Pane::Pci.index()],
     );
-    f.render_widget(pci_table, chunks[0]);
 
     // USB Table
     let usb_header = ["Bus:Addr", "Vendor", "Product"]
         .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+        .map(|h| Cell::from(t(h)).style(Style::default().fg(theme().header.into())));
     let usb_header_row = Row::new(usb_header).height(1).bottom_margin(1);
 
     let mut usb_rows = Vec::new();
-    for usb in &app.report.usb {
+    let mut visible = Vec::new();
+    for (i, usb) in app.report.usb.iter().enumerate() {
         let bus_addr = format!("{:03}:{:03}", usb.bus, usb.address);
         let vendor = usb
             .manufacturer
@@ -573,11 +2342,30 @@ fn render_peripherals(f: &mut Frame, app: &App, area: Rect) {
             .product
             .clone()
             .unwrap_or_else(|| format!("0x{:04x}", usb.product_id));
-        usb_rows.push(Row::new(vec![
-            Cell::from(bus_addr),
-            Cell::from(vendor),
-            Cell::from(product),
-        ]));
+        if !app.matches_search(&[&bus_addr, &vendor, &product]) {
+            continue;
+        }
+        visible.push(i);
+        usb_rows.push(
+            Row::new(vec![
+                Cell::from(bus_addr),
+                Cell::from(vendor),
+                Cell::from(product),
+            ])
+            .style(app.row_style(&usb_key(usb))),
+        );
+    }
+    let shown = usb_rows.len();
+    app.visible_rows[Pane::Usb.index()] = visible;
+    // Unplugged devices linger (unselectable) below the live rows for a moment.
+    for (usb, _) in &app.removed_usb {
+        usb_rows.push(
+            Row::new(vec![
+                Cell::from(format!("{:03}:{:03}", usb.bus, usb.address)),
+                Cell::from(format!("{} ({})", usb_label(usb), t("removed"))),
+            ])
+            .style(removed_style()),
+        );
     }
     let usb_table = Table::new(
         usb_rows,
@@ -588,10 +2376,353 @@ fn render_peripherals(f: &mut Frame, app: &App, area: Rect) {
         ],
     )
     .header(usb_header_row)
+    .block(pane_block(
+        app,
+        Pane::Usb,
+        t("USB Devices"),
+        shown,
+        app.report.usb.len(),
+    ))
+    .row_highlight_style(highlight_style(app, Pane::Usb));
+    app.pane_areas[Pane::Usb.index()] = chunks[1];
+    f.render_stateful_widget(
+        usb_table,
+        chunks[1],
+        &mut app.table_states[Pane::Usb.index()],
+    );
+}
+
+fn render_thermal(f: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(area);
+
+    // Temperature Table
+    let header = ["Sensor", "Temperature", "Max", "Critical"]
+        .iter()
+        .map(|h| Cell::from(t(h)).style(Style::default().fg(theme().header.into())));
+    let header_row = Row::new(header).height(1).bottom_margin(1);
+
+    let thresholds = &app.config.temperature_thresholds;
+    let format_temp = |v: Option<f32>| {
+        v.map(|c| format!("{:.1} °C", c))
+            .unwrap_or_else(|| "N/A".to_string())
+    };
+    let mut rows = Vec::new();
+    let mut visible = Vec::new();
+    for (i, sensor) in app.report.temperatures.iter().enumerate() {
+        if !app.matches_search(&[&sensor.label]) {
+            continue;
+        }
+        let color = Color::from(match sensor.temperature {
+            Some(c) => theme().level(c, thresholds),
+            None => theme().muted,
+        });
+        visible.push(i);
+        rows.push(Row::new(vec![
+            Cell::from(sensor.label.clone()),
+            Cell::from(format_temp(sensor.temperature)).style(Style::default().fg(color)),
+            Cell::from(format_temp(sensor.max)),
+            Cell::from(format_temp(sensor.critical)),
+        ]));
+    }
+    let shown = rows.len();
+    app.visible_rows[Pane::Sensors.index()] = visible;
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(46),
+            Constraint::Percentage(18),
+            Constraint::Percentage(18),
+            Constraint::Percentage(18),
+        ],
+    )
+    .header(header_row)
+    .block(pane_block(
+        app,
+        Pane::Sensors,
+        t("Temperatures"),
+        shown,
+        app.report.temperatures.len(),
+    ))
+    .row_highlight_style(highlight_style(app, Pane::Sensors));
+    app.pane_areas[Pane::Sensors.index()] = chunks[0];
+    f.render_stateful_widget(
+        table,
+        chunks[0],
+        &mut app.table_states[Pane::Sensors.index()],
+    );
+
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+
+    // Fans
+    let fan_header = ["Fan", "RPM"]
+        .iter()
+        .map(|h| Cell::from(t(h)).style(Style::default().fg(theme().header.into())));
+    let fan_rows: Vec<Row> = app
+        .report
+        .fans
+        .iter()
+        .map(|fan| {
+            let color = Color::from(if fan.rpm == 0 {
+                theme().muted
+            } else {
+                theme().ok
+            });
+            Row::new(vec![
+                Cell::from(fan.label.clone()),
+                Cell::from(fan.rpm.to_string()).style(Style::default().fg(color)),
+            ])
+        })
+        .collect();
+    let fan_table = Table::new(
+        fan_rows,
+        [Constraint::Percentage(70), Constraint::Percentage(30)],
+    )
+    .header(Row::new(fan_header).height(1).bottom_margin(1))
     .block(
         Block::default()
-            .title(" USB Devices ")
+            .title(format!(" {} ", t("Fans")))
             .borders(Borders::ALL),
     );
-    f.render_widget(usb_table, chunks[1]);
+    f.render_widget(fan_table, right[0]);
+
+    // Battery
+    let mut lines = Vec::new();
+    for bat in &app.report.battery {
+        let color = Color::from(theme().alert(alerts::AlertLevel::classify_charge(
+            bat.capacity,
+            &app.config.battery,
+        )));
+        lines.push(Line::styled(
+            format!(" {}: {}% ({})", bat.name, bat.capacity, bat.status),
+            Style::default().fg(color),
+        ));
+        if let Some(wear) = bat.wear_percent {
+            let color = Color::from(
+                theme().alert(alerts::AlertLevel::classify_wear(wear, &app.config.battery)),
+            );
+            lines.push(Line::styled(
+                format!(" {}: {:.1}%", t("Wear"), wear),
+                Style::default().fg(color),
+            ));
+        }
+        let rate = match bat.power_watts {
+            Some(w) if bat.status == "Discharging" => {
+                format!(" {}: {:.2} W", t("Discharge rate"), w)
+            }
+            Some(w) if bat.status == "Charging" => format!(" {}: {:.2} W", t("Charge rate"), w),
+            Some(w) => format!(" {}: {:.2} W", t("Power"), w),
+            None => format!(" {}: N/A", t("Power")),
+        };
+        lines.push(Line::from(rate));
+        if let Some(left) = battery_time_left(bat) {
+            lines.push(Line::from(format!(" {}: {}", t("Time Left"), left)));
+        }
+    }
+    if lines.is_empty() {
+        lines.push(Line::from(format!(" {}", t("No battery detected"))));
+    }
+    let battery_block = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" {} ", t("Battery")))
+            .borders(Borders::ALL),
+    );
+    f.render_widget(battery_block, right[1]);
+}
+
+fn render_gpus(f: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(app.report.gpus.len().max(1) as u16 + 2),
+            Constraint::Min(0),
+        ])
+        .split(area);
+    let mib =
+        |bytes: Option<u64>| bytes.map_or("N/A".to_string(), |b| (b / 1024 / 1024).to_string());
+
+    let mut lines: Vec<Line> = app
+        .report
+        .gpus
+        .iter()
+        .map(|gpu| {
+            Line::from(format!(
+                " {}  {}  {}: {}  {}: {}",
+                gpu.slot,
+                gpu.name,
+                t("Driver"),
+                gpu.driver.as_deref().unwrap_or("N/A"),
+                t("VRAM (MiB)"),
+                mib(gpu.vram_total),
+            ))
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from(format!(" {}", t("No GPU detected"))));
+    }
+    let gpu_block = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" {} ", t("GPUs")))
+            .borders(Borders::ALL),
+    );
+    f.render_widget(gpu_block, chunks[0]);
+
+    let header = ["PID", "Name", "Slot", "VRAM (MiB)"]
+        .iter()
+        .map(|h| Cell::from(t(h)).style(Style::default().fg(theme().header.into())));
+    let header_row = Row::new(header).height(1).bottom_margin(1);
+
+    // Biggest VRAM consumers first, like the CPU process list sorted by usage
+    let mut order: Vec<usize> = (0..app.report.gpu_processes.len()).collect();
+    order.sort_by(|&a, &b| {
+        let (a, b) = (&app.report.gpu_processes[a], &app.report.gpu_processes[b]);
+        b.vram_used.cmp(&a.vram_used).then(a.pid.cmp(&b.pid))
+    });
+    let mut rows = Vec::new();
+    let mut visible = Vec::new();
+    for i in order {
+        let process = &app.report.gpu_processes[i];
+        let pid = process.pid.to_string();
+        if !app.matches_search(&[&pid, &process.name, &process.slot]) {
+            continue;
+        }
+        visible.push(i);
+        rows.push(Row::new(vec![
+            Cell::from(pid),
+            Cell::from(process.name.clone()),
+            Cell::from(process.slot.clone()),
+            Cell::from(mib(process.vram_used)),
+        ]));
+    }
+    let shown = rows.len();
+    app.visible_rows[Pane::Gpus.index()] = visible;
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Min(10),
+            Constraint::Length(14),
+            Constraint::Length(12),
+        ],
+    )
+    .header(header_row)
+    .block(pane_block(
+        app,
+        Pane::Gpus,
+        t("GPU Processes"),
+        shown,
+        app.report.gpu_processes.len(),
+    ))
+    .row_highlight_style(highlight_style(app, Pane::Gpus));
+    app.pane_areas[Pane::Gpus.index()] = chunks[1];
+    f.render_stateful_widget(table, chunks[1], &mut app.table_states[Pane::Gpus.index()]);
+}
+
+fn render_processes(f: &mut Frame, app: &mut App, area: Rect) {
+    let columns = [
+        (ProcessSort::Pid, "PID"),
+        (ProcessSort::User, "User"),
+        (ProcessSort::Cpu, "CPU (%)"),
+        (ProcessSort::Memory, "RSS (MiB)"),
+        (ProcessSort::Name, "Name"),
+    ];
+    let arrow = if app.process_sort_desc { "▼" } else { "▲" };
+    let header = columns.iter().map(|(sort, title)| {
+        let title = if *sort == app.process_sort {
+            format!("{} {}", t(title), arrow)
+        } else {
+            t(title).to_string()
+        };
+        Cell::from(title).style(Style::default().fg(theme().header.into()))
+    });
+    let header_row = Row::new(header).height(1).bottom_margin(1);
+
+    let thresholds = &app.config.cpu_thresholds;
+    let mut rows = Vec::new();
+    let mut visible = Vec::new();
+    for (i, process) in app.processes.iter().enumerate() {
+        let pid = process.pid.to_string();
+        if !app.matches_search(&[&pid, &process.user, &process.name]) {
+            continue;
+        }
+        visible.push(i);
+        rows.push(Row::new(vec![
+            Cell::from(pid),
+            Cell::from(process.user.clone()),
+            Cell::from(format!("{:.1}", process.cpu))
+                .style(Style::default().fg(theme().level(process.cpu, thresholds).into())),
+            Cell::from(format!("{:.1}", process.rss as f64 / 1024.0 / 1024.0)),
+            Cell::from(process.name.clone()),
+        ]));
+    }
+    let shown = rows.len();
+    app.visible_rows[Pane::Processes.index()] = visible;
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Length(14),
+            Constraint::Length(10),
+            Constraint::Length(12),
+            Constraint::Min(10),
+        ],
+    )
+    .header(header_row)
+    .block(
+        pane_block(
+            app,
+            Pane::Processes,
+            t("Processes"),
+            shown,
+            app.processes.len(),
+        )
+        .title_bottom(
+            Line::from(format!(
+                " {} ",
+                t("o: sort column | r: reverse | k: SIGTERM | K: SIGKILL")
+            ))
+            .right_aligned(),
+        ),
+    )
+    .row_highlight_style(highlight_style(app, Pane::Processes));
+    app.pane_areas[Pane::Processes.index()] = area;
+    f.render_stateful_widget(table, area, &mut app.table_states[Pane::Processes.index()]);
+}
+
+fn pane_block(app: &App, pane: Pane, title: &str, shown: usize, total: usize) -> Block<'static> {
+    let focused = app.focused_pane() == Some(pane);
+    let marker = if focused { "▶ " } else { "" };
+    let title = if app.search.is_empty() {
+        format!(" {}{} ", marker, title)
+    } else {
+        format!(" {}{} ({}/{}) ", marker, title, shown, total)
+    };
+    let block = Block::default().title(title).borders(Borders::ALL);
+    if focused {
+        block.border_style(Style::default().fg(theme().accent.into()))
+    } else {
+        block
+    }
+}
+
+fn removed_style() -> Style {
+    Style::default()
+        .fg(theme().critical.into())
+        .add_modifier(Modifier::CROSSED_OUT)
+}
+
+fn highlight_style(app: &App, pane: Pane) -> Style {
+    if app.focused_pane() == Some(pane) {
+        Style::default()
+            .fg(theme().selection.into())
+            .bg(theme().accent.into())
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    }
 }
@@ -0,0 +1,231 @@
+use crate::cli::{OutputFormat, ValidateArgs, ValidateCommand};
+use crate::i18n::t;
+use crate::theme::theme;
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::{Cell, Color, Table};
+use rusb::{Device, Speed, UsbContext};
+use serde::Serialize;
+use std::path::Path;
+
+const MASS_STORAGE_CLASS: u8 = 0x08;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UsbSpeedStatus {
+    Ok,
+    /// The port (or a hub in between) cannot go faster than the link runs.
+    LimitedByPort,
+    /// Both ends support a faster mode than was negotiated: usually a USB2
+    /// cable, a worn connector or a device plugged in too slowly.
+    Mismatch,
+    Unknown,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UsbSpeedCheck {
+    pub bus: u8,
+    pub port: String,
+    pub vendor_id: u16,
+    pub product_id: u16,
+    pub name: String,
+    pub device_max_mbps: Option<u32>,
+    pub port_max_mbps: Option<u32>,
+    pub negotiated_mbps: Option<u32>,
+    pub status: UsbSpeedStatus,
+}
+
+fn speed_mbps(speed: Speed) -> Option<u32> {
+    match speed {
+        Speed::Low => Some(1),
+        Speed::Full => Some(12),
+        Speed::High => Some(480),
+        Speed::Super => Some(5000),
+        Speed::SuperPlus => Some(10000),
+        _ => None,
+    }
+}
+
+fn format_mbps(mbps: Option<u32>) -> String {
+    match mbps {
+        Some(1) => "1.5 Mbps".to_string(),
+        Some(m) if m >= 1000 => format!("{} Gbps", m / 1000),
+        Some(m) => format!("{} Mbps", m),
+        None => t("Unknown").to_string(),
+    }
+}
+
+// Fastest mode the device claims in bcdUSB. USB 3.1+ devices may do 10 Gbps,
+// but only the BOS descriptor says so; 5 Gbps is the safe floor.
+fn device_max_mbps(bcd_major: u8, bcd_minor: u8) -> u32 {
+    match (bcd_major, bcd_minor) {
+        (3.., _) => 5000,
+        (2, _) => 480,
+        (1, 1..) => 12,
+        _ => 1,
+    }
+}
+
+fn is_mass_storage<T: UsbContext>(device: &Device<T>) -> bool {
+    let Ok(config) = device
+        .active_config_descriptor()
+        .or_else(|_| device.config_descriptor(0))
+    else {
+        return false;
+    };
+    config.interfaces().any(|interface| {
+        interface
+            .descriptors()
+            .any(|d| d.class_code() == MASS_STORAGE_CLASS)
+    })
+}
+
+// On Linux a USB3 port is a pair of a USB2 and a SuperSpeed port, linked by
+// `port/peer` in sysfs. A device seen on the USB2 half of such a pair sits in
+// a port that could have run it at SuperSpeed.
+fn has_superspeed_peer(bus: u8, ports: &[u8]) -> bool {
+    let path: Vec<String> = ports.iter().map(u8::to_string).collect();
+    Path::new(&format!(
+        "/sys/bus/usb/devices/{}-{}/port/peer",
+        bus,
+        path.join(".")
+    ))
+    .exists()
+}
+
+fn check_device<T: UsbContext>(device: &Device<T>) -> Option<UsbSpeedCheck> {
+    let desc = device.device_descriptor().ok()?;
+    if !is_mass_storage(device) {
+        return None;
+    }
+    let ports = device.port_numbers().unwrap_or_default();
+    let version = desc.usb_version();
+    let device_max = device_max_mbps(version.major(), version.minor());
+    let negotiated = speed_mbps(device.speed());
+
+    // A port runs no faster than the hub it belongs to, unless it has a SuperSpeed twin.
+    let hub_speed = device.get_parent().and_then(|hub| speed_mbps(hub.speed()));
+    let port_max = if has_superspeed_peer(device.bus_number(), &ports) {
+        Some(hub_speed.unwrap_or(0).max(5000))
+    } else {
+        hub_speed
+    };
+
+    let status = match (negotiated, port_max) {
+        (Some(link), Some(port)) if link >= device_max.min(port) => UsbSpeedStatus::Ok,
+        (Some(link), Some(port)) if link >= port => UsbSpeedStatus::LimitedByPort,
+        (Some(_), Some(_)) => UsbSpeedStatus::Mismatch,
+        (Some(link), None) if link >= device_max => UsbSpeedStatus::Ok,
+        _ => UsbSpeedStatus::Unknown,
+    };
+
+    let name = device
+        .open()
+        .ok()
+        .and_then(|h| {
+            let manufacturer = h.read_manufacturer_string_ascii(&desc).ok();
+            let product = h.read_product_string_ascii(&desc).ok()?;
+            Some(match manufacturer {
+                Some(m) => format!("{} {}", m.trim(), product.trim()),
+                None => product.trim().to_string(),
+            })
+        })
+        .unwrap_or_else(|| format!("{:04x}:{:04x}", desc.vendor_id(), desc.product_id()));
+
+    Some(UsbSpeedCheck {
+        bus: device.bus_number(),
+        port: ports
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join("."),
+        vendor_id: desc.vendor_id(),
+        product_id: desc.product_id(),
+        name,
+        device_max_mbps: Some(device_max),
+        port_max_mbps: port_max,
+        negotiated_mbps: negotiated,
+        status,
+    })
+}
+
+pub fn check_usb_speeds() -> Result<Vec<UsbSpeedCheck>> {
+    let context = rusb::Context::new().context("failed to initialize libusb")?;
+    let devices = context.devices().context("failed to list USB devices")?;
+    Ok(devices.iter().filter_map(|d| check_device(&d)).collect())
+}
+
+fn status_cell(status: UsbSpeedStatus) -> Cell {
+    match status {
+        UsbSpeedStatus::Ok => Cell::new(t("OK")).fg(Color::from(theme().ok)),
+        UsbSpeedStatus::LimitedByPort => {
+            Cell::new(t("Limited by port")).fg(Color::from(theme().warning))
+        }
+        UsbSpeedStatus::Mismatch => Cell::new(t("MISMATCH")).fg(Color::from(theme().critical)),
+        UsbSpeedStatus::Unknown => Cell::new(t("Unknown")),
+    }
+}
+
+pub fn run_validate(args: &ValidateArgs, format: OutputFormat) -> Result<()> {
+    let checks = match args.command {
+        ValidateCommand::Usb => check_usb_speeds()?,
+    };
+
+    match format {
+        OutputFormat::Table => {
+            if checks.is_empty() {
+                println!("{}", t("No USB mass-storage devices found"));
+                return Ok(());
+            }
+            let mut table = Table::new();
+            table
+                .load_preset(UTF8_FULL)
+                .apply_modifier(UTF8_ROUND_CORNERS)
+                .set_header(vec![
+                    t("Device"),
+                    t("Port"),
+                    t("Device max"),
+                    t("Port max"),
+                    t("Negotiated"),
+                    t("Result"),
+                ]);
+            for check in &checks {
+                table.add_row(vec![
+                    Cell::new(&check.name),
+                    Cell::new(format!("{}-{}", check.bus, check.port)),
+                    Cell::new(format_mbps(check.device_max_mbps)),
+                    Cell::new(format_mbps(check.port_max_mbps)),
+                    Cell::new(format_mbps(check.negotiated_mbps)),
+                    status_cell(check.status),
+                ]);
+            }
+            println!("{table}");
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&checks)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&checks)?),
+        _ => bail!("validate supports the table, json and yaml formats"),
+    }
+
+    let mismatches = checks
+        .iter()
+        .filter(|c| c.status == UsbSpeedStatus::Mismatch)
+        .count();
+    if mismatches > 0 {
+        if format == OutputFormat::Table {
+            println!(
+                "{}",
+                format!(
+                    "{} {}",
+                    mismatches,
+                    t("device(s) slower than both the port and device support; check the cable and connector")
+                )
+                .color(theme().critical)
+                .bold()
+            );
+        }
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use crate::cli::{OutputFormat, VerifyArgs};
+use crate::discovery::{get_cpu_flags, get_filesystems, get_gpus};
+use crate::i18n::t;
+use crate::theme::theme;
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use comfy_table::modifiers::UTF8_ROUND_CORNERS;
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::{Cell, Color, Table};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, System};
+
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// A `requirements.yaml` spec. Every rule is optional; unknown keys are
+/// rejected so a typo cannot silently disable a rule.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Requirements {
+    /// Physical cores
+    #[serde(default)]
+    pub min_cores: Option<usize>,
+    /// Logical CPUs
+    #[serde(default)]
+    pub min_threads: Option<usize>,
+    #[serde(default)]
+    pub min_ram_gib: Option<f64>,
+    /// Flags as named in /proc/cpuinfo, e.g. `avx2`, `sse4_2`, `aes`
+    #[serde(default)]
+    pub cpu_flags: Vec<String>,
+    #[serde(default)]
+    pub disks: Vec<DiskRequirement>,
+    /// Met if any one GPU has this much VRAM
+    #[serde(default)]
+    pub min_gpu_vram_gib: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct DiskRequirement {
+    /// A mount point, or any directory on the file system to check
+    pub path: PathBuf,
+    pub min_free_gib: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuleResult {
+    pub rule: String,
+    pub required: String,
+    pub found: String,
+    pub passed: bool,
+}
+
+impl Requirements {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        serde_yaml::from_str(&text).with_context(|| format!("invalid {}", path.display()))
+    }
+
+    /// Checks every rule against this machine.
+    pub fn evaluate(&self) -> Vec<RuleResult> {
+        let mut results = Vec::new();
+        let gib = |bytes: u64| format!("{:.1} GiB", bytes as f64 / GIB);
+
+        if self.min_cores.is_some() || self.min_threads.is_some() || self.min_ram_gib.is_some() {
+            let sys = System::new_with_specifics(
+                RefreshKind::nothing()
+                    .with_cpu(CpuRefreshKind::nothing())
+                    .with_memory(MemoryRefreshKind::nothing().with_ram()),
+            );
+            let threads = sys.cpus().len();
+            if let Some(min) = self.min_cores {
+                let cores = System::physical_core_count().unwrap_or(threads);
+                results.push(RuleResult {
+                    rule: t("CPU cores").to_string(),
+                    required: format!(">= {}", min),
+                    found: cores.to_string(),
+                    passed: cores >= min,
+                });
+            }
+            if let Some(min) = self.min_threads {
+                results.push(RuleResult {
+                    rule: t("CPU threads").to_string(),
+                    required: format!(">= {}", min),
+                    found: threads.to_string(),
+                    passed: threads >= min,
+                });
+            }
+            if let Some(min) = self.min_ram_gib {
+                let total = sys.total_memory();
+                results.push(RuleResult {
+                    rule: t("RAM").to_string(),
+                    required: format!(">= {} GiB", min),
+                    found: gib(total),
+                    passed: total as f64 >= min * GIB,
+                });
+            }
+        }
+
+        if !self.cpu_flags.is_empty() {
+            let flags = get_cpu_flags();
+            for flag in &self.cpu_flags {
+                let present = flags.iter().any(|f| f.eq_ignore_ascii_case(flag));
+                results.push(RuleResult {
+                    rule: format!("{} {}", t("CPU flag"), flag),
+                    required: t("present").to_string(),
+                    found: if present { t("present") } else { t("missing") }.to_string(),
+                    passed: present,
+                });
+            }
+        }
+
+        if !self.disks.is_empty() {
+            let filesystems = get_filesystems();
+            for disk in &self.disks {
+                let path = std::fs::canonicalize(&disk.path).unwrap_or_else(|_| disk.path.clone());
+                // The file system holding `path` is the one with the longest matching mount point.
+                let mount = filesystems
+                    .iter()
+                    .filter(|s| path.starts_with(&s.mount_point))
+                    .max_by_key(|s| s.mount_point.len());
+                results.push(RuleResult {
+                    rule: format!("{} {}", t("Free space on"), disk.path.display()),
+                    required: format!(">= {} GiB", disk.min_free_gib),
+                    found: match mount {
+                        Some(m) if Path::new(&m.mount_point) != path => {
+                            format!("{} ({})", gib(m.free), m.mount_point)
+                        }
+                        Some(m) => gib(m.free),
+                        None => t("not mounted").to_string(),
+                    },
+                    passed: mount.is_some_and(|m| m.free as f64 >= disk.min_free_gib * GIB),
+                });
+            }
+        }
+
+        if let Some(min) = self.min_gpu_vram_gib {
+            let best = get_gpus()
+                .into_iter()
+                .filter(|g| g.vram_total.is_some())
+                .max_by_key(|g| g.vram_total);
+            results.push(RuleResult {
+                rule: t("GPU VRAM").to_string(),
+                required: format!(">= {} GiB", min),
+                found: match &best {
+                    Some(gpu) => format!("{} ({})", gib(gpu.vram_total.unwrap_or(0)), gpu.name),
+                    None => t("no GPU with known VRAM").to_string(),
+                },
+                passed: best.is_some_and(|g| g.vram_total.unwrap_or(0) as f64 >= min * GIB),
+            });
+        }
+
+        results
+    }
+}
+
+pub fn run_verify(args: &VerifyArgs, format: OutputFormat) -> Result<()> {
+    let requirements = Requirements::load(&args.spec)?;
+    let results = requirements.evaluate();
+    if results.is_empty() {
+        bail!("{} has no rules", args.spec.display());
+    }
+
+    match format {
+        OutputFormat::Table => {
+            let mut table = Table::new();
+            table
+                .load_preset(UTF8_FULL)
+                .apply_modifier(UTF8_ROUND_CORNERS)
+                .set_header(vec![t("Rule"), t("Required"), t("Found"), t("Result")]);
+            for result in &results {
+                let status = if result.passed {
+                    Cell::new(t("PASS")).fg(Color::from(theme().ok))
+                } else {
+                    Cell::new(t("FAIL")).fg(Color::from(theme().critical))
+                };
+                table.add_row(vec![
+                    Cell::new(&result.rule),
+                    Cell::new(&result.required),
+                    Cell::new(&result.found),
+                    status,
+                ]);
+            }
+            println!("{table}");
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&results)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&results)?),
+        _ => bail!("verify supports the table, json and yaml formats"),
+    }
+
+    let failed = results.iter().filter(|r| !r.passed).count();
+    if failed > 0 {
+        if format == OutputFormat::Table {
+            println!(
+                "{}",
+                format!(
+                    "{} / {} {}",
+                    failed,
+                    results.len(),
+                    t("requirements not met")
+                )
+                .color(theme().critical)
+                .bold()
+            );
+        }
+        std::process::exit(1);
+    }
+    if format == OutputFormat::Table {
+        println!("{}", t("All requirements met").color(theme().ok).bold());
+    }
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use crate::alerts::Alerter;
+use crate::cli::{StreamFormat, WatchArgs};
+use crate::config::Config;
+use crate::discovery::MetricsSampler;
+use crate::i18n::t;
+use crate::model::MetricsSample;
+use crate::redact::redact_sample;
+use crate::theme::theme;
+use anyhow::Result;
+use colored::Colorize;
+use std::time::Duration;
+
+pub fn run_watch(args: &WatchArgs, config: &Config) -> Result<()> {
+    let interval = args.interval.max(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    let mut sampler = MetricsSampler::new();
+    let mut printer = SamplePrinter::new(args.format, config);
+    let mut alerter = Alerter::new(&args.alert_target, config)?;
+    loop {
+        std::thread::sleep(interval);
+        let sample = sampler.sample();
+        alerter.check(&sample);
+        printer.print(&sample)?;
+    }
+}
+
+/// Prints one line per sample: a compact table row, or the whole sample as a JSON object.
+pub struct SamplePrinter<'a> {
+    format: StreamFormat,
+    config: &'a Config,
+    prev: Option<MetricsSample>,
+}
+
+impl<'a> SamplePrinter<'a> {
+    pub fn new(format: StreamFormat, config: &'a Config) -> Self {
+        if format == StreamFormat::Table {
+            println!(
+                "{}",
+                format!(
+                    "{:<8}  {:>6}  {:>6}  {:>6}  {:>7}  {:>11}  {:>11}",
+                    t("TIME"),
+                    "CPU",
+                    "RAM",
+                    t("SWAP"),
+                    t("TEMP"),
+                    "RX",
+                    "TX"
+                )
+                .bold()
+                .color(theme().header)
+            );
+        }
+        Self {
+            format,
+            config,
+            prev: None,
+        }
+    }
+
+    pub fn print(&mut self, sample: &MetricsSample) -> Result<()> {
+        match self.format {
+            StreamFormat::Jsonl => {
+                let mut sample = sample.clone();
+                redact_sample(&mut sample, &self.config.redact);
+                println!("{}", serde_json::to_string(&sample)?)
+            }
+            StreamFormat::Table => self.print_row(sample),
+        }
+        self.prev = Some(sample.clone());
+        Ok(())
+    }
+
+    fn print_row(&self, sample: &MetricsSample) {
+        let time = sample.timestamp.get(11..19).unwrap_or(&sample.timestamp);
+        let percent = |used: u64, total: u64| {
+            if total == 0 {
+                0.0
+            } else {
+                used as f32 / total as f32 * 100.0
+            }
+        };
+        let ram = percent(sample.ram_used, sample.ram_total);
+        let swap = percent(sample.swap_used, sample.swap_total);
+        let hottest = sample
+            .temperatures
+            .iter()
+            .map(|s| s.celsius)
+            .fold(None, |acc: Option<f32>, c| {
+                Some(acc.map_or(c, |a| a.max(c)))
+            });
+        let (rx, tx) = self.network_rates(sample);
+
+        let config = self.config;
+        let cell = |text: String, value: f32, thresholds| {
+            text.color(theme().level(value, thresholds)).to_string()
+        };
+        println!(
+            "{:<8}  {}  {}  {}  {}  {:>11}  {:>11}",
+            time,
+            cell(
+                format!("{:>5.1}%", sample.cpu_usage),
+                sample.cpu_usage,
+                &config.cpu_thresholds
+            ),
+            cell(format!("{:>5.1}%", ram), ram, &config.ram_thresholds),
+            cell(format!("{:>5.1}%", swap), swap, &config.ram_thresholds),
+            match hottest {
+                Some(c) => cell(format!("{:>5.1}°C", c), c, &config.temperature_thresholds),
+                None => format!("{:>7}", "N/A").color(theme().muted).to_string(),
+            },
+            rx.map(format_rate).unwrap_or_else(|| "-".to_string()),
+            tx.map(format_rate).unwrap_or_else(|| "-".to_string()),
+        );
+    }
+
+    // Total RX/TX bytes per second across all interfaces except loopback, since the previous sample.
+    fn network_rates(&self, sample: &MetricsSample) -> (Option<f64>, Option<f64>) {
+        let Some(prev) = &self.prev else {
+            return (None, None);
+        };
+        let elapsed = match (
+            chrono::DateTime::parse_from_rfc3339(&prev.timestamp),
+            chrono::DateTime::parse_from_rfc3339(&sample.timestamp),
+        ) {
+            (Ok(a), Ok(b)) => (b - a).to_std().unwrap_or_default(),
+            _ => Duration::ZERO,
+        };
+        if elapsed.is_zero() {
+            return (None, None);
+        }
+        let (mut rx, mut tx) = (0u64, 0u64);
+        for net in sample.network.iter().filter(|n| n.name != "lo") {
+            if let Some(before) = prev.network.iter().find(|n| n.name == net.name) {
+                rx += net.received.saturating_sub(before.received);
+                tx += net.transmitted.saturating_sub(before.transmitted);
+            }
+        }
+        let secs = elapsed.as_secs_f64();
+        (Some(rx as f64 / secs), Some(tx as f64 / secs))
+    }
+}
+
+fn format_rate(bytes_per_sec: f64) -> String {
+    crate::history::format_value(bytes_per_sec, "B/s")
+}
@@ -0,0 +1,137 @@
+use crate::alerts::{AlertLevel, AlertSink, ThresholdEvent, WebhookConfig, WebhookFormat};
+use crate::model::MetricsSample;
+use anyhow::{Result, bail};
+use serde_json::{Value, json};
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+// Embed/attachment accent, matching the default critical color.
+const CRITICAL_COLOR: u32 = 0xd00000;
+
+/// Posts critical threshold crossings to every configured webhook.
+pub struct WebhookSink {
+    agent: ureq::Agent,
+    hooks: Vec<WebhookConfig>,
+}
+
+impl WebhookSink {
+    pub fn new(hooks: &[WebhookConfig]) -> Result<Self> {
+        if hooks.is_empty() {
+            bail!("the webhook alert target needs at least one entry under `alerts.webhooks`");
+        }
+        let agent = ureq::Agent::new_with_config(
+            ureq::Agent::config_builder()
+                .timeout_global(Some(TIMEOUT))
+                .build(),
+        );
+        Ok(Self {
+            agent,
+            hooks: hooks.to_vec(),
+        })
+    }
+}
+
+impl AlertSink for WebhookSink {
+    fn send(&mut self, events: &[ThresholdEvent], _sample: &MetricsSample) -> Result<()> {
+        let mut posts = Vec::new();
+        for event in events.iter().filter(|e| e.level == AlertLevel::Critical) {
+            for (i, hook) in self.hooks.iter().enumerate() {
+                posts.push((i, hook.url.clone(), payload(hook.format, event).to_string()));
+            }
+        }
+        let failed = self.deliver(posts);
+        if !failed.is_empty() {
+            bail!(failed.join("; "));
+        }
+        Ok(())
+    }
+}
+
+impl WebhookSink {
+    /// Posts each `(hook index, url, body)` in turn; returns the failures.
+    #[cfg(not(feature = "async"))]
+    fn deliver(&self, posts: Vec<(usize, String, String)>) -> Vec<String> {
+        posts
+            .into_iter()
+            .filter_map(|(i, url, body)| post(&self.agent, i, &url, body).err())
+            .collect()
+    }
+
+    /// Posts concurrently on the shared runtime, so one slow hook does not
+    /// hold up the others.
+    #[cfg(feature = "async")]
+    fn deliver(&self, posts: Vec<(usize, String, String)>) -> Vec<String> {
+        crate::runtime::runtime().block_on(async {
+            let mut tasks = tokio::task::JoinSet::new();
+            for (i, url, body) in posts {
+                let agent = self.agent.clone();
+                tasks.spawn_blocking(move || post(&agent, i, &url, body));
+            }
+            let mut failed = Vec::new();
+            while let Some(result) = tasks.join_next().await {
+                match result {
+                    Ok(Ok(())) => {}
+                    Ok(Err(err)) => failed.push(err),
+                    Err(err) => failed.push(format!("webhook: {}", err)),
+                }
+            }
+            failed.sort();
+            failed
+        })
+    }
+}
+
+fn post(agent: &ureq::Agent, index: usize, url: &str, body: String) -> Result<(), String> {
+    agent
+        .post(url)
+        .header("Content-Type", "application/json")
+        .send(body)
+        .map(drop)
+        // Webhook URLs usually embed a secret token, so refer to them by position.
+        .map_err(|err| format!("webhook #{}: {}", index + 1, err))
+}
+
+fn payload(format: WebhookFormat, event: &ThresholdEvent) -> Value {
+    let value = format!("{:.1}{}", event.value, event.unit);
+    let threshold = event
+        .threshold
+        .map(|t| format!("{}{}", t, event.unit))
+        .unwrap_or_default();
+    let title = format!("[{}] {}", event.hostname, event.message());
+    match format {
+        WebhookFormat::Json => {
+            let mut body = serde_json::to_value(event).unwrap_or_default();
+            body["message"] = Value::String(event.message());
+            body
+        }
+        WebhookFormat::Slack => json!({
+            "text": format!(":rotating_light: {}", title),
+            "attachments": [{
+                "color": format!("#{:06x}", CRITICAL_COLOR),
+                "fields": [
+                    { "title": "Host", "value": event.hostname, "short": true },
+                    { "title": "Metric", "value": event.metric, "short": true },
+                    { "title": "Value", "value": value, "short": true },
+                    { "title": "Threshold", "value": threshold, "short": true },
+                ],
+                "ts": chrono::DateTime::parse_from_rfc3339(&event.timestamp)
+                    .map(|t| t.timestamp())
+                    .unwrap_or_default(),
+            }],
+        }),
+        WebhookFormat::Discord => json!({
+            "content": format!(":rotating_light: {}", title),
+            "embeds": [{
+                "title": event.message(),
+                "color": CRITICAL_COLOR,
+                "timestamp": event.timestamp,
+                "fields": [
+                    { "name": "Host", "value": event.hostname, "inline": true },
+                    { "name": "Metric", "value": event.metric, "inline": true },
+                    { "name": "Value", "value": value, "inline": true },
+                    { "name": "Threshold", "value": threshold, "inline": true },
+                ],
+            }],
+        }),
+    }
+}
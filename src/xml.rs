@@ -0,0 +1,138 @@
+//! `--format xml` for asset databases that only ingest XML.
+//!
+//! The document mirrors the JSON report field for field, so its schema is
+//! exactly as stable as the JSON one:
+//!
+//! - the root is `<hardware_report schema="1">`
+//! - every object key becomes an element of the same name, in alphabetical
+//!   order; keys that are not valid XML names (e.g. free-form `--meta` keys)
+//!   become `<entry key="...">`
+//! - arrays become their field's element wrapping one `<item>` per entry
+//! - `null` fields are left out, like the JSON serializer's skipped ones
+
+use crate::model::HardwareReport;
+use anyhow::Result;
+use serde_json::Value;
+use std::fmt::Write as _;
+
+/// Bumped whenever an element is renamed or moved, not when one is added.
+const SCHEMA_VERSION: u32 = 1;
+
+pub fn render_xml(report: &HardwareReport) -> Result<String> {
+    let value = serde_json::to_value(report)?;
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = write!(out, "<hardware_report schema=\"{}\">", SCHEMA_VERSION);
+    if let Value::Object(fields) = &value {
+        for (key, value) in fields {
+            element(&mut out, key, value, 1);
+        }
+    }
+    out.push_str("\n</hardware_report>\n");
+    Ok(out)
+}
+
+fn element(out: &mut String, key: &str, value: &Value, depth: usize) {
+    if value.is_null() {
+        return;
+    }
+    let indent = "  ".repeat(depth);
+    let (open, close) = if is_xml_name(key) {
+        (key.to_string(), key)
+    } else {
+        (format!("entry key=\"{}\"", escape(key)), "entry")
+    };
+    match value {
+        Value::Object(fields) if fields.is_empty() => {
+            let _ = write!(out, "\n{}<{}/>", indent, open);
+        }
+        Value::Array(items) if items.is_empty() => {
+            let _ = write!(out, "\n{}<{}/>", indent, open);
+        }
+        Value::Object(fields) => {
+            let _ = write!(out, "\n{}<{}>", indent, open);
+            for (key, value) in fields {
+                element(out, key, value, depth + 1);
+            }
+            let _ = write!(out, "\n{}</{}>", indent, close);
+        }
+        Value::Array(items) => {
+            let _ = write!(out, "\n{}<{}>", indent, open);
+            for item in items {
+                element(out, "item", item, depth + 1);
+            }
+            let _ = write!(out, "\n{}</{}>", indent, close);
+        }
+        Value::String(s) => {
+            let _ = write!(out, "\n{}<{}>{}</{}>", indent, open, escape(s), close);
+        }
+        scalar => {
+            let _ = write!(out, "\n{}<{}>{}</{}>", indent, open, scalar, close);
+        }
+    }
+}
+
+/// Letters, digits, `_`, `-` and `.`, not starting with a digit, `-`, `.` or
+/// the reserved `xml` prefix.
+fn is_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    (first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        && !name.to_ascii_lowercase().starts_with("xml")
+}
+
+fn escape(s: &str) -> String {
+    s.chars()
+        .filter(|&c| !c.is_control() || matches!(c, '\t' | '\n' | '\r'))
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn render(value: Value) -> String {
+        let mut out = String::new();
+        if let Value::Object(fields) = &value {
+            for (key, value) in fields {
+                element(&mut out, key, value, 0);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn objects_arrays_and_nulls() {
+        let xml = render(json!({
+            "hostname": "db-1",
+            "serial": null,
+            "disks": [{"name": "sda", "size": 512}],
+            "battery": [],
+            "custom": {"cost center": "4100"}
+        }));
+        assert_eq!(
+            xml,
+            "\n<battery/>\n<custom>\n  <entry key=\"cost center\">4100</entry>\n</custom>\
+             \n<disks>\n  <item>\n    <name>sda</name>\n    <size>512</size>\n  </item>\n</disks>\
+             \n<hostname>db-1</hostname>"
+        );
+    }
+
+    #[test]
+    fn text_is_escaped() {
+        assert_eq!(
+            escape("R&D <lab> \"4\"\u{1}"),
+            "R&amp;D &lt;lab&gt; &quot;4&quot;"
+        );
+        assert!(is_xml_name("os_version"));
+        assert!(!is_xml_name("2nd"));
+        assert!(!is_xml_name("xmlns"));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::formatter::custom_rows;
+use crate::model::HardwareReport;
+use serde_json::{Value, json};
+use std::fmt::Write as _;
+
+// Item key parameters need quoting when they contain anything beyond plain words.
+fn key_param(param: &str) -> String {
+    if param.is_empty() || param.contains([',', '[', ']', '"', ' ']) {
+        format!("\"{}\"", param.replace('"', "\\\""))
+    } else {
+        param.to_string()
+    }
+}
+
+fn percent(used: u64, total: u64) -> String {
+    if total == 0 {
+        "0".to_string()
+    } else {
+        format!("{:.2}", used as f64 / total as f64 * 100.0)
+    }
+}
+
+/// Renders the report as `key=value` lines using Zabbix item key syntax
+/// (`hw.fs.pused[/]=42.10`), so a UserParameter can pick single values out of it.
+/// The bracketed parameters match the `{#NAME}` macros of [`render_lld`].
+pub fn render_kv(report: &HardwareReport) -> String {
+    let mut items: Vec<(String, String)> = vec![
+        ("hw.hostname".to_string(), report.hostname.clone()),
+        ("hw.os".to_string(), report.os_name.clone()),
+        ("hw.os.version".to_string(), report.os_version.clone()),
+        ("hw.kernel".to_string(), report.kernel_version.clone()),
+        ("hw.uptime".to_string(), report.uptime.to_string()),
+    ];
+
+    for core in report.cores() {
+        items.push((
+            format!("hw.cpu.usage[{}]", core.id),
+            format!("{:.2}", core.usage),
+        ));
+        items.push((
+            format!("hw.cpu.freq[{}]", core.id),
+            core.frequency.to_string(),
+        ));
+    }
+
+    let ram = &report.ram;
+    items.extend([
+        ("hw.memory.total".to_string(), ram.total.to_string()),
+        ("hw.memory.used".to_string(), ram.used.to_string()),
+        ("hw.memory.free".to_string(), ram.free.to_string()),
+        ("hw.memory.pused".to_string(), percent(ram.used, ram.total)),
+        ("hw.swap.total".to_string(), ram.swap_total.to_string()),
+        ("hw.swap.used".to_string(), ram.swap_used.to_string()),
+        (
+            "hw.swap.pused".to_string(),
+            percent(ram.swap_used, ram.swap_total),
+        ),
+    ]);
+
+    for fs in &report.filesystems {
+        let mount = key_param(&fs.mount_point);
+        items.push((format!("hw.fs.total[{}]", mount), fs.total.to_string()));
+        items.push((format!("hw.fs.used[{}]", mount), fs.used.to_string()));
+        items.push((format!("hw.fs.free[{}]", mount), fs.free.to_string()));
+        items.push((
+            format!("hw.fs.pused[{}]", mount),
+            percent(fs.used, fs.total),
+        ));
+    }
+
+    for disk in &report.disks {
+        let name = key_param(&disk.name);
+        items.push((format!("hw.disk.size[{}]", name), disk.size.to_string()));
+        if let Some(smart) = &disk.smart {
+            items.push((
+                format!("hw.disk.smart[{}]", name),
+                u8::from(smart.passed).to_string(),
+            ));
+        }
+    }
+
+    for net in &report.network {
+        let name = key_param(&net.name);
+        items.push((format!("hw.net.in[{}]", name), net.received.to_string()));
+        items.push((format!("hw.net.out[{}]", name), net.transmitted.to_string()));
+    }
+
+    for sensor in &report.temperatures {
+        if let Some(celsius) = sensor.temperature {
+            items.push((
+                format!("hw.temp[{}]", key_param(&sensor.label)),
+                format!("{:.1}", celsius),
+            ));
+        }
+    }
+
+    for fan in &report.fans {
+        items.push((
+            format!("hw.fan[{}]", key_param(&fan.label)),
+            fan.rpm.to_string(),
+        ));
+    }
+
+    for battery in &report.battery {
+        let name = key_param(&battery.name);
+        items.push((
+            format!("hw.battery.capacity[{}]", name),
+            battery.capacity.to_string(),
+        ));
+        items.push((
+            format!("hw.battery.status[{}]", name),
+            battery.status.clone(),
+        ));
+    }
+
+    for row in custom_rows(&report.custom) {
+        let key = if row[1].is_empty() {
+            format!("hw.custom[{}]", key_param(&row[0]))
+        } else {
+            format!("hw.custom[{},{}]", key_param(&row[0]), key_param(&row[1]))
+        };
+        items.push((key, row[2].clone()));
+    }
+
+    let mut out = String::new();
+    for (key, value) in items {
+        // Values are single-line by contract.
+        let _ = writeln!(out, "{}={}", key, value.replace(['\n', '\r'], " "));
+    }
+    out.truncate(out.trim_end().len());
+    out
+}
+
+/// Renders a Zabbix low-level discovery array. Every entry carries `{#TYPE}`
+/// (cpu, fs, net, temp, fan, battery) so one discovery rule per type can filter on it.
+pub fn render_lld(report: &HardwareReport) -> String {
+    let mut entries: Vec<Value> = Vec::new();
+    for package in &report.cpu {
+        for core in &package.cores {
+            entries.push(json!({
+                "{#TYPE}": "cpu",
+                "{#NAME}": core.id.to_string(),
+                "{#MODEL}": package.model.trim(),
+            }));
+        }
+    }
+    for fs in &report.filesystems {
+        entries.push(json!({
+            "{#TYPE}": "fs",
+            "{#NAME}": fs.mount_point,
+            "{#DEVICE}": fs.name,
+            "{#FSTYPE}": fs.filesystem,
+        }));
+    }
+    for disk in &report.disks {
+        entries.push(json!({
+            "{#TYPE}": "disk",
+            "{#NAME}": disk.name,
+            "{#MODEL}": disk.model_name.as_deref().unwrap_or_default(),
+        }));
+    }
+    for net in &report.network {
+        entries.push(json!({
+            "{#TYPE}": "net",
+            "{#NAME}": net.name,
+            "{#MAC}": net.mac_address,
+        }));
+    }
+    for sensor in report
+        .temperatures
+        .iter()
+        .filter(|s| s.temperature.is_some())
+    {
+        let mut entry = json!({ "{#TYPE}": "temp", "{#NAME}": sensor.label });
+        if let Some(critical) = sensor.critical {
+            entry["{#CRIT}"] = json!(critical);
+        }
+        entries.push(entry);
+    }
+    for fan in &report.fans {
+        entries.push(json!({ "{#TYPE}": "fan", "{#NAME}": fan.label }));
+    }
+    for battery in &report.battery {
+        entries.push(json!({ "{#TYPE}": "battery", "{#NAME}": battery.name }));
+    }
+    Value::Array(entries).to_string()
+}
@@ -0,0 +1,117 @@
+//! `LinuxProbe` against the checked-in tree in `tests/fixtures/sysfs`: a
+//! hybrid laptop with an NVMe SSD, a SATA disk, a loop device and a battery.
+#![cfg(target_os = "linux")]
+
+use hw_checker::discovery::{LinuxProbe, Probe};
+use hw_checker::model::{CacheKind, CoreKind};
+use hw_checker::sysfs::SysfsRoot;
+use std::path::Path;
+
+fn probe() -> LinuxProbe {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sysfs");
+    LinuxProbe::with_root(SysfsRoot::open(&dir).unwrap())
+}
+
+#[test]
+fn cpus() {
+    let packages = probe().cpus();
+    assert_eq!(packages.len(), 1);
+    let cpu = &packages[0];
+    assert_eq!(cpu.model, "12th Gen Intel(R) Core(TM) i5-1240P");
+    assert_eq!(cpu.vendor, "GenuineIntel");
+    assert_eq!((cpu.physical_cores, cpu.logical_cores), (4, 4));
+    assert_eq!(cpu.cores[0].frequency, 3400);
+    assert_eq!(cpu.cores[0].kind, Some(CoreKind::Performance));
+    assert_eq!(cpu.cores[3].kind, Some(CoreKind::Efficiency));
+
+    let groups: Vec<_> = cpu
+        .core_groups
+        .iter()
+        .map(|g| (g.kind, g.logical_cores, g.base_mhz, g.boost_mhz))
+        .collect();
+    assert_eq!(
+        groups,
+        vec![
+            (CoreKind::Performance, 2, Some(1700), Some(4400)),
+            (CoreKind::Efficiency, 2, Some(1200), Some(3300)),
+        ]
+    );
+
+    let caches: Vec<_> = cpu
+        .caches
+        .iter()
+        .map(|c| (c.level, c.kind, c.size_kb, c.shared_by))
+        .collect();
+    assert_eq!(
+        caches,
+        vec![
+            (1, CacheKind::Data, 48, 2),
+            (1, CacheKind::Instruction, 32, 2),
+            (2, CacheKind::Unified, 1280, 2),
+            (3, CacheKind::Unified, 12 * 1024, 4),
+        ]
+    );
+}
+
+#[test]
+fn memory() {
+    let ram = probe().memory();
+    assert_eq!(ram.total, 16106920 * 1024);
+    assert_eq!(ram.used, (16106920 - 10485760) * 1024);
+    assert_eq!(ram.free, 6123456 * 1024);
+    assert_eq!(ram.swap_total, 8388604 * 1024);
+    assert_eq!(ram.swap_used, (8388604 - 8126460) * 1024);
+    // No DMI table in the tree
+    assert!(ram.sticks.is_empty());
+    assert!(ram.virtualized.is_none());
+
+    let settings = ram.settings.unwrap();
+    assert_eq!(settings.hugepage_size, 2048 * 1024);
+    assert_eq!(settings.transparent_hugepages.as_deref(), Some("madvise"));
+    assert_eq!(settings.overcommit_memory, Some(0));
+    assert_eq!(settings.committed, Some(12345678 * 1024));
+}
+
+#[test]
+fn disks() {
+    let disks = probe().disks();
+    let names: Vec<_> = disks.iter().map(|d| d.name.as_str()).collect();
+    // loop0 has no device behind it
+    assert_eq!(names, ["nvme0n1", "sda"]);
+
+    let nvme = &disks[0];
+    assert_eq!(nvme.size, 1000215216 * 512);
+    assert_eq!(nvme.disk_type.as_deref(), Some("SSD"));
+    assert_eq!(
+        nvme.model_name.as_deref(),
+        Some("SAMSUNG MZVL2512HCJQ-00B00")
+    );
+    assert_eq!(nvme.serial_number.as_deref(), Some("S675NX0T123456"));
+    assert_eq!(nvme.interface.as_deref(), Some("NVMe"));
+    assert_eq!(nvme.link_speed.as_deref(), Some("PCIe 16.0 GT/s x4"));
+
+    let sata = &disks[1];
+    assert_eq!(sata.disk_type.as_deref(), Some("HDD"));
+    assert_eq!(sata.rotational, Some(true));
+    assert_eq!(sata.vendor.as_deref(), Some("ATA"));
+    assert_eq!(sata.model_name.as_deref(), Some("ST2000LM015-2E81"));
+    assert_eq!(sata.interface.as_deref(), Some("SATA"));
+    assert_eq!(sata.link_speed.as_deref(), Some("6.0 Gb/s"));
+}
+
+#[test]
+fn batteries() {
+    // AC is not a battery
+    let batteries = probe().batteries();
+    assert_eq!(batteries.len(), 1);
+    let battery = &batteries[0];
+    assert_eq!(battery.name, "BAT0");
+    assert_eq!(battery.status, "Discharging");
+    assert_eq!(battery.capacity, 64);
+    assert_eq!(battery.power_watts, Some(8.0));
+    assert_eq!(battery.cycle_count, Some(412));
+    let wear = battery.wear_percent.unwrap();
+    assert!((wear - 12.28).abs() < 0.01, "wear {}", wear);
+    // 32 Wh left at 8 W
+    assert_eq!(battery.time_remaining, Some(4 * 3600));
+}